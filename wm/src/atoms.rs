@@ -0,0 +1,40 @@
+use xcb::connection::Connection;
+use xcb::result::Error;
+
+pub struct Atoms {
+    pub net_wm_name: u32,
+    pub net_active_window: u32,
+    pub net_number_of_desktops: u32,
+    pub net_current_desktop: u32,
+    pub net_desktop_names: u32,
+    pub net_wm_desktop: u32,
+    pub net_wm_state: u32,
+    pub net_wm_state_sticky: u32,
+    pub utf8_string: u32,
+}
+
+impl Atoms {
+    pub fn new(connection: &Connection) -> Result<Self, Error> {
+        let net_wm_name = connection.intern_atom("_NET_WM_NAME");
+        let net_active_window = connection.intern_atom("_NET_ACTIVE_WINDOW");
+        let net_number_of_desktops = connection.intern_atom("_NET_NUMBER_OF_DESKTOPS");
+        let net_current_desktop = connection.intern_atom("_NET_CURRENT_DESKTOP");
+        let net_desktop_names = connection.intern_atom("_NET_DESKTOP_NAMES");
+        let net_wm_desktop = connection.intern_atom("_NET_WM_DESKTOP");
+        let net_wm_state = connection.intern_atom("_NET_WM_STATE");
+        let net_wm_state_sticky = connection.intern_atom("_NET_WM_STATE_STICKY");
+        let utf8_string = connection.intern_atom("UTF8_STRING");
+
+        Ok(Self {
+            net_wm_name: net_wm_name.get_result()?,
+            net_active_window: net_active_window.get_result()?,
+            net_number_of_desktops: net_number_of_desktops.get_result()?,
+            net_current_desktop: net_current_desktop.get_result()?,
+            net_desktop_names: net_desktop_names.get_result()?,
+            net_wm_desktop: net_wm_desktop.get_result()?,
+            net_wm_state: net_wm_state.get_result()?,
+            net_wm_state_sticky: net_wm_state_sticky.get_result()?,
+            utf8_string: utf8_string.get_result()?,
+        })
+    }
+}
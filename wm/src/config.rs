@@ -0,0 +1,121 @@
+use crate::focus::FocusPolicy;
+use std::path::PathBuf;
+use xcb::event::ModMask;
+
+const DEFAULT_WORKSPACES: &[&str] = &["1", "2", "3", "4"];
+const DEFAULT_TITLE_FONTS: &[&str] = &[
+    "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/truetype/noto/NotoEmoji-Regular.ttf",
+];
+
+#[derive(Clone)]
+pub struct Config {
+    // Held down to move windows with the left button and resize them with the right one
+    // from anywhere inside the window.
+    pub modifier: ModMask,
+    pub focus_policy: FocusPolicy,
+    // Switched to with the modifier and the workspace's number, windows are moved there with
+    // Shift added.
+    pub workspaces: Vec<String>,
+    // The layout workspaces start with, windows float when there is none.
+    pub layout: Option<String>,
+    pub title_fonts: Vec<PathBuf>,
+    // Where the container trees of the tree layout are kept across restarts.
+    pub tree_file: Option<PathBuf>,
+    // Manages every screen of the display rather than only the default one.
+    pub all_screens: bool,
+}
+
+impl Config {
+    pub fn from_env() -> Self {
+        let modifier = match std::env::var("WM_MODIFIER") {
+            Ok(name) => parse_modifier(&name).unwrap_or_else(|| {
+                eprintln!("Unknown modifier {:?}, using super", name);
+
+                ModMask::MOD_4
+            }),
+            Err(_) => ModMask::MOD_4,
+        };
+
+        let focus_policy = match std::env::var("WM_FOCUS") {
+            Ok(name) => parse_focus_policy(&name).unwrap_or_else(|| {
+                eprintln!("Unknown focus policy {:?}, using click", name);
+
+                FocusPolicy::Click
+            }),
+            Err(_) => FocusPolicy::Click,
+        };
+
+        let workspaces = match std::env::var("WM_WORKSPACES") {
+            Ok(names) => names
+                .split(',')
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+                .collect(),
+            Err(_) => vec![],
+        };
+        let workspaces = if workspaces.is_empty() {
+            DEFAULT_WORKSPACES
+                .iter()
+                .map(|name| name.to_string())
+                .collect()
+        } else {
+            workspaces
+        };
+
+        let layout = match std::env::var("WM_LAYOUT") {
+            Ok(name) if name == "floating" => None,
+            Ok(name) if crate::layout::from_name(&name).is_some() => Some(name),
+            Ok(name) => {
+                eprintln!("Unknown layout {:?}, windows will float", name);
+
+                None
+            }
+            Err(_) => None,
+        };
+
+        let title_fonts = match std::env::var("WM_TITLE_FONTS") {
+            Ok(paths) => std::env::split_paths(&paths).collect(),
+            Err(_) => DEFAULT_TITLE_FONTS.iter().map(PathBuf::from).collect(),
+        };
+
+        let tree_file = std::env::var_os("WM_TREE_FILE").map(PathBuf::from);
+        let all_screens = matches!(
+            std::env::var("WM_ALL_SCREENS").as_deref(),
+            Ok("1") | Ok("true") | Ok("yes")
+        );
+
+        Self {
+            modifier,
+            focus_policy,
+            workspaces,
+            layout,
+            title_fonts,
+            tree_file,
+            all_screens,
+        }
+    }
+}
+
+fn parse_modifier(name: &str) -> Option<ModMask> {
+    match name.to_lowercase().as_str() {
+        "shift" => Some(ModMask::SHIFT),
+        "control" | "ctrl" => Some(ModMask::CONTROL),
+        "mod1" | "alt" => Some(ModMask::MOD_1),
+        "mod2" => Some(ModMask::MOD_2),
+        "mod3" => Some(ModMask::MOD_3),
+        "mod4" | "super" => Some(ModMask::MOD_4),
+        "mod5" => Some(ModMask::MOD_5),
+        _ => None,
+    }
+}
+
+fn parse_focus_policy(name: &str) -> Option<FocusPolicy> {
+    match name.to_lowercase().as_str() {
+        "click" => Some(FocusPolicy::Click),
+        "mouse" | "follows-mouse" => Some(FocusPolicy::FollowsMouse),
+        "sloppy" => Some(FocusPolicy::Sloppy),
+        _ => None,
+    }
+}
@@ -0,0 +1,55 @@
+use crate::resize::Edge;
+use xcb::connection::Connection;
+use xcb::cursor::{CursorGlyph, OwnedCursor};
+use xcb::result::Error;
+
+pub struct Cursors<'a> {
+    pub normal: OwnedCursor<'a>,
+    pub moving: OwnedCursor<'a>,
+    edges: Vec<(Edge, OwnedCursor<'a>)>,
+}
+
+impl<'a> Cursors<'a> {
+    pub fn new(connection: &'a Connection) -> Result<Self, Error> {
+        let mut edges = vec![];
+
+        for edge in Edge::ALL.iter() {
+            let (name, glyph) = match edge {
+                Edge::Top => ("top_side", CursorGlyph::TopSide),
+                Edge::Bottom => ("bottom_side", CursorGlyph::BottomSide),
+                Edge::Left => ("left_side", CursorGlyph::LeftSide),
+                Edge::Right => ("right_side", CursorGlyph::RightSide),
+                Edge::TopLeft => ("top_left_corner", CursorGlyph::TopLeftCorner),
+                Edge::TopRight => ("top_right_corner", CursorGlyph::TopRightCorner),
+                Edge::BottomLeft => ("bottom_left_corner", CursorGlyph::BottomLeftCorner),
+                Edge::BottomRight => ("bottom_right_corner", CursorGlyph::BottomRightCorner),
+            };
+
+            edges.push((*edge, load(connection, name, glyph)?));
+        }
+
+        Ok(Self {
+            normal: load(connection, "left_ptr", CursorGlyph::LeftPtr)?,
+            moving: load(connection, "fleur", CursorGlyph::Fleur)?,
+            edges,
+        })
+    }
+
+    pub fn edge(&self, edge: Edge) -> &OwnedCursor<'a> {
+        self.edges
+            .iter()
+            .find(|(cursor_edge, _)| *cursor_edge == edge)
+            .map(|(_, cursor)| cursor)
+            .expect("A cursor is created for every edge")
+    }
+}
+
+// Themed cursors need XRender and an installed theme, the cursor font is always there.
+fn load<'a>(
+    connection: &'a Connection,
+    name: &str,
+    glyph: CursorGlyph,
+) -> Result<OwnedCursor<'a>, Error> {
+    OwnedCursor::from_theme(connection, name)
+        .or_else(|_| OwnedCursor::from_glyph(connection, glyph))
+}
@@ -0,0 +1,193 @@
+use crate::frame::{client_size, frame_rectangle};
+use crate::hints::SizeHints;
+use crate::resize::{Edge, SizeOverlay};
+use crate::text::TextRenderer;
+use xcb::connection::Connection;
+use xcb::result::Error;
+use xcb::Rectangle;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DragKind {
+    Move,
+    Resize(Edge),
+}
+
+pub struct Drag<'a> {
+    pub client: u32,
+    kind: DragKind,
+    start: (i16, i16),
+    frame: Rectangle,
+    hints: SizeHints,
+    overlay: Option<SizeOverlay<'a>>,
+}
+
+impl<'a> Drag<'a> {
+    pub fn new(
+        connection: &'a Connection,
+        kind: DragKind,
+        client: u32,
+        frame: Rectangle,
+        (x, y): (i16, i16),
+        hints: SizeHints,
+        overlay_background: u32,
+    ) -> Result<Self, Error> {
+        // Only resizing windows with resize increments, in practice terminals, shows an
+        // overlay with the size in cells.
+        let overlay = match (kind, hints.increments) {
+            (DragKind::Resize(_), Some(_)) => {
+                Some(SizeOverlay::new(connection, overlay_background)?)
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            client,
+            kind,
+            start: (x, y),
+            frame,
+            hints,
+            overlay,
+        })
+    }
+
+    // The frame geometry from before the drag, to restore it when the drag is cancelled.
+    pub fn original_frame(&self) -> Rectangle {
+        self.frame
+    }
+
+    pub fn update(&self, x: i16, y: i16) -> Rectangle {
+        let offset = (
+            i32::from(x) - i32::from(self.start.0),
+            i32::from(y) - i32::from(self.start.1),
+        );
+
+        let edge = match self.kind {
+            DragKind::Move => {
+                return Rectangle {
+                    x: clamp(i32::from(self.frame.x) + offset.0),
+                    y: clamp(i32::from(self.frame.y) + offset.1),
+                    ..self.frame
+                };
+            }
+            DragKind::Resize(edge) => edge,
+        };
+
+        let (width, height) = client_size(self.frame);
+        let resize = |size: u16, offset: i32, grows: bool, shrinks: bool| {
+            let size = i32::from(size);
+            let size = match (grows, shrinks) {
+                (true, _) => size + offset,
+                (_, true) => size - offset,
+                _ => size,
+            };
+
+            size.clamp(1, i32::from(u16::MAX)) as u16
+        };
+
+        let (width, height) = self.hints.constrain(
+            resize(width, offset.0, edge.right(), edge.left()),
+            resize(height, offset.1, edge.bottom(), edge.top()),
+        );
+        let mut frame = frame_rectangle(self.frame.x, self.frame.y, width, height);
+
+        // Dragging the left or top edge keeps the opposite edge in place.
+        if edge.left() {
+            frame.x = clamp(
+                i32::from(self.frame.x) + i32::from(self.frame.width) - i32::from(frame.width),
+            );
+        }
+
+        if edge.top() {
+            frame.y = clamp(
+                i32::from(self.frame.y) + i32::from(self.frame.height) - i32::from(frame.height),
+            );
+        }
+
+        frame
+    }
+
+    pub fn show_overlay(
+        &self,
+        frame: Rectangle,
+        text_renderer: &mut dyn TextRenderer,
+    ) -> Result<(), Error> {
+        let (width, height) = client_size(frame);
+
+        match (&self.overlay, self.hints.cells(width, height)) {
+            (Some(overlay), Some((columns, rows))) => {
+                overlay.show(frame, &format!("{}x{}", columns, rows), text_renderer)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+// Coordinates past what the protocol can express stop at its edges.
+fn clamp(value: i32) -> i16 {
+    value.clamp(i16::MIN.into(), i16::MAX.into()) as i16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drag(kind: DragKind, frame: Rectangle) -> Drag<'static> {
+        Drag {
+            client: 1,
+            kind,
+            start: (0, 0),
+            frame,
+            hints: SizeHints::default(),
+            overlay: None,
+        }
+    }
+
+    #[test]
+    fn resizing_from_the_left_keeps_the_right_edge() {
+        let frame = Rectangle {
+            x: 100,
+            y: 100,
+            width: 300,
+            height: 200,
+        };
+        let resized = drag(DragKind::Resize(Edge::TopLeft), frame).update(50, -20);
+
+        assert_eq!(resized.x + resized.width as i16, 400);
+        assert_eq!(resized.y + resized.height as i16, 300);
+        assert_eq!((resized.x, resized.y), (150, 80));
+    }
+
+    #[test]
+    fn resizing_near_the_largest_coordinates_stops_at_them() {
+        let frame = Rectangle {
+            x: i16::MAX - 10,
+            y: i16::MIN + 10,
+            width: 1000,
+            height: 1000,
+        };
+        let resize = drag(DragKind::Resize(Edge::TopLeft), frame);
+
+        let unchanged = resize.update(0, 0);
+
+        assert_eq!((unchanged.x, unchanged.y), (frame.x, frame.y));
+
+        // Shrinking moves the left edge past the largest x, growing the top edge past the
+        // smallest y.
+        let resized = resize.update(500, i16::MIN);
+
+        assert_eq!((resized.x, resized.y), (i16::MAX, i16::MIN));
+    }
+
+    #[test]
+    fn moving_stops_at_the_largest_coordinates() {
+        let frame = Rectangle {
+            x: i16::MAX - 10,
+            y: i16::MIN + 10,
+            width: 100,
+            height: 100,
+        };
+        let moved = drag(DragKind::Move, frame).update(i16::MAX, i16::MIN);
+
+        assert_eq!((moved.x, moved.y), (i16::MAX, i16::MIN));
+    }
+}
@@ -0,0 +1,96 @@
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FocusPolicy {
+    Click,
+    // Focus follows the pointer and is dropped when it moves onto the root window.
+    FollowsMouse,
+    // Like FollowsMouse, but the root window doesn't take the focus away.
+    Sloppy,
+}
+
+// Clients in the order they were focused, the focused one is last.
+#[derive(Debug, Default)]
+pub struct FocusHistory {
+    clients: Vec<u32>,
+}
+
+impl FocusHistory {
+    pub fn focused(&self) -> Option<u32> {
+        self.clients.last().copied()
+    }
+
+    pub fn push(&mut self, client: u32) {
+        self.clients.retain(|focused| *focused != client);
+        self.clients.push(client);
+    }
+
+    // Returns whether the removed client had the focus.
+    pub fn remove(&mut self, client: u32) -> bool {
+        let was_focused = self.focused() == Some(client);
+
+        self.clients.retain(|focused| *focused != client);
+
+        was_focused
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(clients: &[u32]) -> FocusHistory {
+        let mut history = FocusHistory::default();
+
+        for client in clients {
+            history.push(*client);
+        }
+
+        history
+    }
+
+    #[test]
+    fn push_moves_the_client_to_the_front() {
+        let mut history = history(&[1, 2, 3]);
+
+        history.push(1);
+
+        assert_eq!(history.clients, vec![2, 3, 1]);
+        assert_eq!(history.focused(), Some(1));
+    }
+
+    #[test]
+    fn push_of_the_focused_client_keeps_the_order() {
+        let mut history = history(&[1, 2]);
+
+        history.push(2);
+
+        assert_eq!(history.clients, vec![1, 2]);
+    }
+
+    #[test]
+    fn remove_of_the_focused_client() {
+        let mut history = history(&[1, 2, 3]);
+
+        assert!(history.remove(3));
+        assert_eq!(history.focused(), Some(2));
+    }
+
+    #[test]
+    fn remove_of_an_unfocused_client() {
+        let mut history = history(&[1, 2, 3]);
+
+        assert!(!history.remove(1));
+        assert_eq!(history.clients, vec![2, 3]);
+    }
+
+    #[test]
+    fn remove_of_an_unknown_client() {
+        let mut history = history(&[1]);
+
+        assert!(!history.remove(2));
+        assert_eq!(history.focused(), Some(1));
+
+        assert!(history.remove(1));
+        assert_eq!(history.focused(), None);
+        assert!(!history.remove(1));
+    }
+}
@@ -0,0 +1,245 @@
+use crate::cursors::Cursors;
+use crate::focus::FocusPolicy;
+use crate::resize::Edge;
+use crate::workspace::Workspaces;
+use xcb::color::OwnedColormap;
+use xcb::connection::Connection;
+use xcb::event::{EventMask, ModMask};
+use xcb::grab::{ButtonGrab, GrabMode};
+use xcb::result::{join, Error};
+use xcb::screen::VisualType;
+use xcb::window::{
+    AttributeValues, ConfigureRequest, OwnedWindow, Window, WindowClass, WindowHandle,
+};
+use xcb::Rectangle;
+
+pub const TITLE_BAR_HEIGHT: u16 = 30;
+pub const FRAME_BORDER: u16 = 4;
+const OPAQUE: u32 = 0xff00_0000;
+
+// Frames around clients with an ARGB visual get one too, so a compositor sees the client's
+// alpha channel instead of the frame's opaque background behind it.
+pub struct ArgbFrames<'a> {
+    visual: VisualType,
+    colormap: OwnedColormap<'a>,
+}
+
+impl<'a> ArgbFrames<'a> {
+    pub fn new(connection: &'a Connection, visual: VisualType) -> Result<Self, Error> {
+        Ok(Self {
+            visual,
+            colormap: OwnedColormap::new(connection, &connection.get_root_window()?, visual)?,
+        })
+    }
+}
+
+pub struct ManagedWindow<'a> {
+    // Declared before the frame so they're destroyed before it, which would take them along.
+    handles: Vec<(Edge, OwnedWindow<'a>)>,
+    pub frame: OwnedWindow<'a>,
+    pub client: WindowHandle<'a>,
+    pub title: String,
+    pub workspace: usize,
+    // Shown on every workspace.
+    pub sticky: bool,
+    // The client unmapped itself, its frame stays hidden until it is mapped again.
+    pub withdrawn: bool,
+    // Left out of the workspace's layout.
+    pub floating: bool,
+    // Of the frame, 32 for ARGB frames.
+    pub depth: u8,
+}
+
+impl<'a> ManagedWindow<'a> {
+    pub fn new(
+        connection: &'a Connection,
+        client: WindowHandle<'a>,
+        background: u32,
+        argb_frames: Option<&ArgbFrames>,
+        cursors: &Cursors,
+        focus_policy: FocusPolicy,
+    ) -> Result<Self, Error> {
+        // Both are requested before waiting, so they take a single round trip.
+        let geometry = client.get_geometry();
+        let attributes = client.get_attributes();
+        let geometry = geometry.get_result()?;
+        let frame_rectangle = frame_rectangle(
+            geometry.rectangle.x,
+            geometry.rectangle.y,
+            geometry.rectangle.width,
+            geometry.rectangle.height,
+        );
+
+        let screen = connection.screen()?;
+        let client_visual = attributes.get_result()?.visual;
+        let argb_frames = argb_frames.filter(|_| {
+            screen
+                .find_visual(client_visual)
+                .is_some_and(|(depth, visual)| depth == 32 && visual.is_argb())
+        });
+
+        let attributes = AttributeValues::new().event_mask(
+            EventMask::SUBSTRUCTURE_NOTIFY
+                | EventMask::SUBSTRUCTURE_REDIRECT
+                | EventMask::ENTER_WINDOW
+                | EventMask::EXPOSURE,
+        );
+        let (frame, depth) = match argb_frames {
+            Some(argb_frames) => (
+                OwnedWindow::builder(connection, frame_rectangle)
+                    .depth(32)
+                    .visual(argb_frames.visual.id)
+                    .attributes(
+                        attributes
+                            .background_pixel(background | OPAQUE)
+                            .border_pixel(0)
+                            .colormap(argb_frames.colormap.id()),
+                    )
+                    .build()?,
+                32,
+            ),
+            None => (
+                OwnedWindow::builder(connection, frame_rectangle)
+                    .attributes(attributes.background_pixel(background))
+                    .build()?,
+                screen.root_depth,
+            ),
+        };
+        let frame_window = frame.window();
+
+        let mut handles = vec![];
+
+        for edge in Edge::ALL.iter() {
+            for rectangle in edge.handle_rectangles(frame_rectangle.width, frame_rectangle.height) {
+                let handle = OwnedWindow::builder(connection, rectangle)
+                    .parent(&frame_window)
+                    .class(WindowClass::InputOnly)
+                    .attributes(
+                        AttributeValues::new()
+                            .cursor(cursors.edge(*edge))
+                            .event_mask(EventMask::BUTTON_PRESS),
+                    )
+                    .build()?;
+
+                handles.push((*edge, handle));
+            }
+        }
+
+        let handle_windows: Vec<_> = handles.iter().map(|(_, handle)| handle.window()).collect();
+        let mut cookies: Vec<_> = handle_windows.iter().map(|handle| handle.map()).collect();
+
+        // Clicks are held until the window manager has focused the window and then replayed
+        // to the client.
+        if focus_policy == FocusPolicy::Click {
+            cookies.push(
+                frame_window.grab_button(
+                    &ButtonGrab::new(None, ModMask::ANY, EventMask::BUTTON_PRESS)
+                        .pointer_mode(GrabMode::Synchronous),
+                ),
+            );
+        }
+
+        cookies.push(frame_window.map());
+        cookies.push(client.set_event_mask(EventMask::PROPERTY_CHANGE));
+        cookies.push(client.reparent(&frame_window, FRAME_BORDER as i16, TITLE_BAR_HEIGHT as i16));
+        cookies.push(client.map());
+
+        // Everything has been sent by now, so the windows and the requests on them share a
+        // single round trip. The windows come first, so they're owned and destroyed again if
+        // anything after them failed.
+        let frame = frame.get_result()?;
+        let handles = handles
+            .into_iter()
+            .map(|(edge, handle)| Ok((edge, handle.get_result()?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        for result in join(cookies) {
+            result?;
+        }
+
+        Ok(Self {
+            handles,
+            frame,
+            client,
+            title: String::new(),
+            workspace: 0,
+            sticky: false,
+            withdrawn: false,
+            floating: false,
+            depth,
+        })
+    }
+
+    pub fn is_visible(&self, workspaces: &Workspaces) -> bool {
+        !self.withdrawn && (self.sticky || workspaces.is_shown(self.workspace))
+    }
+
+    pub fn edge(&self, window: u32) -> Option<Edge> {
+        self.handles
+            .iter()
+            .find(|(_, handle)| handle.id() == window)
+            .map(|(edge, _)| *edge)
+    }
+
+    // Moves and resizes the frame, the client and the resize handles together. `stacking`
+    // may carry a sibling and stack mode for the frame.
+    pub fn place(&self, rectangle: Rectangle, stacking: ConfigureRequest) -> Result<(), Error> {
+        let (width, height) = client_size(rectangle);
+        let mut cookies = vec![
+            self.frame.configure_with(&stacking.rectangle(rectangle)),
+            self.client.configure_with(
+                &ConfigureRequest::new()
+                    .position(FRAME_BORDER as i16, TITLE_BAR_HEIGHT as i16)
+                    .size(width, height)
+                    .border_width(0),
+            ),
+        ];
+
+        let mut handles = self.handles.iter();
+
+        for edge in Edge::ALL.iter() {
+            for (rectangle, (_, handle)) in edge
+                .handle_rectangles(rectangle.width, rectangle.height)
+                .into_iter()
+                .zip(&mut handles)
+            {
+                cookies.push(handle.configure(rectangle));
+            }
+        }
+
+        // ICCCM 4.1.5: the client has been moved relative to the root without its own position
+        // changing, so it has to be told its new root-relative geometry.
+        cookies.push(self.client.send_configure_notify(
+            Rectangle {
+                x: rectangle.x + FRAME_BORDER as i16,
+                y: rectangle.y + TITLE_BAR_HEIGHT as i16,
+                width,
+                height,
+            },
+            0,
+        ));
+
+        // Everything is sent before checking, so this is called on every drag motion at the
+        // cost of a single round trip.
+        join(cookies).into_iter().collect()
+    }
+}
+
+pub fn frame_rectangle(x: i16, y: i16, client_width: u16, client_height: u16) -> Rectangle {
+    Rectangle {
+        x,
+        y,
+        width: client_width.saturating_add(2 * FRAME_BORDER),
+        height: client_height.saturating_add(TITLE_BAR_HEIGHT + FRAME_BORDER),
+    }
+}
+
+pub fn client_size(frame: Rectangle) -> (u16, u16) {
+    (
+        frame.width.saturating_sub(2 * FRAME_BORDER).max(1),
+        frame
+            .height
+            .saturating_sub(TITLE_BAR_HEIGHT + FRAME_BORDER)
+            .max(1),
+    )
+}
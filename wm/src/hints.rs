@@ -0,0 +1,304 @@
+use xcb::window::Window;
+
+const P_MIN_SIZE: u32 = 16;
+const P_MAX_SIZE: u32 = 32;
+const P_RESIZE_INC: u32 = 64;
+const P_ASPECT: u32 = 128;
+const P_BASE_SIZE: u32 = 256;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct SizeHints {
+    pub min_size: Option<(u16, u16)>,
+    pub max_size: Option<(u16, u16)>,
+    pub increments: Option<(u16, u16)>,
+    pub base_size: Option<(u16, u16)>,
+    // The smallest and largest width to height ratios, as numerator and denominator.
+    pub min_aspect: Option<(u16, u16)>,
+    pub max_aspect: Option<(u16, u16)>,
+}
+
+impl SizeHints {
+    // Windows that can't be resized are left floating by tiling layouts.
+    pub fn is_fixed(&self) -> bool {
+        self.min_size.is_some() && self.min_size == self.max_size
+    }
+
+    pub fn get(window: &dyn Window) -> Self {
+        match window
+            .get_property(xcb::atom::WM_NORMAL_HINTS, xcb::atom::WM_SIZE_HINTS)
+            .get_result()
+        {
+            Ok(Some(property)) => Self::from_values(&property.values32()),
+            _ => Self::default(),
+        }
+    }
+
+    fn from_values(values: &[u32]) -> Self {
+        // Clients written against pre-ICCCM Xlib only send the first 15 fields.
+        let flags = match values.first() {
+            Some(flags) => *flags,
+            None => return Self::default(),
+        };
+        let pair = |flag: u32, index: usize| {
+            if flags & flag == 0 || values.len() < index + 2 {
+                return None;
+            }
+
+            let clamp = |value: u32| (value as i32).clamp(0, i32::from(u16::MAX)) as u16;
+
+            Some((clamp(values[index]), clamp(values[index + 1])))
+        };
+        let positive = |(first, second): &(u16, u16)| *first > 0 && *second > 0;
+
+        Self {
+            min_size: pair(P_MIN_SIZE, 5),
+            max_size: pair(P_MAX_SIZE, 7),
+            increments: pair(P_RESIZE_INC, 9).filter(positive),
+            base_size: pair(P_BASE_SIZE, 15),
+            min_aspect: pair(P_ASPECT, 11).filter(positive),
+            max_aspect: pair(P_ASPECT, 13).filter(positive),
+        }
+    }
+
+    pub fn constrain(&self, width: u16, height: u16) -> (u16, u16) {
+        // ICCCM: the base size falls back to the minimum size and vice versa.
+        let (base_width, base_height) = self.base_size.or(self.min_size).unwrap_or((0, 0));
+        let (min_width, min_height) = self.min_size.or(self.base_size).unwrap_or((1, 1));
+
+        let mut width = width.max(min_width).max(1);
+        let mut height = height.max(min_height).max(1);
+
+        if let Some((max_width, max_height)) = self.max_size {
+            width = width.min(max_width.max(min_width)).max(1);
+            height = height.min(max_height.max(min_height)).max(1);
+        }
+
+        // Too wide windows lose width and too tall ones height, so the aspect ratio never makes
+        // them grow past the maximum size.
+        if let Some((numerator, denominator)) = self.max_aspect {
+            if u32::from(width) * u32::from(denominator) > u32::from(height) * u32::from(numerator)
+            {
+                width = (u32::from(height) * u32::from(numerator) / u32::from(denominator))
+                    .clamp(1, u32::from(u16::MAX)) as u16;
+            }
+        }
+
+        if let Some((numerator, denominator)) = self.min_aspect {
+            if u32::from(width) * u32::from(denominator) < u32::from(height) * u32::from(numerator)
+            {
+                height = (u32::from(width) * u32::from(denominator) / u32::from(numerator))
+                    .clamp(1, u32::from(u16::MAX)) as u16;
+            }
+        }
+
+        if let Some((width_increment, height_increment)) = self.increments {
+            if width > base_width {
+                width -= (width - base_width) % width_increment;
+            }
+
+            if width < min_width {
+                width = width.saturating_add(width_increment);
+            }
+
+            if height > base_height {
+                height -= (height - base_height) % height_increment;
+            }
+
+            if height < min_height {
+                height = height.saturating_add(height_increment);
+            }
+        }
+
+        (width.max(1), height.max(1))
+    }
+
+    // The size in resize increments, which is what terminals call rows and columns.
+    pub fn cells(&self, width: u16, height: u16) -> Option<(u16, u16)> {
+        let (width_increment, height_increment) = self.increments?;
+        let (base_width, base_height) = self.base_size.or(self.min_size).unwrap_or((0, 0));
+
+        Some((
+            width.saturating_sub(base_width) / width_increment,
+            height.saturating_sub(base_height) / height_increment,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // WM_SIZE_HINTS with every field, as ICCCM clients send it.
+    fn values(flags: u32, fields: &[(usize, u32)]) -> Vec<u32> {
+        let mut values = vec![0; 18];
+
+        values[0] = flags;
+
+        for (index, value) in fields {
+            values[*index] = *value;
+        }
+
+        values
+    }
+
+    #[test]
+    fn parses_the_flagged_fields() {
+        let hints = SizeHints::from_values(&values(
+            P_MIN_SIZE | P_MAX_SIZE | P_RESIZE_INC | P_BASE_SIZE,
+            &[
+                (5, 100),
+                (6, 50),
+                (7, 800),
+                (8, 600),
+                (9, 7),
+                (10, 14),
+                (15, 4),
+                (16, 2),
+            ],
+        ));
+
+        assert_eq!(hints.min_size, Some((100, 50)));
+        assert_eq!(hints.max_size, Some((800, 600)));
+        assert_eq!(hints.increments, Some((7, 14)));
+        assert_eq!(hints.base_size, Some((4, 2)));
+        assert_eq!(hints.min_aspect, None);
+    }
+
+    #[test]
+    fn fields_without_their_flag_are_ignored() {
+        let hints = SizeHints::from_values(&values(P_MAX_SIZE, &[(5, 100), (6, 50)]));
+
+        assert_eq!(hints.min_size, None);
+        assert_eq!(hints.max_size, Some((0, 0)));
+    }
+
+    #[test]
+    fn short_property_arrays() {
+        assert_eq!(SizeHints::from_values(&[]), SizeHints::default());
+
+        // Pre-ICCCM hints end before the base size.
+        let hints = SizeHints::from_values(&values(P_MIN_SIZE | P_BASE_SIZE, &[(5, 10)])[..15]);
+
+        assert_eq!(hints.min_size, Some((10, 0)));
+        assert_eq!(hints.base_size, None);
+
+        let hints = SizeHints::from_values(&[P_MIN_SIZE, 0, 0, 0, 0, 10]);
+
+        assert_eq!(hints.min_size, None);
+    }
+
+    #[test]
+    fn negative_and_zero_values() {
+        let hints = SizeHints::from_values(&values(
+            P_MIN_SIZE | P_RESIZE_INC | P_ASPECT,
+            &[
+                (5, -5i32 as u32),
+                (6, 70_000),
+                (9, 0),
+                (10, 3),
+                (11, 0),
+                (12, 1),
+            ],
+        ));
+
+        assert_eq!(hints.min_size, Some((0, u16::MAX)));
+        assert_eq!(hints.increments, None);
+        assert_eq!(hints.min_aspect, None);
+    }
+
+    #[test]
+    fn fixed_size() {
+        let hints = SizeHints::from_values(&values(
+            P_MIN_SIZE | P_MAX_SIZE,
+            &[(5, 300), (6, 200), (7, 300), (8, 200)],
+        ));
+
+        assert!(hints.is_fixed());
+        assert_eq!(hints.constrain(10, 1000), (300, 200));
+        assert!(!SizeHints::default().is_fixed());
+    }
+
+    #[test]
+    fn min_and_max_size() {
+        let hints = SizeHints {
+            min_size: Some((100, 50)),
+            max_size: Some((400, 300)),
+            ..SizeHints::default()
+        };
+
+        assert_eq!(hints.constrain(10, 10), (100, 50));
+        assert_eq!(hints.constrain(1000, 1000), (400, 300));
+        assert_eq!(hints.constrain(200, 100), (200, 100));
+    }
+
+    #[test]
+    fn a_max_size_below_the_min_size_is_ignored() {
+        let hints = SizeHints {
+            min_size: Some((100, 50)),
+            max_size: Some((20, 20)),
+            ..SizeHints::default()
+        };
+
+        assert_eq!(hints.constrain(500, 500), (100, 50));
+    }
+
+    #[test]
+    fn zero_sizes_become_one() {
+        assert_eq!(SizeHints::default().constrain(0, 0), (1, 1));
+    }
+
+    #[test]
+    fn increments_count_from_the_base_size() {
+        let hints = SizeHints {
+            increments: Some((10, 20)),
+            base_size: Some((5, 8)),
+            ..SizeHints::default()
+        };
+
+        assert_eq!(hints.constrain(59, 70), (55, 68));
+        assert_eq!(hints.cells(55, 68), Some((5, 3)));
+    }
+
+    #[test]
+    fn the_base_size_falls_back_to_the_min_size() {
+        let hints = SizeHints {
+            min_size: Some((4, 4)),
+            increments: Some((10, 10)),
+            ..SizeHints::default()
+        };
+
+        assert_eq!(hints.constrain(37, 3), (34, 4));
+        assert_eq!(hints.cells(34, 4), Some((3, 0)));
+    }
+
+    #[test]
+    fn increments_stay_above_the_min_size() {
+        let hints = SizeHints {
+            min_size: Some((25, 25)),
+            base_size: Some((0, 0)),
+            increments: Some((10, 10)),
+            ..SizeHints::default()
+        };
+
+        assert_eq!(hints.constrain(27, 31), (30, 30));
+    }
+
+    #[test]
+    fn no_cells_without_increments() {
+        assert_eq!(SizeHints::default().cells(100, 100), None);
+    }
+
+    #[test]
+    fn aspect_ratio() {
+        let hints =
+            SizeHints::from_values(&values(P_ASPECT, &[(11, 1), (12, 2), (13, 2), (14, 1)]));
+
+        assert_eq!(hints.min_aspect, Some((1, 2)));
+        assert_eq!(hints.max_aspect, Some((2, 1)));
+
+        // Too wide, then too tall.
+        assert_eq!(hints.constrain(500, 100), (200, 100));
+        assert_eq!(hints.constrain(100, 500), (100, 200));
+        assert_eq!(hints.constrain(150, 100), (150, 100));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::layout::{Direction, SplitDirection};
+use crate::tree::ContainerLayout;
+use xcb::event::ModMask;
+use xcb::grab::KeyGrab;
+use xcb::keyboard::KeyboardMapping;
+use xcb::result::Error;
+use xcb::window::Window;
+
+const KEYSYM_SPACE: u32 = 0x20;
+const KEYSYM_1: u32 = 0x31;
+const KEYSYM_B: u32 = 0x62;
+const KEYSYM_D: u32 = 0x64;
+const KEYSYM_E: u32 = 0x65;
+const KEYSYM_F: u32 = 0x66;
+const KEYSYM_H: u32 = 0x68;
+const KEYSYM_I: u32 = 0x69;
+const KEYSYM_L: u32 = 0x6c;
+const KEYSYM_Q: u32 = 0x71;
+const KEYSYM_S: u32 = 0x73;
+const KEYSYM_V: u32 = 0x76;
+const KEYSYM_W: u32 = 0x77;
+const KEYSYM_LEFT: u32 = 0xff51;
+const KEYSYM_UP: u32 = 0xff52;
+const KEYSYM_RIGHT: u32 = 0xff53;
+const KEYSYM_DOWN: u32 = 0xff54;
+const RATIO_STEP: f32 = 0.05;
+const RESIZE_STEP: f32 = 0.05;
+// Workspaces past the ninth can only be reached through EWMH messages.
+const MAXIMUM_NUMBERED_WORKSPACES: usize = 9;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Action {
+    SwitchWorkspace(usize),
+    MoveToWorkspace(usize),
+    ToggleSticky,
+    ToggleFloating,
+    CycleLayout,
+    AdjustRatio(f32),
+    AdjustMasterCount(i32),
+    Split(SplitDirection),
+    MoveWindow(Direction),
+    Resize(SplitDirection, f32),
+    // `None` toggles between a horizontal and a vertical split.
+    SetContainerLayout(Option<ContainerLayout>),
+}
+
+pub struct KeyBindings {
+    bindings: Vec<(u8, ModMask, Action)>,
+}
+
+impl KeyBindings {
+    pub fn new(mapping: &KeyboardMapping, modifier: ModMask, workspaces: usize) -> Self {
+        let mut bindings = vec![];
+
+        for index in 0..workspaces.min(MAXIMUM_NUMBERED_WORKSPACES) {
+            for keycode in mapping.keycodes(KEYSYM_1 + index as u32) {
+                bindings.push((keycode, modifier, Action::SwitchWorkspace(index)));
+                bindings.push((
+                    keycode,
+                    modifier | ModMask::SHIFT,
+                    Action::MoveToWorkspace(index),
+                ));
+            }
+        }
+
+        let keys = [
+            (KEYSYM_S, Action::ToggleSticky),
+            (KEYSYM_F, Action::ToggleFloating),
+            (KEYSYM_SPACE, Action::CycleLayout),
+            (KEYSYM_H, Action::AdjustRatio(-RATIO_STEP)),
+            (KEYSYM_L, Action::AdjustRatio(RATIO_STEP)),
+            (KEYSYM_I, Action::AdjustMasterCount(1)),
+            (KEYSYM_D, Action::AdjustMasterCount(-1)),
+            (KEYSYM_B, Action::Split(SplitDirection::Horizontal)),
+            (KEYSYM_V, Action::Split(SplitDirection::Vertical)),
+            (
+                KEYSYM_W,
+                Action::SetContainerLayout(Some(ContainerLayout::Tabbed)),
+            ),
+            (
+                KEYSYM_Q,
+                Action::SetContainerLayout(Some(ContainerLayout::Stacked)),
+            ),
+            (KEYSYM_E, Action::SetContainerLayout(None)),
+        ];
+
+        for (keysym, action) in keys.iter() {
+            for keycode in mapping.keycodes(*keysym) {
+                bindings.push((keycode, modifier, *action));
+            }
+        }
+
+        // Shift moves the focused window, Control resizes it.
+        let arrows = [
+            (KEYSYM_LEFT, Direction::Left, -RESIZE_STEP),
+            (KEYSYM_RIGHT, Direction::Right, RESIZE_STEP),
+            (KEYSYM_UP, Direction::Up, -RESIZE_STEP),
+            (KEYSYM_DOWN, Direction::Down, RESIZE_STEP),
+        ];
+
+        for (keysym, direction, delta) in arrows.iter() {
+            for keycode in mapping.keycodes(*keysym) {
+                bindings.push((
+                    keycode,
+                    modifier | ModMask::SHIFT,
+                    Action::MoveWindow(*direction),
+                ));
+                bindings.push((
+                    keycode,
+                    modifier | ModMask::CONTROL,
+                    Action::Resize(direction.orientation(), *delta),
+                ));
+            }
+        }
+
+        Self { bindings }
+    }
+
+    pub fn grab(&self, window: &dyn Window) -> Result<(), Error> {
+        for (keycode, modifiers, _) in &self.bindings {
+            for locks in lock_combinations().iter() {
+                window
+                    .grab_key(&KeyGrab::new(Some(*keycode), *modifiers | *locks))
+                    .get_result()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn find(&self, keycode: u8, modifiers: ModMask) -> Option<Action> {
+        let modifiers = modifiers - ModMask::LOCK - ModMask::MOD_2;
+
+        self.bindings
+            .iter()
+            .find(|binding| binding.0 == keycode && binding.1 == modifiers)
+            .map(|binding| binding.2)
+    }
+}
+
+// Passive grabs only match the exact modifiers, so Caps Lock and Num Lock have to be
+// grabbed as well.
+pub fn lock_combinations() -> [ModMask; 4] {
+    [
+        ModMask::empty(),
+        ModMask::LOCK,
+        ModMask::MOD_2,
+        ModMask::LOCK | ModMask::MOD_2,
+    ]
+}
@@ -0,0 +1,573 @@
+use crate::tree::{ContainerLayout, Tree};
+use xcb::Rectangle;
+
+const DEFAULT_MASTER_COUNT: usize = 1;
+const DEFAULT_RATIO: f32 = 0.55;
+const MINIMUM_RATIO: f32 = 0.1;
+const MAXIMUM_RATIO: f32 = 0.9;
+
+// The order the layouts are cycled through, floating comes after the last one.
+pub const LAYOUTS: &[&str] = &[
+    "master-stack",
+    "vertical",
+    "horizontal",
+    "grid",
+    "monocle",
+    "bsp",
+    "tree",
+];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SplitDirection {
+    // Side by side.
+    Horizontal,
+    // One above the other.
+    Vertical,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Direction {
+    pub fn orientation(self) -> SplitDirection {
+        match self {
+            Direction::Left | Direction::Right => SplitDirection::Horizontal,
+            Direction::Up | Direction::Down => SplitDirection::Vertical,
+        }
+    }
+
+    pub fn is_forward(self) -> bool {
+        matches!(self, Direction::Right | Direction::Down)
+    }
+}
+
+// Layouts only see client ids and rectangles, so they don't need a connection.
+pub trait Layout {
+    fn name(&self) -> &'static str;
+    // Returns the frame rectangle of each of `windows` inside `area`. The windows are in the
+    // order they were managed in, the first one is the master.
+    fn arrange(&mut self, windows: &[u32], area: Rectangle) -> Vec<(u32, Rectangle)>;
+
+    fn adjust_ratio(&mut self, _delta: f32) {}
+
+    fn adjust_master_count(&mut self, _delta: i32) {}
+
+    // Chooses where the next window opened next to `window` goes.
+    fn split(&mut self, _window: u32, _direction: SplitDirection) {}
+
+    fn focus(&mut self, _window: u32) {}
+
+    fn move_window(&mut self, _window: u32, _direction: Direction) {}
+
+    fn resize(&mut self, _window: u32, _direction: SplitDirection, _delta: f32) {}
+
+    fn set_container_layout(&mut self, _window: u32, _layout: Option<ContainerLayout>) {}
+
+    // The state worth keeping across restarts, only layouts arranged by hand have one.
+    fn save(&self) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+pub fn from_name(name: &str) -> Option<Box<dyn Layout>> {
+    match name {
+        "master-stack" => Some(Box::new(MasterStack::default())),
+        "vertical" => Some(Box::new(Stack(SplitDirection::Vertical))),
+        "horizontal" => Some(Box::new(Stack(SplitDirection::Horizontal))),
+        "grid" => Some(Box::new(Grid)),
+        "monocle" => Some(Box::new(Monocle)),
+        "bsp" => Some(Box::new(Bsp::default())),
+        "tree" => Some(Box::new(Tree::default())),
+        _ => None,
+    }
+}
+
+// The master windows share a column on the left, the others are stacked on the right.
+pub struct MasterStack {
+    master_count: usize,
+    ratio: f32,
+}
+
+impl Default for MasterStack {
+    fn default() -> Self {
+        Self {
+            master_count: DEFAULT_MASTER_COUNT,
+            ratio: DEFAULT_RATIO,
+        }
+    }
+}
+
+impl Layout for MasterStack {
+    fn name(&self) -> &'static str {
+        "master-stack"
+    }
+
+    fn arrange(&mut self, windows: &[u32], area: Rectangle) -> Vec<(u32, Rectangle)> {
+        let master_count = self.master_count.min(windows.len());
+
+        if master_count == 0 || master_count == windows.len() {
+            return windows
+                .iter()
+                .copied()
+                .zip(divide(area, SplitDirection::Vertical, windows.len()))
+                .collect();
+        }
+
+        let (master_area, stack_area) = split(area, SplitDirection::Horizontal, self.ratio);
+        let (masters, stack) = windows.split_at(master_count);
+
+        masters
+            .iter()
+            .copied()
+            .zip(divide(master_area, SplitDirection::Vertical, masters.len()))
+            .chain(stack.iter().copied().zip(divide(
+                stack_area,
+                SplitDirection::Vertical,
+                stack.len(),
+            )))
+            .collect()
+    }
+
+    fn adjust_ratio(&mut self, delta: f32) {
+        self.ratio = (self.ratio + delta).clamp(MINIMUM_RATIO, MAXIMUM_RATIO);
+    }
+
+    fn adjust_master_count(&mut self, delta: i32) {
+        self.master_count = (self.master_count as i32 + delta).max(0) as usize;
+    }
+}
+
+// Every window gets an equal share of the area along one direction.
+pub struct Stack(pub SplitDirection);
+
+impl Layout for Stack {
+    fn name(&self) -> &'static str {
+        match self.0 {
+            SplitDirection::Vertical => "vertical",
+            SplitDirection::Horizontal => "horizontal",
+        }
+    }
+
+    fn arrange(&mut self, windows: &[u32], area: Rectangle) -> Vec<(u32, Rectangle)> {
+        windows
+            .iter()
+            .copied()
+            .zip(divide(area, self.0, windows.len()))
+            .collect()
+    }
+}
+
+// Rows of equal height, the windows of an incomplete last row are widened to fill it.
+pub struct Grid;
+
+impl Layout for Grid {
+    fn name(&self) -> &'static str {
+        "grid"
+    }
+
+    fn arrange(&mut self, windows: &[u32], area: Rectangle) -> Vec<(u32, Rectangle)> {
+        if windows.is_empty() {
+            return vec![];
+        }
+
+        let columns = (windows.len() as f32).sqrt().ceil() as usize;
+        let rows = windows.len().div_ceil(columns);
+
+        windows
+            .chunks(columns)
+            .zip(divide(area, SplitDirection::Vertical, rows))
+            .flat_map(|(row, row_area)| {
+                row.iter()
+                    .copied()
+                    .zip(divide(row_area, SplitDirection::Horizontal, row.len()))
+            })
+            .collect()
+    }
+}
+
+// Every window takes up the whole area, only the one on top is visible.
+pub struct Monocle;
+
+impl Layout for Monocle {
+    fn name(&self) -> &'static str {
+        "monocle"
+    }
+
+    fn arrange(&mut self, windows: &[u32], area: Rectangle) -> Vec<(u32, Rectangle)> {
+        windows.iter().map(|window| (*window, area)).collect()
+    }
+}
+
+enum Node {
+    Leaf(u32),
+    Split {
+        direction: SplitDirection,
+        ratio: f32,
+        first: Box<Node>,
+        second: Box<Node>,
+    },
+}
+
+impl Node {
+    fn contains(&self, window: u32) -> bool {
+        match self {
+            Node::Leaf(leaf) => *leaf == window,
+            Node::Split { first, second, .. } => first.contains(window) || second.contains(window),
+        }
+    }
+
+    fn last_leaf(&self) -> u32 {
+        match self {
+            Node::Leaf(leaf) => *leaf,
+            Node::Split { second, .. } => second.last_leaf(),
+        }
+    }
+
+    // Drops the leaves that aren't in `windows`, a split left with one child is replaced
+    // by it.
+    fn retain(self, windows: &[u32]) -> Option<Node> {
+        match self {
+            Node::Leaf(leaf) if windows.contains(&leaf) => Some(Node::Leaf(leaf)),
+            Node::Leaf(_) => None,
+            Node::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => match (first.retain(windows), second.retain(windows)) {
+                (Some(first), Some(second)) => Some(Node::Split {
+                    direction,
+                    ratio,
+                    first: Box::new(first),
+                    second: Box::new(second),
+                }),
+                (Some(node), None) | (None, Some(node)) => Some(node),
+                (None, None) => None,
+            },
+        }
+    }
+
+    // Splits the leaf of `target`, without a direction splits alternate with the depth so
+    // the windows spiral inwards.
+    fn insert(
+        &mut self,
+        target: u32,
+        window: u32,
+        direction: Option<SplitDirection>,
+        depth: usize,
+    ) -> bool {
+        match self {
+            Node::Leaf(leaf) if *leaf == target => {
+                let direction = direction.unwrap_or(if depth.is_multiple_of(2) {
+                    SplitDirection::Horizontal
+                } else {
+                    SplitDirection::Vertical
+                });
+
+                *self = Node::Split {
+                    direction,
+                    ratio: 0.5,
+                    first: Box::new(Node::Leaf(target)),
+                    second: Box::new(Node::Leaf(window)),
+                };
+
+                true
+            }
+            Node::Leaf(_) => false,
+            Node::Split { first, second, .. } => {
+                first.insert(target, window, direction, depth + 1)
+                    || second.insert(target, window, direction, depth + 1)
+            }
+        }
+    }
+
+    fn place(&self, area: Rectangle, placed: &mut Vec<(u32, Rectangle)>) {
+        match self {
+            Node::Leaf(leaf) => placed.push((*leaf, area)),
+            Node::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => {
+                let (first_area, second_area) = split(area, *direction, *ratio);
+
+                first.place(first_area, placed);
+                second.place(second_area, placed);
+            }
+        }
+    }
+}
+
+// Binary space partitioning: every new window splits an existing one in two. Which one and
+// in what direction can be chosen beforehand with `split`.
+#[derive(Default)]
+pub struct Bsp {
+    root: Option<Node>,
+    preselection: Option<(u32, SplitDirection)>,
+}
+
+impl Layout for Bsp {
+    fn name(&self) -> &'static str {
+        "bsp"
+    }
+
+    fn arrange(&mut self, windows: &[u32], area: Rectangle) -> Vec<(u32, Rectangle)> {
+        self.root = self.root.take().and_then(|root| root.retain(windows));
+
+        for window in windows {
+            let root = match self.root.as_mut() {
+                Some(root) if root.contains(*window) => continue,
+                Some(root) => root,
+                None => {
+                    self.root = Some(Node::Leaf(*window));
+                    continue;
+                }
+            };
+
+            let (target, direction) = match self.preselection.take() {
+                Some((target, direction)) if root.contains(target) => (target, Some(direction)),
+                _ => (root.last_leaf(), None),
+            };
+
+            root.insert(target, *window, direction, 0);
+        }
+
+        let mut placed = vec![];
+
+        if let Some(root) = self.root.as_ref() {
+            root.place(area, &mut placed);
+        }
+
+        placed
+    }
+
+    fn split(&mut self, window: u32, direction: SplitDirection) {
+        self.preselection = Some((window, direction));
+    }
+}
+
+pub fn split(area: Rectangle, direction: SplitDirection, ratio: f32) -> (Rectangle, Rectangle) {
+    match direction {
+        SplitDirection::Horizontal => {
+            let first = (f32::from(area.width) * ratio) as u16;
+
+            (
+                Rectangle {
+                    width: first,
+                    ..area
+                },
+                Rectangle {
+                    x: area.x.saturating_add(first as i16),
+                    width: area.width - first,
+                    ..area
+                },
+            )
+        }
+        SplitDirection::Vertical => {
+            let first = (f32::from(area.height) * ratio) as u16;
+
+            (
+                Rectangle {
+                    height: first,
+                    ..area
+                },
+                Rectangle {
+                    y: area.y.saturating_add(first as i16),
+                    height: area.height - first,
+                    ..area
+                },
+            )
+        }
+    }
+}
+
+// Cuts `area` into `count` equal parts, the first ones take the pixels left over.
+pub fn divide(area: Rectangle, direction: SplitDirection, count: usize) -> Vec<Rectangle> {
+    if count == 0 {
+        return vec![];
+    }
+
+    let length = match direction {
+        SplitDirection::Horizontal => area.width,
+        SplitDirection::Vertical => area.height,
+    } as usize;
+    let mut offset = 0;
+
+    (0..count)
+        .map(|index| {
+            let size = length / count + usize::from(index < length % count);
+            let part = match direction {
+                SplitDirection::Horizontal => Rectangle {
+                    x: area.x.saturating_add(offset as i16),
+                    width: size as u16,
+                    ..area
+                },
+                SplitDirection::Vertical => Rectangle {
+                    y: area.y.saturating_add(offset as i16),
+                    height: size as u16,
+                    ..area
+                },
+            };
+
+            offset += size;
+
+            part
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AREA: Rectangle = Rectangle {
+        x: 0,
+        y: 0,
+        width: 100,
+        height: 100,
+    };
+
+    // Rectangle doesn't implement PartialEq.
+    fn bounds(placed: &[(u32, Rectangle)]) -> Vec<(u32, i16, i16, u16, u16)> {
+        placed
+            .iter()
+            .map(|(window, area)| (*window, area.x, area.y, area.width, area.height))
+            .collect()
+    }
+
+    #[test]
+    fn divide_gives_the_remainder_to_the_first_parts() {
+        let area = Rectangle {
+            x: 10,
+            y: 20,
+            width: 11,
+            height: 5,
+        };
+        let parts = divide(area, SplitDirection::Horizontal, 3);
+        let parts: Vec<_> = parts.iter().map(|part| (0, *part)).collect();
+
+        assert_eq!(
+            bounds(&parts),
+            vec![(0, 10, 20, 4, 5), (0, 14, 20, 4, 5), (0, 18, 20, 3, 5)]
+        );
+    }
+
+    #[test]
+    fn divide_into_nothing() {
+        assert!(divide(AREA, SplitDirection::Vertical, 0).is_empty());
+    }
+
+    #[test]
+    fn master_stack() {
+        let mut layout = MasterStack::default();
+
+        assert_eq!(
+            bounds(&layout.arrange(&[1, 2, 3], AREA)),
+            vec![(1, 0, 0, 55, 100), (2, 55, 0, 45, 50), (3, 55, 50, 45, 50)]
+        );
+    }
+
+    #[test]
+    fn master_stack_without_masters_stacks_everything() {
+        let mut layout = MasterStack::default();
+
+        layout.adjust_master_count(-1);
+        layout.adjust_master_count(-1);
+
+        assert_eq!(layout.master_count, 0);
+        assert_eq!(
+            bounds(&layout.arrange(&[1, 2], AREA)),
+            vec![(1, 0, 0, 100, 50), (2, 0, 50, 100, 50)]
+        );
+    }
+
+    #[test]
+    fn master_stack_with_only_masters_stacks_everything() {
+        let mut layout = MasterStack::default();
+
+        layout.adjust_master_count(2);
+
+        assert_eq!(
+            bounds(&layout.arrange(&[1, 2], AREA)),
+            vec![(1, 0, 0, 100, 50), (2, 0, 50, 100, 50)]
+        );
+        assert!(layout.arrange(&[], AREA).is_empty());
+    }
+
+    #[test]
+    fn grid_widens_an_incomplete_last_row() {
+        let area = Rectangle {
+            width: 90,
+            height: 60,
+            ..AREA
+        };
+
+        assert_eq!(
+            bounds(&Grid.arrange(&[1, 2, 3, 4, 5], area)),
+            vec![
+                (1, 0, 0, 30, 30),
+                (2, 30, 0, 30, 30),
+                (3, 60, 0, 30, 30),
+                (4, 0, 30, 45, 30),
+                (5, 45, 30, 45, 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn bsp_spirals_inwards() {
+        let mut layout = Bsp::default();
+
+        assert_eq!(
+            bounds(&layout.arrange(&[1, 2, 3], AREA)),
+            vec![(1, 0, 0, 50, 100), (2, 50, 0, 50, 50), (3, 50, 50, 50, 50)]
+        );
+    }
+
+    #[test]
+    fn bsp_splits_the_preselected_window() {
+        let mut layout = Bsp::default();
+
+        layout.arrange(&[1, 2], AREA);
+        layout.split(1, SplitDirection::Vertical);
+
+        assert_eq!(
+            bounds(&layout.arrange(&[1, 2, 3], AREA)),
+            vec![(1, 0, 0, 50, 50), (3, 0, 50, 50, 50), (2, 50, 0, 50, 100)]
+        );
+
+        // The preselection is used up by the window it was made for.
+        assert_eq!(
+            bounds(&layout.arrange(&[1, 2, 3, 4], AREA)),
+            vec![
+                (1, 0, 0, 50, 50),
+                (3, 0, 50, 50, 50),
+                (2, 50, 0, 50, 50),
+                (4, 50, 50, 50, 50),
+            ]
+        );
+    }
+
+    #[test]
+    fn bsp_gives_the_space_of_a_removed_window_to_its_sibling() {
+        let mut layout = Bsp::default();
+
+        layout.arrange(&[1, 2, 3], AREA);
+
+        assert_eq!(
+            bounds(&layout.arrange(&[1, 3], AREA)),
+            vec![(1, 0, 0, 50, 100), (3, 50, 0, 50, 100)]
+        );
+        assert!(layout.arrange(&[], AREA).is_empty());
+        assert_eq!(
+            bounds(&layout.arrange(&[4], AREA)),
+            vec![(4, 0, 0, 100, 100)]
+        );
+    }
+}
@@ -1,16 +1,687 @@
-use xcb::event::{Event, EventMask, MouseButton};
-use xcb::window::{OwnedWindow, Window};
+mod atoms;
+mod config;
+mod cursors;
+mod drag;
+mod focus;
+mod frame;
+mod hints;
+mod keys;
+mod layout;
+mod resize;
+mod text;
+mod tree;
+mod workspace;
+
+use atoms::Atoms;
+use config::Config;
+use cursors::Cursors;
+use drag::{Drag, DragKind};
+use focus::{FocusHistory, FocusPolicy};
+use frame::{client_size, frame_rectangle, ArgbFrames, ManagedWindow, TITLE_BAR_HEIGHT};
+use hints::SizeHints;
+use keys::{Action, KeyBindings};
+use layout::LAYOUTS;
+use resize::Edge;
+use std::path::PathBuf;
+use text::{AntiAliasedTextRenderer, CoreFontTextRenderer, TextRenderer};
+use workspace::Workspaces;
+use xcb::color::Colormap;
+use xcb::connection::Connection;
+use xcb::event::{CrossingMode, Event, EventMask, MouseButton};
+use xcb::focus::RevertTo;
+use xcb::grab::{AllowEventsMode, ButtonGrab, GrabStatus, KeyboardGrab, PointerGrab};
+use xcb::randr;
+use xcb::render::RenderColor;
+use xcb::result::Error;
+use xcb::window::{AttributeValues, ConfigureRequest, Window, WindowHandle};
 use xcb::Rectangle;
 
+const TITLE_FONT_SIZE: f32 = 14.0;
+const TITLE_CORE_FONT: &str = "fixed";
+const TITLE_FOREGROUND: &str = "#eceff4";
+const TITLE_BACKGROUND: &str = "#3b4252";
+// _NET_WM_DESKTOP of windows shown on every workspace.
+const ALL_WORKSPACES: u32 = 0xffff_ffff;
+const NET_WM_STATE_REMOVE: u32 = 0;
+const NET_WM_STATE_ADD: u32 = 1;
+
+fn create_text_renderer<'a>(
+    connection: &'a Connection,
+    colormap: &Colormap,
+    font_paths: &[PathBuf],
+) -> Box<dyn TextRenderer + 'a> {
+    let foreground = colormap
+        .parse_color(TITLE_FOREGROUND)
+        .expect("Failed to parse the title foreground colour");
+    let background = colormap
+        .parse_color(TITLE_BACKGROUND)
+        .expect("Failed to parse the title background colour");
+
+    match AntiAliasedTextRenderer::new(
+        connection,
+        font_paths,
+        TITLE_FONT_SIZE,
+        RenderColor::from(foreground),
+        RenderColor::from(background),
+    ) {
+        Ok(renderer) => Box::new(renderer),
+        Err(error) => {
+            eprintln!("Falling back to core fonts for titles: {}", error);
+
+            Box::new(
+                CoreFontTextRenderer::new(
+                    connection,
+                    TITLE_CORE_FONT,
+                    colormap
+                        .get_pixel(foreground)
+                        .expect("Failed to allocate the title foreground colour"),
+                    colormap
+                        .get_pixel(background)
+                        .expect("Failed to allocate the title background colour"),
+                )
+                .expect("Failed to open the core font for titles"),
+            )
+        }
+    }
+}
+
+fn get_title(window: &dyn Window, atoms: &Atoms) -> String {
+    if let Ok(Some(property)) = window
+        .get_property(atoms.net_wm_name, atoms.utf8_string)
+        .get_result()
+    {
+        return String::from_utf8_lossy(&property.value).into_owned();
+    }
+
+    if let Ok(Some(property)) = window
+        .get_property(xcb::atom::WM_NAME, xcb::atom::ANY)
+        .get_result()
+    {
+        return property.value.iter().map(|byte| *byte as char).collect();
+    }
+
+    String::new()
+}
+
+fn configure_managed_window(
+    managed_window: &ManagedWindow,
+    windows: &[ManagedWindow],
+    request: &ConfigureRequest,
+) -> Result<(), Error> {
+    let frame_geometry = managed_window.frame.get_geometry().get_result()?;
+    let (current_width, current_height) = client_size(frame_geometry.rectangle);
+
+    let (width, height) = SizeHints::get(&managed_window.client).constrain(
+        request.width.unwrap_or(current_width),
+        request.height.unwrap_or(current_height),
+    );
+
+    // Stacking is relative to the frames, a sibling that isn't managed can't be honoured.
+    let mut stacking = ConfigureRequest::new();
+
+    if let Some(stack_mode) = request.stack_mode {
+        match request.sibling {
+            Some(sibling) => {
+                let sibling = windows
+                    .iter()
+                    .find(|managed_window| managed_window.client.id() == sibling);
+
+                if let Some(sibling) = sibling {
+                    stacking = stacking.sibling(&sibling.frame).stack_mode(stack_mode);
+                }
+            }
+            None => stacking = stacking.stack_mode(stack_mode),
+        }
+    }
+
+    managed_window.place(
+        frame_rectangle(
+            request.x.unwrap_or(frame_geometry.rectangle.x),
+            request.y.unwrap_or(frame_geometry.rectangle.y),
+            width,
+            height,
+        ),
+        stacking,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn start_drag<'a>(
+    connection: &'a Connection,
+    cursors: &Cursors,
+    managed_window: &ManagedWindow,
+    kind: DragKind,
+    frame: Rectangle,
+    pointer: (i16, i16),
+    frame_background: u32,
+    time: u32,
+) -> Option<Drag<'a>> {
+    let cursor = match kind {
+        DragKind::Move => &cursors.moving,
+        DragKind::Resize(edge) => cursors.edge(edge),
+    };
+    let root_window = connection
+        .get_root_window()
+        .expect("Failed to get root window");
+
+    let grab = PointerGrab::new(
+        &root_window,
+        EventMask::POINTER_MOTION | EventMask::BUTTON_RELEASE,
+    )
+    .cursor(cursor)
+    .time(time);
+
+    match connection.grab_pointer(&grab).get_result() {
+        Ok(GrabStatus::Success) => {}
+        grabbed => {
+            eprintln!("Failed to grab the pointer: {:?}", grabbed);
+
+            return None;
+        }
+    }
+
+    // The keyboard is only grabbed so that Escape can cancel the drag.
+    let grab = KeyboardGrab::new(&root_window).time(time);
+
+    if connection.grab_keyboard(&grab).get_result().ok() != Some(GrabStatus::Success) {
+        eprintln!("Failed to grab the keyboard, dragging can't be cancelled");
+    }
+
+    match Drag::new(
+        connection,
+        kind,
+        managed_window.client.id(),
+        frame,
+        pointer,
+        SizeHints::get(&managed_window.client),
+        frame_background,
+    ) {
+        Ok(drag) => Some(drag),
+        Err(error) => {
+            eprintln!("Failed to start dragging: {:?}", error);
+            end_drag(connection, time);
+
+            None
+        }
+    }
+}
+
+fn end_drag(connection: &Connection, time: u32) {
+    if let Err(error) = connection.ungrab_pointer(time).get_result() {
+        eprintln!("Failed to ungrab the pointer: {:?}", error);
+    }
+
+    if let Err(error) = connection.ungrab_keyboard(time).get_result() {
+        eprintln!("Failed to ungrab the keyboard: {:?}", error);
+    }
+}
+
+// Gives the keyboard focus to `client`, or to the root window when there is none, and
+// advertises it through _NET_ACTIVE_WINDOW.
+fn focus(
+    connection: &Connection,
+    root_window: &WindowHandle,
+    atoms: &Atoms,
+    focus_history: &mut FocusHistory,
+    client: Option<&dyn Window>,
+    time: u32,
+) {
+    if let Some(client) = client {
+        focus_history.push(client.id());
+    }
+
+    // The client can be unmapped or destroyed before the request arrives, that's not fatal.
+    if let Err(error) = connection
+        .set_input_focus(
+            Some(client.unwrap_or(root_window)),
+            RevertTo::PointerRoot,
+            time,
+        )
+        .get_result()
+    {
+        eprintln!("Failed to set the input focus: {:?}", error);
+    }
+
+    root_window
+        .change_property32(
+            atoms.net_active_window,
+            xcb::atom::WINDOW,
+            &[client.map_or(xcb::window::NONE, |client| client.id())],
+        )
+        .get_result()
+        .expect("Failed to set the active window");
+}
+
+// Hands the focus back to the most recently focused client that is still around.
+fn focus_previous(
+    connection: &Connection,
+    root_window: &WindowHandle,
+    atoms: &Atoms,
+    focus_history: &mut FocusHistory,
+    windows: &[ManagedWindow],
+) {
+    let previous = focus_history.focused().and_then(|client| {
+        windows
+            .iter()
+            .find(|managed_window| managed_window.client.id() == client)
+    });
+
+    focus(
+        connection,
+        root_window,
+        atoms,
+        focus_history,
+        previous.map(|managed_window| &managed_window.client as &dyn Window),
+        xcb::grab::CURRENT_TIME,
+    );
+}
+
+fn publish_workspaces(root_window: &WindowHandle, atoms: &Atoms, workspaces: &Workspaces) {
+    let names: Vec<u8> = workspaces
+        .names()
+        .flat_map(|name| name.bytes().chain(std::iter::once(0)))
+        .collect();
+
+    root_window
+        .change_property32(
+            atoms.net_number_of_desktops,
+            xcb::atom::CARDINAL,
+            &[workspaces.count() as u32],
+        )
+        .get_result()
+        .expect("Failed to set the number of workspaces");
+    root_window
+        .change_property8(atoms.net_desktop_names, atoms.utf8_string, &names)
+        .get_result()
+        .expect("Failed to set the workspace names");
+    publish_current_workspace(root_window, atoms, workspaces);
+}
+
+fn publish_current_workspace(root_window: &WindowHandle, atoms: &Atoms, workspaces: &Workspaces) {
+    root_window
+        .change_property32(
+            atoms.net_current_desktop,
+            xcb::atom::CARDINAL,
+            &[workspaces.current() as u32],
+        )
+        .get_result()
+        .expect("Failed to set the current workspace");
+}
+
+fn publish_workspace(managed_window: &ManagedWindow, atoms: &Atoms) {
+    let desktop = if managed_window.sticky {
+        ALL_WORKSPACES
+    } else {
+        managed_window.workspace as u32
+    };
+
+    // Only the sticky state is ours, the others were set by the client or other tools.
+    let published = managed_window
+        .client
+        .change_property32(atoms.net_wm_desktop, xcb::atom::CARDINAL, &[desktop])
+        .get_result()
+        .and_then(|_| {
+            managed_window
+                .client
+                .get_property(atoms.net_wm_state, xcb::atom::ATOM)
+                .get_result()
+        })
+        .and_then(|property| {
+            let mut state: Vec<u32> = property
+                .map(|property| property.values32())
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|atom| *atom != atoms.net_wm_state_sticky)
+                .collect();
+
+            if managed_window.sticky {
+                state.push(atoms.net_wm_state_sticky);
+            }
+
+            managed_window
+                .client
+                .change_property32(atoms.net_wm_state, xcb::atom::ATOM, &state)
+                .get_result()
+        });
+
+    // The client can be destroyed before the properties are written.
+    if let Err(error) = published {
+        eprintln!("Failed to set the window's workspace: {:?}", error);
+    }
+}
+
+// Shows the frames of the new workspace before hiding the old ones, the clients stay mapped
+// so they aren't mistaken for withdrawn windows.
+fn switch_workspace(
+    connection: &Connection,
+    root_window: &WindowHandle,
+    atoms: &Atoms,
+    workspaces: &mut Workspaces,
+    windows: &[ManagedWindow],
+    index: usize,
+) {
+    let was_visible: Vec<bool> = windows
+        .iter()
+        .map(|managed_window| managed_window.is_visible(workspaces))
+        .collect();
+
+    if workspaces.switch(index).is_none() {
+        return;
+    }
+
+    for (managed_window, was_visible) in windows.iter().zip(&was_visible) {
+        if managed_window.is_visible(workspaces) && !was_visible {
+            // The workspace may have last been shown on a monitor in another place.
+            if !is_tiled(managed_window, workspaces) {
+                keep_on_monitor(managed_window, workspaces, workspaces.focused_monitor());
+            }
+
+            if let Err(error) = managed_window.frame.map().get_result() {
+                eprintln!("Failed to show window: {:?}", error);
+            }
+        }
+    }
+
+    for (managed_window, was_visible) in windows.iter().zip(&was_visible) {
+        if !managed_window.is_visible(workspaces) && *was_visible {
+            if let Err(error) = managed_window.frame.unmap().get_result() {
+                eprintln!("Failed to hide window: {:?}", error);
+            }
+        }
+    }
+
+    focus_previous(
+        connection,
+        root_window,
+        atoms,
+        workspaces.focus_history(),
+        windows,
+    );
+}
+
+// Moves a client to another workspace, `None` makes it sticky.
+fn set_workspace(
+    connection: &Connection,
+    root_window: &WindowHandle,
+    atoms: &Atoms,
+    workspaces: &mut Workspaces,
+    windows: &mut [ManagedWindow],
+    client: u32,
+    workspace: Option<usize>,
+) {
+    let current = workspaces.current();
+    let was_focused = workspaces.focus_history().focused() == Some(client);
+    let managed_window = match windows
+        .iter_mut()
+        .find(|managed_window| managed_window.client.id() == client)
+    {
+        Some(managed_window) => managed_window,
+        None => return,
+    };
+    let was_visible = managed_window.is_visible(workspaces);
+
+    match workspace {
+        Some(index) if index < workspaces.count() => {
+            managed_window.workspace = index;
+            managed_window.sticky = false;
+        }
+        Some(_) => return,
+        None => managed_window.sticky = true,
+    }
+
+    publish_workspace(managed_window, atoms);
+
+    for index in 0..workspaces.count() {
+        if !managed_window.sticky && managed_window.workspace != index {
+            if let Some(workspace) = workspaces.get_mut(index) {
+                workspace.focus_history.remove(client);
+            }
+        }
+    }
+
+    // The moved window gets the focus once its new workspace is shown.
+    if let Some(workspace) = workspace
+        .filter(|index| *index != current)
+        .and_then(|index| workspaces.get_mut(index))
+    {
+        workspace.focus_history.push(client);
+    }
+
+    let moved_away = !managed_window.sticky && managed_window.workspace != current;
+
+    if managed_window.is_visible(workspaces) {
+        // Shown on another monitor now, or still on this one.
+        if let Some(monitor) = workspaces
+            .monitor_of(managed_window.workspace)
+            .filter(|_| !managed_window.sticky && !is_tiled(managed_window, workspaces))
+        {
+            keep_on_monitor(managed_window, workspaces, monitor);
+        }
+
+        if !was_visible {
+            if let Err(error) = managed_window.frame.map().get_result() {
+                eprintln!("Failed to show window: {:?}", error);
+            }
+        }
+    } else if was_visible {
+        if let Err(error) = managed_window.frame.unmap().get_result() {
+            eprintln!("Failed to hide window: {:?}", error);
+        }
+    }
+
+    if was_focused && moved_away {
+        focus_previous(
+            connection,
+            root_window,
+            atoms,
+            workspaces.focus_history(),
+            windows,
+        );
+    }
+}
+
+fn is_tiled(managed_window: &ManagedWindow, workspaces: &Workspaces) -> bool {
+    !managed_window.floating
+        && !managed_window.sticky
+        && workspaces.is_tiling(managed_window.workspace)
+}
+
+// Lays out the tiled windows of every shown workspace on its monitor, floating ones keep
+// their geometry and are raised above them.
+fn tile(workspaces: &mut Workspaces, windows: &[ManagedWindow]) {
+    for monitor in workspaces.monitors().to_vec() {
+        let tiled: Vec<u32> = windows
+            .iter()
+            .filter(|managed_window| {
+                managed_window.is_visible(workspaces)
+                    && managed_window.workspace == monitor.workspace
+                    && is_tiled(managed_window, workspaces)
+            })
+            .map(|managed_window| managed_window.client.id())
+            .collect();
+
+        let workspace = match workspaces.get_mut(monitor.workspace) {
+            Some(workspace) => workspace,
+            None => continue,
+        };
+        let focused = workspace.focus_history.focused();
+        let layout = match workspace.layout.as_mut() {
+            Some(layout) => layout,
+            None => continue,
+        };
+
+        if let Some(focused) = focused {
+            layout.focus(focused);
+        }
+
+        for (client, rectangle) in layout.arrange(&tiled, monitor.area) {
+            let managed_window = windows
+                .iter()
+                .find(|managed_window| managed_window.client.id() == client);
+
+            // A client that was just destroyed doesn't stop the others from being laid out.
+            if let Some(managed_window) = managed_window {
+                if let Err(error) = managed_window.place(rectangle, ConfigureRequest::new()) {
+                    eprintln!("Failed to tile window: {:?}", error);
+                }
+            }
+        }
+    }
+
+    for managed_window in windows {
+        if managed_window.is_visible(workspaces) && !is_tiled(managed_window, workspaces) {
+            if let Err(error) = managed_window.frame.raise().get_result() {
+                eprintln!("Failed to raise window: {:?}", error);
+            }
+        }
+    }
+}
+
+// `None` when the geometry couldn't be got, which is logged.
+fn frame_geometry(managed_window: &ManagedWindow) -> Option<Rectangle> {
+    match managed_window.frame.get_geometry().get_result() {
+        Ok(geometry) => Some(geometry.rectangle),
+        Err(error) => {
+            eprintln!("Failed to get frame geometry: {:?}", error);
+
+            None
+        }
+    }
+}
+
+fn center(frame: Rectangle) -> (i16, i16) {
+    (
+        frame.x.saturating_add((frame.width / 2) as i16),
+        frame.y.saturating_add((frame.height / 2) as i16),
+    )
+}
+
+fn frame_center(managed_window: &ManagedWindow) -> Option<(i16, i16)> {
+    frame_geometry(managed_window).map(center)
+}
+
+// Moves a floating window onto `monitor` if it isn't there already, at the same position
+// relative to the monitor it was on.
+fn keep_on_monitor(managed_window: &ManagedWindow, workspaces: &Workspaces, monitor: usize) {
+    let frame = match frame_geometry(managed_window) {
+        Some(frame) => frame,
+        None => return,
+    };
+    let (center_x, center_y) = center(frame);
+    let from = workspaces.monitor_at(center_x, center_y);
+
+    if from == Some(monitor) {
+        return;
+    }
+
+    let area = workspaces.monitors()[monitor].area;
+    let (x, y) = match from.map(|from| workspaces.monitors()[from].area) {
+        Some(from) => (
+            i32::from(area.x) + i32::from(frame.x) - i32::from(from.x),
+            i32::from(area.y) + i32::from(frame.y) - i32::from(from.y),
+        ),
+        None => (i32::from(area.x), i32::from(area.y)),
+    };
+    let x = x
+        .min(i32::from(area.x) + i32::from(area.width) - i32::from(frame.width))
+        .max(i32::from(area.x));
+    let y = y
+        .min(i32::from(area.y) + i32::from(area.height) - i32::from(frame.height))
+        .max(i32::from(area.y));
+
+    // The client can be gone already, e.g. when it exits while its monitor is unplugged.
+    if let Err(error) = managed_window.place(
+        Rectangle {
+            x: x as i16,
+            y: y as i16,
+            ..frame
+        },
+        ConfigureRequest::new(),
+    ) {
+        eprintln!("Failed to move window to its monitor: {:?}", error);
+    }
+}
+
+// Focusing a window also focuses the monitor it is on, so it ends up in the history of that
+// monitor's workspace.
+fn focus_monitor_of(workspaces: &mut Workspaces, managed_window: &ManagedWindow) {
+    let monitor = if managed_window.sticky {
+        frame_center(managed_window).and_then(|(x, y)| workspaces.monitor_at(x, y))
+    } else {
+        workspaces.monitor_of(managed_window.workspace)
+    };
+
+    if let Some(monitor) = monitor {
+        workspaces.focus_monitor(monitor);
+    }
+}
+
+fn draw_title(managed_window: &ManagedWindow, text_renderer: &mut dyn TextRenderer) {
+    let frame = match frame_geometry(managed_window) {
+        Some(frame) => frame,
+        None => return,
+    };
+
+    if let Err(error) = text_renderer.draw(
+        &managed_window.frame,
+        managed_window.depth,
+        Rectangle {
+            x: 0,
+            y: 0,
+            width: frame.width,
+            height: TITLE_BAR_HEIGHT,
+        },
+        &managed_window.title,
+    ) {
+        eprintln!("Failed to draw title: {:?}", error);
+    }
+}
+
 fn main() {
-    let connection = xcb::connection::Connection::new().unwrap();
+    let config = Config::from_env();
+
+    if !config.all_screens {
+        run(Connection::new().unwrap(), config);
+        return;
+    }
+
+    // Every screen has its own root window, each one is managed over its own connection on
+    // a thread of its own.
+    let screen_count = Connection::new().unwrap().screens().count() as u32;
+    let threads: Vec<_> = (0..screen_count)
+        .map(|screen| {
+            let mut config = config.clone();
+
+            config.tree_file = config.tree_file.map(|path| {
+                let mut path = path.into_os_string();
+
+                path.push(format!(".{}", screen));
+                PathBuf::from(path)
+            });
+
+            std::thread::spawn(move || {
+                run(
+                    Connection::with_screen(screen).expect("Failed to connect to the screen"),
+                    config,
+                )
+            })
+        })
+        .collect();
+
+    for thread in threads {
+        thread.join().expect("Failed to manage a screen");
+    }
+}
+
+fn run(connection: Connection, config: Config) {
     let root_window = connection.get_root_window().unwrap();
     root_window
         .set_event_mask(
             EventMask::SUBSTRUCTURE_NOTIFY |
             EventMask::SUBSTRUCTURE_REDIRECT |
             EventMask::BUTTON_PRESS |
-            EventMask::BUTTON_RELEASE
+            EventMask::BUTTON_RELEASE |
+            EventMask::ENTER_WINDOW
         )
         .get_result()
         .expect("Failed to get SubstructureNotify and SubstructureRedirect event masks. Is another WM already running?");
@@ -18,100 +689,715 @@ fn main() {
     println!("Vendor: {}", connection.get_vendor().unwrap());
     println!("Window: {:?}", root_window);
 
-    let mut windows = vec![];
-    let mut move_start = None;
-    let mut move_window = None;
+    let atoms = Atoms::new(&connection).expect("Failed to intern atoms");
+    let colormap = Colormap::default(&connection).expect("Failed to get the default colormap");
+    let mut text_renderer = create_text_renderer(&connection, &colormap, &config.title_fonts);
+    let frame_background = colormap
+        .get_pixel_for(TITLE_BACKGROUND)
+        .expect("Failed to allocate the frame background colour");
+    let argb_frames = connection
+        .screen()
+        .expect("Failed to get the screen")
+        .find_argb_visual()
+        .map(|visual| ArgbFrames::new(&connection, visual))
+        .transpose()
+        .expect("Failed to create the colormap for ARGB frames");
+
+    let cursors = Cursors::new(&connection).expect("Failed to create cursors");
+    root_window
+        .change_attributes(&AttributeValues::new().cursor(&cursors.normal))
+        .get_result()
+        .expect("Failed to set the root window cursor");
+    let keyboard_mapping = connection
+        .get_keyboard_mapping()
+        .get_result()
+        .expect("Failed to get the keyboard mapping");
+    let escape_keycodes = keyboard_mapping.keycodes(xcb::keyboard::KEYSYM_ESCAPE);
+
+    let mut workspaces = Workspaces::new(
+        &config.workspaces,
+        config.layout.as_deref(),
+        &connection.monitors().expect("Failed to query the monitors"),
+    );
+    let mut saved_trees = vec![];
+    if let Some(path) = config.tree_file.as_ref() {
+        workspace::restore_trees(path, &mut workspaces);
+    }
+    publish_workspaces(&root_window, &atoms, &workspaces);
+
+    if randr::screen_change_notify_event(&connection).is_some() {
+        randr::select_input(&connection, &root_window, randr::NotifyMask::SCREEN_CHANGE)
+            .get_result()
+            .expect("Failed to select screen change events");
+    }
+
+    let key_bindings = KeyBindings::new(&keyboard_mapping, config.modifier, workspaces.count());
+    key_bindings
+        .grab(&root_window)
+        .expect("Failed to grab the workspace keys");
+
+    for locks in keys::lock_combinations().iter() {
+        for button in [MouseButton::Left, MouseButton::Right].iter() {
+            root_window
+                .grab_button(&ButtonGrab::new(
+                    Some(*button),
+                    config.modifier | *locks,
+                    EventMask::BUTTON_PRESS | EventMask::BUTTON_RELEASE | EventMask::POINTER_MOTION,
+                ))
+                .get_result()
+                .expect("Failed to grab the move and resize buttons");
+        }
+    }
+
+    let mut windows: Vec<ManagedWindow> = vec![];
+    let mut drag: Option<Drag> = None;
+    let mut pending_event = None;
 
     loop {
-        let event = connection.wait_for_event();
+        let event = match pending_event.take() {
+            Some(event) => event,
+            None => connection.wait_for_event(),
+        };
+
+        // Set when the windows of the current workspace have to be laid out again.
+        let mut retile = false;
+        // Tabbed and stacked containers only show the focused window.
+        let focused = workspaces.focus_history().focused();
+        let current = workspaces.current();
 
         match event {
-            Event::WindowConfigurationRequest { window, rectangle } => window
-                .configure(rectangle)
-                .get_result()
-                .expect("Failed to configure window"),
-            Event::WindowMappingRequest { window } => {
-                let geometry = window
-                    .get_geometry()
-                    .get_result()
-                    .expect("Failed to get window geometry");
+            Event::WindowConfigurationRequest { window, request } => {
+                let managed_window = windows
+                    .iter()
+                    .find(|managed_window| managed_window.client.id() == window.id());
 
-                let new_parent = OwnedWindow::new(
-                    &connection,
-                    Rectangle {
-                        x: geometry.rectangle.x,
-                        y: geometry.rectangle.y,
-                        width: geometry.rectangle.width,
-                        height: geometry.rectangle.height + 30,
-                    },
-                )
-                .unwrap();
+                let configured = match managed_window {
+                    // Tiled windows keep the geometry the layout gave them, the client is only
+                    // told about it again.
+                    Some(managed_window) if is_tiled(managed_window, &workspaces) => managed_window
+                        .frame
+                        .get_geometry()
+                        .get_result()
+                        .and_then(|geometry| {
+                            managed_window.place(geometry.rectangle, ConfigureRequest::new())
+                        }),
+                    Some(managed_window) => {
+                        configure_managed_window(managed_window, &windows, &request)
+                    }
+                    None => window.configure_with(&request).get_result(),
+                };
+
+                // The client may exit right after asking, then the request is ignored.
+                if let Err(error) = configured {
+                    eprintln!("Failed to configure window: {:?}", error);
+                }
+            }
+            Event::WindowMappingRequest { window }
+                if windows
+                    .iter()
+                    .any(|managed_window| managed_window.client.id() == window.id()) =>
+            {
+                // A withdrawn client coming back keeps its frame and shows up on the current
+                // workspace.
+                let managed_window = windows
+                    .iter_mut()
+                    .find(|managed_window| managed_window.client.id() == window.id())
+                    .unwrap();
+
+                managed_window.withdrawn = false;
+                managed_window.workspace = workspaces.current();
+                publish_workspace(managed_window, &atoms);
+
+                if let Err(error) = managed_window.frame.map().get_result() {
+                    eprintln!("Failed to show window: {:?}", error);
+                }
 
-                new_parent.map().get_result().expect("Failed to map window");
+                // The client may already be gone again.
+                if let Err(error) = window.map().get_result() {
+                    eprintln!("Failed to map window: {:?}", error);
+                }
 
-                new_parent
-                    .set_event_mask(EventMask::SUBSTRUCTURE_NOTIFY | EventMask::SUBSTRUCTURE_REDIRECT)
+                retile = true;
+            }
+            Event::WindowMappingRequest { window } => {
+                // New windows open on the monitor with the pointer, or the focused one.
+                let pointer = root_window
+                    .query_pointer()
                     .get_result()
-                    .expect("Failed to set event mask during reparenting");
+                    .expect("Failed to query the pointer");
 
-                window.reparent(&new_parent, 0, 30);
+                if let Some(monitor) = workspaces.monitor_at(pointer.root_x, pointer.root_y) {
+                    workspaces.focus_monitor(monitor);
+                }
 
-                window.map().get_result().expect("Failed to map window");
+                // The client can be destroyed before it's framed, it's left alone then.
+                let mut managed_window = match ManagedWindow::new(
+                    &connection,
+                    window,
+                    frame_background,
+                    argb_frames.as_ref(),
+                    &cursors,
+                    config.focus_policy,
+                ) {
+                    Ok(managed_window) => managed_window,
+                    Err(error) => {
+                        eprintln!("Failed to manage window: {:?}", error);
+                        continue;
+                    }
+                };
+
+                managed_window.title = get_title(&window, &atoms);
+                managed_window.workspace = workspaces.current();
+                managed_window.floating = SizeHints::get(&window).is_fixed();
+                publish_workspace(&managed_window, &atoms);
+
+                if !is_tiled(&managed_window, &workspaces) {
+                    keep_on_monitor(&managed_window, &workspaces, workspaces.focused_monitor());
+                }
+                windows.push(managed_window);
+                retile = true;
 
-                windows.push((new_parent, window));
+                if config.focus_policy == FocusPolicy::Click {
+                    focus(
+                        &connection,
+                        &root_window,
+                        &atoms,
+                        workspaces.focus_history(),
+                        Some(&window),
+                        xcb::grab::CURRENT_TIME,
+                    );
+                }
             }
             Event::WindowUnmapped { window } => {
-                let parent = &windows.iter().find(|(_, child)| child.id() == window.id());
+                let managed_window = windows
+                    .iter_mut()
+                    .find(|managed_window| managed_window.client.id() == window.id());
+
+                if let Some(managed_window) = managed_window {
+                    managed_window.withdrawn = true;
+                    retile = true;
 
-                if let Some(parent_child) = parent {
-                    parent_child.0.unmap();
+                    if let Err(error) = managed_window.frame.unmap().get_result() {
+                        eprintln!("Failed to unmap frame: {:?}", error);
+                    }
+                }
+
+                if workspaces.forget(window.id()) {
+                    focus_previous(
+                        &connection,
+                        &root_window,
+                        &atoms,
+                        workspaces.focus_history(),
+                        &windows,
+                    );
                 }
             }
             Event::WindowDestroyed { window } => {
-                windows.retain(|(_, child)| child.id() != window.id());
+                windows.retain(|managed_window| managed_window.client.id() != window.id());
+                retile = true;
+
+                if workspaces.forget(window.id()) {
+                    focus_previous(
+                        &connection,
+                        &root_window,
+                        &atoms,
+                        workspaces.focus_history(),
+                        &windows,
+                    );
+                }
+            }
+            Event::PointerEntered {
+                window,
+                mode: CrossingMode::Normal,
+                time,
+            } if drag.is_none() && config.focus_policy != FocusPolicy::Click => {
+                if window.id() == root_window.id() {
+                    let pointer = root_window
+                        .query_pointer()
+                        .get_result()
+                        .expect("Failed to query the pointer");
+
+                    if let Some(monitor) = workspaces.monitor_at(pointer.root_x, pointer.root_y) {
+                        workspaces.focus_monitor(monitor);
+                    }
+
+                    if config.focus_policy == FocusPolicy::FollowsMouse {
+                        focus(
+                            &connection,
+                            &root_window,
+                            &atoms,
+                            workspaces.focus_history(),
+                            None,
+                            time,
+                        );
+                    }
+                } else {
+                    let managed_window = windows
+                        .iter()
+                        .find(|managed_window| managed_window.frame.id() == window.id());
+
+                    if let Some(managed_window) = managed_window {
+                        focus_monitor_of(&mut workspaces, managed_window);
+                        focus(
+                            &connection,
+                            &root_window,
+                            &atoms,
+                            workspaces.focus_history(),
+                            Some(&managed_window.client),
+                            time,
+                        );
+                    }
+                }
+            }
+            Event::WindowExposed { window, count, .. } => {
+                let managed_window = windows
+                    .iter()
+                    .find(|managed_window| managed_window.frame.id() == window.id());
+
+                if let (Some(managed_window), 0) = (managed_window, count) {
+                    draw_title(managed_window, text_renderer.as_mut());
+                }
+            }
+            Event::WindowPropertyChanged { window, atom }
+                if atom == xcb::atom::WM_NAME || atom == atoms.net_wm_name =>
+            {
+                let managed_window = windows
+                    .iter_mut()
+                    .find(|managed_window| managed_window.client.id() == window.id());
+
+                if let Some(managed_window) = managed_window {
+                    managed_window.title = get_title(&window, &atoms);
+
+                    draw_title(managed_window, text_renderer.as_mut());
+                }
             }
             Event::ButtonPressed {
-                button: MouseButton::Left,
+                window,
                 child_window,
+                button,
+                x,
+                y,
+                modifiers,
+                time,
+                ..
+            } if drag.is_none() => {
+                // Presses on a resize handle are reported on the handle, or on the frame when
+                // it grabs clicks for focusing. Anything else that isn't handled by the client
+                // itself arrives on the root.
+                let target = windows.iter().find_map(|managed_window| {
+                    if let Some(edge) = managed_window.edge(window.id()) {
+                        return Some((managed_window, Some(edge)));
+                    }
+
+                    if managed_window.frame.id() == window.id() {
+                        let edge = child_window.and_then(|child| managed_window.edge(child.id()));
+
+                        return Some((managed_window, edge));
+                    }
+
+                    child_window
+                        .filter(|child| managed_window.frame.id() == child.id())
+                        .map(|_| (managed_window, None))
+                });
+
+                if let Some((managed_window, edge)) = target {
+                    focus_monitor_of(&mut workspaces, managed_window);
+
+                    if workspaces.focus_history().focused() != Some(managed_window.client.id()) {
+                        focus(
+                            &connection,
+                            &root_window,
+                            &atoms,
+                            workspaces.focus_history(),
+                            Some(&managed_window.client),
+                            time,
+                        );
+                    }
+
+                    if let Err(error) = managed_window.frame.raise().get_result() {
+                        eprintln!("Failed to raise window: {:?}", error);
+                    }
+
+                    // Without the frame's geometry there's no drag, the click still has to be
+                    // released below.
+                    let frame = frame_geometry(managed_window);
+                    let with_modifier = modifiers.contains(config.modifier);
+
+                    let kind = frame.and_then(|frame| {
+                        let in_title_bar =
+                            i32::from(y) - i32::from(frame.y) < i32::from(TITLE_BAR_HEIGHT);
+
+                        match (button, edge) {
+                            (MouseButton::Left, Some(edge)) => Some(DragKind::Resize(edge)),
+                            (MouseButton::Left, None) if with_modifier || in_title_bar => {
+                                Some(DragKind::Move)
+                            }
+                            (MouseButton::Right, None) if with_modifier => {
+                                Some(DragKind::Resize(Edge::nearest(frame, x, y)))
+                            }
+                            _ => None,
+                        }
+                    });
+
+                    if let (Some(kind), Some(frame)) = (kind, frame) {
+                        drag = start_drag(
+                            &connection,
+                            &cursors,
+                            managed_window,
+                            kind,
+                            frame,
+                            (x, y),
+                            frame_background,
+                            time,
+                        );
+                    }
+
+                    // A click that was held by the focus grab is either consumed by the drag
+                    // or passed on to the client.
+                    let mode = if kind.is_some() {
+                        AllowEventsMode::AsyncPointer
+                    } else {
+                        AllowEventsMode::ReplayPointer
+                    };
+
+                    if let Err(error) = connection.allow_events(mode, time).get_result() {
+                        eprintln!("Failed to release the grabbed click: {:?}", error);
+                    }
+                } else if let Some(monitor) = workspaces.monitor_at(x, y) {
+                    // A click on an empty part of another monitor moves the focus there.
+                    if monitor != workspaces.focused_monitor() {
+                        workspaces.focus_monitor(monitor);
+                        focus_previous(
+                            &connection,
+                            &root_window,
+                            &atoms,
+                            workspaces.focus_history(),
+                            &windows,
+                        );
+                    }
+                }
+
+                // Dragging a tiled window takes it out of the layout.
+                if let Some(drag) = drag.as_ref() {
+                    let managed_window = windows.iter_mut().find(|managed_window| {
+                        managed_window.client.id() == drag.client
+                            && is_tiled(managed_window, &workspaces)
+                    });
+
+                    if let Some(managed_window) = managed_window {
+                        managed_window.floating = true;
+                        retile = true;
+                    }
+                }
+            }
+            Event::ButtonReleased { time, .. } if drag.is_some() => {
+                drag = None;
+
+                end_drag(&connection, time);
+            }
+            Event::KeyPressed { keycode, time, .. }
+                if drag.is_some() && escape_keycodes.contains(&keycode) =>
+            {
+                let cancelled = drag.take().unwrap();
+                let managed_window = windows
+                    .iter()
+                    .find(|managed_window| managed_window.client.id() == cancelled.client);
+
+                // The client may have died during the drag, then there's nothing to restore.
+                if let Some(managed_window) = managed_window {
+                    if let Err(error) =
+                        managed_window.place(cancelled.original_frame(), ConfigureRequest::new())
+                    {
+                        eprintln!("Failed to restore window geometry: {:?}", error);
+                    }
+                }
+
+                end_drag(&connection, time);
+            }
+            Event::KeyPressed {
+                keycode, modifiers, ..
+            } if drag.is_none() => {
+                let focused = workspaces.focus_history().focused();
+                let current = workspaces.current();
+                let layout = workspaces
+                    .get_mut(current)
+                    .and_then(|workspace| workspace.layout.as_mut());
+
+                match key_bindings.find(keycode, modifiers) {
+                    Some(Action::SwitchWorkspace(index)) => switch_workspace(
+                        &connection,
+                        &root_window,
+                        &atoms,
+                        &mut workspaces,
+                        &windows,
+                        index,
+                    ),
+                    Some(Action::ToggleFloating) => {
+                        let managed_window = windows
+                            .iter_mut()
+                            .find(|managed_window| Some(managed_window.client.id()) == focused);
+
+                        if let Some(managed_window) = managed_window {
+                            managed_window.floating = !managed_window.floating;
+                        }
+                    }
+                    Some(Action::CycleLayout) => {
+                        let next = match layout {
+                            Some(layout) => LAYOUTS
+                                .iter()
+                                .position(|name| *name == layout.name())
+                                .and_then(|index| LAYOUTS.get(index + 1)),
+                            None => LAYOUTS.first(),
+                        };
+
+                        if let Some(workspace) = workspaces.get_mut(current) {
+                            workspace.layout = next.and_then(|name| layout::from_name(name));
+                        }
+                    }
+                    Some(Action::AdjustRatio(delta)) => {
+                        if let Some(layout) = layout {
+                            layout.adjust_ratio(delta);
+                        }
+                    }
+                    Some(Action::AdjustMasterCount(delta)) => {
+                        if let Some(layout) = layout {
+                            layout.adjust_master_count(delta);
+                        }
+                    }
+                    Some(Action::Split(direction)) => {
+                        if let (Some(layout), Some(client)) = (layout, focused) {
+                            layout.split(client, direction);
+                        }
+                    }
+                    Some(Action::MoveWindow(direction)) => {
+                        if let (Some(layout), Some(client)) = (layout, focused) {
+                            layout.move_window(client, direction);
+                        }
+                    }
+                    Some(Action::Resize(direction, delta)) => {
+                        if let (Some(layout), Some(client)) = (layout, focused) {
+                            layout.resize(client, direction, delta);
+                        }
+                    }
+                    Some(Action::SetContainerLayout(container_layout)) => {
+                        if let (Some(layout), Some(client)) = (layout, focused) {
+                            layout.set_container_layout(client, container_layout);
+                        }
+                    }
+                    Some(Action::MoveToWorkspace(index)) => {
+                        if let Some(client) = focused {
+                            set_workspace(
+                                &connection,
+                                &root_window,
+                                &atoms,
+                                &mut workspaces,
+                                &mut windows,
+                                client,
+                                Some(index),
+                            );
+                        }
+                    }
+                    Some(Action::ToggleSticky) => {
+                        if let Some(client) = focused {
+                            let sticky = windows.iter().any(|managed_window| {
+                                managed_window.client.id() == client && managed_window.sticky
+                            });
+                            let workspace = if sticky {
+                                Some(workspaces.current())
+                            } else {
+                                None
+                            };
+
+                            set_workspace(
+                                &connection,
+                                &root_window,
+                                &atoms,
+                                &mut workspaces,
+                                &mut windows,
+                                client,
+                                workspace,
+                            );
+                        }
+                    }
+                    None => {}
+                }
+
+                retile = true;
+            }
+            Event::ClientMessage {
+                message_type, data, ..
+            } if message_type == atoms.net_current_desktop => {
+                switch_workspace(
+                    &connection,
+                    &root_window,
+                    &atoms,
+                    &mut workspaces,
+                    &windows,
+                    data[0] as usize,
+                );
+                retile = true;
+            }
+            Event::ClientMessage {
+                window,
+                message_type,
+                data,
                 ..
-            } => {
-                let grabbed = connection.grab_pointer().get_result();
-                // todo replace with actual error handling & logging
-                println!("Grabbed? {:?}", grabbed);
-                move_window = Some(child_window.unwrap().id());
-            }
-            Event::ButtonReleased {
-                button: MouseButton::Left,
+            } if message_type == atoms.net_wm_desktop => {
+                let workspace = if data[0] == ALL_WORKSPACES {
+                    None
+                } else {
+                    Some(data[0] as usize)
+                };
+
+                set_workspace(
+                    &connection,
+                    &root_window,
+                    &atoms,
+                    &mut workspaces,
+                    &mut windows,
+                    window.id(),
+                    workspace,
+                );
+                retile = true;
+            }
+            // Only stickiness is supported out of the _NET_WM_STATE changes.
+            Event::ClientMessage {
+                window,
+                message_type,
+                data,
                 ..
-            } => {
-                connection.ungrab_pointer().get_result().unwrap(); // todo only do that if we actually grabbed the pointer previously
-                move_start = None;
-                move_window = None;
-            }
-            Event::MotionNotify { x, y, .. } => {
-                if let Some((start_x, start_y)) = move_start {
-                    let offset = (x - start_x, y - start_y);
-                    let parent = &windows
-                        .iter()
-                        .find(|(parent, _)| parent.id() == move_window.unwrap())
-                        .unwrap()
-                        .0;
-
-                    let current_geometry = parent.get_geometry().get_result().unwrap();
-                    parent
-                        .configure(Rectangle {
-                            x: current_geometry.rectangle.x + offset.0,
-                            y: current_geometry.rectangle.y + offset.1,
-                            width: current_geometry.rectangle.width,
-                            height: current_geometry.rectangle.height,
-                        })
-                        .get_result()
-                        .unwrap();
+            } if message_type == atoms.net_wm_state
+                && (data[1] == atoms.net_wm_state_sticky
+                    || data[2] == atoms.net_wm_state_sticky) =>
+            {
+                let sticky = windows.iter().any(|managed_window| {
+                    managed_window.client.id() == window.id() && managed_window.sticky
+                });
+                let make_sticky = match data[0] {
+                    NET_WM_STATE_REMOVE => false,
+                    NET_WM_STATE_ADD => true,
+                    _ => !sticky,
+                };
+                let workspace = if make_sticky {
+                    None
+                } else {
+                    Some(workspaces.current())
+                };
+
+                if make_sticky != sticky {
+                    set_workspace(
+                        &connection,
+                        &root_window,
+                        &atoms,
+                        &mut workspaces,
+                        &mut windows,
+                        window.id(),
+                        workspace,
+                    );
+                    retile = true;
+                }
+            }
+            Event::ScreenChanged { root, .. } if root.id() == root_window.id() => {
+                let removed = workspaces
+                    .set_monitors(&connection.monitors().expect("Failed to query the monitors"));
+                let current = workspaces.current();
+
+                // The windows of a monitor that went away move to the focused one.
+                let orphans: Vec<u32> = windows
+                    .iter()
+                    .filter(|managed_window| {
+                        !managed_window.sticky && removed.contains(&managed_window.workspace)
+                    })
+                    .map(|managed_window| managed_window.client.id())
+                    .collect();
+
+                for client in orphans {
+                    set_workspace(
+                        &connection,
+                        &root_window,
+                        &atoms,
+                        &mut workspaces,
+                        &mut windows,
+                        client,
+                        Some(current),
+                    );
                 }
 
-                move_start = Some((x, y));
+                for managed_window in &windows {
+                    if managed_window.sticky && !managed_window.withdrawn {
+                        let off_monitors = frame_center(managed_window)
+                            .is_some_and(|(x, y)| workspaces.monitor_at(x, y).is_none());
+
+                        if off_monitors {
+                            keep_on_monitor(
+                                managed_window,
+                                &workspaces,
+                                workspaces.focused_monitor(),
+                            );
+                        }
+                    }
+                }
+
+                retile = true;
+            }
+            Event::MotionNotify { x, y, .. } if drag.is_some() => {
+                let active_drag = drag.as_ref().unwrap();
+                let (mut x, mut y) = (x, y);
+
+                // Only the latest pointer position matters, so skip over the motion events
+                // that are already queued behind this one.
+                while let Some(queued_event) = connection.poll_for_queued_event() {
+                    match queued_event {
+                        Event::MotionNotify {
+                            x: queued_x,
+                            y: queued_y,
+                            ..
+                        } => {
+                            x = queued_x;
+                            y = queued_y;
+                        }
+                        queued_event => {
+                            pending_event = Some(queued_event);
+                            break;
+                        }
+                    }
+                }
+
+                let managed_window = windows
+                    .iter()
+                    .find(|managed_window| managed_window.client.id() == active_drag.client);
+
+                if let Some(managed_window) = managed_window {
+                    let frame = active_drag.update(x, y);
+
+                    // The client can be unmapped or destroyed in the middle of the drag, which
+                    // ends it.
+                    let dragged = managed_window
+                        .place(frame, ConfigureRequest::new())
+                        .and_then(|_| active_drag.show_overlay(frame, text_renderer.as_mut()));
+
+                    if let Err(error) = dragged {
+                        eprintln!("Failed to move or resize window: {:?}", error);
+
+                        drag = None;
+                        end_drag(&connection, xcb::grab::CURRENT_TIME);
+                        continue;
+                    }
+                }
+            }
+            event => eprintln!("Unhandled event: {:?}", event),
+        }
+
+        if workspaces.current() != current {
+            publish_current_workspace(&root_window, &atoms, &workspaces);
+        }
+
+        if retile || workspaces.focus_history().focused() != focused {
+            tile(&mut workspaces, &windows);
+
+            if let Some(path) = config.tree_file.as_ref() {
+                workspace::save_trees(path, &workspaces, &mut saved_trees);
             }
-            e => println!("[ ] Got an event! {:?}", e),
         }
     }
 }
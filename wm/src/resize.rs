@@ -0,0 +1,188 @@
+use crate::frame::{FRAME_BORDER, TITLE_BAR_HEIGHT};
+use crate::text::TextRenderer;
+use xcb::connection::Connection;
+use xcb::result::Error;
+use xcb::window::{AttributeValues, ConfigureRequest, OwnedWindow, StackMode, Window};
+use xcb::Rectangle;
+
+const CORNER_SIZE: u16 = 16;
+const OVERLAY_WIDTH: u16 = 120;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Edge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Edge {
+    pub const ALL: [Edge; 8] = [
+        Edge::Top,
+        Edge::Bottom,
+        Edge::Left,
+        Edge::Right,
+        Edge::TopLeft,
+        Edge::TopRight,
+        Edge::BottomLeft,
+        Edge::BottomRight,
+    ];
+
+    // The corner of the quadrant of the frame the pointer is in.
+    pub fn nearest(frame: Rectangle, x: i16, y: i16) -> Self {
+        let left = i32::from(x) - i32::from(frame.x) < i32::from(frame.width) / 2;
+        let top = i32::from(y) - i32::from(frame.y) < i32::from(frame.height) / 2;
+
+        match (left, top) {
+            (true, true) => Edge::TopLeft,
+            (false, true) => Edge::TopRight,
+            (true, false) => Edge::BottomLeft,
+            (false, false) => Edge::BottomRight,
+        }
+    }
+
+    pub fn left(self) -> bool {
+        matches!(self, Edge::Left | Edge::TopLeft | Edge::BottomLeft)
+    }
+
+    pub fn right(self) -> bool {
+        matches!(self, Edge::Right | Edge::TopRight | Edge::BottomRight)
+    }
+
+    pub fn top(self) -> bool {
+        matches!(self, Edge::Top | Edge::TopLeft | Edge::TopRight)
+    }
+
+    pub fn bottom(self) -> bool {
+        matches!(self, Edge::Bottom | Edge::BottomLeft | Edge::BottomRight)
+    }
+
+    // Handles cover the frame border, corners are made of one piece along each of their
+    // edges so that they never overlap the client.
+    pub fn handle_rectangles(self, width: u16, height: u16) -> Vec<Rectangle> {
+        let rectangle = |x: u16, y: u16, width: u16, height: u16| Rectangle {
+            x: x as i16,
+            y: y as i16,
+            width: width.max(1),
+            height: height.max(1),
+        };
+        let corner_width = CORNER_SIZE.min(width);
+        let corner_height = CORNER_SIZE.min(height);
+        let right = width.saturating_sub(FRAME_BORDER);
+        let bottom = height.saturating_sub(FRAME_BORDER);
+
+        match self {
+            Edge::Top => vec![rectangle(
+                corner_width,
+                0,
+                width.saturating_sub(2 * corner_width),
+                FRAME_BORDER,
+            )],
+            Edge::Bottom => vec![rectangle(
+                corner_width,
+                bottom,
+                width.saturating_sub(2 * corner_width),
+                FRAME_BORDER,
+            )],
+            Edge::Left => vec![rectangle(
+                0,
+                corner_height,
+                FRAME_BORDER,
+                height.saturating_sub(2 * corner_height),
+            )],
+            Edge::Right => vec![rectangle(
+                right,
+                corner_height,
+                FRAME_BORDER,
+                height.saturating_sub(2 * corner_height),
+            )],
+            Edge::TopLeft => vec![
+                rectangle(0, 0, corner_width, FRAME_BORDER),
+                rectangle(0, 0, FRAME_BORDER, corner_height),
+            ],
+            Edge::TopRight => vec![
+                rectangle(width - corner_width, 0, corner_width, FRAME_BORDER),
+                rectangle(right, 0, FRAME_BORDER, corner_height),
+            ],
+            Edge::BottomLeft => vec![
+                rectangle(0, bottom, corner_width, FRAME_BORDER),
+                rectangle(0, height - corner_height, FRAME_BORDER, corner_height),
+            ],
+            Edge::BottomRight => vec![
+                rectangle(width - corner_width, bottom, corner_width, FRAME_BORDER),
+                rectangle(right, height - corner_height, FRAME_BORDER, corner_height),
+            ],
+        }
+    }
+}
+
+pub struct SizeOverlay<'a> {
+    window: OwnedWindow<'a>,
+    depth: u8,
+}
+
+impl<'a> SizeOverlay<'a> {
+    pub fn new(connection: &'a Connection, background: u32) -> Result<Self, Error> {
+        let window = OwnedWindow::builder(
+            connection,
+            Rectangle {
+                x: 0,
+                y: 0,
+                width: OVERLAY_WIDTH,
+                height: TITLE_BAR_HEIGHT,
+            },
+        )
+        .attributes(
+            AttributeValues::new()
+                .background_pixel(background)
+                .override_redirect(true),
+        )
+        .build()?
+        .get_result()?;
+
+        window.map().get_result()?;
+
+        Ok(Self {
+            window,
+            depth: connection.screen()?.root_depth,
+        })
+    }
+
+    pub fn show(
+        &self,
+        frame: Rectangle,
+        text: &str,
+        text_renderer: &mut dyn TextRenderer,
+    ) -> Result<(), Error> {
+        let rectangle = Rectangle {
+            x: frame.x + (frame.width as i16 - OVERLAY_WIDTH as i16) / 2,
+            y: frame.y + (frame.height as i16 - TITLE_BAR_HEIGHT as i16) / 2,
+            width: OVERLAY_WIDTH,
+            height: TITLE_BAR_HEIGHT,
+        };
+
+        self.window
+            .configure_with(
+                &ConfigureRequest::new()
+                    .rectangle(rectangle)
+                    .stack_mode(StackMode::Above),
+            )
+            .get_result()?;
+
+        text_renderer.draw(
+            &self.window,
+            self.depth,
+            Rectangle {
+                x: 0,
+                y: 0,
+                width: OVERLAY_WIDTH,
+                height: TITLE_BAR_HEIGHT,
+            },
+            text,
+        )
+    }
+}
@@ -0,0 +1,361 @@
+use fontdue::{Font, FontSettings};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use xcb::connection::Connection;
+use xcb::drawable::Drawable;
+use xcb::font::OwnedFont;
+use xcb::graphics::OwnedGraphicsContext;
+use xcb::render::{
+    GlyphInfo, OwnedGlyphSet, OwnedPicture, PictOp, PictureFormat, RenderColor, StandardFormat,
+};
+use xcb::result::Error;
+use xcb::Rectangle;
+
+const PADDING: i16 = 8;
+
+#[derive(Debug)]
+pub enum TextError {
+    Xcb(Error),
+    NoFonts,
+    UnsupportedVisual,
+}
+
+impl std::fmt::Display for TextError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TextError::Xcb(error) => write!(formatter, "X request failed: {:?}", error),
+            TextError::NoFonts => write!(formatter, "none of the configured fonts could be loaded"),
+            TextError::UnsupportedVisual => {
+                write!(formatter, "no XRender picture format matches the visual")
+            }
+        }
+    }
+}
+
+impl From<Error> for TextError {
+    fn from(original_error: Error) -> Self {
+        TextError::Xcb(original_error)
+    }
+}
+
+pub trait TextRenderer {
+    fn measure(&mut self, text: &str) -> Result<u32, Error>;
+    fn ellipsis(&self) -> &'static str;
+    fn draw(
+        &mut self,
+        drawable: &dyn Drawable,
+        depth: u8,
+        rectangle: Rectangle,
+        text: &str,
+    ) -> Result<(), Error>;
+
+    fn ellipsize(&mut self, text: &str, maximum_width: u32) -> Result<String, Error> {
+        if self.measure(text)? <= maximum_width {
+            return Ok(text.to_string());
+        }
+
+        let ellipsis = self.ellipsis();
+        let boundaries: Vec<usize> = text.char_indices().map(|(index, _)| index).collect();
+        let ellipsized = |characters: usize| {
+            format!("{}{}", text[..boundaries[characters]].trim_end(), ellipsis)
+        };
+
+        let (mut low, mut high) = (0, boundaries.len() - 1);
+
+        while low < high {
+            let middle = (low + high).div_ceil(2);
+
+            if self.measure(&ellipsized(middle))? <= maximum_width {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        let candidate = ellipsized(low);
+
+        if self.measure(&candidate)? <= maximum_width {
+            Ok(candidate)
+        } else {
+            Ok(String::new())
+        }
+    }
+}
+
+// Pixels of 32-bit drawables carry an alpha channel above the colour.
+const OPAQUE: u32 = 0xff00_0000;
+
+pub struct CoreFontTextRenderer<'a> {
+    connection: &'a Connection,
+    font: OwnedFont<'a>,
+    foreground: u32,
+    background: u32,
+    // Graphics contexts only work on drawables of the depth they were created for, those for
+    // other depths than the root's are created on first use.
+    contexts: HashMap<u8, (OwnedGraphicsContext<'a>, OwnedGraphicsContext<'a>)>,
+    ascent: i16,
+    descent: i16,
+}
+
+impl<'a> CoreFontTextRenderer<'a> {
+    pub fn new(
+        connection: &'a Connection,
+        name: &str,
+        foreground: u32,
+        background: u32,
+    ) -> Result<Self, Error> {
+        let font = OwnedFont::open(connection, name)?;
+        let extents = font.query_text_extents("").get_result()?;
+
+        let mut renderer = Self {
+            connection,
+            font,
+            foreground,
+            background,
+            contexts: HashMap::new(),
+            ascent: extents.font_ascent,
+            descent: extents.font_descent,
+        };
+
+        renderer.contexts(
+            &connection.get_root_window()?,
+            connection.screen()?.root_depth,
+        )?;
+
+        Ok(renderer)
+    }
+
+    fn contexts(
+        &mut self,
+        drawable: &dyn Drawable,
+        depth: u8,
+    ) -> Result<&(OwnedGraphicsContext<'a>, OwnedGraphicsContext<'a>), Error> {
+        if !self.contexts.contains_key(&depth) {
+            let alpha = if depth == 32 { OPAQUE } else { 0 };
+            let text_context = OwnedGraphicsContext::new(self.connection, drawable)?;
+            let background_context = OwnedGraphicsContext::new(self.connection, drawable)?;
+
+            text_context.set_font(&self.font).get_result()?;
+            text_context
+                .set_foreground(self.foreground | alpha)
+                .get_result()?;
+            text_context
+                .set_background(self.background | alpha)
+                .get_result()?;
+            background_context
+                .set_foreground(self.background | alpha)
+                .get_result()?;
+
+            self.contexts
+                .insert(depth, (text_context, background_context));
+        }
+
+        Ok(&self.contexts[&depth])
+    }
+
+    fn to_ascii(text: &str) -> String {
+        text.chars()
+            .map(|character| if character.is_ascii() { character } else { '?' })
+            .collect()
+    }
+}
+
+impl TextRenderer for CoreFontTextRenderer<'_> {
+    fn measure(&mut self, text: &str) -> Result<u32, Error> {
+        let extents = self
+            .font
+            .query_text_extents(&Self::to_ascii(text))
+            .get_result()?;
+
+        Ok(extents.overall_width as u32)
+    }
+
+    fn ellipsis(&self) -> &'static str {
+        "..."
+    }
+
+    fn draw(
+        &mut self,
+        drawable: &dyn Drawable,
+        depth: u8,
+        rectangle: Rectangle,
+        text: &str,
+    ) -> Result<(), Error> {
+        let text = self.ellipsize(
+            &Self::to_ascii(text),
+            u32::from(rectangle.width).saturating_sub(2 * PADDING as u32),
+        )?;
+
+        let ascent = self.ascent;
+        let descent = self.descent;
+        let (text_context, background_context) = self.contexts(drawable, depth)?;
+
+        background_context
+            .fill_rectangle(drawable, rectangle)
+            .get_result()?;
+        text_context
+            .image_text8(
+                drawable,
+                rectangle.x + PADDING,
+                rectangle.y + (rectangle.height as i16 + ascent - descent) / 2,
+                text.as_bytes(),
+            )
+            .get_result()
+    }
+}
+
+pub struct AntiAliasedTextRenderer<'a> {
+    connection: &'a Connection,
+    fonts: Vec<Font>,
+    size: f32,
+    ascent: i16,
+    descent: i16,
+    glyph_set: OwnedGlyphSet<'a>,
+    advances: HashMap<char, i16>,
+    window_format: PictureFormat,
+    // For the titles of frames with an ARGB visual.
+    argb_format: Option<PictureFormat>,
+    pen: OwnedPicture<'a>,
+    background: RenderColor,
+}
+
+impl<'a> AntiAliasedTextRenderer<'a> {
+    pub fn new(
+        connection: &'a Connection,
+        font_paths: &[PathBuf],
+        size: f32,
+        foreground: RenderColor,
+        background: RenderColor,
+    ) -> Result<Self, TextError> {
+        let fonts: Vec<Font> = font_paths
+            .iter()
+            .filter_map(|path| std::fs::read(path).ok())
+            .filter_map(|data| Font::from_bytes(data, FontSettings::default()).ok())
+            .collect();
+
+        let line_metrics = fonts
+            .first()
+            .and_then(|font| font.horizontal_line_metrics(size))
+            .ok_or(TextError::NoFonts)?;
+
+        xcb::render::query_version(connection).get_result()?;
+        let formats = xcb::render::query_picture_formats(connection).get_result()?;
+
+        let window_format = formats
+            .find_for_visual(connection.get_root_visual()?)
+            .ok_or(TextError::UnsupportedVisual)?;
+        let glyph_format = formats
+            .find_standard(StandardFormat::A8)
+            .ok_or(TextError::UnsupportedVisual)?;
+
+        Ok(Self {
+            connection,
+            fonts,
+            size,
+            ascent: line_metrics.ascent.round() as i16,
+            descent: (-line_metrics.descent).round() as i16,
+            glyph_set: OwnedGlyphSet::new(connection, glyph_format)?,
+            advances: HashMap::new(),
+            window_format,
+            argb_format: formats.find_standard(StandardFormat::Argb32),
+            pen: OwnedPicture::solid_fill(connection, foreground)?,
+            background,
+        })
+    }
+
+    fn load_glyph(&mut self, character: char) -> Result<i16, Error> {
+        if let Some(advance) = self.advances.get(&character) {
+            return Ok(*advance);
+        }
+
+        let font = self
+            .fonts
+            .iter()
+            .find(|font| font.lookup_glyph_index(character) != 0)
+            .unwrap_or(&self.fonts[0]);
+        let (metrics, bitmap) = font.rasterize(character, self.size);
+
+        let stride = (metrics.width + 3) & !3;
+        let mut data = vec![0; stride * metrics.height];
+
+        for (row, pixels) in bitmap.chunks(metrics.width.max(1)).enumerate() {
+            data[row * stride..row * stride + pixels.len()].copy_from_slice(pixels);
+        }
+
+        let advance = metrics.advance_width.round() as i16;
+
+        self.glyph_set
+            .add_glyph(
+                character as u32,
+                GlyphInfo {
+                    width: metrics.width as u16,
+                    height: metrics.height as u16,
+                    x: -metrics.xmin as i16,
+                    y: (metrics.height as i32 + metrics.ymin) as i16,
+                    x_offset: advance,
+                    y_offset: 0,
+                },
+                &data,
+            )
+            .get_result()?;
+
+        self.advances.insert(character, advance);
+
+        Ok(advance)
+    }
+}
+
+impl TextRenderer for AntiAliasedTextRenderer<'_> {
+    fn measure(&mut self, text: &str) -> Result<u32, Error> {
+        let mut width = 0;
+
+        for character in text.chars() {
+            width += i32::from(self.load_glyph(character)?);
+        }
+
+        Ok(width.max(0) as u32)
+    }
+
+    fn ellipsis(&self) -> &'static str {
+        "\u{2026}"
+    }
+
+    fn draw(
+        &mut self,
+        drawable: &dyn Drawable,
+        depth: u8,
+        rectangle: Rectangle,
+        text: &str,
+    ) -> Result<(), Error> {
+        let text = self.ellipsize(
+            text,
+            u32::from(rectangle.width).saturating_sub(2 * PADDING as u32),
+        )?;
+        let format = Some(self.window_format)
+            .filter(|format| format.depth == depth)
+            .or(self.argb_format.filter(|format| format.depth == depth))
+            .ok_or(Error::UnsupportedDepth(depth))?;
+        let picture = OwnedPicture::new(self.connection, drawable, format)?;
+
+        picture
+            .fill_rectangles(PictOp::Src, self.background, &[rectangle])
+            .get_result()?;
+
+        let glyphs: Vec<u32> = text.chars().map(|character| character as u32).collect();
+
+        if glyphs.is_empty() {
+            return Ok(());
+        }
+
+        self.glyph_set
+            .composite(
+                PictOp::Over,
+                &self.pen,
+                &picture,
+                rectangle.x + PADDING,
+                rectangle.y + (rectangle.height as i16 + self.ascent - self.descent) / 2,
+                &glyphs,
+            )
+            .get_result()
+    }
+}
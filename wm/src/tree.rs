@@ -0,0 +1,726 @@
+use crate::frame::TITLE_BAR_HEIGHT;
+use crate::layout::{divide, Direction, Layout, SplitDirection};
+use serde::{Deserialize, Serialize};
+use xcb::Rectangle;
+
+const MINIMUM_SIZE: f32 = 0.05;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContainerLayout {
+    Horizontal,
+    Vertical,
+    // Only the focused child is shown, the others are reduced to their title bars, side by
+    // side in tabbed containers and one above the other in stacked ones.
+    Tabbed,
+    Stacked,
+}
+
+impl ContainerLayout {
+    fn orientation(self) -> SplitDirection {
+        match self {
+            ContainerLayout::Horizontal | ContainerLayout::Tabbed => SplitDirection::Horizontal,
+            ContainerLayout::Vertical | ContainerLayout::Stacked => SplitDirection::Vertical,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Container {
+    pub layout: ContainerLayout,
+    #[serde(default)]
+    pub focused: usize,
+    pub children: Vec<Node>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Node {
+    // Share of the parent along its orientation, the shares of siblings add up to 1.
+    pub size: f32,
+    #[serde(flatten)]
+    pub content: Content,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Content {
+    Window {
+        window: u32,
+        // Restored windows hold their place until a window is managed to fill it.
+        #[serde(skip_serializing, default = "restored")]
+        placeholder: bool,
+    },
+    Container(Container),
+}
+
+fn restored() -> bool {
+    true
+}
+
+impl Node {
+    fn window(window: u32) -> Self {
+        Self {
+            size: 1.0,
+            content: Content::Window {
+                window,
+                placeholder: false,
+            },
+        }
+    }
+}
+
+impl Container {
+    fn new(layout: ContainerLayout) -> Self {
+        Self {
+            layout,
+            focused: 0,
+            children: vec![],
+        }
+    }
+
+    // The child indices leading to the leaf of `window`.
+    fn path_to(&self, window: u32) -> Option<Vec<usize>> {
+        self.children
+            .iter()
+            .enumerate()
+            .find_map(|(index, child)| match &child.content {
+                Content::Window {
+                    window: leaf,
+                    placeholder: false,
+                } if *leaf == window => Some(vec![index]),
+                Content::Container(container) => container.path_to(window).map(|mut path| {
+                    path.insert(0, index);
+                    path
+                }),
+                Content::Window { .. } => None,
+            })
+    }
+
+    fn container_at(&mut self, path: &[usize]) -> &mut Container {
+        match path.split_first() {
+            None => self,
+            Some((index, rest)) => match &mut self.children[*index].content {
+                Content::Container(container) => container.container_at(rest),
+                Content::Window { .. } => unreachable!("Path leads through a window"),
+            },
+        }
+    }
+
+    // Inserts a child with an equal share, the others shrink to make room for it.
+    fn adopt(&mut self, index: usize, mut node: Node) {
+        let count = self.children.len() as f32;
+
+        node.size = 1.0 / (count + 1.0);
+
+        for child in &mut self.children {
+            child.size *= count / (count + 1.0);
+        }
+
+        self.children.insert(index.min(self.children.len()), node);
+    }
+
+    fn release(&mut self, index: usize) -> Node {
+        let node = self.children.remove(index);
+        let total: f32 = self.children.iter().map(|child| child.size).sum();
+
+        for child in &mut self.children {
+            child.size /= total.max(f32::EPSILON);
+        }
+
+        if self.focused >= self.children.len() {
+            self.focused = self.children.len().saturating_sub(1);
+        }
+
+        node
+    }
+
+    // Removes managed windows that are gone and the containers left empty.
+    fn retain(&mut self, windows: &[u32]) {
+        let mut index = 0;
+
+        while index < self.children.len() {
+            let keep = match &mut self.children[index].content {
+                Content::Window {
+                    window,
+                    placeholder,
+                } => *placeholder || windows.contains(window),
+                Content::Container(container) => {
+                    container.retain(windows);
+
+                    !container.children.is_empty()
+                }
+            };
+
+            if keep {
+                index += 1;
+            } else {
+                self.release(index);
+            }
+        }
+    }
+
+    // Removes the containers a window was moved out of.
+    fn prune(&mut self) {
+        let mut index = 0;
+
+        while index < self.children.len() {
+            let keep = match &mut self.children[index].content {
+                Content::Window { .. } => true,
+                Content::Container(container) => {
+                    container.prune();
+
+                    !container.children.is_empty()
+                }
+            };
+
+            if keep {
+                index += 1;
+            } else {
+                self.release(index);
+            }
+        }
+    }
+
+    // Fills the placeholder restored for `window`, or the first one if there is none.
+    fn fill_placeholder(&mut self, window: u32, exact: bool) -> bool {
+        self.children
+            .iter_mut()
+            .any(|child| match &mut child.content {
+                Content::Window {
+                    window: leaf,
+                    placeholder,
+                } if *placeholder && (!exact || *leaf == window) => {
+                    *leaf = window;
+                    *placeholder = false;
+
+                    true
+                }
+                Content::Container(container) => container.fill_placeholder(window, exact),
+                Content::Window { .. } => false,
+            })
+    }
+
+    fn place(&self, area: Rectangle, placed: &mut Vec<(u32, Rectangle)>) {
+        // The root stays around when its last window closes.
+        if self.children.is_empty() {
+            return;
+        }
+
+        match self.layout {
+            ContainerLayout::Horizontal | ContainerLayout::Vertical => {
+                let direction = self.layout.orientation();
+                let length = match direction {
+                    SplitDirection::Horizontal => area.width,
+                    SplitDirection::Vertical => area.height,
+                } as f32;
+                let total: f32 = self.children.iter().map(|child| child.size).sum();
+                let mut start = 0.0;
+
+                for child in &self.children {
+                    let end = start + child.size / total.max(f32::EPSILON);
+                    let offset = (start * length).round() as u16;
+                    let size = (end * length).round() as u16 - offset;
+                    let child_area = match direction {
+                        SplitDirection::Horizontal => Rectangle {
+                            x: area.x.saturating_add(offset as i16),
+                            width: size,
+                            ..area
+                        },
+                        SplitDirection::Vertical => Rectangle {
+                            y: area.y.saturating_add(offset as i16),
+                            height: size,
+                            ..area
+                        },
+                    };
+
+                    child.place(child_area, placed);
+                    start = end;
+                }
+            }
+            ContainerLayout::Tabbed | ContainerLayout::Stacked => {
+                if self.children.len() == 1 {
+                    self.children[0].place(area, placed);
+
+                    return;
+                }
+
+                // The title bars of the hidden children form the strip, the focused child
+                // gets the rest of the area below it.
+                let focused = self.focused.min(self.children.len() - 1);
+                let hidden = self.children.len() - 1;
+                let strip_height = match self.layout {
+                    ContainerLayout::Tabbed => TITLE_BAR_HEIGHT,
+                    _ => TITLE_BAR_HEIGHT.saturating_mul(hidden as u16),
+                }
+                .min(area.height.saturating_sub(1));
+                let strip = Rectangle {
+                    height: strip_height,
+                    ..area
+                };
+                let titles = divide(strip, self.layout.orientation(), hidden);
+
+                for (child, title) in self
+                    .children
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| *index != focused)
+                    .map(|(_, child)| child)
+                    .zip(titles)
+                {
+                    child.place(title, placed);
+                }
+
+                self.children[focused].place(
+                    Rectangle {
+                        y: area.y.saturating_add(strip_height as i16),
+                        height: area.height - strip_height,
+                        ..area
+                    },
+                    placed,
+                );
+            }
+        }
+    }
+}
+
+impl Node {
+    fn place(&self, area: Rectangle, placed: &mut Vec<(u32, Rectangle)>) {
+        match &self.content {
+            Content::Window {
+                window,
+                placeholder: false,
+            } => placed.push((*window, area)),
+            Content::Window { .. } => {}
+            Content::Container(container) => container.place(area, placed),
+        }
+    }
+}
+
+// Manual tiling with nested containers. New windows are opened next to the focused one,
+// which can then be moved around, resized and regrouped into tabbed and stacked containers.
+pub struct Tree {
+    root: Container,
+    focused: Option<u32>,
+}
+
+impl Default for Tree {
+    fn default() -> Self {
+        Self {
+            root: Container::new(ContainerLayout::Horizontal),
+            focused: None,
+        }
+    }
+}
+
+impl Tree {
+    pub fn save(&self) -> serde_json::Value {
+        serde_json::to_value(&self.root).unwrap_or(serde_json::Value::Null)
+    }
+
+    pub fn restore(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            root: serde_json::from_value(value)?,
+            focused: None,
+        })
+    }
+
+    fn insert(&mut self, window: u32) {
+        if self.root.fill_placeholder(window, true) || self.root.fill_placeholder(window, false) {
+            return;
+        }
+
+        let path = self.focused.and_then(|focused| self.root.path_to(focused));
+
+        match path {
+            Some(path) => {
+                let (index, parent) = path.split_last().unwrap();
+
+                self.root
+                    .container_at(parent)
+                    .adopt(index + 1, Node::window(window));
+            }
+            None => {
+                let count = self.root.children.len();
+
+                self.root.adopt(count, Node::window(window));
+            }
+        }
+    }
+}
+
+impl Layout for Tree {
+    fn name(&self) -> &'static str {
+        "tree"
+    }
+
+    fn arrange(&mut self, windows: &[u32], area: Rectangle) -> Vec<(u32, Rectangle)> {
+        self.root.retain(windows);
+
+        for window in windows {
+            if self.root.path_to(*window).is_none() {
+                self.insert(*window);
+            }
+        }
+
+        let mut placed = vec![];
+
+        self.root.place(area, &mut placed);
+
+        placed
+    }
+
+    fn focus(&mut self, window: u32) {
+        let path = match self.root.path_to(window) {
+            Some(path) => path,
+            None => return,
+        };
+
+        self.focused = Some(window);
+
+        for depth in 0..path.len() {
+            self.root.container_at(&path[..depth]).focused = path[depth];
+        }
+    }
+
+    // Wraps the window in a new container, so the windows opened next to it are split along
+    // `direction`.
+    fn split(&mut self, window: u32, direction: SplitDirection) {
+        let path = match self.root.path_to(window) {
+            Some(path) => path,
+            None => return,
+        };
+        let (index, parent) = path.split_last().unwrap();
+        let node = &mut self.root.container_at(parent).children[*index];
+        let layout = match direction {
+            SplitDirection::Horizontal => ContainerLayout::Horizontal,
+            SplitDirection::Vertical => ContainerLayout::Vertical,
+        };
+        let leaf = std::mem::replace(
+            &mut node.content,
+            Content::Container(Container::new(layout)),
+        );
+
+        if let Content::Container(container) = &mut node.content {
+            container.children.push(Node {
+                size: 1.0,
+                content: leaf,
+            });
+        }
+    }
+
+    // Swaps the window with its neighbour, moves it into a neighbouring container or out of
+    // its own one, like i3 does.
+    fn move_window(&mut self, window: u32, direction: Direction) {
+        let path = match self.root.path_to(window) {
+            Some(path) => path,
+            None => return,
+        };
+        let orientation = direction.orientation();
+        let forward = direction.is_forward();
+
+        for depth in (0..path.len()).rev() {
+            let container = self.root.container_at(&path[..depth]);
+
+            let index = path[depth];
+            let is_parent = depth == path.len() - 1;
+
+            // Moving out of the only child would leave the container empty.
+            if container.layout.orientation() != orientation
+                || (!is_parent && container.children.len() < 2)
+            {
+                continue;
+            }
+
+            if is_parent {
+                let neighbour = if forward {
+                    index + 1
+                } else {
+                    match index.checked_sub(1) {
+                        Some(neighbour) => neighbour,
+                        None => continue,
+                    }
+                };
+
+                if neighbour >= container.children.len() {
+                    continue;
+                }
+
+                if let Content::Container(_) = container.children[neighbour].content {
+                    let node = container.release(index);
+                    let neighbour = if forward { neighbour - 1 } else { neighbour };
+
+                    if let Content::Container(target) = &mut container.children[neighbour].content {
+                        let position = if forward { 0 } else { target.children.len() };
+
+                        target.adopt(position, node);
+                    }
+                } else {
+                    container.children.swap(index, neighbour);
+                }
+            } else {
+                let count = container.children.len();
+                let (leaf_index, parent) = path.split_last().unwrap();
+                let node = self.root.container_at(parent).release(*leaf_index);
+
+                self.root.prune();
+
+                // The container the window came from may have been removed with it.
+                let container = self.root.container_at(&path[..depth]);
+                let position = if container.children.len() < count || !forward {
+                    index
+                } else {
+                    index + 1
+                };
+
+                container.adopt(position, node);
+            }
+
+            return;
+        }
+
+        // Nothing along `direction` to move past, so the window goes to the edge of a new
+        // root container.
+        let (leaf_index, parent) = path.split_last().unwrap();
+        let node = self.root.container_at(parent).release(*leaf_index);
+
+        self.root.prune();
+
+        let layout = match orientation {
+            SplitDirection::Horizontal => ContainerLayout::Horizontal,
+            SplitDirection::Vertical => ContainerLayout::Vertical,
+        };
+        let old_root = std::mem::replace(&mut self.root, Container::new(layout));
+
+        if !old_root.children.is_empty() {
+            self.root.adopt(
+                0,
+                Node {
+                    size: 1.0,
+                    content: Content::Container(old_root),
+                },
+            );
+        }
+
+        let position = if forward { self.root.children.len() } else { 0 };
+
+        self.root.adopt(position, node);
+    }
+
+    // Grows or shrinks the window, or the closest container around it that is split along
+    // `direction`, taking the space from its siblings.
+    fn resize(&mut self, window: u32, direction: SplitDirection, delta: f32) {
+        let path = match self.root.path_to(window) {
+            Some(path) => path,
+            None => return,
+        };
+
+        for depth in (0..path.len()).rev() {
+            let container = self.root.container_at(&path[..depth]);
+
+            if container.layout.orientation() != direction || container.children.len() < 2 {
+                continue;
+            }
+
+            let index = path[depth];
+            let old_size = container.children[index].size;
+            let new_size = (old_size + delta).clamp(MINIMUM_SIZE, 1.0 - MINIMUM_SIZE);
+            let scale = (1.0 - new_size) / (1.0 - old_size).max(f32::EPSILON);
+
+            for (sibling, child) in container.children.iter_mut().enumerate() {
+                if sibling == index {
+                    child.size = new_size;
+                } else {
+                    child.size *= scale;
+                }
+            }
+
+            return;
+        }
+    }
+
+    // `None` toggles between horizontal and vertical splits.
+    fn set_container_layout(&mut self, window: u32, layout: Option<ContainerLayout>) {
+        let path = match self.root.path_to(window) {
+            Some(path) => path,
+            None => return,
+        };
+        let container = self.root.container_at(&path[..path.len() - 1]);
+
+        container.layout = layout.unwrap_or(match container.layout {
+            ContainerLayout::Horizontal => ContainerLayout::Vertical,
+            _ => ContainerLayout::Horizontal,
+        });
+    }
+
+    fn save(&self) -> Option<serde_json::Value> {
+        Some(Tree::save(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AREA: Rectangle = Rectangle {
+        x: 0,
+        y: 0,
+        width: 100,
+        height: 100,
+    };
+
+    // Rectangle doesn't implement PartialEq.
+    fn bounds(placed: &[(u32, Rectangle)]) -> Vec<(u32, i16, i16, u16, u16)> {
+        placed
+            .iter()
+            .map(|(window, area)| (*window, area.x, area.y, area.width, area.height))
+            .collect()
+    }
+
+    // Window 3 is opened below window 1, in a vertical container next to window 2.
+    fn split_tree() -> Tree {
+        let mut tree = Tree::default();
+
+        tree.arrange(&[1, 2], AREA);
+        tree.focus(1);
+        tree.split(1, SplitDirection::Vertical);
+        tree.arrange(&[1, 2, 3], AREA);
+
+        tree
+    }
+
+    #[test]
+    fn windows_are_opened_next_to_the_focused_one() {
+        let mut tree = Tree::default();
+
+        tree.arrange(&[1, 2], AREA);
+        tree.focus(1);
+
+        assert_eq!(
+            bounds(&tree.arrange(&[1, 2, 3], AREA)),
+            vec![(1, 0, 0, 33, 100), (3, 33, 0, 34, 100), (2, 67, 0, 33, 100)]
+        );
+    }
+
+    #[test]
+    fn split_opens_windows_along_the_direction() {
+        let mut tree = split_tree();
+
+        assert_eq!(
+            bounds(&tree.arrange(&[1, 2, 3], AREA)),
+            vec![(1, 0, 0, 50, 50), (3, 0, 50, 50, 50), (2, 50, 0, 50, 100)]
+        );
+    }
+
+    #[test]
+    fn empty_containers_are_removed() {
+        let mut tree = split_tree();
+
+        assert_eq!(bounds(&tree.arrange(&[2], AREA)), vec![(2, 0, 0, 100, 100)]);
+    }
+
+    #[test]
+    fn move_swaps_with_the_neighbour() {
+        let mut tree = Tree::default();
+
+        tree.arrange(&[1, 2, 3], AREA);
+        tree.move_window(1, Direction::Right);
+
+        assert_eq!(
+            bounds(&tree.arrange(&[1, 2, 3], AREA)),
+            vec![(2, 0, 0, 33, 100), (1, 33, 0, 34, 100), (3, 67, 0, 33, 100)]
+        );
+    }
+
+    #[test]
+    fn move_enters_a_neighbouring_container() {
+        let mut tree = split_tree();
+
+        tree.move_window(2, Direction::Left);
+
+        assert_eq!(
+            bounds(&tree.arrange(&[1, 2, 3], AREA)),
+            vec![(1, 0, 0, 100, 33), (3, 0, 33, 100, 34), (2, 0, 67, 100, 33)]
+        );
+    }
+
+    #[test]
+    fn move_leaves_the_container() {
+        let mut tree = split_tree();
+
+        tree.move_window(3, Direction::Right);
+
+        assert_eq!(
+            bounds(&tree.arrange(&[1, 2, 3], AREA)),
+            vec![(1, 0, 0, 33, 100), (3, 33, 0, 34, 100), (2, 67, 0, 33, 100)]
+        );
+    }
+
+    #[test]
+    fn move_past_the_edge_creates_a_new_root() {
+        let mut tree = Tree::default();
+
+        tree.arrange(&[1, 2], AREA);
+        tree.move_window(2, Direction::Up);
+
+        assert_eq!(
+            bounds(&tree.arrange(&[1, 2], AREA)),
+            vec![(2, 0, 0, 100, 50), (1, 0, 50, 100, 50)]
+        );
+    }
+
+    #[test]
+    fn resize_takes_the_space_from_the_siblings() {
+        let mut tree = Tree::default();
+
+        tree.arrange(&[1, 2, 3], AREA);
+        tree.resize(1, SplitDirection::Horizontal, 0.17);
+
+        assert_eq!(
+            bounds(&tree.arrange(&[1, 2, 3], AREA)),
+            vec![(1, 0, 0, 50, 100), (2, 50, 0, 25, 100), (3, 75, 0, 25, 100)]
+        );
+
+        tree.resize(1, SplitDirection::Horizontal, 1.0);
+
+        assert_eq!(
+            bounds(&tree.arrange(&[1, 2, 3], AREA)),
+            vec![(1, 0, 0, 95, 100), (2, 95, 0, 3, 100), (3, 98, 0, 2, 100)]
+        );
+    }
+
+    #[test]
+    fn resize_uses_the_closest_container_along_the_direction() {
+        let mut tree = split_tree();
+
+        tree.resize(3, SplitDirection::Horizontal, 0.25);
+
+        assert_eq!(
+            bounds(&tree.arrange(&[1, 2, 3], AREA)),
+            vec![(1, 0, 0, 75, 50), (3, 0, 50, 75, 50), (2, 75, 0, 25, 100)]
+        );
+    }
+
+    #[test]
+    fn tabbed_and_stacked_roots_can_be_emptied() {
+        for layout in [ContainerLayout::Tabbed, ContainerLayout::Stacked].iter() {
+            let mut tree = Tree::default();
+
+            tree.arrange(&[1, 2], AREA);
+            tree.set_container_layout(1, Some(*layout));
+
+            assert!(tree.arrange(&[], AREA).is_empty());
+        }
+    }
+
+    #[test]
+    fn restored_placeholders_are_filled() {
+        let tree = split_tree();
+        let mut restored = Tree::restore(tree.save()).unwrap();
+
+        assert!(restored.arrange(&[], AREA).is_empty());
+        assert_eq!(
+            bounds(&restored.arrange(&[3, 4], AREA)),
+            vec![(4, 0, 0, 50, 50), (3, 0, 50, 50, 50)]
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use crate::focus::FocusHistory;
+use crate::layout::Layout;
+use crate::tree::Tree;
+use std::path::Path;
+use xcb::Rectangle;
+
+pub struct Workspace {
+    pub name: String,
+    // Only holds clients that are visible on this workspace, sticky ones included.
+    pub focus_history: FocusHistory,
+    // Windows are floating when there is no layout.
+    pub layout: Option<Box<dyn Layout>>,
+}
+
+// Every monitor shows one workspace, monitors beyond the number of workspaces are left
+// empty.
+#[derive(Debug, Copy, Clone)]
+pub struct Monitor {
+    pub area: Rectangle,
+    pub workspace: usize,
+}
+
+pub struct Workspaces {
+    workspaces: Vec<Workspace>,
+    monitors: Vec<Monitor>,
+    focused_monitor: usize,
+}
+
+impl Workspaces {
+    // Without any monitors the first workspace is shown on an empty area, until
+    // `set_monitors` gets some.
+    pub fn new(names: &[String], layout: Option<&str>, monitors: &[Rectangle]) -> Self {
+        let empty = [Rectangle {
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+        }];
+        let monitors = if monitors.is_empty() {
+            &empty
+        } else {
+            monitors
+        };
+
+        Self {
+            workspaces: names
+                .iter()
+                .map(|name| Workspace {
+                    name: name.clone(),
+                    focus_history: FocusHistory::default(),
+                    layout: layout.and_then(crate::layout::from_name),
+                })
+                .collect(),
+            monitors: monitors
+                .iter()
+                .take(names.len())
+                .enumerate()
+                .map(|(workspace, area)| Monitor {
+                    area: *area,
+                    workspace,
+                })
+                .collect(),
+            focused_monitor: 0,
+        }
+    }
+
+    // The workspace on the focused monitor.
+    pub fn current(&self) -> usize {
+        self.monitors[self.focused_monitor].workspace
+    }
+
+    pub fn monitors(&self) -> &[Monitor] {
+        &self.monitors
+    }
+
+    pub fn focused_monitor(&self) -> usize {
+        self.focused_monitor
+    }
+
+    pub fn focus_monitor(&mut self, monitor: usize) {
+        if monitor < self.monitors.len() {
+            self.focused_monitor = monitor;
+        }
+    }
+
+    pub fn monitor_at(&self, x: i16, y: i16) -> Option<usize> {
+        self.monitors.iter().position(|monitor| {
+            let area = monitor.area;
+
+            (i32::from(area.x)..i32::from(area.x) + i32::from(area.width)).contains(&i32::from(x))
+                && (i32::from(area.y)..i32::from(area.y) + i32::from(area.height))
+                    .contains(&i32::from(y))
+        })
+    }
+
+    pub fn monitor_of(&self, workspace: usize) -> Option<usize> {
+        self.monitors
+            .iter()
+            .position(|monitor| monitor.workspace == workspace)
+    }
+
+    pub fn is_shown(&self, workspace: usize) -> bool {
+        self.monitor_of(workspace).is_some()
+    }
+
+    // Keeps the workspaces of the monitors that are still there, new monitors show the first
+    // hidden workspaces. Returns the workspaces that were shown on removed monitors. Without
+    // any areas the monitors are kept, as there has to be a current workspace.
+    pub fn set_monitors(&mut self, areas: &[Rectangle]) -> Vec<usize> {
+        let areas = &areas[..areas.len().min(self.workspaces.len())];
+
+        if areas.is_empty() {
+            return vec![];
+        }
+
+        let removed = self
+            .monitors
+            .iter()
+            .skip(areas.len())
+            .map(|monitor| monitor.workspace)
+            .collect();
+
+        self.monitors.truncate(areas.len());
+
+        for (index, area) in areas.iter().enumerate() {
+            match self.monitors.get_mut(index) {
+                Some(monitor) => monitor.area = *area,
+                None => {
+                    let workspace = (0..self.workspaces.len())
+                        .find(|workspace| !self.is_shown(*workspace))
+                        .unwrap();
+
+                    self.monitors.push(Monitor {
+                        area: *area,
+                        workspace,
+                    });
+                }
+            }
+        }
+
+        self.focused_monitor = self.focused_monitor.min(self.monitors.len() - 1);
+
+        removed
+    }
+
+    pub fn count(&self) -> usize {
+        self.workspaces.len()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.workspaces
+            .iter()
+            .map(|workspace| workspace.name.as_str())
+    }
+
+    pub fn is_tiling(&self, index: usize) -> bool {
+        self.workspaces
+            .get(index)
+            .is_some_and(|workspace| workspace.layout.is_some())
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Workspace> {
+        self.workspaces.get_mut(index)
+    }
+
+    pub fn focus_history(&mut self) -> &mut FocusHistory {
+        let current = self.current();
+
+        &mut self.workspaces[current].focus_history
+    }
+
+    // A workspace that is already shown on another monitor is switched to by focusing that
+    // monitor, otherwise it replaces the one on the focused monitor. Returns the workspace
+    // that was current before, or `None` if nothing changed.
+    pub fn switch(&mut self, index: usize) -> Option<usize> {
+        let current = self.current();
+
+        if index == current || index >= self.workspaces.len() {
+            return None;
+        }
+
+        match self.monitor_of(index) {
+            Some(monitor) => self.focused_monitor = monitor,
+            None => self.monitors[self.focused_monitor].workspace = index,
+        }
+
+        Some(current)
+    }
+
+    // Drops a client from every workspace's history, returns whether it had the focus on
+    // the current one.
+    pub fn forget(&mut self, client: u32) -> bool {
+        let current = self.current();
+
+        self.workspaces
+            .iter_mut()
+            .enumerate()
+            .fold(false, |was_focused, (index, workspace)| {
+                let removed = workspace.focus_history.remove(client);
+
+                was_focused || (index == current && removed)
+            })
+    }
+}
+
+// Writes the layout state of every workspace, `null` for those without any. Nothing is
+// written while it's the same as `saved`, the state written last.
+pub fn save_trees(path: &Path, workspaces: &Workspaces, saved: &mut Vec<serde_json::Value>) {
+    let trees: Vec<serde_json::Value> = workspaces
+        .workspaces
+        .iter()
+        .map(|workspace| {
+            workspace
+                .layout
+                .as_ref()
+                .and_then(|layout| layout.save())
+                .unwrap_or(serde_json::Value::Null)
+        })
+        .collect();
+
+    if trees == *saved {
+        return;
+    }
+
+    let result = serde_json::to_string_pretty(&trees)
+        .map_err(|error| error.to_string())
+        .and_then(|json| std::fs::write(path, json).map_err(|error| error.to_string()));
+
+    match result {
+        Ok(()) => *saved = trees,
+        Err(error) => {
+            eprintln!("Failed to save the layout trees to {:?}: {}", path, error);
+        }
+    }
+}
+
+// Workspaces with a saved tree switch to the tree layout, windows opened later take the
+// places of the saved ones.
+pub fn restore_trees(path: &Path, workspaces: &mut Workspaces) {
+    let json = match std::fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(_) => return,
+    };
+    let trees: Vec<serde_json::Value> = match serde_json::from_str(&json) {
+        Ok(trees) => trees,
+        Err(error) => {
+            eprintln!("Failed to parse the layout trees in {:?}: {}", path, error);
+
+            return;
+        }
+    };
+
+    for (workspace, tree) in workspaces.workspaces.iter_mut().zip(trees) {
+        if tree.is_null() {
+            continue;
+        }
+
+        match Tree::restore(tree) {
+            Ok(tree) => workspace.layout = Some(Box::new(tree)),
+            Err(error) => {
+                eprintln!(
+                    "Failed to restore the tree of {:?}: {}",
+                    workspace.name, error
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(x: i16) -> Rectangle {
+        Rectangle {
+            x,
+            y: 0,
+            width: 100,
+            height: 100,
+        }
+    }
+
+    fn names() -> Vec<String> {
+        vec!["1".to_string(), "2".to_string(), "3".to_string()]
+    }
+
+    #[test]
+    fn no_monitors_still_show_a_workspace() {
+        let mut workspaces = Workspaces::new(&names(), None, &[]);
+
+        assert_eq!(workspaces.current(), 0);
+        assert_eq!(workspaces.monitors().len(), 1);
+
+        workspaces.set_monitors(&[area(0), area(100)]);
+
+        assert_eq!(workspaces.monitor_at(150, 50), Some(1));
+        assert_eq!(workspaces.monitors()[1].workspace, 1);
+    }
+
+    #[test]
+    fn monitors_are_kept_without_any_areas() {
+        let mut workspaces = Workspaces::new(&names(), None, &[area(0), area(100)]);
+
+        workspaces.focus_monitor(1);
+
+        assert!(workspaces.set_monitors(&[]).is_empty());
+        assert_eq!(workspaces.monitors().len(), 2);
+        assert_eq!(workspaces.current(), 1);
+
+        assert_eq!(workspaces.set_monitors(&[area(0)]), vec![1]);
+        assert_eq!(workspaces.current(), 0);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::Error;
+use std::io::Read;
+use xml::reader::{EventReader, XmlEvent};
+
+// Just enough of a document tree for the protocol descriptions, which are small and only
+// read once.
+#[derive(Debug, Default)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Element>,
+    pub text: String,
+}
+
+impl Element {
+    pub fn parse<R: Read>(source: R) -> Result<Self, Error> {
+        let mut stack: Vec<Element> = vec![];
+
+        for event in EventReader::new(source) {
+            match event? {
+                XmlEvent::StartElement {
+                    name, attributes, ..
+                } => stack.push(Element {
+                    name: name.local_name,
+                    attributes: attributes
+                        .into_iter()
+                        .map(|attribute| (attribute.name.local_name, attribute.value))
+                        .collect(),
+                    ..Default::default()
+                }),
+                XmlEvent::EndElement { .. } => {
+                    let element = stack
+                        .pop()
+                        .ok_or(Error::InvalidProtocol("unbalanced closing tag".to_string()))?;
+
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                }
+                XmlEvent::Characters(text) | XmlEvent::CData(text) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&text);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Err(Error::InvalidProtocol("empty document".to_string()))
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn required(&self, name: &str) -> Result<&str, Error> {
+        self.attribute(name).ok_or_else(|| {
+            Error::InvalidProtocol(format!("<{}> without a {} attribute", self.name, name))
+        })
+    }
+
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+}
@@ -0,0 +1,989 @@
+use crate::model::{BitCase, Event, Expression, Field, Item, Module, Request, Value};
+use crate::names::{c_field, camel, rust_field, screaming, snake, split};
+use crate::types::{c_prefix, Kind, Registry, Type};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+// A field of a generated struct, along with where its value comes from in the raw one.
+enum Member {
+    Value {
+        name: String,
+        raw_name: String,
+        field_type: Type,
+    },
+    Array {
+        name: String,
+        raw_name: String,
+        field_type: Type,
+        length: u32,
+    },
+    // A list in a reply, read through libxcb's accessors.
+    List {
+        name: String,
+        accessor: String,
+        field_type: Type,
+    },
+}
+
+impl Member {
+    fn declaration(&self) -> String {
+        match self {
+            Member::Value {
+                name, field_type, ..
+            } => format!("    pub {}: {},\n", name, field_type.rust),
+            Member::Array {
+                name,
+                field_type,
+                length,
+                ..
+            } => format!("    pub {}: [{}; {}],\n", name, field_type.rust, length),
+            Member::List {
+                name, field_type, ..
+            } => format!("    pub {}: Vec<{}>,\n", name, field_type.rust),
+        }
+    }
+
+    // Reads the member out of `raw`, which is either a raw struct or a reply.
+    fn read(&self, raw: &str) -> String {
+        match self {
+            Member::Value {
+                name,
+                raw_name,
+                field_type,
+            } => format!(
+                "{}: {},",
+                name,
+                field_type.convert_from_raw(&format!("{}.{}", raw, raw_name))
+            ),
+            Member::Array {
+                name,
+                raw_name,
+                field_type,
+                ..
+            } => {
+                if field_type.is_transparent() && field_type.kind != Kind::Char {
+                    format!("{}: {}.{},", name, raw, raw_name)
+                } else {
+                    format!(
+                        "{}: {}.{}.map(|value| {}),",
+                        name,
+                        raw,
+                        raw_name,
+                        field_type.convert_from_raw("value")
+                    )
+                }
+            }
+            Member::List {
+                name,
+                accessor,
+                field_type,
+            } => {
+                let element = match field_type.kind {
+                    Kind::Bool | Kind::Char | Kind::Void => "u8",
+                    Kind::Struct => &field_type.raw,
+                    _ => &field_type.rust,
+                };
+                let slice = format!(
+                    "std::slice::from_raw_parts(xcb_system::{}(reply.as_ptr()) as *const {}, \
+                     xcb_system::{}_length(reply.as_ptr()) as usize)",
+                    accessor, element, accessor
+                );
+
+                if field_type.is_transparent() {
+                    format!("{}: {}.to_vec(),", name, slice)
+                } else {
+                    format!(
+                        "{}: {}.iter().map(|&value| {}).collect(),",
+                        name,
+                        slice,
+                        field_type.convert_from_raw("value")
+                    )
+                }
+            }
+        }
+    }
+
+    fn write(&self, value: &str) -> String {
+        match self {
+            Member::Value {
+                name,
+                raw_name,
+                field_type,
+            } => format!(
+                "raw.{} = {};",
+                raw_name,
+                field_type.convert_to_raw(&format!("{}.{}", value, name))
+            ),
+            Member::Array {
+                name,
+                raw_name,
+                field_type,
+                ..
+            } => format!(
+                "raw.{} = {}.{}.map(|value| {});",
+                raw_name,
+                value,
+                name,
+                field_type.convert_to_raw("value")
+            ),
+            Member::List { .. } => unreachable!("lists only appear in replies"),
+        }
+    }
+}
+
+pub struct Emitter<'a> {
+    registry: &'a Registry,
+    module: &'a Module,
+    prefix: String,
+    output: String,
+    // Wrapper type names, which enums with the same name have to stay clear of.
+    type_names: HashSet<String>,
+    // The enum `decode_event` returns.
+    event_enum: String,
+}
+
+impl<'a> Emitter<'a> {
+    pub fn new(registry: &'a Registry, header: &str) -> Self {
+        let module = registry.module(header);
+        let mut type_names: HashSet<String> = module
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Xid(name)
+                | Item::Typedef { new: name, .. }
+                | Item::Struct { name, .. }
+                | Item::Union { name, .. } => Some(camel(name)),
+                _ => None,
+            })
+            .collect();
+        // Present has an EVENT xid, so its decoder is named after the module instead.
+        let event_enum = if type_names.contains("Event") {
+            format!("{}Event", camel(header))
+        } else {
+            String::from("Event")
+        };
+        let has_events = module
+            .items
+            .iter()
+            .any(|item| matches!(item, Item::Event(_) | Item::EventCopy { .. }));
+
+        // Enums with the decoder's name, like the screen saver's event mask, make way for it.
+        if has_events {
+            type_names.insert(event_enum.clone());
+        }
+
+        Self {
+            registry,
+            module,
+            prefix: c_prefix(module),
+            output: String::new(),
+            type_names,
+            event_enum,
+        }
+    }
+
+    pub fn emit(mut self) -> String {
+        writeln!(
+            self.output,
+            "// Generated by xcb-codegen from {}.xml, don't edit.\n",
+            self.module.header
+        )
+        .unwrap();
+
+        let mut events = vec![];
+
+        for item in &self.module.items {
+            let result = match item {
+                Item::Xid(name) => {
+                    self.line(&format!("pub type {} = u32;\n", camel(name)));
+
+                    Ok(())
+                }
+                Item::Typedef { old, new } => self.typedef(old, new),
+                Item::Struct { name, fields } => self.structure(name, fields),
+                Item::Union { name, fields } => self.union(name, fields),
+                Item::Enum { name, items } => {
+                    self.enumeration(name, items);
+
+                    Ok(())
+                }
+                Item::Event(event) => self.event(event).map(|_| {
+                    events.push((camel(&event.name), event.number, snake(&event.name)));
+                }),
+                Item::EventCopy {
+                    name,
+                    number,
+                    reference,
+                } => self.event_copy(name, reference, &events).map(|_| {
+                    events.push((camel(name), *number, snake(name)));
+                }),
+                Item::Request(request) => self.request(request),
+            };
+
+            if let Err(reason) = result {
+                let name = match item {
+                    Item::Struct { name, .. }
+                    | Item::Union { name, .. }
+                    | Item::Typedef { new: name, .. }
+                    | Item::EventCopy { name, .. }
+                    | Item::Event(Event { name, .. })
+                    | Item::Request(Request { name, .. }) => name,
+                    _ => "",
+                };
+
+                self.line(&format!("// {} isn't generated, {}.\n", name, reason));
+            }
+        }
+
+        self.event_decoder(&events);
+
+        self.output
+    }
+
+    fn line(&mut self, text: &str) {
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+
+    fn resolve(&self, name: &str) -> Result<Type, String> {
+        self.registry
+            .resolve(&self.module.header, &self.module.header, name)
+            .ok_or_else(|| format!("{} is an unknown type", name))
+    }
+
+    fn typedef(&mut self, old: &str, new: &str) -> Result<(), String> {
+        let old = self.resolve(old)?;
+
+        self.line(&format!("pub type {} = {};\n", camel(new), old.rust));
+
+        Ok(())
+    }
+
+    fn members(&self, fields: &[Field], reply: Option<&str>) -> Result<Vec<Member>, String> {
+        let mut members = vec![];
+
+        for field in fields {
+            match field {
+                Field::Pad(Some(_)) => {}
+                Field::Plain { name, type_name } => members.push(Member::Value {
+                    name: rust_field(name),
+                    raw_name: rust_field(&c_field(name)),
+                    field_type: self.fixed(type_name)?,
+                }),
+                Field::List {
+                    name,
+                    type_name,
+                    length: Some(Expression::Value(length)),
+                } => members.push(Member::Array {
+                    name: rust_field(name),
+                    raw_name: rust_field(&c_field(name)),
+                    field_type: self.fixed(type_name)?,
+                    length: *length,
+                }),
+                Field::List {
+                    name, type_name, ..
+                } => match reply {
+                    Some(request) => {
+                        let field_type = self.fixed(type_name)?;
+
+                        if field_type.kind == Kind::Union {
+                            return Err(format!("{} is a list of unions", name));
+                        }
+
+                        // libxcb snake cases the names of its list accessors, like
+                        // "validValues" into "valid_values".
+                        members.push(Member::List {
+                            name: rust_field(&snake(name)),
+                            accessor: format!(
+                                "{}{}_{}",
+                                self.prefix,
+                                request,
+                                c_field(&snake(name))
+                            ),
+                            field_type,
+                        })
+                    }
+                    None => return Err(format!("{} has a variable length", name)),
+                },
+                Field::Pad(None) => return Err("it has alignment pads".to_string()),
+                Field::Switch { name, .. } => return Err(format!("{} is a switch", name)),
+                Field::Unsupported(kind) => return Err(format!("it has an {} field", kind)),
+            }
+        }
+
+        Ok(members)
+    }
+
+    fn fixed(&self, type_name: &str) -> Result<Type, String> {
+        let field_type = self.resolve(type_name)?;
+
+        match field_type.size {
+            Some(_) => Ok(field_type),
+            None => Err(format!("{} has a variable size", type_name)),
+        }
+    }
+
+    fn declare(&mut self, name: &str, derives: &str, members: &[Member]) {
+        let mut declaration = format!("#[derive({})]\npub struct {} {{\n", derives, name);
+
+        for member in members {
+            declaration.push_str(&member.declaration());
+        }
+
+        declaration.push_str("}\n");
+        self.line(&declaration);
+    }
+
+    fn implement_from_raw(&mut self, name: &str, raw: &str, members: &[Member]) {
+        let parameter = if members.is_empty() { "_raw" } else { "raw" };
+        let mut conversion = format!(
+            "impl From<{raw}> for {name} {{\n    fn from({parameter}: {raw}) -> Self {{\n        Self {{\n",
+            raw = raw,
+            name = name,
+            parameter = parameter
+        );
+
+        for member in members {
+            writeln!(conversion, "            {}", member.read("raw")).unwrap();
+        }
+
+        conversion.push_str("        }\n    }\n}\n");
+        self.line(&conversion);
+    }
+
+    fn structure(&mut self, name: &str, fields: &[Field]) -> Result<(), String> {
+        let members = self.members(fields, None)?;
+        let rust = camel(name);
+        let raw = format!("xcb_system::{}{}_t", self.prefix, snake(name));
+
+        self.declare(&rust, "Copy, Clone, Debug, PartialEq", &members);
+        self.implement_from_raw(&rust, &raw, &members);
+
+        let mut conversion = format!(
+            "impl From<{rust}> for {raw} {{\n    fn from(value: {rust}) -> Self {{\n        \
+             let mut raw: Self = unsafe {{ std::mem::zeroed() }};\n\n",
+            rust = rust,
+            raw = raw
+        );
+
+        for member in &members {
+            writeln!(conversion, "        {}", member.write("value")).unwrap();
+        }
+
+        conversion.push_str("\n        raw\n    }\n}\n");
+        self.line(&conversion);
+
+        Ok(())
+    }
+
+    // Unions are kept as their bytes, the protocol leaves it to other fields to tell which
+    // member is meant.
+    fn union(&mut self, name: &str, fields: &[Field]) -> Result<(), String> {
+        let size = self
+            .resolve(name)?
+            .size
+            .ok_or_else(|| "a member has a variable size".to_string())?;
+
+        // Still check the members, so unions of unknown types are skipped too.
+        for field in fields {
+            if let Field::Plain { type_name, .. } | Field::List { type_name, .. } = field {
+                self.fixed(type_name)?;
+            }
+        }
+
+        self.line(&format!("pub type {} = [u8; {}];\n", camel(name), size));
+
+        Ok(())
+    }
+
+    fn enumeration(&mut self, name: &str, items: &[(String, Value)]) {
+        let mut rust = camel(name);
+
+        if self.type_names.contains(&rust) {
+            rust.push_str("Enum");
+        }
+
+        let is_mask = items
+            .iter()
+            .any(|(_, value)| matches!(value, Value::Bit(_)))
+            && items
+                .iter()
+                .all(|(_, value)| !matches!(value, Value::Value(value) if *value != 0));
+        let first_word = split(name).first().cloned().unwrap_or_default();
+        let mut constants = vec![];
+        let mut seen = HashSet::new();
+
+        for (item, value) in items {
+            let mut constant = screaming(item);
+
+            if constant.starts_with(|c: char| c.is_ascii_digit()) {
+                constant = format!("{}_{}", first_word.to_uppercase(), constant);
+            }
+
+            if !seen.insert(constant.clone()) {
+                continue;
+            }
+
+            let value = match value {
+                Value::Value(value) => *value,
+                Value::Bit(bit) => 1 << bit,
+            };
+
+            constants.push((constant, value));
+        }
+
+        let mut declaration = String::new();
+
+        if is_mask {
+            writeln!(declaration, "bitflags! {{\n    pub struct {}: u32 {{", rust).unwrap();
+
+            for (constant, value) in constants {
+                writeln!(declaration, "        const {} = {:#x};", constant, value).unwrap();
+            }
+
+            declaration.push_str("    }\n}\n");
+        } else {
+            writeln!(
+                declaration,
+                "#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]\npub struct {}(pub u32);\n",
+                rust
+            )
+            .unwrap();
+            writeln!(declaration, "impl {} {{", rust).unwrap();
+
+            for (constant, value) in constants {
+                writeln!(
+                    declaration,
+                    "    pub const {}: Self = Self({});",
+                    constant, value
+                )
+                .unwrap();
+            }
+
+            declaration.push_str("}\n");
+        }
+
+        self.line(&declaration);
+    }
+
+    fn event(&mut self, event: &Event) -> Result<(), String> {
+        if event.xge {
+            return Err("it's a generic event".to_string());
+        }
+
+        let members = self.members(&event.fields, None)?;
+        let rust = format!("{}Event", camel(&event.name));
+        let raw = format!("xcb_system::{}{}_event_t", self.prefix, snake(&event.name));
+
+        self.declare(&rust, "Copy, Clone, Debug, PartialEq", &members);
+        self.implement_from_raw(&rust, &raw, &members);
+
+        Ok(())
+    }
+
+    fn event_copy(
+        &mut self,
+        name: &str,
+        reference: &str,
+        events: &[(String, u8, String)],
+    ) -> Result<(), String> {
+        if !events.iter().any(|(event, ..)| *event == camel(reference)) {
+            return Err(format!("{} isn't generated", reference));
+        }
+
+        self.line(&format!(
+            "pub type {}Event = {}Event;\n",
+            camel(name),
+            camel(reference)
+        ));
+
+        Ok(())
+    }
+
+    fn event_decoder(&mut self, events: &[(String, u8, String)]) {
+        if events.is_empty() {
+            return;
+        }
+
+        let mut decoder = format!(
+            "#[derive(Copy, Clone, Debug, PartialEq)]\npub enum {} {{\n",
+            self.event_enum
+        );
+
+        for (name, ..) in events {
+            writeln!(decoder, "    {}({}Event),", name, name).unwrap();
+        }
+
+        decoder.push_str("}\n\n");
+
+        // Extension events are numbered from the first event the server assigned them.
+        let (parameters, number) = if self.module.extension.is_some() {
+            (
+                "event: &xcb_system::xcb_generic_event_t, first_event: u8",
+                "(event.response_type & 0x7f).wrapping_sub(first_event)",
+            )
+        } else {
+            (
+                "event: &xcb_system::xcb_generic_event_t",
+                "event.response_type & 0x7f",
+            )
+        };
+
+        // Present has an Option enum.
+        writeln!(
+            decoder,
+            "pub fn decode_event({}) -> std::option::Option<{}> {{\n    \
+             let raw = event as *const xcb_system::xcb_generic_event_t;\n\n    \
+             match {} {{",
+            parameters, self.event_enum, number
+        )
+        .unwrap();
+
+        for (name, number, raw) in events {
+            writeln!(
+                decoder,
+                "        {} => Some({}::{}(\n            \
+                 unsafe {{ *(raw as *const xcb_system::{}{}_event_t) }}.into(),\n        )),",
+                number, self.event_enum, name, self.prefix, raw
+            )
+            .unwrap();
+        }
+
+        decoder.push_str("        _ => None,\n    }\n}\n");
+        self.line(&decoder);
+    }
+
+    fn request(&mut self, request: &Request) -> Result<(), String> {
+        let function = snake(&request.name);
+        let mut parameters = vec!["connection: &'a crate::connection::Connection".to_string()];
+        let mut prelude = vec![];
+        let mut arguments = vec!["connection.get_connection()".to_string()];
+        // Length fields libxcb wants, but which are taken from the lists they measure.
+        let mut lengths = HashMap::new();
+        // Masks libxcb wants, but which are taken from the value lists they describe.
+        let mut masks = HashMap::new();
+        let mut plain = HashMap::new();
+        let mut declarations = vec![];
+
+        for field in &request.fields {
+            if let Field::List {
+                name,
+                length: Some(Expression::Field(length)),
+                ..
+            } = field
+            {
+                lengths
+                    .entry(length.clone())
+                    .or_insert_with(|| rust_field(name));
+            }
+
+            if let Field::Switch {
+                mask: Expression::Field(mask),
+                ..
+            } = field
+            {
+                masks.insert(mask.clone(), None);
+            }
+        }
+
+        for field in &request.fields {
+            match field {
+                Field::Pad(_) => {}
+                Field::Plain { name, type_name } => {
+                    let field_type = self.fixed(type_name)?;
+
+                    match lengths.get(name) {
+                        Some(list) => arguments.push(format!("{}.len() as _", list)),
+                        None if masks.contains_key(name) => {
+                            arguments.push(rust_field(name));
+                            masks.insert(name.clone(), Some(field_type));
+                        }
+                        None => {
+                            let parameter = rust_field(name);
+
+                            parameters.push(format!("{}: {}", parameter, field_type.rust));
+                            arguments.push(field_type.convert_to_raw(&parameter));
+                            plain.insert(name.clone(), (parameter, field_type));
+                        }
+                    }
+                }
+                Field::List {
+                    name,
+                    type_name,
+                    length,
+                } => {
+                    let field_type = self.fixed(type_name)?;
+                    let parameter = rust_field(name);
+
+                    if field_type.kind == Kind::Union {
+                        return Err(format!("{} is a list of unions", name));
+                    }
+
+                    match length {
+                        Some(Expression::Value(length)) => parameters
+                            .push(format!("{}: &[{}; {}]", parameter, field_type.rust, length)),
+                        _ => parameters.push(format!("{}: &[{}]", parameter, field_type.rust)),
+                    }
+
+                    match length {
+                        None => arguments.push(format!("{}.len() as u32", parameter)),
+                        // Lists sharing a length field have to be as long as each other.
+                        Some(Expression::Field(length)) => {
+                            if lengths[length] != parameter {
+                                prelude.push(format!(
+                                    "assert_eq!({}.len(), {}.len());",
+                                    parameter, lengths[length]
+                                ));
+                            }
+                        }
+                        Some(Expression::Value(_)) => {}
+                        Some(expression) => {
+                            let expression = render(expression, &plain).ok_or_else(|| {
+                                format!("the length of {} can't be computed", name)
+                            })?;
+
+                            prelude.push(format!(
+                                "assert!({}.len() >= {} as usize);",
+                                parameter, expression
+                            ));
+                        }
+                    }
+
+                    if !field_type.is_transparent() {
+                        prelude.push(format!(
+                            "let {}: Vec<{}> = {}.iter().map(|&value| {}).collect();",
+                            parameter,
+                            if field_type.kind == Kind::Bool {
+                                "u8"
+                            } else {
+                                &field_type.raw
+                            },
+                            parameter,
+                            field_type.convert_to_raw("value")
+                        ));
+                    }
+
+                    arguments.push(format!("{}.as_ptr() as *const _", parameter));
+                }
+                Field::Switch { name, mask, cases } => {
+                    let (mask, mask_type) = match mask {
+                        Expression::Field(mask) => masks
+                            .get(mask)
+                            .cloned()
+                            .flatten()
+                            .map(|mask_type| (rust_field(mask), mask_type)),
+                        _ => None,
+                    }
+                    .ok_or_else(|| format!("the mask of {} isn't a plain field", name))?;
+                    let parameter = rust_field(name);
+                    let value_list = format!(
+                        "{}{}",
+                        camel(&request.name),
+                        split(name)
+                            .iter()
+                            .map(|part| part[..1].to_uppercase() + &part[1..])
+                            .collect::<String>()
+                    );
+
+                    declarations.push(self.value_list(
+                        &value_list,
+                        &function,
+                        &parameter,
+                        (&mask, &mask_type),
+                        cases,
+                    )?);
+                    parameters.push(format!("{}: &{}", parameter, value_list));
+                    prelude.push(format!(
+                        "let ({}, {}) = {}.serialize();",
+                        mask, parameter, parameter
+                    ));
+                    arguments.push(format!("{}.as_ptr() as *const _", parameter));
+                }
+                Field::Unsupported(kind) => return Err(format!("it has an {} field", kind)),
+            }
+        }
+
+        for declaration in declarations {
+            self.line(&declaration);
+        }
+
+        let mut body = String::new();
+        let reply = match &request.reply {
+            Some(fields) => {
+                let members = self.members(fields, Some(&function))?;
+                let rust = format!("{}Reply", camel(&request.name));
+
+                self.declare(&rust, "Clone, Debug, PartialEq", &members);
+
+                Some((members, rust))
+            }
+            None => None,
+        };
+        let request_type = match reply {
+            Some((members, rust)) => {
+                let request_type = format!("{}Request", camel(&request.name));
+
+                self.reply_request(&request_type, &function, &members, &rust);
+
+                request_type
+            }
+            None => "crate::result::Void".to_string(),
+        };
+        let cookie = |body: &mut String, suffix: &str, returns: &str| {
+            writeln!(
+                body,
+                "pub fn {}{}<'a>({}) -> {} {{",
+                rust_field(&function),
+                suffix,
+                parameters.join(", "),
+                returns
+            )
+            .unwrap();
+
+            for line in &prelude {
+                writeln!(body, "    {}", line).unwrap();
+            }
+
+            if !prelude.is_empty() {
+                body.push('\n');
+            }
+        };
+
+        cookie(
+            &mut body,
+            "",
+            &format!("crate::result::Cookie<'a, {}>", request_type),
+        );
+
+        if request.reply.is_some() {
+            write!(
+                body,
+                "    let cookie = unsafe {{ xcb_system::{}{}({}) }};\n\n    \
+                 crate::result::Cookie::new(cookie.sequence, {}, connection)\n}}\n",
+                self.prefix,
+                function,
+                arguments.join(", "),
+                request_type
+            )
+            .unwrap();
+        } else {
+            writeln!(
+                body,
+                "    let cookie = unsafe {{ xcb_system::{}{}_checked({}) }};\n\n    \
+                 crate::result::Cookie::new_void(cookie, connection)\n}}\n",
+                self.prefix,
+                function,
+                arguments.join(", ")
+            )
+            .unwrap();
+
+            // Errors of unchecked requests arrive as events, nothing waits for them.
+            cookie(&mut body, "_unchecked", "crate::result::UncheckedCookie");
+            write!(
+                body,
+                "    let cookie = unsafe {{ xcb_system::{}{}({}) }};\n\n    \
+                 crate::result::UncheckedCookie::new(cookie)\n}}\n",
+                self.prefix,
+                function,
+                arguments.join(", ")
+            )
+            .unwrap();
+        }
+
+        self.line(&body);
+
+        Ok(())
+    }
+
+    // A builder for the values of a switch, which sets the bits of its mask along with them so
+    // the two can't disagree. Only switches of single 4 byte values are supported, which is
+    // what the value lists of the core protocol are.
+    fn value_list(
+        &self,
+        name: &str,
+        function: &str,
+        parameter: &str,
+        (mask, mask_type): (&str, &Type),
+        cases: &[BitCase],
+    ) -> Result<String, String> {
+        let mut values = vec![];
+
+        for case in cases {
+            match (case.bits.as_slice(), case.fields.as_slice()) {
+                (
+                    [Expression::EnumRef(enum_name, item)],
+                    [Field::Plain {
+                        name: field,
+                        type_name,
+                    }],
+                ) => {
+                    let field_type = self.fixed(type_name)?;
+
+                    if field_type.size != Some(4) || !matches!(field_type.kind, Kind::Int(_)) {
+                        return Err(format!("{} has values of other sizes", parameter));
+                    }
+
+                    let bit = self
+                        .registry
+                        .enum_value(&self.module.header, enum_name, item)
+                        .ok_or_else(|| format!("{}::{} is an unknown value", enum_name, item))?;
+
+                    values.push((bit, rust_field(field), field_type));
+                }
+                _ => return Err(format!("{} has cases of more than one value", parameter)),
+            }
+        }
+
+        // The values go in the order of their bits.
+        values.sort_by_key(|(bit, ..)| *bit);
+
+        let mut declaration = format!(
+            "/// The values of `{}`, every one that is set adds its bit to `{}`.\n\
+             #[derive(Copy, Clone, Debug, Default, PartialEq)]\n\
+             pub struct {} {{\n",
+            rust_field(function),
+            mask,
+            name
+        );
+
+        for (_, field, field_type) in &values {
+            writeln!(
+                declaration,
+                "    pub {}: Option<{}>,",
+                field, field_type.rust
+            )
+            .unwrap();
+        }
+
+        writeln!(declaration, "}}\n\nimpl {} {{", name).unwrap();
+
+        for (_, field, field_type) in &values {
+            writeln!(
+                declaration,
+                "    pub fn {field}(mut self, {field}: {rust}) -> Self {{\n        \
+                 self.{field} = Some({field});\n        \
+                 self\n    \
+                 }}\n",
+                field = field,
+                rust = field_type.rust
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            declaration,
+            "    pub(crate) fn serialize(&self) -> ({}, Vec<u32>) {{\n        \
+             let mut mask = 0;\n        \
+             let mut values = vec![];\n",
+            mask_type.rust
+        )
+        .unwrap();
+
+        for (bit, field, field_type) in &values {
+            let value = if field_type.kind == Kind::Int("u32") {
+                "value"
+            } else {
+                "value as u32"
+            };
+
+            writeln!(
+                declaration,
+                "        if let Some(value) = self.{} {{\n            \
+                 mask |= {:#x};\n            \
+                 values.push({});\n        \
+                 }}\n",
+                field, bit, value
+            )
+            .unwrap();
+        }
+
+        declaration.push_str("        (mask, values)\n    }\n}\n");
+
+        Ok(declaration)
+    }
+
+    // The request a cookie waits with, converting the raw reply once it arrives.
+    fn reply_request(&mut self, name: &str, function: &str, members: &[Member], reply: &str) {
+        let raw_reply = format!("xcb_system::{}{}_reply_t", self.prefix, function);
+        let mut request = format!(
+            "pub struct {name};\n\n\
+             impl crate::result::Request for {name} {{\n    \
+             type Reply = {reply};\n\n    \
+             fn wait(\n        \
+             self,\n        \
+             connection: &crate::connection::Connection,\n        \
+             sequence: crate::result::Sequence,\n    \
+             ) -> Result<{reply}, crate::result::Error> {{\n",
+            name = name,
+            reply = reply
+        );
+
+        if members.is_empty() {
+            writeln!(
+                request,
+                "        crate::result::wait_for_reply::<{}>(connection, sequence)?;\n\n        \
+                 Ok({} {{}})",
+                raw_reply, reply
+            )
+            .unwrap();
+        } else {
+            let lists = members
+                .iter()
+                .any(|member| matches!(member, Member::List { .. }));
+
+            writeln!(
+                request,
+                "        let reply = crate::result::wait_for_reply::<{}>(connection, sequence)?;\n",
+                raw_reply
+            )
+            .unwrap();
+            writeln!(
+                request,
+                "        Ok({}{} {{",
+                if lists { "unsafe { " } else { "" },
+                reply
+            )
+            .unwrap();
+
+            for member in members {
+                writeln!(request, "            {}", member.read("reply")).unwrap();
+            }
+
+            writeln!(request, "        }}{})", if lists { " }" } else { "" }).unwrap();
+        }
+
+        request.push_str("    }\n}\n");
+        self.line(&request);
+    }
+}
+
+// Turns a list length into Rust, as a `usize` computed from the request's parameters.
+fn render(expression: &Expression, parameters: &HashMap<String, (String, Type)>) -> Option<String> {
+    Some(match expression {
+        Expression::Field(name) => {
+            let (parameter, field_type) = parameters.get(name)?;
+
+            match field_type.kind {
+                Kind::Int(_) => format!("({} as usize)", parameter),
+                _ => return None,
+            }
+        }
+        Expression::Value(value) => value.to_string(),
+        Expression::Op(operator, left, right) => match operator.as_str() {
+            "+" | "-" | "*" | "/" | "&" | "|" | "<<" | ">>" => format!(
+                "({} {} {})",
+                render(left, parameters)?,
+                operator,
+                render(right, parameters)?
+            ),
+            _ => return None,
+        },
+        Expression::Unop(operator, operand) if operator == "~" => {
+            format!("(!{})", render(operand, parameters)?)
+        }
+        Expression::Popcount(operand) => {
+            format!("({}.count_ones() as usize)", render(operand, parameters)?)
+        }
+        _ => return None,
+    })
+}
@@ -0,0 +1,37 @@
+use std::path::Path;
+
+fn main() {
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+
+    if arguments.len() < 2 {
+        eprintln!("usage: xcb-codegen <proto directory> <output directory> [header...]");
+        std::process::exit(2);
+    }
+
+    let directory = Path::new(&arguments[0]);
+    let output = Path::new(&arguments[1]);
+    let mut headers = arguments[2..].to_vec();
+
+    // Without headers every description in the directory is generated.
+    if headers.is_empty() {
+        let entries = std::fs::read_dir(directory).unwrap_or_else(|error| {
+            eprintln!("{}: {}", directory.display(), error);
+            std::process::exit(1);
+        });
+
+        headers = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == "xml"))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect();
+        headers.sort();
+    }
+
+    for header in headers {
+        if let Err(error) = xcb_codegen::generate_file(directory, &header, output) {
+            eprintln!("{}: {}", header, error);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use crate::dom::Element;
+use crate::Error;
+
+// One protocol description, xproto.xml or an extension's file.
+#[derive(Debug)]
+pub struct Module {
+    pub header: String,
+    // The extension's name as libxcb spells it, "RandR" or "XFixes".
+    pub extension: Option<String>,
+    pub imports: Vec<String>,
+    pub items: Vec<Item>,
+}
+
+#[derive(Debug)]
+pub enum Item {
+    Xid(String),
+    Typedef {
+        old: String,
+        new: String,
+    },
+    Struct {
+        name: String,
+        fields: Vec<Field>,
+    },
+    Union {
+        name: String,
+        fields: Vec<Field>,
+    },
+    Enum {
+        name: String,
+        items: Vec<(String, Value)>,
+    },
+    Event(Event),
+    EventCopy {
+        name: String,
+        number: u8,
+        reference: String,
+    },
+    Request(Request),
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Value {
+    Value(u32),
+    Bit(u32),
+}
+
+#[derive(Debug)]
+pub struct Event {
+    pub name: String,
+    pub number: u8,
+    // Generic events are read through the xge extension and aren't decoded here.
+    pub xge: bool,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub name: String,
+    pub fields: Vec<Field>,
+    pub reply: Option<Vec<Field>>,
+}
+
+#[derive(Debug)]
+pub enum Field {
+    // Alignment pads, which have no fixed size, are `None`.
+    Pad(Option<usize>),
+    Plain {
+        name: String,
+        type_name: String,
+    },
+    List {
+        name: String,
+        type_name: String,
+        length: Option<Expression>,
+    },
+    Switch {
+        name: String,
+        mask: Expression,
+        cases: Vec<BitCase>,
+    },
+    Unsupported(String),
+}
+
+// A part of a switch that is present when any of the bits in `bits` is set in its mask.
+#[derive(Debug)]
+pub struct BitCase {
+    pub bits: Vec<Expression>,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Debug)]
+pub enum Expression {
+    Field(String),
+    Value(u32),
+    Op(String, Box<Expression>, Box<Expression>),
+    Unop(String, Box<Expression>),
+    Popcount(Box<Expression>),
+    // An item of an enum, by the enum's name and the item's.
+    EnumRef(String, String),
+    Unsupported,
+}
+
+impl Module {
+    pub fn parse(root: &Element) -> Result<Self, Error> {
+        let mut imports = vec![];
+        let mut items = vec![];
+
+        for element in &root.children {
+            let name = || element.required("name").map(str::to_string);
+
+            match element.name.as_str() {
+                "import" => imports.push(element.text.trim().to_string()),
+                "xidtype" | "xidunion" => items.push(Item::Xid(name()?)),
+                "typedef" => items.push(Item::Typedef {
+                    old: element.required("oldname")?.to_string(),
+                    new: element.required("newname")?.to_string(),
+                }),
+                "struct" => items.push(Item::Struct {
+                    name: name()?,
+                    fields: parse_fields(element)?,
+                }),
+                "union" => items.push(Item::Union {
+                    name: name()?,
+                    fields: parse_fields(element)?,
+                }),
+                "enum" => items.push(Item::Enum {
+                    name: name()?,
+                    items: parse_enum_items(element)?,
+                }),
+                "event" => items.push(Item::Event(Event {
+                    name: name()?,
+                    number: parse_number(element.required("number")?)?,
+                    xge: element.attribute("xge") == Some("true"),
+                    fields: parse_fields(element)?,
+                })),
+                "eventcopy" => items.push(Item::EventCopy {
+                    name: name()?,
+                    number: parse_number(element.required("number")?)?,
+                    reference: element.required("ref")?.to_string(),
+                }),
+                "request" => items.push(Item::Request(Request {
+                    name: name()?,
+                    fields: parse_fields(element)?,
+                    reply: match element.child("reply") {
+                        Some(reply) => Some(parse_fields(reply)?),
+                        None => None,
+                    },
+                })),
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            header: root.required("header")?.to_string(),
+            extension: root.attribute("extension-name").map(str::to_string),
+            imports,
+            items,
+        })
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(text: &str) -> Result<T, Error> {
+    text.trim()
+        .parse()
+        .map_err(|_| Error::InvalidProtocol(format!("{} isn't a number", text)))
+}
+
+fn parse_enum_items(element: &Element) -> Result<Vec<(String, Value)>, Error> {
+    let mut items = vec![];
+
+    for item in element.children.iter().filter(|child| child.name == "item") {
+        let value = if let Some(bit) = item.child("bit") {
+            Value::Bit(parse_number(&bit.text)?)
+        } else if let Some(value) = item.child("value") {
+            // A few enums have negative values, meant as their unsigned bit patterns.
+            match parse_number::<u32>(&value.text) {
+                Ok(value) => Value::Value(value),
+                Err(_) => Value::Value(parse_number::<i32>(&value.text)? as u32),
+            }
+        } else {
+            return Err(Error::InvalidProtocol(format!(
+                "enum item {} without a value",
+                item.required("name")?
+            )));
+        };
+
+        items.push((item.required("name")?.to_string(), value));
+    }
+
+    Ok(items)
+}
+
+fn parse_fields(element: &Element) -> Result<Vec<Field>, Error> {
+    let mut fields = vec![];
+
+    for child in &element.children {
+        let field = match child.name.as_str() {
+            "pad" => Field::Pad(match child.attribute("bytes") {
+                Some(bytes) => Some(parse_number(bytes)?),
+                None => None,
+            }),
+            "field" => Field::Plain {
+                name: child.required("name")?.to_string(),
+                type_name: child.required("type")?.to_string(),
+            },
+            "list" => Field::List {
+                name: child.required("name")?.to_string(),
+                type_name: child.required("type")?.to_string(),
+                length: match child.children.first() {
+                    Some(length) => Some(parse_expression(length)?),
+                    None => None,
+                },
+            },
+            "switch" if child.child("case").is_some() => Field::Unsupported("case".to_string()),
+            "switch" => {
+                let mask = child.children.first().ok_or_else(|| {
+                    Error::InvalidProtocol(format!("switch {} without a mask", child.name))
+                })?;
+                let mut cases = vec![];
+
+                for case in child.children.iter().filter(|case| case.name == "bitcase") {
+                    let mut bits = vec![];
+
+                    for bit in case.children.iter().filter(|bit| bit.name == "enumref") {
+                        bits.push(parse_expression(bit)?);
+                    }
+
+                    cases.push(BitCase {
+                        bits,
+                        fields: parse_fields(case)?,
+                    });
+                }
+
+                Field::Switch {
+                    name: child.required("name")?.to_string(),
+                    mask: parse_expression(mask)?,
+                    cases,
+                }
+            }
+            "exprfield" | "fd" => Field::Unsupported(child.name.clone()),
+            _ => continue,
+        };
+
+        fields.push(field);
+    }
+
+    Ok(fields)
+}
+
+fn parse_expression(element: &Element) -> Result<Expression, Error> {
+    let operand = |index: usize| match element.children.get(index) {
+        Some(child) => parse_expression(child).map(Box::new),
+        None => Err(Error::InvalidProtocol(format!(
+            "<{}> without enough operands",
+            element.name
+        ))),
+    };
+
+    Ok(match element.name.as_str() {
+        "fieldref" => Expression::Field(element.text.trim().to_string()),
+        "value" => Expression::Value(parse_number(&element.text)?),
+        "op" => Expression::Op(
+            element.required("op")?.to_string(),
+            operand(0)?,
+            operand(1)?,
+        ),
+        "unop" => Expression::Unop(element.required("op")?.to_string(), operand(0)?),
+        "popcount" => Expression::Popcount(operand(0)?),
+        "enumref" => Expression::EnumRef(
+            element.required("ref")?.to_string(),
+            element.text.trim().to_string(),
+        ),
+        _ => Expression::Unsupported,
+    })
+}
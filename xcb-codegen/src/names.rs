@@ -0,0 +1,93 @@
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "override", "priv", "pub", "ref", "return", "self", "static", "struct", "super",
+    "trait", "true", "type", "unsafe", "use", "where", "while", "yield",
+];
+// Renamed by libxcb so its headers work from C++.
+const C_RENAMED: &[&str] = &["class", "new", "delete", "default"];
+
+// Splits a name the way xcbgen does, so "GetWindowAttributes", "CHAR2B" and "Button1Motion"
+// become ["Get", "Window", "Attributes"], ["CHAR2B"] and ["Button", "1", "Motion"].
+pub fn split(name: &str) -> Vec<String> {
+    let characters: Vec<char> = name.chars().collect();
+    let is_upper = |c: char| c.is_ascii_uppercase() || c.is_ascii_digit();
+    let mut parts = vec![];
+    let mut start = 0;
+
+    while start < characters.len() {
+        let character = characters[start];
+        let mut end = start + 1;
+
+        if is_upper(character) {
+            if characters.get(end).is_some_and(|c| c.is_ascii_lowercase()) {
+                while characters.get(end).is_some_and(|c| c.is_ascii_lowercase()) {
+                    end += 1;
+                }
+            } else {
+                while characters.get(end).is_some_and(|c| is_upper(*c)) {
+                    end += 1;
+                }
+
+                // The last capital starts the next word.
+                if end - start > 1 && characters.get(end).is_some_and(|c| c.is_ascii_lowercase()) {
+                    end -= 1;
+                }
+            }
+        } else if character.is_ascii_lowercase() {
+            while characters.get(end).is_some_and(|c| c.is_ascii_lowercase()) {
+                end += 1;
+            }
+        } else {
+            start += 1;
+
+            continue;
+        }
+
+        parts.push(characters[start..end].iter().collect());
+        start = end;
+    }
+
+    parts
+}
+
+pub fn snake(name: &str) -> String {
+    split(name).join("_").to_lowercase()
+}
+
+pub fn screaming(name: &str) -> String {
+    split(name).join("_").to_uppercase()
+}
+
+// Type names in capitals, like "WINDOW" or "CHAR2B", become "Window" and "Char2b".
+pub fn camel(name: &str) -> String {
+    if name.chars().any(|c| c.is_ascii_lowercase()) {
+        let mut characters = name.chars();
+
+        return match characters.next() {
+            Some(first) => first.to_ascii_uppercase().to_string() + characters.as_str(),
+            None => String::new(),
+        };
+    }
+
+    split(name)
+        .iter()
+        .map(|part| part[..1].to_string() + &part[1..].to_lowercase())
+        .collect()
+}
+
+pub fn rust_field(name: &str) -> String {
+    if RUST_KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
+
+pub fn c_field(name: &str) -> String {
+    if C_RENAMED.contains(&name) {
+        format!("_{}", name)
+    } else {
+        name.to_string()
+    }
+}
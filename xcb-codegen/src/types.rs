@@ -0,0 +1,305 @@
+use crate::dom::Element;
+use crate::model::{Field, Item, Module, Value};
+use crate::names::{camel, snake};
+use crate::Error;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+
+// A module and everything it imports, which its types may refer to.
+pub struct Registry {
+    modules: HashMap<String, Module>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Kind {
+    Int(&'static str),
+    Float(&'static str),
+    Bool,
+    Char,
+    Void,
+    Struct,
+    Union,
+}
+
+// How a protocol type looks on both sides, `rust` being the path of the wrapper type and
+// `raw` the one bindgen generated for libxcb's.
+#[derive(Clone, Debug)]
+pub struct Type {
+    pub kind: Kind,
+    pub rust: String,
+    pub raw: String,
+    // `None` for types whose size depends on their contents.
+    pub size: Option<usize>,
+}
+
+const BASE_TYPES: &[(&str, Kind, usize)] = &[
+    ("CARD8", Kind::Int("u8"), 1),
+    ("CARD16", Kind::Int("u16"), 2),
+    ("CARD32", Kind::Int("u32"), 4),
+    ("CARD64", Kind::Int("u64"), 8),
+    ("INT8", Kind::Int("i8"), 1),
+    ("INT16", Kind::Int("i16"), 2),
+    ("INT32", Kind::Int("i32"), 4),
+    ("INT64", Kind::Int("i64"), 8),
+    ("BYTE", Kind::Int("u8"), 1),
+    ("BOOL", Kind::Bool, 1),
+    ("char", Kind::Char, 1),
+    ("void", Kind::Void, 1),
+    ("float", Kind::Float("f32"), 4),
+    ("double", Kind::Float("f64"), 8),
+];
+
+impl Type {
+    pub fn convert_from_raw(&self, value: &str) -> String {
+        match self.kind {
+            Kind::Int(_) | Kind::Float(_) | Kind::Void => value.to_string(),
+            Kind::Bool => format!("{} != 0", value),
+            Kind::Char => format!("{} as u8", value),
+            Kind::Struct => format!("{}::from({})", self.rust, value),
+            Kind::Union => format!(
+                "unsafe {{ std::mem::transmute::<{}, {}>({}) }}",
+                self.raw, self.rust, value
+            ),
+        }
+    }
+
+    pub fn convert_to_raw(&self, value: &str) -> String {
+        match self.kind {
+            Kind::Int(_) | Kind::Float(_) | Kind::Void => value.to_string(),
+            Kind::Bool => format!("u8::from({})", value),
+            Kind::Char => format!("{} as _", value),
+            Kind::Struct => format!("{}::from({})", self.raw, value),
+            Kind::Union => format!(
+                "unsafe {{ std::mem::transmute::<{}, {}>({}) }}",
+                self.rust, self.raw, value
+            ),
+        }
+    }
+
+    // Whether the wrapper type has the same layout as the raw one, so slices can be passed
+    // as they are.
+    pub fn is_transparent(&self) -> bool {
+        matches!(
+            self.kind,
+            Kind::Int(_) | Kind::Float(_) | Kind::Char | Kind::Void
+        )
+    }
+}
+
+impl Registry {
+    pub fn load(directory: &Path, header: &str) -> Result<Self, Error> {
+        let mut registry = Self {
+            modules: HashMap::new(),
+        };
+
+        registry.load_module(directory, header)?;
+
+        Ok(registry)
+    }
+
+    fn load_module(&mut self, directory: &Path, header: &str) -> Result<(), Error> {
+        if self.modules.contains_key(header) {
+            return Ok(());
+        }
+
+        let file = File::open(directory.join(format!("{}.xml", header)))?;
+        let module = Module::parse(&Element::parse(file)?)?;
+        let imports = module.imports.clone();
+
+        self.modules.insert(header.to_string(), module);
+
+        for import in imports {
+            self.load_module(directory, &import)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn module(&self, header: &str) -> &Module {
+        &self.modules[header]
+    }
+
+    // Finds the type `name` refers to from within `module`, naming it as seen from `current`,
+    // the module being generated.
+    pub fn resolve(&self, current: &str, module: &str, name: &str) -> Option<Type> {
+        let (module, name) = match name.find(':') {
+            Some(separator) => (&name[..separator], &name[separator + 1..]),
+            None => {
+                if let Some((_, kind, size)) = BASE_TYPES.iter().find(|base| base.0 == name) {
+                    let rust = match kind {
+                        Kind::Int(rust) | Kind::Float(rust) => rust,
+                        Kind::Bool => "bool",
+                        _ => "u8",
+                    };
+
+                    return Some(Type {
+                        kind: kind.clone(),
+                        rust: rust.to_string(),
+                        raw: match kind {
+                            Kind::Int(raw) | Kind::Float(raw) => raw.to_string(),
+                            _ => "u8".to_string(),
+                        },
+                        size: Some(*size),
+                    });
+                }
+
+                (module, name)
+            }
+        };
+        let (found, item) = self.find(module, name, &mut vec![])?;
+        let rust = if found.header == current {
+            camel(name)
+        } else {
+            format!("super::{}::{}", found.header, camel(name))
+        };
+        let raw = format!("xcb_system::{}{}_t", c_prefix(found), snake(name));
+
+        match item {
+            Item::Xid(_) => Some(Type {
+                kind: Kind::Int("u32"),
+                rust,
+                raw: "u32".to_string(),
+                size: Some(4),
+            }),
+            Item::Typedef { old, .. } => Some(Type {
+                rust,
+                ..self.resolve(current, &found.header, old)?
+            }),
+            Item::Struct { fields, .. } => Some(Type {
+                kind: Kind::Struct,
+                rust,
+                raw,
+                size: self.size_of_fields(&found.header, fields),
+            }),
+            Item::Union { fields, .. } => {
+                let mut size = 0;
+
+                for field in fields {
+                    size =
+                        size.max(self.size_of_fields(&found.header, std::slice::from_ref(field))?);
+                }
+
+                Some(Type {
+                    kind: Kind::Union,
+                    rust,
+                    raw,
+                    size: Some(size),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn find<'a>(
+        &'a self,
+        module: &str,
+        name: &str,
+        visited: &mut Vec<String>,
+    ) -> Option<(&'a Module, &'a Item)> {
+        if visited.iter().any(|header| header == module) {
+            return None;
+        }
+
+        visited.push(module.to_string());
+
+        let found = self.modules.get(module)?;
+        let item = found.items.iter().find(|item| match item {
+            Item::Xid(item_name)
+            | Item::Typedef { new: item_name, .. }
+            | Item::Struct {
+                name: item_name, ..
+            }
+            | Item::Union {
+                name: item_name, ..
+            } => item_name == name,
+            _ => false,
+        });
+
+        match item {
+            Some(item) => Some((found, item)),
+            None => found
+                .imports
+                .iter()
+                .find_map(|import| self.find(import, name, visited)),
+        }
+    }
+
+    // The value of `item` in the enum `name`, which `module` refers to.
+    pub fn enum_value(&self, module: &str, name: &str, item: &str) -> Option<u32> {
+        let (module, name) = match name.find(':') {
+            Some(separator) => (&name[..separator], &name[separator + 1..]),
+            None => (module, name),
+        };
+        let items = self.find_enum(module, name, &mut vec![])?;
+
+        items
+            .iter()
+            .find(|(item_name, _)| item_name == item)
+            .map(|(_, value)| match value {
+                Value::Value(value) => *value,
+                Value::Bit(bit) => 1 << bit,
+            })
+    }
+
+    fn find_enum<'a>(
+        &'a self,
+        module: &str,
+        name: &str,
+        visited: &mut Vec<String>,
+    ) -> Option<&'a [(String, Value)]> {
+        if visited.iter().any(|header| header == module) {
+            return None;
+        }
+
+        visited.push(module.to_string());
+
+        let found = self.modules.get(module)?;
+        let items = found.items.iter().find_map(|item| match item {
+            Item::Enum {
+                name: item_name,
+                items,
+            } if item_name == name => Some(items.as_slice()),
+            _ => None,
+        });
+
+        match items {
+            Some(items) => Some(items),
+            None => found
+                .imports
+                .iter()
+                .find_map(|import| self.find_enum(import, name, visited)),
+        }
+    }
+
+    pub fn size_of_fields(&self, module: &str, fields: &[Field]) -> Option<usize> {
+        let mut size = 0;
+
+        for field in fields {
+            size += match field {
+                Field::Pad(bytes) => (*bytes)?,
+                Field::Plain { type_name, .. } => self.resolve(module, module, type_name)?.size?,
+                Field::List {
+                    type_name,
+                    length: Some(crate::model::Expression::Value(length)),
+                    ..
+                } => self.resolve(module, module, type_name)?.size? * *length as usize,
+                _ => return None,
+            };
+        }
+
+        Some(size)
+    }
+}
+
+// The prefix of everything libxcb names for a module, "xcb_" for the core protocol and
+// "xcb_randr_" for RandR.
+pub fn c_prefix(module: &Module) -> String {
+    match module.extension.as_deref() {
+        None => "xcb_".to_string(),
+        Some("XPrint") => "xcb_x_print_".to_string(),
+        Some("XCMisc") => "xcb_xc_misc_".to_string(),
+        Some("BigRequests") => "xcb_big_requests_".to_string(),
+        Some(name) => format!("xcb_{}_", name.to_lowercase()),
+    }
+}
@@ -1,28 +1,156 @@
+#[cfg(feature = "regenerate-bindings")]
 extern crate bindgen;
+extern crate pkg_config;
 
 use std::env;
+use std::fs;
 use std::path::PathBuf;
 
-fn main() {
-    // Tell cargo to tell rustc to link the system bzip2
-    // shared library.
-    println!("cargo:rustc-link-lib=xcb");
-
-    // The bindgen::Builder is the main entry point
-    // to bindgen, and lets you build up options for
-    // the resulting bindings.
-    let bindings = bindgen::Builder::default()
-        // The input header we would like to generate
-        // bindings for.
-        .header("wrapper.h")
-        // Finish the builder and generate the bindings.
+// Cargo feature, header under xcb/, pkg-config package and library, and the prefix of the
+// extension's items, for every extension that can be enabled.
+const EXTENSIONS: &[(&str, &str, &str, &str)] = &[
+    ("randr", "randr.h", "xcb-randr", "randr"),
+    ("xinerama", "xinerama.h", "xcb-xinerama", "xinerama"),
+    ("xkb", "xkb.h", "xcb-xkb", "xkb"),
+    ("render", "render.h", "xcb-render", "render"),
+    ("composite", "composite.h", "xcb-composite", "composite"),
+    ("damage", "damage.h", "xcb-damage", "damage"),
+    ("xfixes", "xfixes.h", "xcb-xfixes", "xfixes"),
+    ("shape", "shape.h", "xcb-shape", "shape"),
+    ("sync", "sync.h", "xcb-sync", "sync"),
+    ("dri3", "dri3.h", "xcb-dri3", "dri3"),
+    ("present", "present.h", "xcb-present", "present"),
+    ("xinput", "xinput.h", "xcb-xinput", "input"),
+    ("res", "res.h", "xcb-res", "res"),
+    ("dpms", "dpms.h", "xcb-dpms", "dpms"),
+    (
+        "screensaver",
+        "screensaver.h",
+        "xcb-screensaver",
+        "screensaver",
+    ),
+];
+
+fn is_enabled(feature: &str) -> bool {
+    env::var_os(format!(
+        "CARGO_FEATURE_{}",
+        feature.to_uppercase().replace('-', "_")
+    ))
+    .is_some()
+}
+
+// Links the library and returns its include paths. pkg-config honours PKG_CONFIG_PATH for
+// custom prefixes and PKG_CONFIG_ALL_STATIC or <LIBRARY>_STATIC for static linking, without
+// it the library is linked from the default search path.
+fn find_library(package: &str) -> Vec<PathBuf> {
+    match pkg_config::Config::new()
+        .statik(is_enabled("static"))
+        .probe(package)
+    {
+        Ok(library) => library.include_paths,
+        Err(error) => {
+            println!("cargo:warning=pkg-config failed for {}: {}", package, error);
+            println!("cargo:rustc-link-lib={}", package);
+
+            vec![]
+        }
+    }
+}
+
+// Extension bindings only hold the extension's own items, the types they refer to come from
+// the core bindings or those of the extensions they build on.
+#[cfg(feature = "regenerate-bindings")]
+fn generate(header: &str, prefix: Option<&str>, include_paths: &[PathBuf]) -> String {
+    let mut builder = bindgen::Builder::default()
+        .header_contents("wrapper.h", header)
+        // The generated layout tests dereference null pointers, which newer compilers reject.
+        .layout_tests(false)
+        .clang_args(
+            include_paths
+                .iter()
+                .map(|path| format!("-I{}", path.display())),
+        );
+
+    if let Some(prefix) = prefix {
+        builder = builder
+            .whitelist_recursively(false)
+            .whitelist_function(format!("xcb_{}_.*", prefix))
+            .whitelist_type(format!("xcb_{}_.*", prefix))
+            .whitelist_var(format!("(xcb_{}|XCB_{})_.*", prefix, prefix.to_uppercase()))
+            // A core protocol type that happens to match the xinput prefix.
+            .blacklist_type("xcb_input_focus_t");
+    }
+
+    builder
         .generate()
-        // Unwrap the Result and panic on failure.
-        .expect("Unable to generate bindings");
+        .expect("Unable to generate bindings")
+        .to_string()
+}
+
+// Runs bindgen for the module and keeps a copy of the result in $OUT_DIR/bindings, only this
+// needs libclang.
+#[cfg(feature = "regenerate-bindings")]
+fn bindings(module: &str, header: &str, prefix: Option<&str>, include_paths: &[PathBuf]) -> String {
+    let directory = PathBuf::from(env::var("OUT_DIR").unwrap()).join("bindings");
+    let bindings = generate(header, prefix, include_paths);
+
+    fs::create_dir_all(&directory).expect("Couldn't create $OUT_DIR/bindings!");
+    fs::write(directory.join(format!("{}.rs", module)), &bindings)
+        .expect("Couldn't write the generated bindings!");
+
+    bindings
+}
+
+// Uses the checked-in bindings in src/bindings.
+#[cfg(all(feature = "prebuilt-bindings", not(feature = "regenerate-bindings")))]
+fn bindings(
+    module: &str,
+    _header: &str,
+    _prefix: Option<&str>,
+    _include_paths: &[PathBuf],
+) -> String {
+    let path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("src")
+        .join("bindings")
+        .join(format!("{}.rs", module));
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    fs::read_to_string(&path).unwrap_or_else(|error| {
+        panic!(
+            "Couldn't read {}, enable the regenerate-bindings feature to generate it: {}",
+            path.display(),
+            error
+        )
+    })
+}
+
+#[cfg(not(any(feature = "prebuilt-bindings", feature = "regenerate-bindings")))]
+compile_error!("Enable either the prebuilt-bindings or the regenerate-bindings feature.");
+
+fn main() {
+    println!("cargo:rerun-if-changed=wrapper.h");
+
+    let mut include_paths = find_library("xcb");
+    let mut output = bindings(
+        "xcb",
+        &fs::read_to_string("wrapper.h").expect("Couldn't read wrapper.h!"),
+        None,
+        &include_paths,
+    );
+
+    for (feature, header, package, prefix) in EXTENSIONS {
+        if is_enabled(feature) {
+            include_paths.extend(find_library(package));
+            output.push_str(&bindings(
+                feature,
+                &format!("#include <xcb/{}>\n", header),
+                Some(prefix),
+                &include_paths,
+            ));
+        }
+    }
 
     // Write the bindings to the $OUT_DIR/bindings.rs file.
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-    bindings
-        .write_to_file(out_path.join("bindings.rs"))
-        .expect("Couldn't write bindings!");
+    fs::write(out_path.join("bindings.rs"), output).expect("Couldn't write bindings!");
 }
@@ -0,0 +1,79 @@
+extern crate pkg_config;
+extern crate xcb_codegen;
+
+use std::env;
+use std::path::PathBuf;
+
+// The protocol descriptions the wrappers in src/proto are generated from, with the feature
+// enabling each. RandR, Render and Xinerama are always built, like their raw bindings.
+const MODULES: &[(&str, Option<&str>)] = &[
+    ("xproto", None),
+    ("render", None),
+    ("randr", None),
+    ("xinerama", None),
+    ("xkb", Some("xkb")),
+    ("composite", Some("composite")),
+    ("damage", Some("damage")),
+    ("xfixes", Some("xfixes")),
+    ("shape", Some("shape")),
+    ("sync", Some("sync")),
+    ("present", Some("present")),
+    ("xinput", Some("xinput")),
+    ("res", Some("res")),
+    ("dpms", Some("dpms")),
+    ("screensaver", Some("screensaver")),
+];
+
+fn is_enabled(feature: &str) -> bool {
+    env::var_os(format!(
+        "CARGO_FEATURE_{}",
+        feature.to_uppercase().replace('-', "_")
+    ))
+    .is_some()
+}
+
+// XCB_PROTO_DIR points at the XML files when they aren't where xcb-proto's pkg-config file
+// says they are.
+fn proto_directory() -> PathBuf {
+    println!("cargo:rerun-if-env-changed=XCB_PROTO_DIR");
+
+    if let Some(directory) = env::var_os("XCB_PROTO_DIR") {
+        return PathBuf::from(directory);
+    }
+
+    match pkg_config::get_variable("xcb-proto", "xcbincludedir") {
+        Ok(directory) => PathBuf::from(directory),
+        Err(error) => panic!(
+            "The xcb-proto XML descriptions weren't found, install xcb-proto or set \
+             XCB_PROTO_DIR to their directory: {}",
+            error
+        ),
+    }
+}
+
+// The wrappers are checked in under src/proto, so xcb-proto is only needed with the
+// regenerate-proto feature, which generates them into OUT_DIR for the enabled modules. The
+// checked-in ones are updated with the xcb-codegen binary.
+fn main() {
+    if !is_enabled("regenerate-proto") {
+        return;
+    }
+
+    let directory = proto_directory();
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    for (header, feature) in MODULES {
+        if feature.is_some_and(|feature| !is_enabled(feature)) {
+            continue;
+        }
+
+        println!(
+            "cargo:rerun-if-changed={}",
+            directory.join(format!("{}.xml", header)).display()
+        );
+
+        if let Err(error) = xcb_codegen::generate_file(&directory, header, &out_path) {
+            panic!("Couldn't generate the {} wrappers: {}", header, error);
+        }
+    }
+}
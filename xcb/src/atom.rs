@@ -0,0 +1,9 @@
+pub const NONE: u32 = xcb_system::xcb_atom_enum_t_XCB_ATOM_NONE;
+pub const ANY: u32 = xcb_system::xcb_atom_enum_t_XCB_ATOM_ANY;
+pub const ATOM: u32 = xcb_system::xcb_atom_enum_t_XCB_ATOM_ATOM;
+pub const CARDINAL: u32 = xcb_system::xcb_atom_enum_t_XCB_ATOM_CARDINAL;
+pub const STRING: u32 = xcb_system::xcb_atom_enum_t_XCB_ATOM_STRING;
+pub const WINDOW: u32 = xcb_system::xcb_atom_enum_t_XCB_ATOM_WINDOW;
+pub const WM_NAME: u32 = xcb_system::xcb_atom_enum_t_XCB_ATOM_WM_NAME;
+pub const WM_NORMAL_HINTS: u32 = xcb_system::xcb_atom_enum_t_XCB_ATOM_WM_NORMAL_HINTS;
+pub const WM_SIZE_HINTS: u32 = xcb_system::xcb_atom_enum_t_XCB_ATOM_WM_SIZE_HINTS;
@@ -0,0 +1,303 @@
+use crate::connection::Connection;
+use crate::proto::xproto;
+use crate::result::{Cookie, Error, Request, Sequence, Void};
+use crate::screen::VisualType;
+use crate::window::Window;
+use std::str::FromStr;
+use xcb_system::{xcb_colormap_t, xcb_visualtype_t};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Color {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+}
+
+impl Color {
+    fn parse_hex(digits: &str) -> Option<Self> {
+        let length = digits.len() / 3;
+
+        if !is_hex(digits) || !digits.len().is_multiple_of(3) || length == 0 || length > 4 {
+            return None;
+        }
+
+        // In the "#" syntax the digits are the most significant bits of the value, they
+        // are not scaled like in the "rgb:" syntax.
+        let component = |index: usize| {
+            u16::from_str_radix(&digits[index * length..(index + 1) * length], 16)
+                .ok()
+                .map(|value| value << (16 - 4 * length))
+        };
+
+        Some(Self {
+            red: component(0)?,
+            green: component(1)?,
+            blue: component(2)?,
+        })
+    }
+
+    fn parse_rgb(components: &str) -> Option<Self> {
+        let components: Vec<&str> = components.split('/').collect();
+
+        if components.len() != 3 {
+            return None;
+        }
+
+        let component = |index: usize| {
+            let digits: &str = components[index];
+
+            if !is_hex(digits) || digits.is_empty() || digits.len() > 4 {
+                return None;
+            }
+
+            let maximum = (1u32 << (4 * digits.len())) - 1;
+
+            u32::from_str_radix(digits, 16)
+                .ok()
+                .map(|value| (value * 0xffff / maximum) as u16)
+        };
+
+        Some(Self {
+            red: component(0)?,
+            green: component(1)?,
+            blue: component(2)?,
+        })
+    }
+}
+
+// from_str_radix also takes a sign.
+fn is_hex(digits: &str) -> bool {
+    digits.bytes().all(|byte| byte.is_ascii_hexdigit())
+}
+
+impl FromStr for Color {
+    type Err = Error;
+
+    fn from_str(specification: &str) -> Result<Self, Self::Err> {
+        let parsed = if let Some(digits) = specification.strip_prefix('#') {
+            Self::parse_hex(digits)
+        } else if let Some(components) = specification.strip_prefix("rgb:") {
+            Self::parse_rgb(components)
+        } else {
+            None
+        };
+
+        parsed.ok_or_else(|| Error::InvalidColor(specification.to_string()))
+    }
+}
+
+pub struct LookupColor(xproto::LookupColorRequest);
+
+impl Request for LookupColor {
+    type Reply = Color;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<Color, Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        Ok(Color {
+            red: reply.exact_red,
+            green: reply.exact_green,
+            blue: reply.exact_blue,
+        })
+    }
+}
+
+pub struct AllocColor(xproto::AllocColorRequest);
+
+impl Request for AllocColor {
+    type Reply = u32;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<u32, Error> {
+        Ok(self.0.wait(connection, sequence)?.pixel)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Colormap<'a> {
+    connection: &'a Connection,
+    handle: xcb_colormap_t,
+    visual: xcb_visualtype_t,
+}
+
+impl<'a> Colormap<'a> {
+    pub fn default(connection: &'a Connection) -> Result<Self, Error> {
+        Ok(Self {
+            connection,
+            handle: connection.get_default_colormap()?,
+            visual: connection.get_visual_type(connection.get_root_visual()?)?,
+        })
+    }
+
+    pub fn id(&self) -> u32 {
+        self.handle
+    }
+
+    pub fn lookup_color(&self, name: &str) -> Cookie<'_, LookupColor> {
+        xproto::lookup_color(self.connection, self.handle, name.as_bytes()).wrap(LookupColor)
+    }
+
+    pub fn alloc_color(&self, color: Color) -> Cookie<'_, AllocColor> {
+        xproto::alloc_color(
+            self.connection,
+            self.handle,
+            color.red,
+            color.green,
+            color.blue,
+        )
+        .wrap(AllocColor)
+    }
+
+    pub fn get_pixel(&self, color: Color) -> Result<u32, Error> {
+        if u32::from(self.visual._class)
+            == xcb_system::xcb_visual_class_t_XCB_VISUAL_CLASS_TRUE_COLOR
+        {
+            let scale = |value: u16, mask: u32| {
+                if mask == 0 {
+                    return 0;
+                }
+
+                let bits = mask.count_ones();
+                let value = if bits > 16 {
+                    u32::from(value) << (bits - 16)
+                } else {
+                    u32::from(value) >> (16 - bits)
+                };
+
+                value << mask.trailing_zeros()
+            };
+
+            return Ok(scale(color.red, self.visual.red_mask)
+                | scale(color.green, self.visual.green_mask)
+                | scale(color.blue, self.visual.blue_mask));
+        }
+
+        self.alloc_color(color).get_result()
+    }
+
+    pub fn parse_color(&self, specification: &str) -> Result<Color, Error> {
+        match specification.parse() {
+            Ok(color) => Ok(color),
+            Err(Error::InvalidColor(_)) => self.lookup_color(specification).get_result(),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn get_pixel_for(&self, specification: &str) -> Result<u32, Error> {
+        self.get_pixel(self.parse_color(specification)?)
+    }
+}
+
+// Windows with a visual other than the root's need a colormap created for that visual.
+#[derive(Debug)]
+pub struct OwnedColormap<'a> {
+    connection: &'a Connection,
+    handle: xcb_colormap_t,
+    visual: VisualType,
+}
+
+impl<'a> OwnedColormap<'a> {
+    pub fn new(
+        connection: &'a Connection,
+        window: &dyn Window,
+        visual: VisualType,
+    ) -> Result<Self, Error> {
+        let handle = unsafe { xcb_system::xcb_generate_id(connection.get_connection()) };
+
+        xproto::create_colormap(
+            connection,
+            xproto::ColormapAlloc::NONE.0 as u8,
+            handle,
+            window.id(),
+            visual.id,
+        )
+        .get_result()?;
+
+        Ok(Self {
+            connection,
+            handle,
+            visual,
+        })
+    }
+
+    pub fn id(&self) -> u32 {
+        self.handle
+    }
+
+    pub fn visual(&self) -> VisualType {
+        self.visual
+    }
+
+    fn free(&self) -> Cookie<'_, Void> {
+        xproto::free_colormap(self.connection, self.handle)
+    }
+}
+
+impl Drop for OwnedColormap<'_> {
+    fn drop(&mut self) {
+        self.free().discard();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color(red: u16, green: u16, blue: u16) -> Option<Color> {
+        Some(Color { red, green, blue })
+    }
+
+    #[test]
+    fn specifications() {
+        let cases = [
+            ("#fff", color(0xf000, 0xf000, 0xf000)),
+            ("#123", color(0x1000, 0x2000, 0x3000)),
+            ("#3b4252", color(0x3b00, 0x4200, 0x5200)),
+            ("#ECEFF4", color(0xec00, 0xef00, 0xf400)),
+            ("#123456789", color(0x1230, 0x4560, 0x7890)),
+            ("#0000ffff8000", color(0x0000, 0xffff, 0x8000)),
+            ("rgb:f/0/8", color(0xffff, 0x0000, 0x8888)),
+            ("rgb:ff/80/00", color(0xffff, 0x8080, 0x0000)),
+            ("rgb:fff/800/000", color(0xffff, 0x8007, 0x0000)),
+            ("rgb:ffff/8000/0001", color(0xffff, 0x8000, 0x0001)),
+            ("rgb:f/80/800", color(0xffff, 0x8080, 0x8007)),
+        ];
+
+        for (specification, expected) in cases.iter() {
+            assert_eq!(specification.parse().ok(), *expected, "{}", specification);
+        }
+    }
+
+    #[test]
+    fn malformed_specifications() {
+        let cases = [
+            "",
+            "fff",
+            "#",
+            "#ff",
+            "#ffff",
+            "#fffffffffffffff",
+            "#ggg",
+            "#+1+2+3",
+            "#-1-2-3",
+            "#ää",
+            "rgb:",
+            "rgb:f/f",
+            "rgb:f/f/f/f",
+            "rgb:f//f",
+            "rgb:fffff/0/0",
+            "rgb:+f/0/0",
+            "rgb:g/0/0",
+            "rgb: f/0/0",
+            "RGB:f/0/0",
+            "red",
+        ];
+
+        for specification in cases.iter() {
+            assert!(
+                matches!(specification.parse::<Color>(), Err(Error::InvalidColor(_))),
+                "{}",
+                specification
+            );
+        }
+    }
+}
@@ -1,6 +1,16 @@
-use crate::event::{Event, EventMask, MouseButton};
-use crate::result::{Error, XcbResult};
-use crate::window::{Window, WindowHandle};
+use crate::drawable::Drawable;
+use crate::event::{CrossingMode, Event, ModMask, MouseButton};
+use crate::extension::{Extension, ExtensionInfo};
+use crate::focus::{FocusTarget, InputFocus, RevertTo};
+use crate::grab::{AllowEventsMode, GrabStatus, KeyboardGrab, PointerGrab};
+use crate::image::{ByteOrder, Image, PixmapFormat};
+use crate::keyboard::KeyboardMapping;
+use crate::proto::{self, xproto};
+use crate::randr;
+use crate::result::{Batch, Cookie, Error, Request, Sequence, Void};
+use crate::screen::{Screen, Screens};
+use crate::window::{ConfigureRequest, Window, WindowHandle};
+use crate::xinerama;
 use crate::Rectangle;
 use xcb_system::{
     xcb_connect, xcb_connection_has_error, xcb_connection_t, xcb_disconnect, xcb_get_setup,
@@ -47,6 +57,86 @@ impl Connection {
         }
     }
 
+    // A connection to no display, for tests that don't reach a server. libxcb ignores it
+    // when it's disconnected.
+    #[cfg(test)]
+    pub(crate) fn disconnected() -> Self {
+        Connection {
+            connection: std::ptr::null_mut(),
+            setup: std::ptr::null(),
+            default_screen: 0,
+        }
+    }
+
+    // Connects to the display in $DISPLAY, but uses `screen` in place of its default screen.
+    pub fn with_screen(screen: u32) -> Result<Self, Error> {
+        let mut connection = Self::new()?;
+
+        connection.get_screen(screen)?;
+        connection.default_screen = screen;
+
+        Ok(connection)
+    }
+
+    pub fn screen_number(&self) -> u32 {
+        self.default_screen
+    }
+
+    pub fn screen(&self) -> Result<Screen<'_>, Error> {
+        self.screens()
+            .nth(self.default_screen as usize)
+            .ok_or(Error::ScreenNotFound(self.default_screen))
+    }
+
+    pub fn screens(&self) -> Screens<'_> {
+        Screens::new(self, unsafe {
+            xcb_system::xcb_setup_roots_iterator(self.setup)
+        })
+    }
+
+    // Asks the server for any extension by name, `None` if it doesn't have it.
+    pub fn query_extension(&self, name: &str) -> Cookie<'_, QueryExtension> {
+        xproto::query_extension(self, name.as_bytes()).wrap(QueryExtension)
+    }
+
+    // Sends the query for an extension without waiting for it, so a later `extension_data`
+    // doesn't cost a round trip.
+    pub fn prefetch_extension_data(&self, extension: Extension) {
+        unsafe { xcb_system::xcb_prefetch_extension_data(self.connection, extension.raw()) };
+    }
+
+    // libxcb caches the answer, only the first call for an extension waits for the server.
+    // Requests to a missing extension close the connection, so this has to be checked first.
+    pub fn extension_data(&self, extension: Extension) -> Option<ExtensionInfo> {
+        let data = unsafe { xcb_system::xcb_get_extension_data(self.connection, extension.raw()) };
+
+        if data.is_null() {
+            return None;
+        }
+
+        ExtensionInfo::from_reply(unsafe { &*data })
+    }
+
+    // Sends the requests libxcb is still holding, which it otherwise only does once it has to
+    // wait for something. Discarded results never make it wait.
+    pub fn flush(&self) {
+        unsafe { xcb_system::xcb_flush(self.connection) };
+    }
+
+    // Makes the requests `requests` pushes into the batch, and only then waits for their
+    // results, which come back in the order they were pushed.
+    pub fn batch<'a, R: Request>(
+        &'a self,
+        requests: impl FnOnce(&mut Batch<'a, R>),
+    ) -> Vec<Result<R::Reply, Error>> {
+        let mut batch = Batch::new();
+
+        requests(&mut batch);
+        self.flush();
+
+        batch.resolve()
+    }
+
     pub fn get_vendor(&self) -> Result<String, Error> {
         let length = unsafe { xcb_system::xcb_setup_vendor_length(self.setup) } as usize;
         let vendor = unsafe { xcb_system::xcb_setup_vendor(self.setup) };
@@ -60,198 +150,380 @@ impl Connection {
         Ok(String::from_utf8(buf)?)
     }
 
-    pub fn get_root_window(&self) -> Result<WindowHandle, Error> {
+    pub fn get_root_window(&self) -> Result<WindowHandle<'_>, Error> {
         let screen = self.get_screen(self.default_screen)?;
 
-        Ok(WindowHandle::new(screen.root, &self))
+        Ok(WindowHandle::new(screen.root, self))
     }
 
-    pub(crate) fn get_root_visual(&self) -> Result<xcb_system::xcb_visualid_t, Error> {
+    pub fn get_root_visual(&self) -> Result<xcb_system::xcb_visualid_t, Error> {
         Ok(self.get_screen(self.default_screen)?.root_visual)
     }
 
-    pub fn wait_for_event(&self) -> Event {
-        let event_ptr = unsafe { xcb_system::xcb_wait_for_event(self.connection) };
+    pub(crate) fn get_default_colormap(&self) -> Result<xcb_system::xcb_colormap_t, Error> {
+        Ok(self.get_screen(self.default_screen)?.default_colormap)
+    }
 
-        if event_ptr.is_null() {
-            panic!("failed to wait for event");
+    // The monitors of the default screen from RandR 1.5, or Xinerama on servers without it,
+    // or the whole screen if there is neither.
+    pub fn monitors(&self) -> Result<Vec<Rectangle>, Error> {
+        let root = self.get_root_window()?;
+
+        if randr::screen_change_notify_event(self).is_some()
+            && randr::query_version(self).get_result()? >= (1, 5)
+        {
+            let monitors = randr::get_monitors(self, &root, true).get_result()?;
+
+            if !monitors.is_empty() {
+                return Ok(monitors
+                    .into_iter()
+                    .map(|monitor| monitor.rectangle)
+                    .collect());
+            }
         }
 
-        let event = unsafe { *event_ptr };
-
-        match u32::from(event.response_type & !0x80) {
-            xcb_system::XCB_CREATE_NOTIFY => {
-                let create_notify_event =
-                    unsafe { *(event_ptr as *const xcb_system::xcb_create_notify_event_t) };
+        if xinerama::is_present(self) && xinerama::is_active(self).get_result()? {
+            let screens = xinerama::query_screens(self).get_result()?;
 
-                Event::WindowCreated {
-                    window: WindowHandle::new(create_notify_event.window, &self),
-                }
+            if !screens.is_empty() {
+                return Ok(screens);
             }
-            xcb_system::XCB_DESTROY_NOTIFY => {
-                let destroy_notify_event =
-                    unsafe { *(event_ptr as *const xcb_system::xcb_destroy_notify_event_t) };
+        }
 
-                Event::WindowDestroyed {
-                    window: WindowHandle::new(destroy_notify_event.window, &self),
-                }
-            }
-            xcb_system::XCB_CONFIGURE_NOTIFY => {
-                let configure_notify_event =
-                    unsafe { *(event_ptr as *const xcb_system::xcb_configure_notify_event_t) };
+        let screen = self.get_screen(self.default_screen)?;
 
-                Event::WindowConfigured {
-                    window: WindowHandle::new(configure_notify_event.window, &self),
-                }
-            }
-            xcb_system::XCB_UNMAP_NOTIFY => {
-                let unmap_notify_event =
-                    unsafe { *(event_ptr as *const xcb_system::xcb_unmap_notify_event_t) };
+        Ok(vec![Rectangle {
+            x: 0,
+            y: 0,
+            width: screen.width_in_pixels,
+            height: screen.height_in_pixels,
+        }])
+    }
 
-                Event::WindowUnmapped {
-                    window: WindowHandle::new(unmap_notify_event.window, &self),
-                }
-            }
-            xcb_system::XCB_MAP_NOTIFY => {
-                let map_notify_event =
-                    unsafe { *(event_ptr as *const xcb_system::xcb_map_notify_event_t) };
+    pub(crate) fn get_visual_type(
+        &self,
+        visual: xcb_system::xcb_visualid_t,
+    ) -> Result<xcb_system::xcb_visualtype_t, Error> {
+        let mut screens = unsafe { xcb_system::xcb_setup_roots_iterator(self.setup) };
 
-                Event::WindowMapped {
-                    window: WindowHandle::new(map_notify_event.window, &self),
-                }
-            }
-            xcb_system::XCB_CONFIGURE_REQUEST => {
-                let configure_request =
-                    unsafe { *(event_ptr as *const xcb_system::xcb_configure_request_event_t) };
+        while screens.rem > 0 {
+            let mut depths =
+                unsafe { xcb_system::xcb_screen_allowed_depths_iterator(screens.data) };
 
-                Event::WindowConfigurationRequest {
-                    window: WindowHandle::new(configure_request.window, &self),
-                    rectangle: Rectangle {
-                        x: (configure_request.x),
-                        y: (configure_request.y),
-                        width: (configure_request.width),
-                        height: (configure_request.height),
-                    },
-                }
-            }
-            xcb_system::XCB_MAP_REQUEST => {
-                let map_request =
-                    unsafe { *(event_ptr as *const xcb_system::xcb_map_request_event_t) };
+            while depths.rem > 0 {
+                let mut visuals = unsafe { xcb_system::xcb_depth_visuals_iterator(depths.data) };
+
+                while visuals.rem > 0 {
+                    let visual_type = unsafe { *visuals.data };
+
+                    if visual_type.visual_id == visual {
+                        return Ok(visual_type);
+                    }
 
-                Event::WindowMappingRequest {
-                    window: WindowHandle::new(map_request.window, &self),
+                    unsafe { xcb_system::xcb_visualtype_next(&mut visuals) };
                 }
+
+                unsafe { xcb_system::xcb_depth_next(&mut depths) };
             }
-            xcb_system::XCB_REPARENT_NOTIFY => {
-                let reparent =
-                    unsafe { *(event_ptr as *const xcb_system::xcb_reparent_notify_event_t) };
 
-                Event::WindowReparented {
-                    window: WindowHandle::new(reparent.window, &self),
+            unsafe { xcb_system::xcb_screen_next(&mut screens) };
+        }
+
+        Err(Error::VisualNotFound(visual))
+    }
+
+    pub fn image_byte_order(&self) -> ByteOrder {
+        ByteOrder::from_raw(unsafe { (*self.setup).image_byte_order })
+    }
+
+    pub fn bitmap_bit_order(&self) -> ByteOrder {
+        ByteOrder::from_raw(unsafe { (*self.setup).bitmap_format_bit_order })
+    }
+
+    pub fn pixmap_formats(&self) -> Vec<PixmapFormat> {
+        let length = unsafe { xcb_system::xcb_setup_pixmap_formats_length(self.setup) } as usize;
+        let formats = unsafe { xcb_system::xcb_setup_pixmap_formats(self.setup) };
+
+        (0..length)
+            .map(|i| {
+                let format = unsafe { *formats.add(i) };
+
+                PixmapFormat {
+                    depth: format.depth,
+                    bits_per_pixel: format.bits_per_pixel,
+                    scanline_pad: format.scanline_pad,
                 }
-            }
-            xcb_system::XCB_MOTION_NOTIFY => {
-                let motion_notify =
-                    unsafe { *(event_ptr as *const xcb_system::xcb_motion_notify_event_t) };
-
-                Event::MotionNotify {
-                    window: WindowHandle::new(motion_notify.event, &self),
-                    x: motion_notify.root_x,
-                    y: motion_notify.root_y,
+            })
+            .collect()
+    }
+
+    pub fn get_pixmap_format(&self, depth: u8) -> Result<PixmapFormat, Error> {
+        self.pixmap_formats()
+            .into_iter()
+            .find(|format| format.depth == depth)
+            .ok_or(Error::UnsupportedDepth(depth))
+    }
+
+    pub fn create_image(&self, width: u16, height: u16, depth: u8) -> Result<Image, Error> {
+        Ok(Image::new(
+            width,
+            height,
+            self.get_pixmap_format(depth)?,
+            self.image_byte_order(),
+            self.bitmap_bit_order(),
+        ))
+    }
+
+    pub fn get_image(&self, drawable: &dyn Drawable, rectangle: Rectangle) -> Cookie<'_, GetImage> {
+        let formats = self.pixmap_formats();
+        let (byte_order, bit_order) = (self.image_byte_order(), self.bitmap_bit_order());
+
+        xproto::get_image(
+            self,
+            xproto::ImageFormat::Z_PIXMAP.0 as u8,
+            drawable.drawable_id(),
+            rectangle.x,
+            rectangle.y,
+            rectangle.width,
+            rectangle.height,
+            !0,
+        )
+        .wrap(|request| GetImage {
+            request,
+            width: rectangle.width,
+            height: rectangle.height,
+            formats,
+            byte_order,
+            bit_order,
+        })
+    }
+
+    pub fn intern_atom(&self, name: &str) -> Cookie<'_, InternAtom> {
+        xproto::intern_atom(self, false, name.as_bytes()).wrap(InternAtom)
+    }
+
+    pub fn wait_for_event(&self) -> Event<'_> {
+        let event_ptr = unsafe { xcb_system::xcb_wait_for_event(self.connection) };
+
+        if event_ptr.is_null() {
+            panic!("failed to wait for event");
+        }
+
+        self.parse_event(event_ptr)
+    }
+
+    // Only returns events that have already been read from the connection, without
+    // touching the socket.
+    pub fn poll_for_queued_event(&self) -> Option<Event<'_>> {
+        let event_ptr = unsafe { xcb_system::xcb_poll_for_queued_event(self.connection) };
+
+        if event_ptr.is_null() {
+            None
+        } else {
+            Some(self.parse_event(event_ptr))
+        }
+    }
+
+    // The event is decoded into owned values, so it's freed straight away.
+    fn parse_event(&self, event_ptr: *mut xcb_system::xcb_generic_event_t) -> Event<'_> {
+        let event = unsafe { &*event_ptr };
+        let core = xproto::decode_event(event);
+        let randr = self
+            .extension_data(Extension::RandR)
+            .and_then(|info| proto::randr::decode_event(event, info.first_event));
+
+        unsafe { libc::free(event_ptr as *mut libc::c_void) };
+
+        match (core, randr) {
+            (Some(event), _) => self.core_event(event),
+            (None, Some(proto::randr::Event::ScreenChangeNotify(screen_change))) => {
+                Event::ScreenChanged {
+                    root: WindowHandle::new(screen_change.root, self),
+                    width: screen_change.width,
+                    height: screen_change.height,
+                    timestamp: screen_change.timestamp,
+                    config_timestamp: screen_change.config_timestamp,
                 }
             }
-            xcb_system::XCB_BUTTON_PRESS => {
-                let button_press =
-                    unsafe { *(event_ptr as *const xcb_system::xcb_button_press_event_t) };
-
-                Event::ButtonPressed {
-                    root_window: WindowHandle::new(button_press.root, &self),
-                    child_window: if button_press.child == 0 {
-                        None
-                    } else {
-                        Some(WindowHandle::new(button_press.child, &self))
-                    },
-                    button: match button_press.detail {
-                        1 => MouseButton::Left,
-                        2 => MouseButton::Middle,
-                        3 => MouseButton::Right,
-                        4 => MouseButton::ScrollUp,
-                        5 => MouseButton::ScrollDown,
-                        _ => panic!("Unknown mouse button {}", button_press.detail),
-                    },
+            _ => Event::Unknown,
+        }
+    }
+
+    fn core_event(&self, event: xproto::Event) -> Event<'_> {
+        match event {
+            xproto::Event::CreateNotify(create_notify) => Event::WindowCreated {
+                window: WindowHandle::new(create_notify.window, self),
+            },
+            xproto::Event::DestroyNotify(destroy_notify) => Event::WindowDestroyed {
+                window: WindowHandle::new(destroy_notify.window, self),
+            },
+            xproto::Event::ConfigureNotify(configure_notify) => Event::WindowConfigured {
+                window: WindowHandle::new(configure_notify.window, self),
+            },
+            xproto::Event::UnmapNotify(unmap_notify) => Event::WindowUnmapped {
+                window: WindowHandle::new(unmap_notify.window, self),
+            },
+            xproto::Event::MapNotify(map_notify) => Event::WindowMapped {
+                window: WindowHandle::new(map_notify.window, self),
+            },
+            xproto::Event::ConfigureRequest(configure_request) => {
+                Event::WindowConfigurationRequest {
+                    window: WindowHandle::new(configure_request.window, self),
+                    request: ConfigureRequest::from_event(&configure_request),
                 }
             }
-            xcb_system::XCB_BUTTON_RELEASE => {
-                let button_release =
-                    unsafe { *(event_ptr as *const xcb_system::xcb_button_release_event_t) };
-
-                Event::ButtonReleased {
-                    root_window: WindowHandle::new(button_release.root, &self),
-                    child_window: if button_release.child == 0 {
-                        None
-                    } else {
-                        Some(WindowHandle::new(button_release.child, &self))
-                    },
-                    button: match button_release.detail {
-                        1 => MouseButton::Left,
-                        2 => MouseButton::Middle,
-                        3 => MouseButton::Right,
-                        4 => MouseButton::ScrollUp,
-                        5 => MouseButton::ScrollDown,
-                        _ => panic!("Unknown mouse button {}", button_release.detail),
-                    },
+            xproto::Event::MapRequest(map_request) => Event::WindowMappingRequest {
+                window: WindowHandle::new(map_request.window, self),
+            },
+            xproto::Event::ReparentNotify(reparent) => Event::WindowReparented {
+                window: WindowHandle::new(reparent.window, self),
+            },
+            xproto::Event::MotionNotify(motion_notify) => Event::MotionNotify {
+                window: WindowHandle::new(motion_notify.event, self),
+                x: motion_notify.root_x,
+                y: motion_notify.root_y,
+            },
+            xproto::Event::ButtonPress(button_press) => Event::ButtonPressed {
+                window: WindowHandle::new(button_press.event, self),
+                root_window: WindowHandle::new(button_press.root, self),
+                child_window: if button_press.child == 0 {
+                    None
+                } else {
+                    Some(WindowHandle::new(button_press.child, self))
+                },
+                button: MouseButton::from_raw(button_press.detail),
+                x: button_press.root_x,
+                y: button_press.root_y,
+                modifiers: ModMask::from_bits_truncate(u32::from(button_press.state)),
+                time: button_press.time,
+            },
+            xproto::Event::ButtonRelease(button_release) => Event::ButtonReleased {
+                window: WindowHandle::new(button_release.event, self),
+                root_window: WindowHandle::new(button_release.root, self),
+                child_window: if button_release.child == 0 {
+                    None
+                } else {
+                    Some(WindowHandle::new(button_release.child, self))
+                },
+                button: MouseButton::from_raw(button_release.detail),
+                x: button_release.root_x,
+                y: button_release.root_y,
+                modifiers: ModMask::from_bits_truncate(u32::from(button_release.state)),
+                time: button_release.time,
+            },
+            xproto::Event::KeyPress(key_press) => Event::KeyPressed {
+                window: WindowHandle::new(key_press.event, self),
+                keycode: key_press.detail,
+                modifiers: ModMask::from_bits_truncate(u32::from(key_press.state)),
+                time: key_press.time,
+            },
+            xproto::Event::EnterNotify(enter_notify) => Event::PointerEntered {
+                window: WindowHandle::new(enter_notify.event, self),
+                mode: CrossingMode::from_raw(enter_notify.mode),
+                time: enter_notify.time,
+            },
+            xproto::Event::ClientMessage(client_message) => {
+                let mut data = [0; 5];
+
+                for (value, bytes) in data.iter_mut().zip(client_message.data.chunks_exact(4)) {
+                    *value = u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
                 }
-            }
-            _ => {
-                println!("Unknown event: {:?}", event);
 
-                Event::Unknown
+                Event::ClientMessage {
+                    window: WindowHandle::new(client_message.window, self),
+                    message_type: client_message.type_,
+                    format: client_message.format,
+                    data,
+                }
             }
+            xproto::Event::Expose(expose) => Event::WindowExposed {
+                window: WindowHandle::new(expose.window, self),
+                rectangle: Rectangle {
+                    x: expose.x as i16,
+                    y: expose.y as i16,
+                    width: expose.width,
+                    height: expose.height,
+                },
+                count: expose.count,
+            },
+            xproto::Event::PropertyNotify(property_notify) => Event::WindowPropertyChanged {
+                window: WindowHandle::new(property_notify.window, self),
+                atom: property_notify.atom,
+            },
+            _ => Event::Unknown,
         }
     }
 
-    pub fn grab_pointer(&self) -> XcbResult<xcb_system::xcb_grab_pointer_reply_t, bool> {
-        let cookie = unsafe {
-            xcb_system::xcb_grab_pointer(
-                self.connection,
-                0,
-                self.get_root_window().unwrap().id(),
-                (EventMask::POINTER_MOTION | EventMask::BUTTON_RELEASE).bits() as u16,
-                xcb_system::xcb_grab_mode_t_XCB_GRAB_MODE_ASYNC as u8,
-                xcb_system::xcb_grab_mode_t_XCB_GRAB_MODE_ASYNC as u8,
-                xcb_system::XCB_NONE,
-                xcb_system::XCB_NONE,
-                xcb_system::XCB_CURRENT_TIME,
-            )
-        };
+    pub fn grab_pointer(&self, grab: &PointerGrab) -> Cookie<'_, GrabPointer> {
+        xproto::grab_pointer(
+            self,
+            grab.owner_events,
+            grab.grab_window,
+            grab.event_mask.bits() as u16,
+            grab.pointer_mode as u8,
+            grab.keyboard_mode as u8,
+            grab.confine_to,
+            grab.cursor,
+            grab.time,
+        )
+        .wrap(GrabPointer)
+    }
 
-        XcbResult::new(
-            Box::new(move |connection: &Connection| {
-                let mut error: *mut xcb_system::xcb_generic_error_t = std::ptr::null_mut();
-
-                let reply = unsafe {
-                    *xcb_system::xcb_grab_pointer_reply(
-                        connection.get_connection(),
-                        cookie,
-                        &mut error,
-                    )
-                };
-
-                (reply, error)
-            }),
-            Box::new(|reply| {
-                reply.status == xcb_system::xcb_grab_status_t_XCB_GRAB_STATUS_SUCCESS as u8
-            }),
-            &self,
+    pub fn ungrab_pointer(&self, time: u32) -> Cookie<'_, Void> {
+        xproto::ungrab_pointer(self, time)
+    }
+
+    pub fn grab_keyboard(&self, grab: &KeyboardGrab) -> Cookie<'_, GrabKeyboard> {
+        xproto::grab_keyboard(
+            self,
+            grab.owner_events,
+            grab.grab_window,
+            grab.time,
+            grab.pointer_mode as u8,
+            grab.keyboard_mode as u8,
         )
+        .wrap(GrabKeyboard)
     }
 
-    pub fn ungrab_pointer(&self) -> XcbResult<(), ()> {
-        let cookie = unsafe {
-            xcb_system::xcb_ungrab_pointer_checked(self.connection, xcb_system::XCB_CURRENT_TIME)
-        };
+    pub fn ungrab_keyboard(&self, time: u32) -> Cookie<'_, Void> {
+        xproto::ungrab_keyboard(self, time)
+    }
 
-        XcbResult::new_void(cookie, &self)
+    // Releases events queued by a synchronous grab, `ReplayPointer` hands the click that
+    // triggered a passive grab on to the window underneath.
+    pub fn allow_events(&self, mode: AllowEventsMode, time: u32) -> Cookie<'_, Void> {
+        xproto::allow_events(self, mode as u8, time)
+    }
+
+    // `None` discards keyboard input until the focus is set again.
+    pub fn set_input_focus(
+        &self,
+        window: Option<&dyn Window>,
+        revert_to: RevertTo,
+        time: u32,
+    ) -> Cookie<'_, Void> {
+        xproto::set_input_focus(
+            self,
+            revert_to as u8,
+            window.map_or(xcb_system::XCB_NONE, |window| window.id()),
+            time,
+        )
+    }
+
+    pub fn get_input_focus(&self) -> Cookie<'_, GetInputFocus> {
+        xproto::get_input_focus(self).wrap(GetInputFocus)
+    }
+
+    pub fn get_keyboard_mapping(&self) -> Cookie<'_, GetKeyboardMapping> {
+        let (min_keycode, max_keycode) =
+            unsafe { ((*self.setup).min_keycode, (*self.setup).max_keycode) };
+
+        xproto::get_keyboard_mapping(self, min_keycode, max_keycode - min_keycode + 1).wrap(
+            |request| GetKeyboardMapping {
+                request,
+                min_keycode,
+            },
+        )
     }
 
     pub(crate) fn get_connection(&self) -> *mut xcb_connection_t {
@@ -284,3 +556,133 @@ impl Drop for Connection {
         }
     }
 }
+
+pub struct QueryExtension(xproto::QueryExtensionRequest);
+
+impl Request for QueryExtension {
+    type Reply = Option<ExtensionInfo>;
+
+    fn wait(
+        self,
+        connection: &Connection,
+        sequence: Sequence,
+    ) -> Result<Option<ExtensionInfo>, Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        Ok(Some(ExtensionInfo {
+            major_opcode: reply.major_opcode,
+            first_event: reply.first_event,
+            first_error: reply.first_error,
+        })
+        .filter(|_| reply.present))
+    }
+}
+
+pub struct InternAtom(xproto::InternAtomRequest);
+
+impl Request for InternAtom {
+    type Reply = u32;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<u32, Error> {
+        Ok(self.0.wait(connection, sequence)?.atom)
+    }
+}
+
+pub struct GetImage {
+    request: xproto::GetImageRequest,
+    width: u16,
+    height: u16,
+    formats: Vec<PixmapFormat>,
+    byte_order: ByteOrder,
+    bit_order: ByteOrder,
+}
+
+impl Request for GetImage {
+    type Reply = Image;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<Image, Error> {
+        let reply = self.request.wait(connection, sequence)?;
+
+        // The server only returns depths it has a pixmap format for, and at least as much
+        // data as was asked for.
+        let format = self
+            .formats
+            .iter()
+            .find(|format| format.depth == reply.depth)
+            .ok_or(Error::InvalidReply)?;
+
+        Image::from_data(
+            self.width,
+            self.height,
+            *format,
+            self.byte_order,
+            self.bit_order,
+            reply.data,
+        )
+        .map_err(|_| Error::InvalidReply)
+    }
+}
+
+pub struct GrabPointer(xproto::GrabPointerRequest);
+
+impl Request for GrabPointer {
+    type Reply = GrabStatus;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<GrabStatus, Error> {
+        Ok(GrabStatus::from_raw(
+            self.0.wait(connection, sequence)?.status,
+        ))
+    }
+}
+
+pub struct GrabKeyboard(xproto::GrabKeyboardRequest);
+
+impl Request for GrabKeyboard {
+    type Reply = GrabStatus;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<GrabStatus, Error> {
+        Ok(GrabStatus::from_raw(
+            self.0.wait(connection, sequence)?.status,
+        ))
+    }
+}
+
+pub struct GetInputFocus(xproto::GetInputFocusRequest);
+
+impl Request for GetInputFocus {
+    type Reply = InputFocus;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<InputFocus, Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        Ok(InputFocus {
+            focus: match reply.focus {
+                xcb_system::XCB_NONE => FocusTarget::None,
+                xcb_system::xcb_input_focus_t_XCB_INPUT_FOCUS_POINTER_ROOT => {
+                    FocusTarget::PointerRoot
+                }
+                window => FocusTarget::Window(window),
+            },
+            revert_to: RevertTo::from_raw(reply.revert_to),
+        })
+    }
+}
+
+pub struct GetKeyboardMapping {
+    request: xproto::GetKeyboardMappingRequest,
+    min_keycode: u8,
+}
+
+impl Request for GetKeyboardMapping {
+    type Reply = KeyboardMapping;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<KeyboardMapping, Error> {
+        let reply = self.request.wait(connection, sequence)?;
+
+        Ok(KeyboardMapping::new(
+            self.min_keycode,
+            reply.keysyms_per_keycode,
+            reply.keysyms,
+        ))
+    }
+}
@@ -0,0 +1,424 @@
+use crate::connection::Connection;
+use crate::font::OwnedFont;
+use crate::graphics::OwnedGraphicsContext;
+use crate::pixmap::OwnedPixmap;
+use crate::proto::{render, xproto};
+use crate::render::{OwnedPicture, StandardFormat};
+use crate::result::{Cookie, Error, Void};
+use std::path::{Path, PathBuf};
+use xcb_system::xcb_cursor_t;
+
+const DEFAULT_CURSOR_PATH: &str =
+    "~/.local/share/icons:~/.icons:/usr/share/icons:/usr/share/pixmaps";
+const DEFAULT_CURSOR_THEME: &str = "default";
+const DEFAULT_CURSOR_SIZE: u32 = 24;
+const MAXIMUM_THEME_DEPTH: u32 = 8;
+
+const XCURSOR_MAGIC: &[u8] = b"Xcur";
+const XCURSOR_IMAGE_TYPE: u32 = 0xfffd_0002;
+const XCURSOR_MAXIMUM_SIZE: u32 = 0x7fff;
+
+// Glyph indices of the standard cursor font, see X11/cursorfont.h.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CursorGlyph {
+    XCursor = 0,
+    BottomLeftCorner = 12,
+    BottomRightCorner = 14,
+    BottomSide = 16,
+    Crosshair = 34,
+    Fleur = 52,
+    Hand2 = 60,
+    LeftPtr = 68,
+    LeftSide = 70,
+    RightSide = 96,
+    Sizing = 120,
+    TopLeftCorner = 134,
+    TopRightCorner = 136,
+    TopSide = 138,
+    Watch = 150,
+    XTerm = 152,
+}
+
+#[derive(Debug, Clone)]
+pub struct CursorImage {
+    pub width: u16,
+    pub height: u16,
+    pub x_hot: u16,
+    pub y_hot: u16,
+    // Premultiplied ARGB, row by row.
+    pub pixels: Vec<u32>,
+}
+
+#[derive(Debug)]
+pub struct OwnedCursor<'a> {
+    connection: &'a Connection,
+    handle: xcb_cursor_t,
+}
+
+impl<'a> OwnedCursor<'a> {
+    pub fn from_glyph(connection: &'a Connection, glyph: CursorGlyph) -> Result<Self, Error> {
+        // The server keeps the font alive for as long as the cursor references it.
+        let font = OwnedFont::open(connection, "cursor")?;
+        let handle = unsafe { xcb_system::xcb_generate_id(connection.get_connection()) };
+
+        // Every glyph in the cursor font is followed by its mask.
+        xproto::create_glyph_cursor(
+            connection,
+            handle,
+            font.id(),
+            font.id(),
+            glyph as u16,
+            glyph as u16 + 1,
+            0,
+            0,
+            0,
+            0xffff,
+            0xffff,
+            0xffff,
+        )
+        .get_result()?;
+
+        Ok(Self { connection, handle })
+    }
+
+    // Loads a cursor by name from the Xcursor theme named by XCURSOR_THEME, searching
+    // XCURSOR_PATH and following the themes it inherits from.
+    pub fn from_theme(connection: &'a Connection, name: &str) -> Result<Self, Error> {
+        let theme =
+            std::env::var("XCURSOR_THEME").unwrap_or_else(|_| DEFAULT_CURSOR_THEME.to_string());
+        let size = std::env::var("XCURSOR_SIZE")
+            .ok()
+            .and_then(|size| size.parse().ok())
+            .unwrap_or(DEFAULT_CURSOR_SIZE);
+
+        let path = find_cursor_file(&search_path(), &theme, name, 0)
+            .ok_or_else(|| Error::CursorNotFound(name.to_string()))?;
+        let data = std::fs::read(&path).map_err(|_| Error::CursorNotFound(name.to_string()))?;
+
+        Self::from_image(connection, &parse_xcursor(&data, size)?)
+    }
+
+    pub fn from_image(connection: &'a Connection, image: &CursorImage) -> Result<Self, Error> {
+        if image.pixels.len() < usize::from(image.width) * usize::from(image.height) {
+            return Err(Error::InvalidImageData);
+        }
+
+        crate::render::query_version(connection).get_result()?;
+        let format = crate::render::query_picture_formats(connection)
+            .get_result()?
+            .find_standard(StandardFormat::Argb32)
+            .ok_or(Error::UnsupportedDepth(32))?;
+
+        let pixmap = OwnedPixmap::new(connection, 32, image.width, image.height)?;
+        let mut pixels = connection.create_image(image.width, image.height, 32)?;
+
+        for y in 0..image.height {
+            for x in 0..image.width {
+                let index = usize::from(y) * usize::from(image.width) + usize::from(x);
+
+                pixels.put_pixel(x, y, image.pixels[index]);
+            }
+        }
+
+        OwnedGraphicsContext::new(connection, &pixmap)?
+            .put_image(&pixmap, 0, 0, &pixels)?
+            .get_result()?;
+
+        let picture = OwnedPicture::new(connection, &pixmap, format)?;
+        let handle = unsafe { xcb_system::xcb_generate_id(connection.get_connection()) };
+
+        render::create_cursor(connection, handle, picture.id(), image.x_hot, image.y_hot)
+            .get_result()?;
+
+        Ok(Self { connection, handle })
+    }
+
+    pub fn id(&self) -> u32 {
+        self.handle
+    }
+
+    fn free(&self) -> Cookie<'_, Void> {
+        xproto::free_cursor(self.connection, self.handle)
+    }
+}
+
+impl Drop for OwnedCursor<'_> {
+    fn drop(&mut self) {
+        self.free().discard();
+    }
+}
+
+fn search_path() -> Vec<PathBuf> {
+    let path = std::env::var("XCURSOR_PATH").unwrap_or_else(|_| DEFAULT_CURSOR_PATH.to_string());
+    let home = std::env::var("HOME").ok();
+
+    path.split(':')
+        .filter(|directory| !directory.is_empty())
+        .filter_map(|directory| match directory.strip_prefix('~') {
+            Some(rest) => home
+                .as_ref()
+                .map(|home| PathBuf::from(format!("{}{}", home, rest))),
+            None => Some(PathBuf::from(directory)),
+        })
+        .collect()
+}
+
+fn find_cursor_file(
+    search_path: &[PathBuf],
+    theme: &str,
+    name: &str,
+    depth: u32,
+) -> Option<PathBuf> {
+    if depth > MAXIMUM_THEME_DEPTH {
+        return None;
+    }
+
+    let found = search_path
+        .iter()
+        .map(|directory| directory.join(theme).join("cursors").join(name))
+        .find(|path| path.is_file());
+
+    if found.is_some() {
+        return found;
+    }
+
+    search_path
+        .iter()
+        .filter_map(|directory| inherited_themes(&directory.join(theme).join("index.theme")))
+        .flatten()
+        .filter(|parent| parent != theme)
+        .find_map(|parent| find_cursor_file(search_path, &parent, name, depth + 1))
+}
+
+fn inherited_themes(index: &Path) -> Option<Vec<String>> {
+    let contents = std::fs::read_to_string(index).ok()?;
+    let inherits = contents
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Inherits"))?
+        .trim_start()
+        .strip_prefix('=')?;
+
+    Some(
+        inherits
+            .split([',', ';'])
+            .map(|theme| theme.trim().to_string())
+            .filter(|theme| !theme.is_empty())
+            .collect(),
+    )
+}
+
+// Picks the image whose nominal size is closest to the requested one, the first frame of
+// animated cursors is used.
+fn parse_xcursor(data: &[u8], size: u32) -> Result<CursorImage, Error> {
+    let read = |offset: usize| -> Result<u32, Error> {
+        data.get(offset..offset + 4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .ok_or(Error::InvalidCursorFile)
+    };
+
+    if data.get(0..4) != Some(XCURSOR_MAGIC) {
+        return Err(Error::InvalidCursorFile);
+    }
+
+    let header_length = read(4)? as usize;
+    let entries = read(12)? as usize;
+    let mut best: Option<(u32, usize)> = None;
+
+    for index in 0..entries {
+        let entry = header_length + index * 12;
+
+        if read(entry)? != XCURSOR_IMAGE_TYPE {
+            continue;
+        }
+
+        let nominal_size = read(entry + 4)?;
+        let position = read(entry + 8)? as usize;
+        let distance = (i64::from(nominal_size) - i64::from(size)).unsigned_abs() as u32;
+
+        if best.is_none_or(|(best_distance, _)| distance < best_distance) {
+            best = Some((distance, position));
+        }
+    }
+
+    let (_, position) = best.ok_or(Error::InvalidCursorFile)?;
+    let chunk_length = read(position)? as usize;
+    let width = read(position + 16)?;
+    let height = read(position + 20)?;
+
+    if read(position + 4)? != XCURSOR_IMAGE_TYPE
+        || width == 0
+        || height == 0
+        || width > XCURSOR_MAXIMUM_SIZE
+        || height > XCURSOR_MAXIMUM_SIZE
+    {
+        return Err(Error::InvalidCursorFile);
+    }
+
+    let pixels = (0..(width * height) as usize)
+        .map(|index| read(position + chunk_length + index * 4))
+        .collect::<Result<Vec<u32>, Error>>()?;
+
+    Ok(CursorImage {
+        width: width as u16,
+        height: height as u16,
+        x_hot: read(position + 24)?.min(width - 1) as u16,
+        y_hot: read(position + 28)?.min(height - 1) as u16,
+        pixels,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER_LENGTH: u32 = 16;
+    const TOC_ENTRY_LENGTH: u32 = 12;
+    const IMAGE_HEADER_LENGTH: u32 = 36;
+
+    // An Xcursor file with a square image of every nominal size, each filled with its size.
+    fn xcursor(sizes: &[u32]) -> Vec<u8> {
+        fn push(data: &mut Vec<u8>, value: u32) {
+            data.extend(&value.to_le_bytes());
+        }
+
+        let mut data = vec![];
+
+        data.extend(XCURSOR_MAGIC);
+        push(&mut data, HEADER_LENGTH);
+        push(&mut data, 0x0001_0000);
+        push(&mut data, sizes.len() as u32);
+
+        let mut position = HEADER_LENGTH + TOC_ENTRY_LENGTH * sizes.len() as u32;
+
+        for size in sizes {
+            push(&mut data, XCURSOR_IMAGE_TYPE);
+            push(&mut data, *size);
+            push(&mut data, position);
+            position += IMAGE_HEADER_LENGTH + size * size * 4;
+        }
+
+        for size in sizes {
+            push(&mut data, IMAGE_HEADER_LENGTH);
+            push(&mut data, XCURSOR_IMAGE_TYPE);
+            push(&mut data, *size);
+            push(&mut data, 1);
+            push(&mut data, *size);
+            push(&mut data, *size);
+            push(&mut data, size / 2);
+            push(&mut data, size / 2);
+            push(&mut data, 0);
+
+            for _ in 0..size * size {
+                push(&mut data, *size);
+            }
+        }
+
+        data
+    }
+
+    #[test]
+    fn minimal_file() {
+        let image = parse_xcursor(&xcursor(&[2]), 24).unwrap();
+
+        assert_eq!((image.width, image.height), (2, 2));
+        assert_eq!((image.x_hot, image.y_hot), (1, 1));
+        assert_eq!(image.pixels, vec![2; 4]);
+    }
+
+    #[test]
+    fn nearest_size() {
+        let data = xcursor(&[4, 8, 16]);
+
+        assert_eq!(parse_xcursor(&data, 7).unwrap().width, 8);
+        assert_eq!(parse_xcursor(&data, 13).unwrap().width, 16);
+        assert_eq!(parse_xcursor(&data, 1).unwrap().width, 4);
+        assert_eq!(parse_xcursor(&data, 64).unwrap().width, 16);
+        // Ties go to the first image.
+        assert_eq!(parse_xcursor(&data, 12).unwrap().width, 8);
+    }
+
+    #[test]
+    fn not_an_xcursor_file() {
+        let mut data = xcursor(&[2]);
+
+        data[0] = b'P';
+
+        assert!(matches!(
+            parse_xcursor(&data, 24),
+            Err(Error::InvalidCursorFile)
+        ));
+    }
+
+    #[test]
+    fn truncated_header() {
+        let data = xcursor(&[2]);
+
+        for length in &[0, 3, 8, 15] {
+            assert!(matches!(
+                parse_xcursor(&data[..*length], 24),
+                Err(Error::InvalidCursorFile)
+            ));
+        }
+    }
+
+    #[test]
+    fn truncated_image() {
+        let data = xcursor(&[2]);
+
+        assert!(matches!(
+            parse_xcursor(&data[..data.len() - 1], 24),
+            Err(Error::InvalidCursorFile)
+        ));
+    }
+
+    #[test]
+    fn toc_entry_past_the_end_of_the_file() {
+        let mut data = xcursor(&[2]);
+        let position = (HEADER_LENGTH + 8) as usize;
+
+        data[position..position + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+
+        assert!(matches!(
+            parse_xcursor(&data, 24),
+            Err(Error::InvalidCursorFile)
+        ));
+    }
+
+    #[test]
+    fn more_toc_entries_than_the_file_holds() {
+        let mut data = xcursor(&[2]);
+
+        data[12..16].copy_from_slice(&1000u32.to_le_bytes());
+
+        assert!(matches!(
+            parse_xcursor(&data, 24),
+            Err(Error::InvalidCursorFile)
+        ));
+    }
+
+    #[test]
+    fn inherited_themes_are_searched() {
+        let directory =
+            std::env::temp_dir().join(format!("xcb-cursor-test-{}", std::process::id()));
+        let base = directory.join("base").join("cursors");
+
+        std::fs::create_dir_all(&base).unwrap();
+        std::fs::create_dir_all(directory.join("derived")).unwrap();
+        std::fs::write(base.join("left_ptr"), xcursor(&[2])).unwrap();
+        std::fs::write(
+            directory.join("derived").join("index.theme"),
+            "[Icon Theme]\nInherits = derived, base\n",
+        )
+        .unwrap();
+
+        let search_path = [PathBuf::from("/nonexistent"), directory.clone()];
+
+        assert_eq!(
+            find_cursor_file(&search_path, "derived", "left_ptr", 0),
+            Some(base.join("left_ptr"))
+        );
+        assert_eq!(find_cursor_file(&search_path, "derived", "watch", 0), None);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,11 @@
+use crate::window::Window;
+
+pub trait Drawable {
+    fn drawable_id(&self) -> u32;
+}
+
+impl<T: Window + ?Sized> Drawable for T {
+    fn drawable_id(&self) -> u32 {
+        self.id()
+    }
+}
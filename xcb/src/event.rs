@@ -1,13 +1,63 @@
-use crate::window::WindowHandle;
+use crate::window::{ConfigureRequest, WindowHandle};
 use crate::Rectangle;
 
-#[derive(Copy, Clone, Debug)]
+pub use crate::proto::xproto::{EventMask, ModMask};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum MouseButton {
-    Left = 1,
-    Middle = 2,
-    Right = 3,
-    ScrollUp = 4,
-    ScrollDown = 5,
+    Left,
+    Middle,
+    Right,
+    ScrollUp,
+    ScrollDown,
+    // Horizontal scrolling, back, forward and whatever else the pointer has.
+    Other(u8),
+}
+
+impl MouseButton {
+    pub(crate) fn from_raw(raw: u8) -> Self {
+        match raw {
+            1 => MouseButton::Left,
+            2 => MouseButton::Middle,
+            3 => MouseButton::Right,
+            4 => MouseButton::ScrollUp,
+            5 => MouseButton::ScrollDown,
+            _ => MouseButton::Other(raw),
+        }
+    }
+
+    pub(crate) fn to_raw(self) -> u8 {
+        match self {
+            MouseButton::Left => 1,
+            MouseButton::Middle => 2,
+            MouseButton::Right => 3,
+            MouseButton::ScrollUp => 4,
+            MouseButton::ScrollDown => 5,
+            MouseButton::Other(raw) => raw,
+        }
+    }
+}
+
+// Why a pointer crossing happened, crossings caused by grabs aren't real pointer motion.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CrossingMode {
+    Normal,
+    Grab,
+    Ungrab,
+    WhileGrabbed,
+}
+
+impl CrossingMode {
+    pub(crate) fn from_raw(raw: u8) -> Self {
+        match u32::from(raw) {
+            xcb_system::xcb_notify_mode_t_XCB_NOTIFY_MODE_GRAB => CrossingMode::Grab,
+            xcb_system::xcb_notify_mode_t_XCB_NOTIFY_MODE_UNGRAB => CrossingMode::Ungrab,
+            xcb_system::xcb_notify_mode_t_XCB_NOTIFY_MODE_WHILE_GRABBED => {
+                CrossingMode::WhileGrabbed
+            }
+            _ => CrossingMode::Normal,
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -29,7 +79,7 @@ pub enum Event<'a> {
     },
     WindowConfigurationRequest {
         window: WindowHandle<'a>,
-        rectangle: Rectangle,
+        request: ConfigureRequest,
     },
     WindowMappingRequest {
         window: WindowHandle<'a>,
@@ -43,45 +93,73 @@ pub enum Event<'a> {
         y: i16,
     },
     ButtonPressed {
+        window: WindowHandle<'a>,
         root_window: WindowHandle<'a>,
         child_window: Option<WindowHandle<'a>>,
         button: MouseButton,
+        x: i16,
+        y: i16,
+        modifiers: ModMask,
+        time: u32,
     },
     ButtonReleased {
+        window: WindowHandle<'a>,
         root_window: WindowHandle<'a>,
         child_window: Option<WindowHandle<'a>>,
         button: MouseButton,
+        x: i16,
+        y: i16,
+        modifiers: ModMask,
+        time: u32,
+    },
+    KeyPressed {
+        window: WindowHandle<'a>,
+        keycode: u8,
+        modifiers: ModMask,
+        time: u32,
+    },
+    PointerEntered {
+        window: WindowHandle<'a>,
+        mode: CrossingMode,
+        time: u32,
+    },
+    WindowExposed {
+        window: WindowHandle<'a>,
+        rectangle: Rectangle,
+        count: u16,
+    },
+    WindowPropertyChanged {
+        window: WindowHandle<'a>,
+        atom: u32,
+    },
+    ClientMessage {
+        window: WindowHandle<'a>,
+        message_type: u32,
+        format: u8,
+        data: [u32; 5],
+    },
+    // RRScreenChangeNotify, only sent after selecting it with `randr::select_input`.
+    ScreenChanged {
+        root: WindowHandle<'a>,
+        width: u16,
+        height: u16,
+        timestamp: u32,
+        config_timestamp: u32,
     },
     Unknown,
 }
 
-bitflags! {
-    pub struct EventMask : u32 {
-        const NO_EVENT = 0;
-        const KEY_PRESS = 1;
-        const KEY_RELEASE = 2;
-        const BUTTON_PRESS = 4;
-        const BUTTON_RELEASE = 8;
-        const ENTER_WINDOW = 16;
-        const LEAVE_WINDOW = 32;
-        const POINTER_MOTION = 64;
-        const POINTER_MOTION_HINT = 128;
-        const BUTTON_1_MOTION = 256;
-        const BUTTON_2_MOTION = 512;
-        const BUTTON_3_MOTION = 1024;
-        const BUTTON_4_MOTION = 2048;
-        const BUTTON_5_MOTION = 4096;
-        const BUTTON_MOTION = 8192;
-        const KEYMAP_STATE = 16_384;
-        const EXPOSURE = 32_768;
-        const VISIBILITY_CHANGE = 65_536;
-        const STRUCTURE_NOTIFY = 131_072;
-        const RESIZE_REDIRECT = 262_144;
-        const SUBSTRUCTURE_NOTIFY = 524_288;
-        const SUBSTRUCTURE_REDIRECT = 1_048_576;
-        const FOCUS_CHANGE = 2_097_152;
-        const PROPERTY_CHANGE = 4_194_304;
-        const COLOR_MAP_CHANGE = 8_388_608;
-        const OWNER_GRAB_BUTTON = 16_777_216;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mouse_buttons() {
+        for raw in 1..=9 {
+            assert_eq!(MouseButton::from_raw(raw).to_raw(), raw);
+        }
+
+        assert_eq!(MouseButton::from_raw(3), MouseButton::Right);
+        assert_eq!(MouseButton::from_raw(8), MouseButton::Other(8));
     }
 }
@@ -0,0 +1,118 @@
+use xcb_system::{xcb_extension_t, xcb_query_extension_reply_t};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ExtensionInfo {
+    // Of the extension's requests, their minor opcode tells them apart.
+    pub major_opcode: u8,
+    // Extension events and errors are numbered from these.
+    pub first_event: u8,
+    pub first_error: u8,
+}
+
+impl ExtensionInfo {
+    // `None` if the server doesn't have the extension.
+    pub(crate) fn from_reply(reply: &xcb_query_extension_reply_t) -> Option<Self> {
+        if reply.present == 0 {
+            return None;
+        }
+
+        Some(Self {
+            major_opcode: reply.major_opcode,
+            first_event: reply.first_event,
+            first_error: reply.first_error,
+        })
+    }
+}
+
+// The extensions libxcb has bindings for, whose data it caches per connection.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Extension {
+    RandR,
+    Render,
+    Xinerama,
+    #[cfg(feature = "xkb")]
+    Xkb,
+    #[cfg(feature = "composite")]
+    Composite,
+    #[cfg(feature = "damage")]
+    Damage,
+    #[cfg(feature = "xfixes")]
+    XFixes,
+    #[cfg(feature = "shape")]
+    Shape,
+    #[cfg(feature = "sync")]
+    Sync,
+    #[cfg(feature = "present")]
+    Present,
+    #[cfg(feature = "xinput")]
+    XInput,
+    #[cfg(feature = "res")]
+    Res,
+    #[cfg(feature = "dpms")]
+    Dpms,
+    #[cfg(feature = "screensaver")]
+    ScreenSaver,
+}
+
+impl Extension {
+    // The name the server knows the extension by, as passed to `Connection::query_extension`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Extension::RandR => "RANDR",
+            Extension::Render => "RENDER",
+            Extension::Xinerama => "XINERAMA",
+            #[cfg(feature = "xkb")]
+            Extension::Xkb => "XKEYBOARD",
+            #[cfg(feature = "composite")]
+            Extension::Composite => "Composite",
+            #[cfg(feature = "damage")]
+            Extension::Damage => "DAMAGE",
+            #[cfg(feature = "xfixes")]
+            Extension::XFixes => "XFIXES",
+            #[cfg(feature = "shape")]
+            Extension::Shape => "SHAPE",
+            #[cfg(feature = "sync")]
+            Extension::Sync => "SYNC",
+            #[cfg(feature = "present")]
+            Extension::Present => "Present",
+            #[cfg(feature = "xinput")]
+            Extension::XInput => "XInputExtension",
+            #[cfg(feature = "res")]
+            Extension::Res => "X-Resource",
+            #[cfg(feature = "dpms")]
+            Extension::Dpms => "DPMS",
+            #[cfg(feature = "screensaver")]
+            Extension::ScreenSaver => "MIT-SCREEN-SAVER",
+        }
+    }
+
+    pub(crate) fn raw(self) -> *mut xcb_extension_t {
+        match self {
+            Extension::RandR => std::ptr::addr_of_mut!(xcb_system::xcb_randr_id),
+            Extension::Render => std::ptr::addr_of_mut!(xcb_system::xcb_render_id),
+            Extension::Xinerama => std::ptr::addr_of_mut!(xcb_system::xcb_xinerama_id),
+            #[cfg(feature = "xkb")]
+            Extension::Xkb => std::ptr::addr_of_mut!(xcb_system::xcb_xkb_id),
+            #[cfg(feature = "composite")]
+            Extension::Composite => std::ptr::addr_of_mut!(xcb_system::xcb_composite_id),
+            #[cfg(feature = "damage")]
+            Extension::Damage => std::ptr::addr_of_mut!(xcb_system::xcb_damage_id),
+            #[cfg(feature = "xfixes")]
+            Extension::XFixes => std::ptr::addr_of_mut!(xcb_system::xcb_xfixes_id),
+            #[cfg(feature = "shape")]
+            Extension::Shape => std::ptr::addr_of_mut!(xcb_system::xcb_shape_id),
+            #[cfg(feature = "sync")]
+            Extension::Sync => std::ptr::addr_of_mut!(xcb_system::xcb_sync_id),
+            #[cfg(feature = "present")]
+            Extension::Present => std::ptr::addr_of_mut!(xcb_system::xcb_present_id),
+            #[cfg(feature = "xinput")]
+            Extension::XInput => std::ptr::addr_of_mut!(xcb_system::xcb_input_id),
+            #[cfg(feature = "res")]
+            Extension::Res => std::ptr::addr_of_mut!(xcb_system::xcb_res_id),
+            #[cfg(feature = "dpms")]
+            Extension::Dpms => std::ptr::addr_of_mut!(xcb_system::xcb_dpms_id),
+            #[cfg(feature = "screensaver")]
+            Extension::ScreenSaver => std::ptr::addr_of_mut!(xcb_system::xcb_screensaver_id),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RevertTo {
+    None = 0,
+    PointerRoot = 1,
+    Parent = 2,
+}
+
+impl RevertTo {
+    pub(crate) fn from_raw(raw: u8) -> Self {
+        match u32::from(raw) {
+            xcb_system::xcb_input_focus_t_XCB_INPUT_FOCUS_POINTER_ROOT => RevertTo::PointerRoot,
+            xcb_system::xcb_input_focus_t_XCB_INPUT_FOCUS_PARENT => RevertTo::Parent,
+            _ => RevertTo::None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FocusTarget {
+    None,
+    PointerRoot,
+    Window(u32),
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct InputFocus {
+    pub focus: FocusTarget,
+    pub revert_to: RevertTo,
+}
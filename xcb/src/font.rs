@@ -0,0 +1,98 @@
+use crate::connection::Connection;
+use crate::proto::xproto;
+use crate::result::{wait_for_reply, Cookie, Error, Request, Sequence, Void};
+use xcb_system::{xcb_char2b_t, xcb_font_t, xcb_query_text_extents_reply_t};
+
+#[derive(Debug, Copy, Clone)]
+pub struct TextExtents {
+    pub font_ascent: i16,
+    pub font_descent: i16,
+    pub overall_ascent: i16,
+    pub overall_descent: i16,
+    pub overall_width: i32,
+    pub overall_left: i32,
+    pub overall_right: i32,
+}
+
+pub struct QueryTextExtents;
+
+impl Request for QueryTextExtents {
+    type Reply = TextExtents;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<TextExtents, Error> {
+        let extents = wait_for_reply::<xcb_query_text_extents_reply_t>(connection, sequence)?;
+
+        Ok(TextExtents {
+            font_ascent: extents.font_ascent,
+            font_descent: extents.font_descent,
+            overall_ascent: extents.overall_ascent,
+            overall_descent: extents.overall_descent,
+            overall_width: extents.overall_width,
+            overall_left: extents.overall_left,
+            overall_right: extents.overall_right,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct OwnedFont<'a> {
+    connection: &'a Connection,
+    handle: xcb_font_t,
+}
+
+pub(crate) fn encode_char2b(text: &str) -> Vec<xproto::Char2b> {
+    text.chars()
+        .map(|character| {
+            let code = if (character as u32) > 0xffff {
+                '?' as u32
+            } else {
+                character as u32
+            };
+
+            xproto::Char2b {
+                byte1: (code >> 8) as u8,
+                byte2: code as u8,
+            }
+        })
+        .collect()
+}
+
+impl<'a> OwnedFont<'a> {
+    pub fn open(connection: &'a Connection, name: &str) -> Result<Self, Error> {
+        let handle = unsafe { xcb_system::xcb_generate_id(connection.get_connection()) };
+
+        xproto::open_font(connection, handle, name.as_bytes()).get_result()?;
+
+        Ok(Self { connection, handle })
+    }
+
+    pub fn id(&self) -> u32 {
+        self.handle
+    }
+
+    pub fn query_text_extents(&self, text: &str) -> Cookie<'_, QueryTextExtents> {
+        // QueryTextExtents isn't generated, the length of its string is only implied by the
+        // request's length.
+        let string: Vec<xcb_char2b_t> = encode_char2b(text).into_iter().map(Into::into).collect();
+        let cookie = unsafe {
+            xcb_system::xcb_query_text_extents(
+                self.connection.get_connection(),
+                self.handle,
+                string.len() as u32,
+                string.as_ptr(),
+            )
+        };
+
+        Cookie::new(cookie.sequence, QueryTextExtents, self.connection)
+    }
+
+    fn close(&self) -> Cookie<'_, Void> {
+        xproto::close_font(self.connection, self.handle)
+    }
+}
+
+impl Drop for OwnedFont<'_> {
+    fn drop(&mut self) {
+        self.close().discard();
+    }
+}
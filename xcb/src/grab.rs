@@ -0,0 +1,234 @@
+use crate::cursor::OwnedCursor;
+use crate::event::{EventMask, ModMask, MouseButton};
+use crate::window::Window;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GrabStatus {
+    Success,
+    AlreadyGrabbed,
+    InvalidTime,
+    NotViewable,
+    Frozen,
+}
+
+impl GrabStatus {
+    pub(crate) fn from_raw(raw: u8) -> Self {
+        match u32::from(raw) {
+            xcb_system::xcb_grab_status_t_XCB_GRAB_STATUS_SUCCESS => GrabStatus::Success,
+            xcb_system::xcb_grab_status_t_XCB_GRAB_STATUS_ALREADY_GRABBED => {
+                GrabStatus::AlreadyGrabbed
+            }
+            xcb_system::xcb_grab_status_t_XCB_GRAB_STATUS_INVALID_TIME => GrabStatus::InvalidTime,
+            xcb_system::xcb_grab_status_t_XCB_GRAB_STATUS_NOT_VIEWABLE => GrabStatus::NotViewable,
+            _ => GrabStatus::Frozen,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GrabMode {
+    Synchronous = 0,
+    Asynchronous = 1,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AllowEventsMode {
+    AsyncPointer = 0,
+    SyncPointer = 1,
+    ReplayPointer = 2,
+    AsyncKeyboard = 3,
+    SyncKeyboard = 4,
+    ReplayKeyboard = 5,
+    AsyncBoth = 6,
+    SyncBoth = 7,
+}
+
+pub const CURRENT_TIME: u32 = xcb_system::XCB_CURRENT_TIME;
+
+#[derive(Debug, Copy, Clone)]
+pub struct PointerGrab {
+    pub(crate) grab_window: u32,
+    pub(crate) owner_events: bool,
+    pub(crate) event_mask: EventMask,
+    pub(crate) pointer_mode: GrabMode,
+    pub(crate) keyboard_mode: GrabMode,
+    pub(crate) confine_to: u32,
+    pub(crate) cursor: u32,
+    pub(crate) time: u32,
+}
+
+impl PointerGrab {
+    pub fn new(grab_window: &dyn Window, event_mask: EventMask) -> Self {
+        Self {
+            grab_window: grab_window.id(),
+            owner_events: false,
+            event_mask,
+            pointer_mode: GrabMode::Asynchronous,
+            keyboard_mode: GrabMode::Asynchronous,
+            confine_to: xcb_system::XCB_NONE,
+            cursor: xcb_system::XCB_NONE,
+            time: CURRENT_TIME,
+        }
+    }
+
+    pub fn owner_events(mut self, owner_events: bool) -> Self {
+        self.owner_events = owner_events;
+        self
+    }
+
+    pub fn pointer_mode(mut self, mode: GrabMode) -> Self {
+        self.pointer_mode = mode;
+        self
+    }
+
+    pub fn keyboard_mode(mut self, mode: GrabMode) -> Self {
+        self.keyboard_mode = mode;
+        self
+    }
+
+    pub fn confine_to(mut self, window: &dyn Window) -> Self {
+        self.confine_to = window.id();
+        self
+    }
+
+    pub fn cursor(mut self, cursor: &OwnedCursor) -> Self {
+        self.cursor = cursor.id();
+        self
+    }
+
+    pub fn time(mut self, time: u32) -> Self {
+        self.time = time;
+        self
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct KeyboardGrab {
+    pub(crate) grab_window: u32,
+    pub(crate) owner_events: bool,
+    pub(crate) pointer_mode: GrabMode,
+    pub(crate) keyboard_mode: GrabMode,
+    pub(crate) time: u32,
+}
+
+impl KeyboardGrab {
+    pub fn new(grab_window: &dyn Window) -> Self {
+        Self {
+            grab_window: grab_window.id(),
+            owner_events: false,
+            pointer_mode: GrabMode::Asynchronous,
+            keyboard_mode: GrabMode::Asynchronous,
+            time: CURRENT_TIME,
+        }
+    }
+
+    pub fn owner_events(mut self, owner_events: bool) -> Self {
+        self.owner_events = owner_events;
+        self
+    }
+
+    pub fn pointer_mode(mut self, mode: GrabMode) -> Self {
+        self.pointer_mode = mode;
+        self
+    }
+
+    pub fn keyboard_mode(mut self, mode: GrabMode) -> Self {
+        self.keyboard_mode = mode;
+        self
+    }
+
+    pub fn time(mut self, time: u32) -> Self {
+        self.time = time;
+        self
+    }
+}
+
+// A passive grab, `None` as the button grabs all of them.
+#[derive(Debug, Copy, Clone)]
+pub struct ButtonGrab {
+    pub(crate) button: Option<MouseButton>,
+    pub(crate) modifiers: ModMask,
+    pub(crate) owner_events: bool,
+    pub(crate) event_mask: EventMask,
+    pub(crate) pointer_mode: GrabMode,
+    pub(crate) keyboard_mode: GrabMode,
+    pub(crate) confine_to: u32,
+    pub(crate) cursor: u32,
+}
+
+impl ButtonGrab {
+    pub fn new(button: Option<MouseButton>, modifiers: ModMask, event_mask: EventMask) -> Self {
+        Self {
+            button,
+            modifiers,
+            owner_events: false,
+            event_mask,
+            pointer_mode: GrabMode::Asynchronous,
+            keyboard_mode: GrabMode::Asynchronous,
+            confine_to: xcb_system::XCB_NONE,
+            cursor: xcb_system::XCB_NONE,
+        }
+    }
+
+    pub fn owner_events(mut self, owner_events: bool) -> Self {
+        self.owner_events = owner_events;
+        self
+    }
+
+    pub fn pointer_mode(mut self, mode: GrabMode) -> Self {
+        self.pointer_mode = mode;
+        self
+    }
+
+    pub fn keyboard_mode(mut self, mode: GrabMode) -> Self {
+        self.keyboard_mode = mode;
+        self
+    }
+
+    pub fn confine_to(mut self, window: &dyn Window) -> Self {
+        self.confine_to = window.id();
+        self
+    }
+
+    pub fn cursor(mut self, cursor: &OwnedCursor) -> Self {
+        self.cursor = cursor.id();
+        self
+    }
+}
+
+// A passive grab, `None` as the keycode grabs all keys.
+#[derive(Debug, Copy, Clone)]
+pub struct KeyGrab {
+    pub(crate) keycode: Option<u8>,
+    pub(crate) modifiers: ModMask,
+    pub(crate) owner_events: bool,
+    pub(crate) pointer_mode: GrabMode,
+    pub(crate) keyboard_mode: GrabMode,
+}
+
+impl KeyGrab {
+    pub fn new(keycode: Option<u8>, modifiers: ModMask) -> Self {
+        Self {
+            keycode,
+            modifiers,
+            owner_events: false,
+            pointer_mode: GrabMode::Asynchronous,
+            keyboard_mode: GrabMode::Asynchronous,
+        }
+    }
+
+    pub fn owner_events(mut self, owner_events: bool) -> Self {
+        self.owner_events = owner_events;
+        self
+    }
+
+    pub fn pointer_mode(mut self, mode: GrabMode) -> Self {
+        self.pointer_mode = mode;
+        self
+    }
+
+    pub fn keyboard_mode(mut self, mode: GrabMode) -> Self {
+        self.keyboard_mode = mode;
+        self
+    }
+}
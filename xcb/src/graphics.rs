@@ -0,0 +1,307 @@
+use crate::connection::Connection;
+use crate::drawable::Drawable;
+use crate::font::{encode_char2b, OwnedFont};
+use crate::image::Image;
+use crate::proto::xproto;
+use crate::result::{Cookie, Error, Request, Sequence, Void};
+use crate::Rectangle;
+use std::convert::TryFrom;
+use xcb_system::xcb_gcontext_t;
+
+const PUT_IMAGE_REQUEST_HEADER_LENGTH: usize = 24;
+
+// Images too large for one request are sent in strips of rows, each its own request.
+pub struct PutImage<'a> {
+    strips: Vec<Cookie<'a, Void>>,
+}
+
+impl Request for PutImage<'_> {
+    type Reply = ();
+
+    // Every strip is checked, so none of their errors are left behind in libxcb.
+    fn wait(self, _: &Connection, _: Sequence) -> Result<(), Error> {
+        let mut result = Ok(());
+
+        for strip in self.strips {
+            result = result.and(strip.get_result());
+        }
+
+        result
+    }
+
+    fn discard(self, _: &Connection, _: Sequence) {
+        for strip in self.strips {
+            strip.discard();
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct OwnedGraphicsContext<'a> {
+    connection: &'a Connection,
+    handle: xcb_gcontext_t,
+}
+
+impl<'a> OwnedGraphicsContext<'a> {
+    pub fn new(connection: &'a Connection, drawable: &dyn Drawable) -> Result<Self, Error> {
+        let handle = unsafe { xcb_system::xcb_generate_id(connection.get_connection()) };
+
+        xproto::create_gc(
+            connection,
+            handle,
+            drawable.drawable_id(),
+            &xproto::CreateGCValueList::default().graphics_exposures(0),
+        )
+        .get_result()?;
+
+        Ok(Self { connection, handle })
+    }
+
+    pub fn id(&self) -> u32 {
+        self.handle
+    }
+
+    pub fn set_foreground(&self, pixel: u32) -> Cookie<'_, Void> {
+        self.change(xproto::ChangeGCValueList::default().foreground(pixel))
+    }
+
+    pub fn set_background(&self, pixel: u32) -> Cookie<'_, Void> {
+        self.change(xproto::ChangeGCValueList::default().background(pixel))
+    }
+
+    pub fn set_font(&self, font: &OwnedFont) -> Cookie<'_, Void> {
+        self.change(xproto::ChangeGCValueList::default().font(font.id()))
+    }
+
+    pub fn fill_rectangle(
+        &self,
+        drawable: &dyn Drawable,
+        rectangle: Rectangle,
+    ) -> Cookie<'_, Void> {
+        let rectangle = xproto::Rectangle {
+            x: rectangle.x,
+            y: rectangle.y,
+            width: rectangle.width,
+            height: rectangle.height,
+        };
+
+        xproto::poly_fill_rectangle(
+            self.connection,
+            drawable.drawable_id(),
+            self.handle,
+            &[rectangle],
+        )
+    }
+
+    pub fn image_text8(
+        &self,
+        drawable: &dyn Drawable,
+        x: i16,
+        y: i16,
+        text: &[u8],
+    ) -> Cookie<'_, Void> {
+        let text = &text[..std::cmp::min(text.len(), usize::from(u8::MAX))];
+
+        xproto::image_text_8(
+            self.connection,
+            drawable.drawable_id(),
+            self.handle,
+            x,
+            y,
+            text,
+        )
+    }
+
+    pub fn image_text16(
+        &self,
+        drawable: &dyn Drawable,
+        x: i16,
+        y: i16,
+        text: &str,
+    ) -> Cookie<'_, Void> {
+        let mut string = encode_char2b(text);
+        string.truncate(usize::from(u8::MAX));
+
+        xproto::image_text_16(
+            self.connection,
+            drawable.drawable_id(),
+            self.handle,
+            x,
+            y,
+            &string,
+        )
+    }
+
+    // Fails without sending anything when a single row of the image doesn't fit in a request.
+    pub fn put_image(
+        &self,
+        drawable: &dyn Drawable,
+        x: i16,
+        y: i16,
+        image: &Image,
+    ) -> Result<Cookie<'_, PutImage<'_>>, Error> {
+        let stride = image.stride();
+        let maximum_length =
+            unsafe { xcb_system::xcb_get_maximum_request_length(self.connection.get_connection()) }
+                as usize
+                * 4;
+        let data = &image.data()[..stride * usize::from(image.height())];
+
+        let strips = strips(data, stride, y, maximum_length)?
+            .into_iter()
+            .map(|(strip_y, strip)| {
+                xproto::put_image(
+                    self.connection,
+                    xproto::ImageFormat::Z_PIXMAP.0 as u8,
+                    drawable.drawable_id(),
+                    self.handle,
+                    image.width(),
+                    (strip.len() / stride) as u16,
+                    x,
+                    strip_y,
+                    0,
+                    image.depth(),
+                    strip,
+                )
+            })
+            .collect();
+
+        // The strips are waited for through their own cookies, so this one has no sequence of
+        // its own.
+        Ok(Cookie::new(0, PutImage { strips }, self.connection))
+    }
+
+    pub fn copy_area(
+        &self,
+        source: &dyn Drawable,
+        destination: &dyn Drawable,
+        source_x: i16,
+        source_y: i16,
+        destination_rectangle: Rectangle,
+    ) -> Cookie<'_, Void> {
+        xproto::copy_area(
+            self.connection,
+            source.drawable_id(),
+            destination.drawable_id(),
+            self.handle,
+            source_x,
+            source_y,
+            destination_rectangle.x,
+            destination_rectangle.y,
+            destination_rectangle.width,
+            destination_rectangle.height,
+        )
+    }
+
+    fn change(&self, values: xproto::ChangeGCValueList) -> Cookie<'_, Void> {
+        xproto::change_gc(self.connection, self.handle, &values)
+    }
+
+    fn free(&self) -> Cookie<'_, Void> {
+        xproto::free_gc(self.connection, self.handle)
+    }
+}
+
+impl Drop for OwnedGraphicsContext<'_> {
+    fn drop(&mut self) {
+        self.free().discard();
+    }
+}
+
+// The number of rows of `stride` bytes that fit in a PutImage request of at most
+// `maximum_length` bytes, `None` when not even one does.
+fn rows_per_request(maximum_length: usize, stride: usize) -> Option<usize> {
+    let available = maximum_length.checked_sub(PUT_IMAGE_REQUEST_HEADER_LENGTH)?;
+
+    match available.checked_div(stride) {
+        // Empty rows, there's nothing to send.
+        None => Some(1),
+        Some(0) => None,
+        rows => rows,
+    }
+}
+
+// Splits the rows of `data` into the strips that are sent as requests of their own, along with
+// the y they're drawn at. Strips starting below the largest coordinate can't be seen on any
+// drawable, so they're left out.
+fn strips(
+    data: &[u8],
+    stride: usize,
+    y: i16,
+    maximum_length: usize,
+) -> Result<Vec<(i16, &[u8])>, Error> {
+    let rows_per_request =
+        rows_per_request(maximum_length, stride).ok_or(Error::RequestLengthExceeded)?;
+
+    Ok(data
+        .chunks(std::cmp::max(1, stride * rows_per_request))
+        .enumerate()
+        .map_while(|(i, strip)| {
+            let strip_y = i64::from(y) + (i * rows_per_request) as i64;
+
+            i16::try_from(strip_y).ok().map(|strip_y| (strip_y, strip))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_fill_the_request() {
+        assert_eq!(
+            rows_per_request(1024 + PUT_IMAGE_REQUEST_HEADER_LENGTH, 256),
+            Some(4)
+        );
+        assert_eq!(
+            rows_per_request(1023 + PUT_IMAGE_REQUEST_HEADER_LENGTH, 256),
+            Some(3)
+        );
+        assert_eq!(rows_per_request(262_140, 4096), Some(63));
+    }
+
+    #[test]
+    fn rows_wider_than_a_request_are_rejected() {
+        assert_eq!(rows_per_request(1024, 4096), None);
+        assert_eq!(rows_per_request(16, 4), None);
+        assert!(matches!(
+            strips(&[0; 8192], 4096, 0, 1024),
+            Err(Error::RequestLengthExceeded)
+        ));
+    }
+
+    #[test]
+    fn empty_rows() {
+        assert_eq!(rows_per_request(1024, 0), Some(1));
+        assert!(strips(&[], 0, 0, 1024).unwrap().is_empty());
+    }
+
+    #[test]
+    fn strips_are_placed_below_each_other() {
+        let data = [0; 10 * 4];
+        let strips = strips(&data, 4, 5, PUT_IMAGE_REQUEST_HEADER_LENGTH + 3 * 4).unwrap();
+        let placement: Vec<(i16, usize)> =
+            strips.iter().map(|(y, strip)| (*y, strip.len())).collect();
+
+        assert_eq!(placement, vec![(5, 12), (8, 12), (11, 12), (14, 4)]);
+    }
+
+    #[test]
+    fn strips_past_the_largest_coordinate_are_left_out() {
+        let data = [0; 10 * 4];
+        let strips = strips(&data, 4, i16::MAX - 4, PUT_IMAGE_REQUEST_HEADER_LENGTH + 4).unwrap();
+        let placement: Vec<i16> = strips.iter().map(|(y, _)| *y).collect();
+
+        assert_eq!(
+            placement,
+            vec![
+                i16::MAX - 4,
+                i16::MAX - 3,
+                i16::MAX - 2,
+                i16::MAX - 1,
+                i16::MAX
+            ]
+        );
+    }
+}
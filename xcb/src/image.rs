@@ -0,0 +1,325 @@
+use crate::result::Error;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ByteOrder {
+    LsbFirst,
+    MsbFirst,
+}
+
+impl ByteOrder {
+    pub(crate) fn from_raw(raw: u8) -> Self {
+        if u32::from(raw) == xcb_system::xcb_image_order_t_XCB_IMAGE_ORDER_MSB_FIRST {
+            ByteOrder::MsbFirst
+        } else {
+            ByteOrder::LsbFirst
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct PixmapFormat {
+    pub depth: u8,
+    pub bits_per_pixel: u8,
+    pub scanline_pad: u8,
+}
+
+impl PixmapFormat {
+    pub fn stride(&self, width: u16) -> usize {
+        let pad = usize::from(self.scanline_pad);
+        let bits = usize::from(width) * usize::from(self.bits_per_pixel);
+
+        bits.div_ceil(pad) * pad / 8
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Image {
+    width: u16,
+    height: u16,
+    format: PixmapFormat,
+    byte_order: ByteOrder,
+    bit_order: ByteOrder,
+    data: Vec<u8>,
+}
+
+impl Image {
+    pub(crate) fn new(
+        width: u16,
+        height: u16,
+        format: PixmapFormat,
+        byte_order: ByteOrder,
+        bit_order: ByteOrder,
+    ) -> Self {
+        let data = vec![0; format.stride(width) * usize::from(height)];
+
+        Self {
+            width,
+            height,
+            format,
+            byte_order,
+            bit_order,
+            data,
+        }
+    }
+
+    pub(crate) fn from_data(
+        width: u16,
+        height: u16,
+        format: PixmapFormat,
+        byte_order: ByteOrder,
+        bit_order: ByteOrder,
+        data: Vec<u8>,
+    ) -> Result<Self, Error> {
+        if data.len() < format.stride(width) * usize::from(height) {
+            return Err(Error::InvalidImageData);
+        }
+
+        Ok(Self {
+            width,
+            height,
+            format,
+            byte_order,
+            bit_order,
+            data,
+        })
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    pub fn depth(&self) -> u8 {
+        self.format.depth
+    }
+
+    pub fn bits_per_pixel(&self) -> u8 {
+        self.format.bits_per_pixel
+    }
+
+    pub fn stride(&self) -> usize {
+        self.format.stride(self.width)
+    }
+
+    pub fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    pub fn get_pixel(&self, x: u16, y: u16) -> u32 {
+        assert!(x < self.width && y < self.height);
+
+        let row = usize::from(y) * self.stride();
+        let x = usize::from(x);
+
+        match self.format.bits_per_pixel {
+            1 => {
+                let byte = self.data[row + x / 8];
+                let bit = match self.bit_order {
+                    ByteOrder::LsbFirst => x % 8,
+                    ByteOrder::MsbFirst => 7 - x % 8,
+                };
+
+                u32::from((byte >> bit) & 1)
+            }
+            4 => {
+                let byte = self.data[row + x / 2];
+                let shift = match (self.byte_order, x % 2) {
+                    (ByteOrder::LsbFirst, 0) | (ByteOrder::MsbFirst, 1) => 0,
+                    _ => 4,
+                };
+
+                u32::from((byte >> shift) & 0xf)
+            }
+            bits_per_pixel => {
+                let bytes = usize::from(bits_per_pixel) / 8;
+                let pixel = &self.data[row + x * bytes..row + (x + 1) * bytes];
+
+                match self.byte_order {
+                    ByteOrder::LsbFirst => pixel
+                        .iter()
+                        .rev()
+                        .fold(0, |value, byte| value << 8 | u32::from(*byte)),
+                    ByteOrder::MsbFirst => pixel
+                        .iter()
+                        .fold(0, |value, byte| value << 8 | u32::from(*byte)),
+                }
+            }
+        }
+    }
+
+    pub fn put_pixel(&mut self, x: u16, y: u16, value: u32) {
+        assert!(x < self.width && y < self.height);
+
+        let row = usize::from(y) * self.stride();
+        let x = usize::from(x);
+
+        match self.format.bits_per_pixel {
+            1 => {
+                let bit = match self.bit_order {
+                    ByteOrder::LsbFirst => x % 8,
+                    ByteOrder::MsbFirst => 7 - x % 8,
+                };
+                let byte = &mut self.data[row + x / 8];
+
+                *byte = (*byte & !(1 << bit)) | (((value & 1) as u8) << bit);
+            }
+            4 => {
+                let shift = match (self.byte_order, x % 2) {
+                    (ByteOrder::LsbFirst, 0) | (ByteOrder::MsbFirst, 1) => 0,
+                    _ => 4,
+                };
+                let byte = &mut self.data[row + x / 2];
+
+                *byte = (*byte & !(0xf << shift)) | (((value & 0xf) as u8) << shift);
+            }
+            bits_per_pixel => {
+                let bytes = usize::from(bits_per_pixel) / 8;
+                let pixel = &mut self.data[row + x * bytes..row + (x + 1) * bytes];
+
+                for (i, byte) in pixel.iter_mut().enumerate() {
+                    let shift = match self.byte_order {
+                        ByteOrder::LsbFirst => i * 8,
+                        ByteOrder::MsbFirst => (bytes - 1 - i) * 8,
+                    };
+
+                    *byte = (value >> shift) as u8;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(depth: u8, bits_per_pixel: u8, scanline_pad: u8) -> PixmapFormat {
+        PixmapFormat {
+            depth,
+            bits_per_pixel,
+            scanline_pad,
+        }
+    }
+
+    fn image(format: PixmapFormat, byte_order: ByteOrder) -> Image {
+        Image::new(3, 2, format, byte_order, ByteOrder::MsbFirst)
+    }
+
+    #[test]
+    fn stride_is_padded_to_the_scanline_pad() {
+        assert_eq!(format(24, 32, 32).stride(3), 12);
+        assert_eq!(format(24, 24, 32).stride(3), 12);
+        assert_eq!(format(24, 24, 32).stride(4), 12);
+        assert_eq!(format(24, 24, 8).stride(3), 9);
+        assert_eq!(format(16, 16, 32).stride(3), 8);
+        assert_eq!(format(1, 1, 32).stride(33), 8);
+        assert_eq!(format(4, 4, 8).stride(3), 2);
+        assert_eq!(format(24, 32, 32).stride(0), 0);
+    }
+
+    #[test]
+    fn rows_start_at_the_stride() {
+        let mut image = image(format(24, 24, 32), ByteOrder::LsbFirst);
+
+        image.put_pixel(0, 1, 0x00ab_cdef);
+
+        assert_eq!(image.data().len(), 24);
+        assert_eq!(&image.data()[12..15], &[0xef, 0xcd, 0xab]);
+        assert!(image.data()[..12].iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn pixels_at_24_bits() {
+        let mut lsb = image(format(24, 24, 32), ByteOrder::LsbFirst);
+        let mut msb = image(format(24, 24, 32), ByteOrder::MsbFirst);
+
+        lsb.put_pixel(1, 0, 0x0012_3456);
+        msb.put_pixel(1, 0, 0x0012_3456);
+
+        assert_eq!(&lsb.data()[3..6], &[0x56, 0x34, 0x12]);
+        assert_eq!(&msb.data()[3..6], &[0x12, 0x34, 0x56]);
+        assert_eq!(lsb.get_pixel(1, 0), 0x0012_3456);
+        assert_eq!(msb.get_pixel(1, 0), 0x0012_3456);
+        assert_eq!(lsb.get_pixel(0, 0), 0);
+        assert_eq!(lsb.get_pixel(2, 0), 0);
+    }
+
+    #[test]
+    fn pixels_at_32_bits() {
+        let mut lsb = image(format(32, 32, 32), ByteOrder::LsbFirst);
+        let mut msb = image(format(32, 32, 32), ByteOrder::MsbFirst);
+
+        lsb.put_pixel(2, 1, 0x8012_3456);
+        msb.put_pixel(2, 1, 0x8012_3456);
+
+        assert_eq!(&lsb.data()[20..24], &[0x56, 0x34, 0x12, 0x80]);
+        assert_eq!(&msb.data()[20..24], &[0x80, 0x12, 0x34, 0x56]);
+        assert_eq!(lsb.get_pixel(2, 1), 0x8012_3456);
+        assert_eq!(msb.get_pixel(2, 1), 0x8012_3456);
+    }
+
+    #[test]
+    fn pixels_are_read_in_the_image_byte_order() {
+        let data = vec![0x01, 0x02, 0x03, 0x04];
+        let lsb = Image::from_data(
+            1,
+            1,
+            format(24, 32, 32),
+            ByteOrder::LsbFirst,
+            ByteOrder::MsbFirst,
+            data.clone(),
+        )
+        .unwrap();
+        let msb = Image::from_data(
+            1,
+            1,
+            format(24, 32, 32),
+            ByteOrder::MsbFirst,
+            ByteOrder::MsbFirst,
+            data,
+        )
+        .unwrap();
+
+        assert_eq!(lsb.get_pixel(0, 0), 0x0403_0201);
+        assert_eq!(msb.get_pixel(0, 0), 0x0102_0304);
+    }
+
+    #[test]
+    fn putting_a_pixel_keeps_its_neighbours() {
+        let mut image = image(format(32, 32, 32), ByteOrder::MsbFirst);
+
+        image.put_pixel(0, 0, 0xffff_ffff);
+        image.put_pixel(2, 0, 0xffff_ffff);
+        image.put_pixel(1, 0, 0x0102_0304);
+        image.put_pixel(1, 0, 0x0a0b_0c0d);
+
+        assert_eq!(image.get_pixel(0, 0), 0xffff_ffff);
+        assert_eq!(image.get_pixel(1, 0), 0x0a0b_0c0d);
+        assert_eq!(image.get_pixel(2, 0), 0xffff_ffff);
+    }
+
+    #[test]
+    fn too_little_data() {
+        let result = Image::from_data(
+            3,
+            2,
+            format(24, 32, 32),
+            ByteOrder::LsbFirst,
+            ByteOrder::MsbFirst,
+            vec![0; 23],
+        );
+
+        assert!(matches!(result, Err(Error::InvalidImageData)));
+    }
+}
@@ -0,0 +1,36 @@
+pub const KEYSYM_ESCAPE: u32 = 0xff1b;
+
+#[derive(Debug, Clone)]
+pub struct KeyboardMapping {
+    min_keycode: u8,
+    keysyms_per_keycode: u8,
+    keysyms: Vec<u32>,
+}
+
+impl KeyboardMapping {
+    pub(crate) fn new(min_keycode: u8, keysyms_per_keycode: u8, keysyms: Vec<u32>) -> Self {
+        Self {
+            min_keycode,
+            keysyms_per_keycode,
+            keysyms,
+        }
+    }
+
+    pub fn keysyms(&self, keycode: u8) -> &[u32] {
+        let width = usize::from(self.keysyms_per_keycode);
+        let start = usize::from(keycode.wrapping_sub(self.min_keycode)) * width;
+
+        self.keysyms.get(start..start + width).unwrap_or(&[])
+    }
+
+    pub fn keycodes(&self, keysym: u32) -> Vec<u8> {
+        let width = usize::from(self.keysyms_per_keycode).max(1);
+
+        self.keysyms
+            .chunks(width)
+            .enumerate()
+            .filter(|(_, keysyms)| keysyms.contains(&keysym))
+            .map(|(index, _)| self.min_keycode + index as u8)
+            .collect()
+    }
+}
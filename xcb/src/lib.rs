@@ -1,10 +1,27 @@
 #[macro_use]
 extern crate bitflags;
 
+pub mod atom;
+pub mod color;
 pub mod connection;
+pub mod cursor;
+pub mod drawable;
 pub mod event;
+pub mod extension;
+pub mod focus;
+pub mod font;
+pub mod grab;
+pub mod graphics;
+pub mod image;
+pub mod keyboard;
+pub mod pixmap;
+pub mod proto;
+pub mod randr;
+pub mod render;
 pub mod result;
+pub mod screen;
 pub mod window;
+pub mod xinerama;
 
 #[derive(Debug, Copy, Clone)]
 pub struct Rectangle {
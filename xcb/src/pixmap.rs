@@ -0,0 +1,76 @@
+use crate::connection::Connection;
+use crate::drawable::Drawable;
+use crate::proto::xproto;
+use crate::result::{Cookie, Error, Void};
+use crate::window::Window;
+use xcb_system::xcb_pixmap_t;
+
+#[derive(Debug)]
+pub struct OwnedPixmap<'a> {
+    connection: &'a Connection,
+    handle: xcb_pixmap_t,
+    depth: u8,
+    width: u16,
+    height: u16,
+}
+
+impl<'a> OwnedPixmap<'a> {
+    pub fn new(
+        connection: &'a Connection,
+        depth: u8,
+        width: u16,
+        height: u16,
+    ) -> Result<Self, Error> {
+        let handle = unsafe { xcb_system::xcb_generate_id(connection.get_connection()) };
+
+        xproto::create_pixmap(
+            connection,
+            depth,
+            handle,
+            connection.get_root_window()?.id(),
+            width,
+            height,
+        )
+        .get_result()?;
+
+        Ok(Self {
+            connection,
+            handle,
+            depth,
+            width,
+            height,
+        })
+    }
+
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    pub fn id(&self) -> u32 {
+        self.handle
+    }
+
+    fn free(&self) -> Cookie<'_, Void> {
+        xproto::free_pixmap(self.connection, self.handle)
+    }
+}
+
+impl Drawable for OwnedPixmap<'_> {
+    fn drawable_id(&self) -> u32 {
+        self.handle
+    }
+}
+
+impl Drop for OwnedPixmap<'_> {
+    fn drop(&mut self) {
+        self.free().discard();
+    }
+}
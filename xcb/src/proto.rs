@@ -0,0 +1,47 @@
+// Wrappers generated from the xcb-proto descriptions by xcb-codegen: a function per request,
+// a struct per reply and event, and the protocol's enums and masks. They stay as close to the
+// protocol as libxcb itself does, the rest of the crate builds on them.
+
+macro_rules! generated {
+    ($($(#[$attribute:meta])* $module:ident),* $(,)?) => {
+        $(
+            $(#[$attribute])*
+            #[allow(clippy::all, non_snake_case, unused_unsafe)]
+            pub mod $module {
+                #[cfg(not(feature = "regenerate-proto"))]
+                include!(concat!("proto/", stringify!($module), ".rs"));
+                #[cfg(feature = "regenerate-proto")]
+                include!(concat!(env!("OUT_DIR"), "/", stringify!($module), ".rs"));
+            }
+        )*
+    };
+}
+
+generated! {
+    xproto,
+    render,
+    randr,
+    xinerama,
+    #[cfg(feature = "xkb")]
+    xkb,
+    #[cfg(feature = "composite")]
+    composite,
+    #[cfg(feature = "damage")]
+    damage,
+    #[cfg(feature = "xfixes")]
+    xfixes,
+    #[cfg(feature = "shape")]
+    shape,
+    #[cfg(feature = "sync")]
+    sync,
+    #[cfg(feature = "present")]
+    present,
+    #[cfg(feature = "xinput")]
+    xinput,
+    #[cfg(feature = "res")]
+    res,
+    #[cfg(feature = "dpms")]
+    dpms,
+    #[cfg(feature = "screensaver")]
+    screensaver,
+}
@@ -0,0 +1,154 @@
+// Generated by xcb-codegen from composite.xml, don't edit.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Redirect(pub u32);
+
+impl Redirect {
+    pub const AUTOMATIC: Self = Self(0);
+    pub const MANUAL: Self = Self(1);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryVersionReply {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+pub struct QueryVersionRequest;
+
+impl crate::result::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_composite_query_version_reply_t>(connection, sequence)?;
+
+        Ok(QueryVersionReply {
+            major_version: reply.major_version,
+            minor_version: reply.minor_version,
+        })
+    }
+}
+
+pub fn query_version<'a>(connection: &'a crate::connection::Connection, client_major_version: u32, client_minor_version: u32) -> crate::result::Cookie<'a, QueryVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_composite_query_version(connection.get_connection(), client_major_version, client_minor_version) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryVersionRequest, connection)
+}
+
+pub fn redirect_window<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, update: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_composite_redirect_window_checked(connection.get_connection(), window, update) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn redirect_window_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, update: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_composite_redirect_window(connection.get_connection(), window, update) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn redirect_subwindows<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, update: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_composite_redirect_subwindows_checked(connection.get_connection(), window, update) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn redirect_subwindows_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, update: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_composite_redirect_subwindows(connection.get_connection(), window, update) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn unredirect_window<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, update: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_composite_unredirect_window_checked(connection.get_connection(), window, update) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn unredirect_window_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, update: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_composite_unredirect_window(connection.get_connection(), window, update) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn unredirect_subwindows<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, update: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_composite_unredirect_subwindows_checked(connection.get_connection(), window, update) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn unredirect_subwindows_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, update: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_composite_unredirect_subwindows(connection.get_connection(), window, update) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn create_region_from_border_clip<'a>(connection: &'a crate::connection::Connection, region: super::xfixes::Region, window: super::xproto::Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_composite_create_region_from_border_clip_checked(connection.get_connection(), region, window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_region_from_border_clip_unchecked<'a>(connection: &'a crate::connection::Connection, region: super::xfixes::Region, window: super::xproto::Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_composite_create_region_from_border_clip(connection.get_connection(), region, window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn name_window_pixmap<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, pixmap: super::xproto::Pixmap) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_composite_name_window_pixmap_checked(connection.get_connection(), window, pixmap) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn name_window_pixmap_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, pixmap: super::xproto::Pixmap) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_composite_name_window_pixmap(connection.get_connection(), window, pixmap) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetOverlayWindowReply {
+    pub overlay_win: super::xproto::Window,
+}
+
+pub struct GetOverlayWindowRequest;
+
+impl crate::result::Request for GetOverlayWindowRequest {
+    type Reply = GetOverlayWindowReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetOverlayWindowReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_composite_get_overlay_window_reply_t>(connection, sequence)?;
+
+        Ok(GetOverlayWindowReply {
+            overlay_win: reply.overlay_win,
+        })
+    }
+}
+
+pub fn get_overlay_window<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, GetOverlayWindowRequest> {
+    let cookie = unsafe { xcb_system::xcb_composite_get_overlay_window(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, GetOverlayWindowRequest, connection)
+}
+
+pub fn release_overlay_window<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_composite_release_overlay_window_checked(connection.get_connection(), window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn release_overlay_window_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_composite_release_overlay_window(connection.get_connection(), window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
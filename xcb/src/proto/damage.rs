@@ -0,0 +1,132 @@
+// Generated by xcb-codegen from damage.xml, don't edit.
+
+pub type Damage = u32;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ReportLevel(pub u32);
+
+impl ReportLevel {
+    pub const RAW_RECTANGLES: Self = Self(0);
+    pub const DELTA_RECTANGLES: Self = Self(1);
+    pub const BOUNDING_BOX: Self = Self(2);
+    pub const NON_EMPTY: Self = Self(3);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryVersionReply {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+pub struct QueryVersionRequest;
+
+impl crate::result::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_damage_query_version_reply_t>(connection, sequence)?;
+
+        Ok(QueryVersionReply {
+            major_version: reply.major_version,
+            minor_version: reply.minor_version,
+        })
+    }
+}
+
+pub fn query_version<'a>(connection: &'a crate::connection::Connection, client_major_version: u32, client_minor_version: u32) -> crate::result::Cookie<'a, QueryVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_damage_query_version(connection.get_connection(), client_major_version, client_minor_version) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryVersionRequest, connection)
+}
+
+pub fn create<'a>(connection: &'a crate::connection::Connection, damage: Damage, drawable: super::xproto::Drawable, level: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_damage_create_checked(connection.get_connection(), damage, drawable, level) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_unchecked<'a>(connection: &'a crate::connection::Connection, damage: Damage, drawable: super::xproto::Drawable, level: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_damage_create(connection.get_connection(), damage, drawable, level) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn destroy<'a>(connection: &'a crate::connection::Connection, damage: Damage) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_damage_destroy_checked(connection.get_connection(), damage) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn destroy_unchecked<'a>(connection: &'a crate::connection::Connection, damage: Damage) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_damage_destroy(connection.get_connection(), damage) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn subtract<'a>(connection: &'a crate::connection::Connection, damage: Damage, repair: super::xfixes::Region, parts: super::xfixes::Region) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_damage_subtract_checked(connection.get_connection(), damage, repair, parts) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn subtract_unchecked<'a>(connection: &'a crate::connection::Connection, damage: Damage, repair: super::xfixes::Region, parts: super::xfixes::Region) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_damage_subtract(connection.get_connection(), damage, repair, parts) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn add<'a>(connection: &'a crate::connection::Connection, drawable: super::xproto::Drawable, region: super::xfixes::Region) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_damage_add_checked(connection.get_connection(), drawable, region) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn add_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: super::xproto::Drawable, region: super::xfixes::Region) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_damage_add(connection.get_connection(), drawable, region) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NotifyEvent {
+    pub level: u8,
+    pub drawable: super::xproto::Drawable,
+    pub damage: Damage,
+    pub timestamp: super::xproto::Timestamp,
+    pub area: super::xproto::Rectangle,
+    pub geometry: super::xproto::Rectangle,
+}
+
+impl From<xcb_system::xcb_damage_notify_event_t> for NotifyEvent {
+    fn from(raw: xcb_system::xcb_damage_notify_event_t) -> Self {
+        Self {
+            level: raw.level,
+            drawable: raw.drawable,
+            damage: raw.damage,
+            timestamp: raw.timestamp,
+            area: super::xproto::Rectangle::from(raw.area),
+            geometry: super::xproto::Rectangle::from(raw.geometry),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    Notify(NotifyEvent),
+}
+
+pub fn decode_event(event: &xcb_system::xcb_generic_event_t, first_event: u8) -> std::option::Option<Event> {
+    let raw = event as *const xcb_system::xcb_generic_event_t;
+
+    match (event.response_type & 0x7f).wrapping_sub(first_event) {
+        0 => Some(Event::Notify(
+            unsafe { *(raw as *const xcb_system::xcb_damage_notify_event_t) }.into(),
+        )),
+        _ => None,
+    }
+}
+
@@ -0,0 +1,184 @@
+// Generated by xcb-codegen from dpms.xml, don't edit.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetVersionReply {
+    pub server_major_version: u16,
+    pub server_minor_version: u16,
+}
+
+pub struct GetVersionRequest;
+
+impl crate::result::Request for GetVersionRequest {
+    type Reply = GetVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_dpms_get_version_reply_t>(connection, sequence)?;
+
+        Ok(GetVersionReply {
+            server_major_version: reply.server_major_version,
+            server_minor_version: reply.server_minor_version,
+        })
+    }
+}
+
+pub fn get_version<'a>(connection: &'a crate::connection::Connection, client_major_version: u16, client_minor_version: u16) -> crate::result::Cookie<'a, GetVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_dpms_get_version(connection.get_connection(), client_major_version, client_minor_version) };
+
+    crate::result::Cookie::new(cookie.sequence, GetVersionRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapableReply {
+    pub capable: bool,
+}
+
+pub struct CapableRequest;
+
+impl crate::result::Request for CapableRequest {
+    type Reply = CapableReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<CapableReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_dpms_capable_reply_t>(connection, sequence)?;
+
+        Ok(CapableReply {
+            capable: reply.capable != 0,
+        })
+    }
+}
+
+pub fn capable<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, CapableRequest> {
+    let cookie = unsafe { xcb_system::xcb_dpms_capable(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, CapableRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetTimeoutsReply {
+    pub standby_timeout: u16,
+    pub suspend_timeout: u16,
+    pub off_timeout: u16,
+}
+
+pub struct GetTimeoutsRequest;
+
+impl crate::result::Request for GetTimeoutsRequest {
+    type Reply = GetTimeoutsReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetTimeoutsReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_dpms_get_timeouts_reply_t>(connection, sequence)?;
+
+        Ok(GetTimeoutsReply {
+            standby_timeout: reply.standby_timeout,
+            suspend_timeout: reply.suspend_timeout,
+            off_timeout: reply.off_timeout,
+        })
+    }
+}
+
+pub fn get_timeouts<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, GetTimeoutsRequest> {
+    let cookie = unsafe { xcb_system::xcb_dpms_get_timeouts(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, GetTimeoutsRequest, connection)
+}
+
+pub fn set_timeouts<'a>(connection: &'a crate::connection::Connection, standby_timeout: u16, suspend_timeout: u16, off_timeout: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_dpms_set_timeouts_checked(connection.get_connection(), standby_timeout, suspend_timeout, off_timeout) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_timeouts_unchecked<'a>(connection: &'a crate::connection::Connection, standby_timeout: u16, suspend_timeout: u16, off_timeout: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_dpms_set_timeouts(connection.get_connection(), standby_timeout, suspend_timeout, off_timeout) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn enable<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_dpms_enable_checked(connection.get_connection()) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn enable_unchecked<'a>(connection: &'a crate::connection::Connection) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_dpms_enable(connection.get_connection()) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn disable<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_dpms_disable_checked(connection.get_connection()) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn disable_unchecked<'a>(connection: &'a crate::connection::Connection) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_dpms_disable(connection.get_connection()) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DPMSMode(pub u32);
+
+impl DPMSMode {
+    pub const ON: Self = Self(0);
+    pub const STANDBY: Self = Self(1);
+    pub const SUSPEND: Self = Self(2);
+    pub const OFF: Self = Self(3);
+}
+
+pub fn force_level<'a>(connection: &'a crate::connection::Connection, power_level: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_dpms_force_level_checked(connection.get_connection(), power_level) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn force_level_unchecked<'a>(connection: &'a crate::connection::Connection, power_level: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_dpms_force_level(connection.get_connection(), power_level) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InfoReply {
+    pub power_level: u16,
+    pub state: bool,
+}
+
+pub struct InfoRequest;
+
+impl crate::result::Request for InfoRequest {
+    type Reply = InfoReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<InfoReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_dpms_info_reply_t>(connection, sequence)?;
+
+        Ok(InfoReply {
+            power_level: reply.power_level,
+            state: reply.state != 0,
+        })
+    }
+}
+
+pub fn info<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, InfoRequest> {
+    let cookie = unsafe { xcb_system::xcb_dpms_info(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, InfoRequest, connection)
+}
+
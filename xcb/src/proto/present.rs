@@ -0,0 +1,230 @@
+// Generated by xcb-codegen from present.xml, don't edit.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EventEnum(pub u32);
+
+impl EventEnum {
+    pub const CONFIGURE_NOTIFY: Self = Self(0);
+    pub const COMPLETE_NOTIFY: Self = Self(1);
+    pub const IDLE_NOTIFY: Self = Self(2);
+    pub const REDIRECT_NOTIFY: Self = Self(3);
+}
+
+bitflags! {
+    pub struct EventMask: u32 {
+        const NO_EVENT = 0x0;
+        const CONFIGURE_NOTIFY = 0x1;
+        const COMPLETE_NOTIFY = 0x2;
+        const IDLE_NOTIFY = 0x4;
+        const REDIRECT_NOTIFY = 0x8;
+    }
+}
+
+bitflags! {
+    pub struct Option: u32 {
+        const NONE = 0x0;
+        const ASYNC = 0x1;
+        const COPY = 0x2;
+        const UST = 0x4;
+        const SUBOPTIMAL = 0x8;
+    }
+}
+
+bitflags! {
+    pub struct Capability: u32 {
+        const NONE = 0x0;
+        const ASYNC = 0x1;
+        const FENCE = 0x2;
+        const UST = 0x4;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CompleteKind(pub u32);
+
+impl CompleteKind {
+    pub const PIXMAP: Self = Self(0);
+    pub const NOTIFY_MSC: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CompleteMode(pub u32);
+
+impl CompleteMode {
+    pub const COPY: Self = Self(0);
+    pub const FLIP: Self = Self(1);
+    pub const SKIP: Self = Self(2);
+    pub const SUBOPTIMAL_COPY: Self = Self(3);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Notify {
+    pub window: super::xproto::Window,
+    pub serial: u32,
+}
+
+impl From<xcb_system::xcb_present_notify_t> for Notify {
+    fn from(raw: xcb_system::xcb_present_notify_t) -> Self {
+        Self {
+            window: raw.window,
+            serial: raw.serial,
+        }
+    }
+}
+
+impl From<Notify> for xcb_system::xcb_present_notify_t {
+    fn from(value: Notify) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.window = value.window;
+        raw.serial = value.serial;
+
+        raw
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryVersionReply {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+pub struct QueryVersionRequest;
+
+impl crate::result::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_present_query_version_reply_t>(connection, sequence)?;
+
+        Ok(QueryVersionReply {
+            major_version: reply.major_version,
+            minor_version: reply.minor_version,
+        })
+    }
+}
+
+pub fn query_version<'a>(connection: &'a crate::connection::Connection, major_version: u32, minor_version: u32) -> crate::result::Cookie<'a, QueryVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_present_query_version(connection.get_connection(), major_version, minor_version) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryVersionRequest, connection)
+}
+
+pub fn pixmap<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, pixmap: super::xproto::Pixmap, serial: u32, valid: super::xfixes::Region, update: super::xfixes::Region, x_off: i16, y_off: i16, target_crtc: super::randr::Crtc, wait_fence: super::sync::Fence, idle_fence: super::sync::Fence, options: u32, target_msc: u64, divisor: u64, remainder: u64, notifies: &[Notify]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let notifies: Vec<xcb_system::xcb_present_notify_t> = notifies.iter().map(|&value| xcb_system::xcb_present_notify_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_present_pixmap_checked(connection.get_connection(), window, pixmap, serial, valid, update, x_off, y_off, target_crtc, wait_fence, idle_fence, options, target_msc, divisor, remainder, notifies.len() as u32, notifies.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn pixmap_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, pixmap: super::xproto::Pixmap, serial: u32, valid: super::xfixes::Region, update: super::xfixes::Region, x_off: i16, y_off: i16, target_crtc: super::randr::Crtc, wait_fence: super::sync::Fence, idle_fence: super::sync::Fence, options: u32, target_msc: u64, divisor: u64, remainder: u64, notifies: &[Notify]) -> crate::result::UncheckedCookie {
+    let notifies: Vec<xcb_system::xcb_present_notify_t> = notifies.iter().map(|&value| xcb_system::xcb_present_notify_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_present_pixmap(connection.get_connection(), window, pixmap, serial, valid, update, x_off, y_off, target_crtc, wait_fence, idle_fence, options, target_msc, divisor, remainder, notifies.len() as u32, notifies.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn notify_msc<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, serial: u32, target_msc: u64, divisor: u64, remainder: u64) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_present_notify_msc_checked(connection.get_connection(), window, serial, target_msc, divisor, remainder) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn notify_msc_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, serial: u32, target_msc: u64, divisor: u64, remainder: u64) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_present_notify_msc(connection.get_connection(), window, serial, target_msc, divisor, remainder) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub type Event = u32;
+
+pub fn select_input<'a>(connection: &'a crate::connection::Connection, eid: Event, window: super::xproto::Window, event_mask: u32) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_present_select_input_checked(connection.get_connection(), eid, window, event_mask) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn select_input_unchecked<'a>(connection: &'a crate::connection::Connection, eid: Event, window: super::xproto::Window, event_mask: u32) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_present_select_input(connection.get_connection(), eid, window, event_mask) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryCapabilitiesReply {
+    pub capabilities: u32,
+}
+
+pub struct QueryCapabilitiesRequest;
+
+impl crate::result::Request for QueryCapabilitiesRequest {
+    type Reply = QueryCapabilitiesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryCapabilitiesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_present_query_capabilities_reply_t>(connection, sequence)?;
+
+        Ok(QueryCapabilitiesReply {
+            capabilities: reply.capabilities,
+        })
+    }
+}
+
+pub fn query_capabilities<'a>(connection: &'a crate::connection::Connection, target: u32) -> crate::result::Cookie<'a, QueryCapabilitiesRequest> {
+    let cookie = unsafe { xcb_system::xcb_present_query_capabilities(connection.get_connection(), target) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryCapabilitiesRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GenericEvent {
+    pub extension: u8,
+    pub length: u32,
+    pub evtype: u16,
+    pub event: Event,
+}
+
+impl From<xcb_system::xcb_present_generic_event_t> for GenericEvent {
+    fn from(raw: xcb_system::xcb_present_generic_event_t) -> Self {
+        Self {
+            extension: raw.extension,
+            length: raw.length,
+            evtype: raw.evtype,
+            event: raw.event,
+        }
+    }
+}
+
+// ConfigureNotify isn't generated, it's a generic event.
+
+// CompleteNotify isn't generated, it's a generic event.
+
+// IdleNotify isn't generated, it's a generic event.
+
+// RedirectNotify isn't generated, it's a generic event.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PresentEvent {
+    Generic(GenericEvent),
+}
+
+pub fn decode_event(event: &xcb_system::xcb_generic_event_t, first_event: u8) -> std::option::Option<PresentEvent> {
+    let raw = event as *const xcb_system::xcb_generic_event_t;
+
+    match (event.response_type & 0x7f).wrapping_sub(first_event) {
+        0 => Some(PresentEvent::Generic(
+            unsafe { *(raw as *const xcb_system::xcb_present_generic_event_t) }.into(),
+        )),
+        _ => None,
+    }
+}
+
@@ -0,0 +1,1599 @@
+// Generated by xcb-codegen from randr.xml, don't edit.
+
+pub type Mode = u32;
+
+pub type Crtc = u32;
+
+pub type Output = u32;
+
+pub type Provider = u32;
+
+pub type Lease = u32;
+
+bitflags! {
+    pub struct Rotation: u32 {
+        const ROTATE_0 = 0x1;
+        const ROTATE_90 = 0x2;
+        const ROTATE_180 = 0x4;
+        const ROTATE_270 = 0x8;
+        const REFLECT_X = 0x10;
+        const REFLECT_Y = 0x20;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScreenSize {
+    pub width: u16,
+    pub height: u16,
+    pub mwidth: u16,
+    pub mheight: u16,
+}
+
+impl From<xcb_system::xcb_randr_screen_size_t> for ScreenSize {
+    fn from(raw: xcb_system::xcb_randr_screen_size_t) -> Self {
+        Self {
+            width: raw.width,
+            height: raw.height,
+            mwidth: raw.mwidth,
+            mheight: raw.mheight,
+        }
+    }
+}
+
+impl From<ScreenSize> for xcb_system::xcb_randr_screen_size_t {
+    fn from(value: ScreenSize) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.width = value.width;
+        raw.height = value.height;
+        raw.mwidth = value.mwidth;
+        raw.mheight = value.mheight;
+
+        raw
+    }
+}
+
+// RefreshRates isn't generated, rates has a variable length.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryVersionReply {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+pub struct QueryVersionRequest;
+
+impl crate::result::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_query_version_reply_t>(connection, sequence)?;
+
+        Ok(QueryVersionReply {
+            major_version: reply.major_version,
+            minor_version: reply.minor_version,
+        })
+    }
+}
+
+pub fn query_version<'a>(connection: &'a crate::connection::Connection, major_version: u32, minor_version: u32) -> crate::result::Cookie<'a, QueryVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_query_version(connection.get_connection(), major_version, minor_version) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryVersionRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SetConfig(pub u32);
+
+impl SetConfig {
+    pub const SUCCESS: Self = Self(0);
+    pub const INVALID_CONFIG_TIME: Self = Self(1);
+    pub const INVALID_TIME: Self = Self(2);
+    pub const FAILED: Self = Self(3);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetScreenConfigReply {
+    pub status: u8,
+    pub new_timestamp: super::xproto::Timestamp,
+    pub config_timestamp: super::xproto::Timestamp,
+    pub root: super::xproto::Window,
+    pub subpixel_order: u16,
+}
+
+pub struct SetScreenConfigRequest;
+
+impl crate::result::Request for SetScreenConfigRequest {
+    type Reply = SetScreenConfigReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<SetScreenConfigReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_set_screen_config_reply_t>(connection, sequence)?;
+
+        Ok(SetScreenConfigReply {
+            status: reply.status,
+            new_timestamp: reply.new_timestamp,
+            config_timestamp: reply.config_timestamp,
+            root: reply.root,
+            subpixel_order: reply.subpixel_order,
+        })
+    }
+}
+
+pub fn set_screen_config<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, timestamp: super::xproto::Timestamp, config_timestamp: super::xproto::Timestamp, sizeID: u16, rotation: u16, rate: u16) -> crate::result::Cookie<'a, SetScreenConfigRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_set_screen_config(connection.get_connection(), window, timestamp, config_timestamp, sizeID, rotation, rate) };
+
+    crate::result::Cookie::new(cookie.sequence, SetScreenConfigRequest, connection)
+}
+
+bitflags! {
+    pub struct NotifyMask: u32 {
+        const SCREEN_CHANGE = 0x1;
+        const CRTC_CHANGE = 0x2;
+        const OUTPUT_CHANGE = 0x4;
+        const OUTPUT_PROPERTY = 0x8;
+        const PROVIDER_CHANGE = 0x10;
+        const PROVIDER_PROPERTY = 0x20;
+        const RESOURCE_CHANGE = 0x40;
+        const LEASE = 0x80;
+    }
+}
+
+pub fn select_input<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, enable: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_select_input_checked(connection.get_connection(), window, enable) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn select_input_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, enable: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_select_input(connection.get_connection(), window, enable) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+// GetScreenInfo isn't generated, RefreshRates has a variable size.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetScreenSizeRangeReply {
+    pub min_width: u16,
+    pub min_height: u16,
+    pub max_width: u16,
+    pub max_height: u16,
+}
+
+pub struct GetScreenSizeRangeRequest;
+
+impl crate::result::Request for GetScreenSizeRangeRequest {
+    type Reply = GetScreenSizeRangeReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetScreenSizeRangeReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_screen_size_range_reply_t>(connection, sequence)?;
+
+        Ok(GetScreenSizeRangeReply {
+            min_width: reply.min_width,
+            min_height: reply.min_height,
+            max_width: reply.max_width,
+            max_height: reply.max_height,
+        })
+    }
+}
+
+pub fn get_screen_size_range<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, GetScreenSizeRangeRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_screen_size_range(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, GetScreenSizeRangeRequest, connection)
+}
+
+pub fn set_screen_size<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, width: u16, height: u16, mm_width: u32, mm_height: u32) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_set_screen_size_checked(connection.get_connection(), window, width, height, mm_width, mm_height) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_screen_size_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, width: u16, height: u16, mm_width: u32, mm_height: u32) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_set_screen_size(connection.get_connection(), window, width, height, mm_width, mm_height) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+bitflags! {
+    pub struct ModeFlag: u32 {
+        const HSYNC_POSITIVE = 0x1;
+        const HSYNC_NEGATIVE = 0x2;
+        const VSYNC_POSITIVE = 0x4;
+        const VSYNC_NEGATIVE = 0x8;
+        const INTERLACE = 0x10;
+        const DOUBLE_SCAN = 0x20;
+        const CSYNC = 0x40;
+        const CSYNC_POSITIVE = 0x80;
+        const CSYNC_NEGATIVE = 0x100;
+        const HSKEW_PRESENT = 0x200;
+        const BCAST = 0x400;
+        const PIXEL_MULTIPLEX = 0x800;
+        const DOUBLE_CLOCK = 0x1000;
+        const HALVE_CLOCK = 0x2000;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ModeInfo {
+    pub id: u32,
+    pub width: u16,
+    pub height: u16,
+    pub dot_clock: u32,
+    pub hsync_start: u16,
+    pub hsync_end: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vsync_start: u16,
+    pub vsync_end: u16,
+    pub vtotal: u16,
+    pub name_len: u16,
+    pub mode_flags: u32,
+}
+
+impl From<xcb_system::xcb_randr_mode_info_t> for ModeInfo {
+    fn from(raw: xcb_system::xcb_randr_mode_info_t) -> Self {
+        Self {
+            id: raw.id,
+            width: raw.width,
+            height: raw.height,
+            dot_clock: raw.dot_clock,
+            hsync_start: raw.hsync_start,
+            hsync_end: raw.hsync_end,
+            htotal: raw.htotal,
+            hskew: raw.hskew,
+            vsync_start: raw.vsync_start,
+            vsync_end: raw.vsync_end,
+            vtotal: raw.vtotal,
+            name_len: raw.name_len,
+            mode_flags: raw.mode_flags,
+        }
+    }
+}
+
+impl From<ModeInfo> for xcb_system::xcb_randr_mode_info_t {
+    fn from(value: ModeInfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.id = value.id;
+        raw.width = value.width;
+        raw.height = value.height;
+        raw.dot_clock = value.dot_clock;
+        raw.hsync_start = value.hsync_start;
+        raw.hsync_end = value.hsync_end;
+        raw.htotal = value.htotal;
+        raw.hskew = value.hskew;
+        raw.vsync_start = value.vsync_start;
+        raw.vsync_end = value.vsync_end;
+        raw.vtotal = value.vtotal;
+        raw.name_len = value.name_len;
+        raw.mode_flags = value.mode_flags;
+
+        raw
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetScreenResourcesReply {
+    pub timestamp: super::xproto::Timestamp,
+    pub config_timestamp: super::xproto::Timestamp,
+    pub num_crtcs: u16,
+    pub num_outputs: u16,
+    pub num_modes: u16,
+    pub names_len: u16,
+    pub crtcs: Vec<Crtc>,
+    pub outputs: Vec<Output>,
+    pub modes: Vec<ModeInfo>,
+    pub names: Vec<u8>,
+}
+
+pub struct GetScreenResourcesRequest;
+
+impl crate::result::Request for GetScreenResourcesRequest {
+    type Reply = GetScreenResourcesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetScreenResourcesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_screen_resources_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetScreenResourcesReply {
+            timestamp: reply.timestamp,
+            config_timestamp: reply.config_timestamp,
+            num_crtcs: reply.num_crtcs,
+            num_outputs: reply.num_outputs,
+            num_modes: reply.num_modes,
+            names_len: reply.names_len,
+            crtcs: std::slice::from_raw_parts(xcb_system::xcb_randr_get_screen_resources_crtcs(reply.as_ptr()) as *const Crtc, xcb_system::xcb_randr_get_screen_resources_crtcs_length(reply.as_ptr()) as usize).to_vec(),
+            outputs: std::slice::from_raw_parts(xcb_system::xcb_randr_get_screen_resources_outputs(reply.as_ptr()) as *const Output, xcb_system::xcb_randr_get_screen_resources_outputs_length(reply.as_ptr()) as usize).to_vec(),
+            modes: std::slice::from_raw_parts(xcb_system::xcb_randr_get_screen_resources_modes(reply.as_ptr()) as *const xcb_system::xcb_randr_mode_info_t, xcb_system::xcb_randr_get_screen_resources_modes_length(reply.as_ptr()) as usize).iter().map(|&value| ModeInfo::from(value)).collect(),
+            names: std::slice::from_raw_parts(xcb_system::xcb_randr_get_screen_resources_names(reply.as_ptr()) as *const u8, xcb_system::xcb_randr_get_screen_resources_names_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_screen_resources<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, GetScreenResourcesRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_screen_resources(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, GetScreenResourcesRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Connection(pub u32);
+
+impl Connection {
+    pub const CONNECTED: Self = Self(0);
+    pub const DISCONNECTED: Self = Self(1);
+    pub const UNKNOWN: Self = Self(2);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetOutputInfoReply {
+    pub status: u8,
+    pub timestamp: super::xproto::Timestamp,
+    pub crtc: Crtc,
+    pub mm_width: u32,
+    pub mm_height: u32,
+    pub connection: u8,
+    pub subpixel_order: u8,
+    pub num_crtcs: u16,
+    pub num_modes: u16,
+    pub num_preferred: u16,
+    pub num_clones: u16,
+    pub name_len: u16,
+    pub crtcs: Vec<Crtc>,
+    pub modes: Vec<Mode>,
+    pub clones: Vec<Output>,
+    pub name: Vec<u8>,
+}
+
+pub struct GetOutputInfoRequest;
+
+impl crate::result::Request for GetOutputInfoRequest {
+    type Reply = GetOutputInfoReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetOutputInfoReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_output_info_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetOutputInfoReply {
+            status: reply.status,
+            timestamp: reply.timestamp,
+            crtc: reply.crtc,
+            mm_width: reply.mm_width,
+            mm_height: reply.mm_height,
+            connection: reply.connection,
+            subpixel_order: reply.subpixel_order,
+            num_crtcs: reply.num_crtcs,
+            num_modes: reply.num_modes,
+            num_preferred: reply.num_preferred,
+            num_clones: reply.num_clones,
+            name_len: reply.name_len,
+            crtcs: std::slice::from_raw_parts(xcb_system::xcb_randr_get_output_info_crtcs(reply.as_ptr()) as *const Crtc, xcb_system::xcb_randr_get_output_info_crtcs_length(reply.as_ptr()) as usize).to_vec(),
+            modes: std::slice::from_raw_parts(xcb_system::xcb_randr_get_output_info_modes(reply.as_ptr()) as *const Mode, xcb_system::xcb_randr_get_output_info_modes_length(reply.as_ptr()) as usize).to_vec(),
+            clones: std::slice::from_raw_parts(xcb_system::xcb_randr_get_output_info_clones(reply.as_ptr()) as *const Output, xcb_system::xcb_randr_get_output_info_clones_length(reply.as_ptr()) as usize).to_vec(),
+            name: std::slice::from_raw_parts(xcb_system::xcb_randr_get_output_info_name(reply.as_ptr()) as *const u8, xcb_system::xcb_randr_get_output_info_name_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_output_info<'a>(connection: &'a crate::connection::Connection, output: Output, config_timestamp: super::xproto::Timestamp) -> crate::result::Cookie<'a, GetOutputInfoRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_output_info(connection.get_connection(), output, config_timestamp) };
+
+    crate::result::Cookie::new(cookie.sequence, GetOutputInfoRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListOutputPropertiesReply {
+    pub num_atoms: u16,
+    pub atoms: Vec<super::xproto::Atom>,
+}
+
+pub struct ListOutputPropertiesRequest;
+
+impl crate::result::Request for ListOutputPropertiesRequest {
+    type Reply = ListOutputPropertiesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<ListOutputPropertiesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_list_output_properties_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { ListOutputPropertiesReply {
+            num_atoms: reply.num_atoms,
+            atoms: std::slice::from_raw_parts(xcb_system::xcb_randr_list_output_properties_atoms(reply.as_ptr()) as *const super::xproto::Atom, xcb_system::xcb_randr_list_output_properties_atoms_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn list_output_properties<'a>(connection: &'a crate::connection::Connection, output: Output) -> crate::result::Cookie<'a, ListOutputPropertiesRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_list_output_properties(connection.get_connection(), output) };
+
+    crate::result::Cookie::new(cookie.sequence, ListOutputPropertiesRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryOutputPropertyReply {
+    pub pending: bool,
+    pub range: bool,
+    pub immutable: bool,
+    pub valid_values: Vec<i32>,
+}
+
+pub struct QueryOutputPropertyRequest;
+
+impl crate::result::Request for QueryOutputPropertyRequest {
+    type Reply = QueryOutputPropertyReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryOutputPropertyReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_query_output_property_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { QueryOutputPropertyReply {
+            pending: reply.pending != 0,
+            range: reply.range != 0,
+            immutable: reply.immutable != 0,
+            valid_values: std::slice::from_raw_parts(xcb_system::xcb_randr_query_output_property_valid_values(reply.as_ptr()) as *const i32, xcb_system::xcb_randr_query_output_property_valid_values_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn query_output_property<'a>(connection: &'a crate::connection::Connection, output: Output, property: super::xproto::Atom) -> crate::result::Cookie<'a, QueryOutputPropertyRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_query_output_property(connection.get_connection(), output, property) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryOutputPropertyRequest, connection)
+}
+
+pub fn configure_output_property<'a>(connection: &'a crate::connection::Connection, output: Output, property: super::xproto::Atom, pending: bool, range: bool, values: &[i32]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_configure_output_property_checked(connection.get_connection(), output, property, u8::from(pending), u8::from(range), values.len() as u32, values.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn configure_output_property_unchecked<'a>(connection: &'a crate::connection::Connection, output: Output, property: super::xproto::Atom, pending: bool, range: bool, values: &[i32]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_configure_output_property(connection.get_connection(), output, property, u8::from(pending), u8::from(range), values.len() as u32, values.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn change_output_property<'a>(connection: &'a crate::connection::Connection, output: Output, property: super::xproto::Atom, type_: super::xproto::Atom, format: u8, mode: u8, num_units: u32, data: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    assert!(data.len() >= ((num_units as usize) * ((format as usize) / 8)) as usize);
+
+    let cookie = unsafe { xcb_system::xcb_randr_change_output_property_checked(connection.get_connection(), output, property, type_, format, mode, num_units, data.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_output_property_unchecked<'a>(connection: &'a crate::connection::Connection, output: Output, property: super::xproto::Atom, type_: super::xproto::Atom, format: u8, mode: u8, num_units: u32, data: &[u8]) -> crate::result::UncheckedCookie {
+    assert!(data.len() >= ((num_units as usize) * ((format as usize) / 8)) as usize);
+
+    let cookie = unsafe { xcb_system::xcb_randr_change_output_property(connection.get_connection(), output, property, type_, format, mode, num_units, data.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn delete_output_property<'a>(connection: &'a crate::connection::Connection, output: Output, property: super::xproto::Atom) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_delete_output_property_checked(connection.get_connection(), output, property) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn delete_output_property_unchecked<'a>(connection: &'a crate::connection::Connection, output: Output, property: super::xproto::Atom) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_delete_output_property(connection.get_connection(), output, property) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetOutputPropertyReply {
+    pub format: u8,
+    pub type_: super::xproto::Atom,
+    pub bytes_after: u32,
+    pub num_items: u32,
+    pub data: Vec<u8>,
+}
+
+pub struct GetOutputPropertyRequest;
+
+impl crate::result::Request for GetOutputPropertyRequest {
+    type Reply = GetOutputPropertyReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetOutputPropertyReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_output_property_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetOutputPropertyReply {
+            format: reply.format,
+            type_: reply.type_,
+            bytes_after: reply.bytes_after,
+            num_items: reply.num_items,
+            data: std::slice::from_raw_parts(xcb_system::xcb_randr_get_output_property_data(reply.as_ptr()) as *const u8, xcb_system::xcb_randr_get_output_property_data_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_output_property<'a>(connection: &'a crate::connection::Connection, output: Output, property: super::xproto::Atom, type_: super::xproto::Atom, long_offset: u32, long_length: u32, delete: bool, pending: bool) -> crate::result::Cookie<'a, GetOutputPropertyRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_output_property(connection.get_connection(), output, property, type_, long_offset, long_length, u8::from(delete), u8::from(pending)) };
+
+    crate::result::Cookie::new(cookie.sequence, GetOutputPropertyRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateModeReply {
+    pub mode: Mode,
+}
+
+pub struct CreateModeRequest;
+
+impl crate::result::Request for CreateModeRequest {
+    type Reply = CreateModeReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<CreateModeReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_create_mode_reply_t>(connection, sequence)?;
+
+        Ok(CreateModeReply {
+            mode: reply.mode,
+        })
+    }
+}
+
+pub fn create_mode<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, mode_info: ModeInfo, name: &[u8]) -> crate::result::Cookie<'a, CreateModeRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_create_mode(connection.get_connection(), window, xcb_system::xcb_randr_mode_info_t::from(mode_info), name.len() as u32, name.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, CreateModeRequest, connection)
+}
+
+pub fn destroy_mode<'a>(connection: &'a crate::connection::Connection, mode: Mode) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_destroy_mode_checked(connection.get_connection(), mode) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn destroy_mode_unchecked<'a>(connection: &'a crate::connection::Connection, mode: Mode) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_destroy_mode(connection.get_connection(), mode) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn add_output_mode<'a>(connection: &'a crate::connection::Connection, output: Output, mode: Mode) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_add_output_mode_checked(connection.get_connection(), output, mode) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn add_output_mode_unchecked<'a>(connection: &'a crate::connection::Connection, output: Output, mode: Mode) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_add_output_mode(connection.get_connection(), output, mode) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn delete_output_mode<'a>(connection: &'a crate::connection::Connection, output: Output, mode: Mode) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_delete_output_mode_checked(connection.get_connection(), output, mode) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn delete_output_mode_unchecked<'a>(connection: &'a crate::connection::Connection, output: Output, mode: Mode) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_delete_output_mode(connection.get_connection(), output, mode) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetCrtcInfoReply {
+    pub status: u8,
+    pub timestamp: super::xproto::Timestamp,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub mode: Mode,
+    pub rotation: u16,
+    pub rotations: u16,
+    pub num_outputs: u16,
+    pub num_possible_outputs: u16,
+    pub outputs: Vec<Output>,
+    pub possible: Vec<Output>,
+}
+
+pub struct GetCrtcInfoRequest;
+
+impl crate::result::Request for GetCrtcInfoRequest {
+    type Reply = GetCrtcInfoReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetCrtcInfoReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_crtc_info_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetCrtcInfoReply {
+            status: reply.status,
+            timestamp: reply.timestamp,
+            x: reply.x,
+            y: reply.y,
+            width: reply.width,
+            height: reply.height,
+            mode: reply.mode,
+            rotation: reply.rotation,
+            rotations: reply.rotations,
+            num_outputs: reply.num_outputs,
+            num_possible_outputs: reply.num_possible_outputs,
+            outputs: std::slice::from_raw_parts(xcb_system::xcb_randr_get_crtc_info_outputs(reply.as_ptr()) as *const Output, xcb_system::xcb_randr_get_crtc_info_outputs_length(reply.as_ptr()) as usize).to_vec(),
+            possible: std::slice::from_raw_parts(xcb_system::xcb_randr_get_crtc_info_possible(reply.as_ptr()) as *const Output, xcb_system::xcb_randr_get_crtc_info_possible_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_crtc_info<'a>(connection: &'a crate::connection::Connection, crtc: Crtc, config_timestamp: super::xproto::Timestamp) -> crate::result::Cookie<'a, GetCrtcInfoRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_crtc_info(connection.get_connection(), crtc, config_timestamp) };
+
+    crate::result::Cookie::new(cookie.sequence, GetCrtcInfoRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetCrtcConfigReply {
+    pub status: u8,
+    pub timestamp: super::xproto::Timestamp,
+}
+
+pub struct SetCrtcConfigRequest;
+
+impl crate::result::Request for SetCrtcConfigRequest {
+    type Reply = SetCrtcConfigReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<SetCrtcConfigReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_set_crtc_config_reply_t>(connection, sequence)?;
+
+        Ok(SetCrtcConfigReply {
+            status: reply.status,
+            timestamp: reply.timestamp,
+        })
+    }
+}
+
+pub fn set_crtc_config<'a>(connection: &'a crate::connection::Connection, crtc: Crtc, timestamp: super::xproto::Timestamp, config_timestamp: super::xproto::Timestamp, x: i16, y: i16, mode: Mode, rotation: u16, outputs: &[Output]) -> crate::result::Cookie<'a, SetCrtcConfigRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_set_crtc_config(connection.get_connection(), crtc, timestamp, config_timestamp, x, y, mode, rotation, outputs.len() as u32, outputs.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, SetCrtcConfigRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetCrtcGammaSizeReply {
+    pub size: u16,
+}
+
+pub struct GetCrtcGammaSizeRequest;
+
+impl crate::result::Request for GetCrtcGammaSizeRequest {
+    type Reply = GetCrtcGammaSizeReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetCrtcGammaSizeReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_crtc_gamma_size_reply_t>(connection, sequence)?;
+
+        Ok(GetCrtcGammaSizeReply {
+            size: reply.size,
+        })
+    }
+}
+
+pub fn get_crtc_gamma_size<'a>(connection: &'a crate::connection::Connection, crtc: Crtc) -> crate::result::Cookie<'a, GetCrtcGammaSizeRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_crtc_gamma_size(connection.get_connection(), crtc) };
+
+    crate::result::Cookie::new(cookie.sequence, GetCrtcGammaSizeRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetCrtcGammaReply {
+    pub size: u16,
+    pub red: Vec<u16>,
+    pub green: Vec<u16>,
+    pub blue: Vec<u16>,
+}
+
+pub struct GetCrtcGammaRequest;
+
+impl crate::result::Request for GetCrtcGammaRequest {
+    type Reply = GetCrtcGammaReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetCrtcGammaReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_crtc_gamma_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetCrtcGammaReply {
+            size: reply.size,
+            red: std::slice::from_raw_parts(xcb_system::xcb_randr_get_crtc_gamma_red(reply.as_ptr()) as *const u16, xcb_system::xcb_randr_get_crtc_gamma_red_length(reply.as_ptr()) as usize).to_vec(),
+            green: std::slice::from_raw_parts(xcb_system::xcb_randr_get_crtc_gamma_green(reply.as_ptr()) as *const u16, xcb_system::xcb_randr_get_crtc_gamma_green_length(reply.as_ptr()) as usize).to_vec(),
+            blue: std::slice::from_raw_parts(xcb_system::xcb_randr_get_crtc_gamma_blue(reply.as_ptr()) as *const u16, xcb_system::xcb_randr_get_crtc_gamma_blue_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_crtc_gamma<'a>(connection: &'a crate::connection::Connection, crtc: Crtc) -> crate::result::Cookie<'a, GetCrtcGammaRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_crtc_gamma(connection.get_connection(), crtc) };
+
+    crate::result::Cookie::new(cookie.sequence, GetCrtcGammaRequest, connection)
+}
+
+pub fn set_crtc_gamma<'a>(connection: &'a crate::connection::Connection, crtc: Crtc, red: &[u16], green: &[u16], blue: &[u16]) -> crate::result::Cookie<'a, crate::result::Void> {
+    assert_eq!(green.len(), red.len());
+    assert_eq!(blue.len(), red.len());
+
+    let cookie = unsafe { xcb_system::xcb_randr_set_crtc_gamma_checked(connection.get_connection(), crtc, red.len() as _, red.as_ptr() as *const _, green.as_ptr() as *const _, blue.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_crtc_gamma_unchecked<'a>(connection: &'a crate::connection::Connection, crtc: Crtc, red: &[u16], green: &[u16], blue: &[u16]) -> crate::result::UncheckedCookie {
+    assert_eq!(green.len(), red.len());
+    assert_eq!(blue.len(), red.len());
+
+    let cookie = unsafe { xcb_system::xcb_randr_set_crtc_gamma(connection.get_connection(), crtc, red.len() as _, red.as_ptr() as *const _, green.as_ptr() as *const _, blue.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetScreenResourcesCurrentReply {
+    pub timestamp: super::xproto::Timestamp,
+    pub config_timestamp: super::xproto::Timestamp,
+    pub num_crtcs: u16,
+    pub num_outputs: u16,
+    pub num_modes: u16,
+    pub names_len: u16,
+    pub crtcs: Vec<Crtc>,
+    pub outputs: Vec<Output>,
+    pub modes: Vec<ModeInfo>,
+    pub names: Vec<u8>,
+}
+
+pub struct GetScreenResourcesCurrentRequest;
+
+impl crate::result::Request for GetScreenResourcesCurrentRequest {
+    type Reply = GetScreenResourcesCurrentReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetScreenResourcesCurrentReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_screen_resources_current_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetScreenResourcesCurrentReply {
+            timestamp: reply.timestamp,
+            config_timestamp: reply.config_timestamp,
+            num_crtcs: reply.num_crtcs,
+            num_outputs: reply.num_outputs,
+            num_modes: reply.num_modes,
+            names_len: reply.names_len,
+            crtcs: std::slice::from_raw_parts(xcb_system::xcb_randr_get_screen_resources_current_crtcs(reply.as_ptr()) as *const Crtc, xcb_system::xcb_randr_get_screen_resources_current_crtcs_length(reply.as_ptr()) as usize).to_vec(),
+            outputs: std::slice::from_raw_parts(xcb_system::xcb_randr_get_screen_resources_current_outputs(reply.as_ptr()) as *const Output, xcb_system::xcb_randr_get_screen_resources_current_outputs_length(reply.as_ptr()) as usize).to_vec(),
+            modes: std::slice::from_raw_parts(xcb_system::xcb_randr_get_screen_resources_current_modes(reply.as_ptr()) as *const xcb_system::xcb_randr_mode_info_t, xcb_system::xcb_randr_get_screen_resources_current_modes_length(reply.as_ptr()) as usize).iter().map(|&value| ModeInfo::from(value)).collect(),
+            names: std::slice::from_raw_parts(xcb_system::xcb_randr_get_screen_resources_current_names(reply.as_ptr()) as *const u8, xcb_system::xcb_randr_get_screen_resources_current_names_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_screen_resources_current<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, GetScreenResourcesCurrentRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_screen_resources_current(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, GetScreenResourcesCurrentRequest, connection)
+}
+
+bitflags! {
+    pub struct Transform: u32 {
+        const UNIT = 0x1;
+        const SCALE_UP = 0x2;
+        const SCALE_DOWN = 0x4;
+        const PROJECTIVE = 0x8;
+    }
+}
+
+pub fn set_crtc_transform<'a>(connection: &'a crate::connection::Connection, crtc: Crtc, transform: super::render::Transform, filter_name: &[u8], filter_params: &[super::render::Fixed]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_set_crtc_transform_checked(connection.get_connection(), crtc, xcb_system::xcb_render_transform_t::from(transform), filter_name.len() as _, filter_name.as_ptr() as *const _, filter_params.len() as u32, filter_params.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_crtc_transform_unchecked<'a>(connection: &'a crate::connection::Connection, crtc: Crtc, transform: super::render::Transform, filter_name: &[u8], filter_params: &[super::render::Fixed]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_set_crtc_transform(connection.get_connection(), crtc, xcb_system::xcb_render_transform_t::from(transform), filter_name.len() as _, filter_name.as_ptr() as *const _, filter_params.len() as u32, filter_params.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+// GetCrtcTransform isn't generated, it has alignment pads.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetPanningReply {
+    pub status: u8,
+    pub timestamp: super::xproto::Timestamp,
+    pub left: u16,
+    pub top: u16,
+    pub width: u16,
+    pub height: u16,
+    pub track_left: u16,
+    pub track_top: u16,
+    pub track_width: u16,
+    pub track_height: u16,
+    pub border_left: i16,
+    pub border_top: i16,
+    pub border_right: i16,
+    pub border_bottom: i16,
+}
+
+pub struct GetPanningRequest;
+
+impl crate::result::Request for GetPanningRequest {
+    type Reply = GetPanningReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetPanningReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_panning_reply_t>(connection, sequence)?;
+
+        Ok(GetPanningReply {
+            status: reply.status,
+            timestamp: reply.timestamp,
+            left: reply.left,
+            top: reply.top,
+            width: reply.width,
+            height: reply.height,
+            track_left: reply.track_left,
+            track_top: reply.track_top,
+            track_width: reply.track_width,
+            track_height: reply.track_height,
+            border_left: reply.border_left,
+            border_top: reply.border_top,
+            border_right: reply.border_right,
+            border_bottom: reply.border_bottom,
+        })
+    }
+}
+
+pub fn get_panning<'a>(connection: &'a crate::connection::Connection, crtc: Crtc) -> crate::result::Cookie<'a, GetPanningRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_panning(connection.get_connection(), crtc) };
+
+    crate::result::Cookie::new(cookie.sequence, GetPanningRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetPanningReply {
+    pub status: u8,
+    pub timestamp: super::xproto::Timestamp,
+}
+
+pub struct SetPanningRequest;
+
+impl crate::result::Request for SetPanningRequest {
+    type Reply = SetPanningReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<SetPanningReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_set_panning_reply_t>(connection, sequence)?;
+
+        Ok(SetPanningReply {
+            status: reply.status,
+            timestamp: reply.timestamp,
+        })
+    }
+}
+
+pub fn set_panning<'a>(connection: &'a crate::connection::Connection, crtc: Crtc, timestamp: super::xproto::Timestamp, left: u16, top: u16, width: u16, height: u16, track_left: u16, track_top: u16, track_width: u16, track_height: u16, border_left: i16, border_top: i16, border_right: i16, border_bottom: i16) -> crate::result::Cookie<'a, SetPanningRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_set_panning(connection.get_connection(), crtc, timestamp, left, top, width, height, track_left, track_top, track_width, track_height, border_left, border_top, border_right, border_bottom) };
+
+    crate::result::Cookie::new(cookie.sequence, SetPanningRequest, connection)
+}
+
+pub fn set_output_primary<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, output: Output) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_set_output_primary_checked(connection.get_connection(), window, output) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_output_primary_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, output: Output) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_set_output_primary(connection.get_connection(), window, output) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetOutputPrimaryReply {
+    pub output: Output,
+}
+
+pub struct GetOutputPrimaryRequest;
+
+impl crate::result::Request for GetOutputPrimaryRequest {
+    type Reply = GetOutputPrimaryReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetOutputPrimaryReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_output_primary_reply_t>(connection, sequence)?;
+
+        Ok(GetOutputPrimaryReply {
+            output: reply.output,
+        })
+    }
+}
+
+pub fn get_output_primary<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, GetOutputPrimaryRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_output_primary(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, GetOutputPrimaryRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetProvidersReply {
+    pub timestamp: super::xproto::Timestamp,
+    pub num_providers: u16,
+    pub providers: Vec<Provider>,
+}
+
+pub struct GetProvidersRequest;
+
+impl crate::result::Request for GetProvidersRequest {
+    type Reply = GetProvidersReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetProvidersReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_providers_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetProvidersReply {
+            timestamp: reply.timestamp,
+            num_providers: reply.num_providers,
+            providers: std::slice::from_raw_parts(xcb_system::xcb_randr_get_providers_providers(reply.as_ptr()) as *const Provider, xcb_system::xcb_randr_get_providers_providers_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_providers<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, GetProvidersRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_providers(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, GetProvidersRequest, connection)
+}
+
+bitflags! {
+    pub struct ProviderCapability: u32 {
+        const SOURCE_OUTPUT = 0x1;
+        const SINK_OUTPUT = 0x2;
+        const SOURCE_OFFLOAD = 0x4;
+        const SINK_OFFLOAD = 0x8;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetProviderInfoReply {
+    pub status: u8,
+    pub timestamp: super::xproto::Timestamp,
+    pub capabilities: u32,
+    pub num_crtcs: u16,
+    pub num_outputs: u16,
+    pub num_associated_providers: u16,
+    pub name_len: u16,
+    pub crtcs: Vec<Crtc>,
+    pub outputs: Vec<Output>,
+    pub associated_providers: Vec<Provider>,
+    pub associated_capability: Vec<u32>,
+    pub name: Vec<u8>,
+}
+
+pub struct GetProviderInfoRequest;
+
+impl crate::result::Request for GetProviderInfoRequest {
+    type Reply = GetProviderInfoReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetProviderInfoReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_provider_info_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetProviderInfoReply {
+            status: reply.status,
+            timestamp: reply.timestamp,
+            capabilities: reply.capabilities,
+            num_crtcs: reply.num_crtcs,
+            num_outputs: reply.num_outputs,
+            num_associated_providers: reply.num_associated_providers,
+            name_len: reply.name_len,
+            crtcs: std::slice::from_raw_parts(xcb_system::xcb_randr_get_provider_info_crtcs(reply.as_ptr()) as *const Crtc, xcb_system::xcb_randr_get_provider_info_crtcs_length(reply.as_ptr()) as usize).to_vec(),
+            outputs: std::slice::from_raw_parts(xcb_system::xcb_randr_get_provider_info_outputs(reply.as_ptr()) as *const Output, xcb_system::xcb_randr_get_provider_info_outputs_length(reply.as_ptr()) as usize).to_vec(),
+            associated_providers: std::slice::from_raw_parts(xcb_system::xcb_randr_get_provider_info_associated_providers(reply.as_ptr()) as *const Provider, xcb_system::xcb_randr_get_provider_info_associated_providers_length(reply.as_ptr()) as usize).to_vec(),
+            associated_capability: std::slice::from_raw_parts(xcb_system::xcb_randr_get_provider_info_associated_capability(reply.as_ptr()) as *const u32, xcb_system::xcb_randr_get_provider_info_associated_capability_length(reply.as_ptr()) as usize).to_vec(),
+            name: std::slice::from_raw_parts(xcb_system::xcb_randr_get_provider_info_name(reply.as_ptr()) as *const u8, xcb_system::xcb_randr_get_provider_info_name_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_provider_info<'a>(connection: &'a crate::connection::Connection, provider: Provider, config_timestamp: super::xproto::Timestamp) -> crate::result::Cookie<'a, GetProviderInfoRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_provider_info(connection.get_connection(), provider, config_timestamp) };
+
+    crate::result::Cookie::new(cookie.sequence, GetProviderInfoRequest, connection)
+}
+
+pub fn set_provider_offload_sink<'a>(connection: &'a crate::connection::Connection, provider: Provider, sink_provider: Provider, config_timestamp: super::xproto::Timestamp) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_set_provider_offload_sink_checked(connection.get_connection(), provider, sink_provider, config_timestamp) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_provider_offload_sink_unchecked<'a>(connection: &'a crate::connection::Connection, provider: Provider, sink_provider: Provider, config_timestamp: super::xproto::Timestamp) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_set_provider_offload_sink(connection.get_connection(), provider, sink_provider, config_timestamp) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn set_provider_output_source<'a>(connection: &'a crate::connection::Connection, provider: Provider, source_provider: Provider, config_timestamp: super::xproto::Timestamp) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_set_provider_output_source_checked(connection.get_connection(), provider, source_provider, config_timestamp) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_provider_output_source_unchecked<'a>(connection: &'a crate::connection::Connection, provider: Provider, source_provider: Provider, config_timestamp: super::xproto::Timestamp) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_set_provider_output_source(connection.get_connection(), provider, source_provider, config_timestamp) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListProviderPropertiesReply {
+    pub num_atoms: u16,
+    pub atoms: Vec<super::xproto::Atom>,
+}
+
+pub struct ListProviderPropertiesRequest;
+
+impl crate::result::Request for ListProviderPropertiesRequest {
+    type Reply = ListProviderPropertiesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<ListProviderPropertiesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_list_provider_properties_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { ListProviderPropertiesReply {
+            num_atoms: reply.num_atoms,
+            atoms: std::slice::from_raw_parts(xcb_system::xcb_randr_list_provider_properties_atoms(reply.as_ptr()) as *const super::xproto::Atom, xcb_system::xcb_randr_list_provider_properties_atoms_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn list_provider_properties<'a>(connection: &'a crate::connection::Connection, provider: Provider) -> crate::result::Cookie<'a, ListProviderPropertiesRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_list_provider_properties(connection.get_connection(), provider) };
+
+    crate::result::Cookie::new(cookie.sequence, ListProviderPropertiesRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryProviderPropertyReply {
+    pub pending: bool,
+    pub range: bool,
+    pub immutable: bool,
+    pub valid_values: Vec<i32>,
+}
+
+pub struct QueryProviderPropertyRequest;
+
+impl crate::result::Request for QueryProviderPropertyRequest {
+    type Reply = QueryProviderPropertyReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryProviderPropertyReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_query_provider_property_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { QueryProviderPropertyReply {
+            pending: reply.pending != 0,
+            range: reply.range != 0,
+            immutable: reply.immutable != 0,
+            valid_values: std::slice::from_raw_parts(xcb_system::xcb_randr_query_provider_property_valid_values(reply.as_ptr()) as *const i32, xcb_system::xcb_randr_query_provider_property_valid_values_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn query_provider_property<'a>(connection: &'a crate::connection::Connection, provider: Provider, property: super::xproto::Atom) -> crate::result::Cookie<'a, QueryProviderPropertyRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_query_provider_property(connection.get_connection(), provider, property) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryProviderPropertyRequest, connection)
+}
+
+pub fn configure_provider_property<'a>(connection: &'a crate::connection::Connection, provider: Provider, property: super::xproto::Atom, pending: bool, range: bool, values: &[i32]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_configure_provider_property_checked(connection.get_connection(), provider, property, u8::from(pending), u8::from(range), values.len() as u32, values.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn configure_provider_property_unchecked<'a>(connection: &'a crate::connection::Connection, provider: Provider, property: super::xproto::Atom, pending: bool, range: bool, values: &[i32]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_configure_provider_property(connection.get_connection(), provider, property, u8::from(pending), u8::from(range), values.len() as u32, values.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn change_provider_property<'a>(connection: &'a crate::connection::Connection, provider: Provider, property: super::xproto::Atom, type_: super::xproto::Atom, format: u8, mode: u8, num_items: u32, data: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    assert!(data.len() >= ((num_items as usize) * ((format as usize) / 8)) as usize);
+
+    let cookie = unsafe { xcb_system::xcb_randr_change_provider_property_checked(connection.get_connection(), provider, property, type_, format, mode, num_items, data.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_provider_property_unchecked<'a>(connection: &'a crate::connection::Connection, provider: Provider, property: super::xproto::Atom, type_: super::xproto::Atom, format: u8, mode: u8, num_items: u32, data: &[u8]) -> crate::result::UncheckedCookie {
+    assert!(data.len() >= ((num_items as usize) * ((format as usize) / 8)) as usize);
+
+    let cookie = unsafe { xcb_system::xcb_randr_change_provider_property(connection.get_connection(), provider, property, type_, format, mode, num_items, data.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn delete_provider_property<'a>(connection: &'a crate::connection::Connection, provider: Provider, property: super::xproto::Atom) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_delete_provider_property_checked(connection.get_connection(), provider, property) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn delete_provider_property_unchecked<'a>(connection: &'a crate::connection::Connection, provider: Provider, property: super::xproto::Atom) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_delete_provider_property(connection.get_connection(), provider, property) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetProviderPropertyReply {
+    pub format: u8,
+    pub type_: super::xproto::Atom,
+    pub bytes_after: u32,
+    pub num_items: u32,
+    pub data: Vec<u8>,
+}
+
+pub struct GetProviderPropertyRequest;
+
+impl crate::result::Request for GetProviderPropertyRequest {
+    type Reply = GetProviderPropertyReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetProviderPropertyReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_randr_get_provider_property_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetProviderPropertyReply {
+            format: reply.format,
+            type_: reply.type_,
+            bytes_after: reply.bytes_after,
+            num_items: reply.num_items,
+            data: std::slice::from_raw_parts(xcb_system::xcb_randr_get_provider_property_data(reply.as_ptr()) as *const u8, xcb_system::xcb_randr_get_provider_property_data_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_provider_property<'a>(connection: &'a crate::connection::Connection, provider: Provider, property: super::xproto::Atom, type_: super::xproto::Atom, long_offset: u32, long_length: u32, delete: bool, pending: bool) -> crate::result::Cookie<'a, GetProviderPropertyRequest> {
+    let cookie = unsafe { xcb_system::xcb_randr_get_provider_property(connection.get_connection(), provider, property, type_, long_offset, long_length, u8::from(delete), u8::from(pending)) };
+
+    crate::result::Cookie::new(cookie.sequence, GetProviderPropertyRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScreenChangeNotifyEvent {
+    pub rotation: u8,
+    pub timestamp: super::xproto::Timestamp,
+    pub config_timestamp: super::xproto::Timestamp,
+    pub root: super::xproto::Window,
+    pub request_window: super::xproto::Window,
+    pub sizeID: u16,
+    pub subpixel_order: u16,
+    pub width: u16,
+    pub height: u16,
+    pub mwidth: u16,
+    pub mheight: u16,
+}
+
+impl From<xcb_system::xcb_randr_screen_change_notify_event_t> for ScreenChangeNotifyEvent {
+    fn from(raw: xcb_system::xcb_randr_screen_change_notify_event_t) -> Self {
+        Self {
+            rotation: raw.rotation,
+            timestamp: raw.timestamp,
+            config_timestamp: raw.config_timestamp,
+            root: raw.root,
+            request_window: raw.request_window,
+            sizeID: raw.sizeID,
+            subpixel_order: raw.subpixel_order,
+            width: raw.width,
+            height: raw.height,
+            mwidth: raw.mwidth,
+            mheight: raw.mheight,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Notify(pub u32);
+
+impl Notify {
+    pub const CRTC_CHANGE: Self = Self(0);
+    pub const OUTPUT_CHANGE: Self = Self(1);
+    pub const OUTPUT_PROPERTY: Self = Self(2);
+    pub const PROVIDER_CHANGE: Self = Self(3);
+    pub const PROVIDER_PROPERTY: Self = Self(4);
+    pub const RESOURCE_CHANGE: Self = Self(5);
+    pub const LEASE: Self = Self(6);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CrtcChange {
+    pub timestamp: super::xproto::Timestamp,
+    pub window: super::xproto::Window,
+    pub crtc: Crtc,
+    pub mode: Mode,
+    pub rotation: u16,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl From<xcb_system::xcb_randr_crtc_change_t> for CrtcChange {
+    fn from(raw: xcb_system::xcb_randr_crtc_change_t) -> Self {
+        Self {
+            timestamp: raw.timestamp,
+            window: raw.window,
+            crtc: raw.crtc,
+            mode: raw.mode,
+            rotation: raw.rotation,
+            x: raw.x,
+            y: raw.y,
+            width: raw.width,
+            height: raw.height,
+        }
+    }
+}
+
+impl From<CrtcChange> for xcb_system::xcb_randr_crtc_change_t {
+    fn from(value: CrtcChange) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.timestamp = value.timestamp;
+        raw.window = value.window;
+        raw.crtc = value.crtc;
+        raw.mode = value.mode;
+        raw.rotation = value.rotation;
+        raw.x = value.x;
+        raw.y = value.y;
+        raw.width = value.width;
+        raw.height = value.height;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OutputChange {
+    pub timestamp: super::xproto::Timestamp,
+    pub config_timestamp: super::xproto::Timestamp,
+    pub window: super::xproto::Window,
+    pub output: Output,
+    pub crtc: Crtc,
+    pub mode: Mode,
+    pub rotation: u16,
+    pub connection: u8,
+    pub subpixel_order: u8,
+}
+
+impl From<xcb_system::xcb_randr_output_change_t> for OutputChange {
+    fn from(raw: xcb_system::xcb_randr_output_change_t) -> Self {
+        Self {
+            timestamp: raw.timestamp,
+            config_timestamp: raw.config_timestamp,
+            window: raw.window,
+            output: raw.output,
+            crtc: raw.crtc,
+            mode: raw.mode,
+            rotation: raw.rotation,
+            connection: raw.connection,
+            subpixel_order: raw.subpixel_order,
+        }
+    }
+}
+
+impl From<OutputChange> for xcb_system::xcb_randr_output_change_t {
+    fn from(value: OutputChange) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.timestamp = value.timestamp;
+        raw.config_timestamp = value.config_timestamp;
+        raw.window = value.window;
+        raw.output = value.output;
+        raw.crtc = value.crtc;
+        raw.mode = value.mode;
+        raw.rotation = value.rotation;
+        raw.connection = value.connection;
+        raw.subpixel_order = value.subpixel_order;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OutputProperty {
+    pub window: super::xproto::Window,
+    pub output: Output,
+    pub atom: super::xproto::Atom,
+    pub timestamp: super::xproto::Timestamp,
+    pub status: u8,
+}
+
+impl From<xcb_system::xcb_randr_output_property_t> for OutputProperty {
+    fn from(raw: xcb_system::xcb_randr_output_property_t) -> Self {
+        Self {
+            window: raw.window,
+            output: raw.output,
+            atom: raw.atom,
+            timestamp: raw.timestamp,
+            status: raw.status,
+        }
+    }
+}
+
+impl From<OutputProperty> for xcb_system::xcb_randr_output_property_t {
+    fn from(value: OutputProperty) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.window = value.window;
+        raw.output = value.output;
+        raw.atom = value.atom;
+        raw.timestamp = value.timestamp;
+        raw.status = value.status;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ProviderChange {
+    pub timestamp: super::xproto::Timestamp,
+    pub window: super::xproto::Window,
+    pub provider: Provider,
+}
+
+impl From<xcb_system::xcb_randr_provider_change_t> for ProviderChange {
+    fn from(raw: xcb_system::xcb_randr_provider_change_t) -> Self {
+        Self {
+            timestamp: raw.timestamp,
+            window: raw.window,
+            provider: raw.provider,
+        }
+    }
+}
+
+impl From<ProviderChange> for xcb_system::xcb_randr_provider_change_t {
+    fn from(value: ProviderChange) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.timestamp = value.timestamp;
+        raw.window = value.window;
+        raw.provider = value.provider;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ProviderProperty {
+    pub window: super::xproto::Window,
+    pub provider: Provider,
+    pub atom: super::xproto::Atom,
+    pub timestamp: super::xproto::Timestamp,
+    pub state: u8,
+}
+
+impl From<xcb_system::xcb_randr_provider_property_t> for ProviderProperty {
+    fn from(raw: xcb_system::xcb_randr_provider_property_t) -> Self {
+        Self {
+            window: raw.window,
+            provider: raw.provider,
+            atom: raw.atom,
+            timestamp: raw.timestamp,
+            state: raw.state,
+        }
+    }
+}
+
+impl From<ProviderProperty> for xcb_system::xcb_randr_provider_property_t {
+    fn from(value: ProviderProperty) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.window = value.window;
+        raw.provider = value.provider;
+        raw.atom = value.atom;
+        raw.timestamp = value.timestamp;
+        raw.state = value.state;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ResourceChange {
+    pub timestamp: super::xproto::Timestamp,
+    pub window: super::xproto::Window,
+}
+
+impl From<xcb_system::xcb_randr_resource_change_t> for ResourceChange {
+    fn from(raw: xcb_system::xcb_randr_resource_change_t) -> Self {
+        Self {
+            timestamp: raw.timestamp,
+            window: raw.window,
+        }
+    }
+}
+
+impl From<ResourceChange> for xcb_system::xcb_randr_resource_change_t {
+    fn from(value: ResourceChange) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.timestamp = value.timestamp;
+        raw.window = value.window;
+
+        raw
+    }
+}
+
+// MonitorInfo isn't generated, outputs has a variable length.
+
+// GetMonitors isn't generated, MonitorInfo has a variable size.
+
+// SetMonitor isn't generated, MonitorInfo has a variable size.
+
+pub fn delete_monitor<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, name: super::xproto::Atom) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_delete_monitor_checked(connection.get_connection(), window, name) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn delete_monitor_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, name: super::xproto::Atom) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_delete_monitor(connection.get_connection(), window, name) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+// CreateLease isn't generated, it has an fd field.
+
+pub fn free_lease<'a>(connection: &'a crate::connection::Connection, lid: Lease, terminate: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_randr_free_lease_checked(connection.get_connection(), lid, terminate) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn free_lease_unchecked<'a>(connection: &'a crate::connection::Connection, lid: Lease, terminate: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_randr_free_lease(connection.get_connection(), lid, terminate) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LeaseNotify {
+    pub timestamp: super::xproto::Timestamp,
+    pub window: super::xproto::Window,
+    pub lease: Lease,
+    pub created: u8,
+}
+
+impl From<xcb_system::xcb_randr_lease_notify_t> for LeaseNotify {
+    fn from(raw: xcb_system::xcb_randr_lease_notify_t) -> Self {
+        Self {
+            timestamp: raw.timestamp,
+            window: raw.window,
+            lease: raw.lease,
+            created: raw.created,
+        }
+    }
+}
+
+impl From<LeaseNotify> for xcb_system::xcb_randr_lease_notify_t {
+    fn from(value: LeaseNotify) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.timestamp = value.timestamp;
+        raw.window = value.window;
+        raw.lease = value.lease;
+        raw.created = value.created;
+
+        raw
+    }
+}
+
+pub type NotifyData = [u8; 28];
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NotifyEvent {
+    pub subCode: u8,
+    pub u: NotifyData,
+}
+
+impl From<xcb_system::xcb_randr_notify_event_t> for NotifyEvent {
+    fn from(raw: xcb_system::xcb_randr_notify_event_t) -> Self {
+        Self {
+            subCode: raw.subCode,
+            u: unsafe { std::mem::transmute::<xcb_system::xcb_randr_notify_data_t, NotifyData>(raw.u) },
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    ScreenChangeNotify(ScreenChangeNotifyEvent),
+    Notify(NotifyEvent),
+}
+
+pub fn decode_event(event: &xcb_system::xcb_generic_event_t, first_event: u8) -> std::option::Option<Event> {
+    let raw = event as *const xcb_system::xcb_generic_event_t;
+
+    match (event.response_type & 0x7f).wrapping_sub(first_event) {
+        0 => Some(Event::ScreenChangeNotify(
+            unsafe { *(raw as *const xcb_system::xcb_randr_screen_change_notify_event_t) }.into(),
+        )),
+        1 => Some(Event::Notify(
+            unsafe { *(raw as *const xcb_system::xcb_randr_notify_event_t) }.into(),
+        )),
+        _ => None,
+    }
+}
+
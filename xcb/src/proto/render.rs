@@ -0,0 +1,1365 @@
+// Generated by xcb-codegen from render.xml, don't edit.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PictType(pub u32);
+
+impl PictType {
+    pub const INDEXED: Self = Self(0);
+    pub const DIRECT: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PictureEnum(pub u32);
+
+impl PictureEnum {
+    pub const NONE: Self = Self(0);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PictOp(pub u32);
+
+impl PictOp {
+    pub const CLEAR: Self = Self(0);
+    pub const SRC: Self = Self(1);
+    pub const DST: Self = Self(2);
+    pub const OVER: Self = Self(3);
+    pub const OVER_REVERSE: Self = Self(4);
+    pub const IN: Self = Self(5);
+    pub const IN_REVERSE: Self = Self(6);
+    pub const OUT: Self = Self(7);
+    pub const OUT_REVERSE: Self = Self(8);
+    pub const ATOP: Self = Self(9);
+    pub const ATOP_REVERSE: Self = Self(10);
+    pub const XOR: Self = Self(11);
+    pub const ADD: Self = Self(12);
+    pub const SATURATE: Self = Self(13);
+    pub const DISJOINT_CLEAR: Self = Self(16);
+    pub const DISJOINT_SRC: Self = Self(17);
+    pub const DISJOINT_DST: Self = Self(18);
+    pub const DISJOINT_OVER: Self = Self(19);
+    pub const DISJOINT_OVER_REVERSE: Self = Self(20);
+    pub const DISJOINT_IN: Self = Self(21);
+    pub const DISJOINT_IN_REVERSE: Self = Self(22);
+    pub const DISJOINT_OUT: Self = Self(23);
+    pub const DISJOINT_OUT_REVERSE: Self = Self(24);
+    pub const DISJOINT_ATOP: Self = Self(25);
+    pub const DISJOINT_ATOP_REVERSE: Self = Self(26);
+    pub const DISJOINT_XOR: Self = Self(27);
+    pub const CONJOINT_CLEAR: Self = Self(32);
+    pub const CONJOINT_SRC: Self = Self(33);
+    pub const CONJOINT_DST: Self = Self(34);
+    pub const CONJOINT_OVER: Self = Self(35);
+    pub const CONJOINT_OVER_REVERSE: Self = Self(36);
+    pub const CONJOINT_IN: Self = Self(37);
+    pub const CONJOINT_IN_REVERSE: Self = Self(38);
+    pub const CONJOINT_OUT: Self = Self(39);
+    pub const CONJOINT_OUT_REVERSE: Self = Self(40);
+    pub const CONJOINT_ATOP: Self = Self(41);
+    pub const CONJOINT_ATOP_REVERSE: Self = Self(42);
+    pub const CONJOINT_XOR: Self = Self(43);
+    pub const MULTIPLY: Self = Self(48);
+    pub const SCREEN: Self = Self(49);
+    pub const OVERLAY: Self = Self(50);
+    pub const DARKEN: Self = Self(51);
+    pub const LIGHTEN: Self = Self(52);
+    pub const COLOR_DODGE: Self = Self(53);
+    pub const COLOR_BURN: Self = Self(54);
+    pub const HARD_LIGHT: Self = Self(55);
+    pub const SOFT_LIGHT: Self = Self(56);
+    pub const DIFFERENCE: Self = Self(57);
+    pub const EXCLUSION: Self = Self(58);
+    pub const HSL_HUE: Self = Self(59);
+    pub const HSL_SATURATION: Self = Self(60);
+    pub const HSL_COLOR: Self = Self(61);
+    pub const HSL_LUMINOSITY: Self = Self(62);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PolyEdge(pub u32);
+
+impl PolyEdge {
+    pub const SHARP: Self = Self(0);
+    pub const SMOOTH: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PolyMode(pub u32);
+
+impl PolyMode {
+    pub const PRECISE: Self = Self(0);
+    pub const IMPRECISE: Self = Self(1);
+}
+
+bitflags! {
+    pub struct Cp: u32 {
+        const REPEAT = 0x1;
+        const ALPHA_MAP = 0x2;
+        const ALPHA_X_ORIGIN = 0x4;
+        const ALPHA_Y_ORIGIN = 0x8;
+        const CLIP_X_ORIGIN = 0x10;
+        const CLIP_Y_ORIGIN = 0x20;
+        const CLIP_MASK = 0x40;
+        const GRAPHICS_EXPOSURE = 0x80;
+        const SUBWINDOW_MODE = 0x100;
+        const POLY_EDGE = 0x200;
+        const POLY_MODE = 0x400;
+        const DITHER = 0x800;
+        const COMPONENT_ALPHA = 0x1000;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SubPixel(pub u32);
+
+impl SubPixel {
+    pub const UNKNOWN: Self = Self(0);
+    pub const HORIZONTAL_RGB: Self = Self(1);
+    pub const HORIZONTAL_BGR: Self = Self(2);
+    pub const VERTICAL_RGB: Self = Self(3);
+    pub const VERTICAL_BGR: Self = Self(4);
+    pub const NONE: Self = Self(5);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Repeat(pub u32);
+
+impl Repeat {
+    pub const NONE: Self = Self(0);
+    pub const NORMAL: Self = Self(1);
+    pub const PAD: Self = Self(2);
+    pub const REFLECT: Self = Self(3);
+}
+
+pub type Glyph = u32;
+
+pub type Glyphset = u32;
+
+pub type Picture = u32;
+
+pub type Pictformat = u32;
+
+pub type Fixed = i32;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Directformat {
+    pub red_shift: u16,
+    pub red_mask: u16,
+    pub green_shift: u16,
+    pub green_mask: u16,
+    pub blue_shift: u16,
+    pub blue_mask: u16,
+    pub alpha_shift: u16,
+    pub alpha_mask: u16,
+}
+
+impl From<xcb_system::xcb_render_directformat_t> for Directformat {
+    fn from(raw: xcb_system::xcb_render_directformat_t) -> Self {
+        Self {
+            red_shift: raw.red_shift,
+            red_mask: raw.red_mask,
+            green_shift: raw.green_shift,
+            green_mask: raw.green_mask,
+            blue_shift: raw.blue_shift,
+            blue_mask: raw.blue_mask,
+            alpha_shift: raw.alpha_shift,
+            alpha_mask: raw.alpha_mask,
+        }
+    }
+}
+
+impl From<Directformat> for xcb_system::xcb_render_directformat_t {
+    fn from(value: Directformat) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.red_shift = value.red_shift;
+        raw.red_mask = value.red_mask;
+        raw.green_shift = value.green_shift;
+        raw.green_mask = value.green_mask;
+        raw.blue_shift = value.blue_shift;
+        raw.blue_mask = value.blue_mask;
+        raw.alpha_shift = value.alpha_shift;
+        raw.alpha_mask = value.alpha_mask;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Pictforminfo {
+    pub id: Pictformat,
+    pub type_: u8,
+    pub depth: u8,
+    pub direct: Directformat,
+    pub colormap: super::xproto::Colormap,
+}
+
+impl From<xcb_system::xcb_render_pictforminfo_t> for Pictforminfo {
+    fn from(raw: xcb_system::xcb_render_pictforminfo_t) -> Self {
+        Self {
+            id: raw.id,
+            type_: raw.type_,
+            depth: raw.depth,
+            direct: Directformat::from(raw.direct),
+            colormap: raw.colormap,
+        }
+    }
+}
+
+impl From<Pictforminfo> for xcb_system::xcb_render_pictforminfo_t {
+    fn from(value: Pictforminfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.id = value.id;
+        raw.type_ = value.type_;
+        raw.depth = value.depth;
+        raw.direct = xcb_system::xcb_render_directformat_t::from(value.direct);
+        raw.colormap = value.colormap;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Pictvisual {
+    pub visual: super::xproto::Visualid,
+    pub format: Pictformat,
+}
+
+impl From<xcb_system::xcb_render_pictvisual_t> for Pictvisual {
+    fn from(raw: xcb_system::xcb_render_pictvisual_t) -> Self {
+        Self {
+            visual: raw.visual,
+            format: raw.format,
+        }
+    }
+}
+
+impl From<Pictvisual> for xcb_system::xcb_render_pictvisual_t {
+    fn from(value: Pictvisual) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.visual = value.visual;
+        raw.format = value.format;
+
+        raw
+    }
+}
+
+// PICTDEPTH isn't generated, visuals has a variable length.
+
+// PICTSCREEN isn't generated, depths has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Indexvalue {
+    pub pixel: u32,
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub alpha: u16,
+}
+
+impl From<xcb_system::xcb_render_indexvalue_t> for Indexvalue {
+    fn from(raw: xcb_system::xcb_render_indexvalue_t) -> Self {
+        Self {
+            pixel: raw.pixel,
+            red: raw.red,
+            green: raw.green,
+            blue: raw.blue,
+            alpha: raw.alpha,
+        }
+    }
+}
+
+impl From<Indexvalue> for xcb_system::xcb_render_indexvalue_t {
+    fn from(value: Indexvalue) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.pixel = value.pixel;
+        raw.red = value.red;
+        raw.green = value.green;
+        raw.blue = value.blue;
+        raw.alpha = value.alpha;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Color {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub alpha: u16,
+}
+
+impl From<xcb_system::xcb_render_color_t> for Color {
+    fn from(raw: xcb_system::xcb_render_color_t) -> Self {
+        Self {
+            red: raw.red,
+            green: raw.green,
+            blue: raw.blue,
+            alpha: raw.alpha,
+        }
+    }
+}
+
+impl From<Color> for xcb_system::xcb_render_color_t {
+    fn from(value: Color) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.red = value.red;
+        raw.green = value.green;
+        raw.blue = value.blue;
+        raw.alpha = value.alpha;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Pointfix {
+    pub x: Fixed,
+    pub y: Fixed,
+}
+
+impl From<xcb_system::xcb_render_pointfix_t> for Pointfix {
+    fn from(raw: xcb_system::xcb_render_pointfix_t) -> Self {
+        Self {
+            x: raw.x,
+            y: raw.y,
+        }
+    }
+}
+
+impl From<Pointfix> for xcb_system::xcb_render_pointfix_t {
+    fn from(value: Pointfix) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.x = value.x;
+        raw.y = value.y;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Linefix {
+    pub p1: Pointfix,
+    pub p2: Pointfix,
+}
+
+impl From<xcb_system::xcb_render_linefix_t> for Linefix {
+    fn from(raw: xcb_system::xcb_render_linefix_t) -> Self {
+        Self {
+            p1: Pointfix::from(raw.p1),
+            p2: Pointfix::from(raw.p2),
+        }
+    }
+}
+
+impl From<Linefix> for xcb_system::xcb_render_linefix_t {
+    fn from(value: Linefix) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.p1 = xcb_system::xcb_render_pointfix_t::from(value.p1);
+        raw.p2 = xcb_system::xcb_render_pointfix_t::from(value.p2);
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Triangle {
+    pub p1: Pointfix,
+    pub p2: Pointfix,
+    pub p3: Pointfix,
+}
+
+impl From<xcb_system::xcb_render_triangle_t> for Triangle {
+    fn from(raw: xcb_system::xcb_render_triangle_t) -> Self {
+        Self {
+            p1: Pointfix::from(raw.p1),
+            p2: Pointfix::from(raw.p2),
+            p3: Pointfix::from(raw.p3),
+        }
+    }
+}
+
+impl From<Triangle> for xcb_system::xcb_render_triangle_t {
+    fn from(value: Triangle) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.p1 = xcb_system::xcb_render_pointfix_t::from(value.p1);
+        raw.p2 = xcb_system::xcb_render_pointfix_t::from(value.p2);
+        raw.p3 = xcb_system::xcb_render_pointfix_t::from(value.p3);
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Trapezoid {
+    pub top: Fixed,
+    pub bottom: Fixed,
+    pub left: Linefix,
+    pub right: Linefix,
+}
+
+impl From<xcb_system::xcb_render_trapezoid_t> for Trapezoid {
+    fn from(raw: xcb_system::xcb_render_trapezoid_t) -> Self {
+        Self {
+            top: raw.top,
+            bottom: raw.bottom,
+            left: Linefix::from(raw.left),
+            right: Linefix::from(raw.right),
+        }
+    }
+}
+
+impl From<Trapezoid> for xcb_system::xcb_render_trapezoid_t {
+    fn from(value: Trapezoid) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.top = value.top;
+        raw.bottom = value.bottom;
+        raw.left = xcb_system::xcb_render_linefix_t::from(value.left);
+        raw.right = xcb_system::xcb_render_linefix_t::from(value.right);
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Glyphinfo {
+    pub width: u16,
+    pub height: u16,
+    pub x: i16,
+    pub y: i16,
+    pub x_off: i16,
+    pub y_off: i16,
+}
+
+impl From<xcb_system::xcb_render_glyphinfo_t> for Glyphinfo {
+    fn from(raw: xcb_system::xcb_render_glyphinfo_t) -> Self {
+        Self {
+            width: raw.width,
+            height: raw.height,
+            x: raw.x,
+            y: raw.y,
+            x_off: raw.x_off,
+            y_off: raw.y_off,
+        }
+    }
+}
+
+impl From<Glyphinfo> for xcb_system::xcb_render_glyphinfo_t {
+    fn from(value: Glyphinfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.width = value.width;
+        raw.height = value.height;
+        raw.x = value.x;
+        raw.y = value.y;
+        raw.x_off = value.x_off;
+        raw.y_off = value.y_off;
+
+        raw
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryVersionReply {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+pub struct QueryVersionRequest;
+
+impl crate::result::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_render_query_version_reply_t>(connection, sequence)?;
+
+        Ok(QueryVersionReply {
+            major_version: reply.major_version,
+            minor_version: reply.minor_version,
+        })
+    }
+}
+
+pub fn query_version<'a>(connection: &'a crate::connection::Connection, client_major_version: u32, client_minor_version: u32) -> crate::result::Cookie<'a, QueryVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_render_query_version(connection.get_connection(), client_major_version, client_minor_version) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryVersionRequest, connection)
+}
+
+// QueryPictFormats isn't generated, PICTSCREEN has a variable size.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryPictIndexValuesReply {
+    pub num_values: u32,
+    pub values: Vec<Indexvalue>,
+}
+
+pub struct QueryPictIndexValuesRequest;
+
+impl crate::result::Request for QueryPictIndexValuesRequest {
+    type Reply = QueryPictIndexValuesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryPictIndexValuesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_render_query_pict_index_values_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { QueryPictIndexValuesReply {
+            num_values: reply.num_values,
+            values: std::slice::from_raw_parts(xcb_system::xcb_render_query_pict_index_values_values(reply.as_ptr()) as *const xcb_system::xcb_render_indexvalue_t, xcb_system::xcb_render_query_pict_index_values_values_length(reply.as_ptr()) as usize).iter().map(|&value| Indexvalue::from(value)).collect(),
+        } })
+    }
+}
+
+pub fn query_pict_index_values<'a>(connection: &'a crate::connection::Connection, format: Pictformat) -> crate::result::Cookie<'a, QueryPictIndexValuesRequest> {
+    let cookie = unsafe { xcb_system::xcb_render_query_pict_index_values(connection.get_connection(), format) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryPictIndexValuesRequest, connection)
+}
+
+/// The values of `create_picture`, every one that is set adds its bit to `value_mask`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct CreatePictureValueList {
+    pub repeat: Option<u32>,
+    pub alphamap: Option<Picture>,
+    pub alphaxorigin: Option<i32>,
+    pub alphayorigin: Option<i32>,
+    pub clipxorigin: Option<i32>,
+    pub clipyorigin: Option<i32>,
+    pub clipmask: Option<super::xproto::Pixmap>,
+    pub graphicsexposure: Option<u32>,
+    pub subwindowmode: Option<u32>,
+    pub polyedge: Option<u32>,
+    pub polymode: Option<u32>,
+    pub dither: Option<super::xproto::Atom>,
+    pub componentalpha: Option<u32>,
+}
+
+impl CreatePictureValueList {
+    pub fn repeat(mut self, repeat: u32) -> Self {
+        self.repeat = Some(repeat);
+        self
+    }
+
+    pub fn alphamap(mut self, alphamap: Picture) -> Self {
+        self.alphamap = Some(alphamap);
+        self
+    }
+
+    pub fn alphaxorigin(mut self, alphaxorigin: i32) -> Self {
+        self.alphaxorigin = Some(alphaxorigin);
+        self
+    }
+
+    pub fn alphayorigin(mut self, alphayorigin: i32) -> Self {
+        self.alphayorigin = Some(alphayorigin);
+        self
+    }
+
+    pub fn clipxorigin(mut self, clipxorigin: i32) -> Self {
+        self.clipxorigin = Some(clipxorigin);
+        self
+    }
+
+    pub fn clipyorigin(mut self, clipyorigin: i32) -> Self {
+        self.clipyorigin = Some(clipyorigin);
+        self
+    }
+
+    pub fn clipmask(mut self, clipmask: super::xproto::Pixmap) -> Self {
+        self.clipmask = Some(clipmask);
+        self
+    }
+
+    pub fn graphicsexposure(mut self, graphicsexposure: u32) -> Self {
+        self.graphicsexposure = Some(graphicsexposure);
+        self
+    }
+
+    pub fn subwindowmode(mut self, subwindowmode: u32) -> Self {
+        self.subwindowmode = Some(subwindowmode);
+        self
+    }
+
+    pub fn polyedge(mut self, polyedge: u32) -> Self {
+        self.polyedge = Some(polyedge);
+        self
+    }
+
+    pub fn polymode(mut self, polymode: u32) -> Self {
+        self.polymode = Some(polymode);
+        self
+    }
+
+    pub fn dither(mut self, dither: super::xproto::Atom) -> Self {
+        self.dither = Some(dither);
+        self
+    }
+
+    pub fn componentalpha(mut self, componentalpha: u32) -> Self {
+        self.componentalpha = Some(componentalpha);
+        self
+    }
+
+    pub(crate) fn serialize(&self) -> (u32, Vec<u32>) {
+        let mut mask = 0;
+        let mut values = vec![];
+
+        if let Some(value) = self.repeat {
+            mask |= 0x1;
+            values.push(value);
+        }
+
+        if let Some(value) = self.alphamap {
+            mask |= 0x2;
+            values.push(value);
+        }
+
+        if let Some(value) = self.alphaxorigin {
+            mask |= 0x4;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.alphayorigin {
+            mask |= 0x8;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.clipxorigin {
+            mask |= 0x10;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.clipyorigin {
+            mask |= 0x20;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.clipmask {
+            mask |= 0x40;
+            values.push(value);
+        }
+
+        if let Some(value) = self.graphicsexposure {
+            mask |= 0x80;
+            values.push(value);
+        }
+
+        if let Some(value) = self.subwindowmode {
+            mask |= 0x100;
+            values.push(value);
+        }
+
+        if let Some(value) = self.polyedge {
+            mask |= 0x200;
+            values.push(value);
+        }
+
+        if let Some(value) = self.polymode {
+            mask |= 0x400;
+            values.push(value);
+        }
+
+        if let Some(value) = self.dither {
+            mask |= 0x800;
+            values.push(value);
+        }
+
+        if let Some(value) = self.componentalpha {
+            mask |= 0x1000;
+            values.push(value);
+        }
+
+        (mask, values)
+    }
+}
+
+pub fn create_picture<'a>(connection: &'a crate::connection::Connection, pid: Picture, drawable: super::xproto::Drawable, format: Pictformat, value_list: &CreatePictureValueList) -> crate::result::Cookie<'a, crate::result::Void> {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_render_create_picture_checked(connection.get_connection(), pid, drawable, format, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_picture_unchecked<'a>(connection: &'a crate::connection::Connection, pid: Picture, drawable: super::xproto::Drawable, format: Pictformat, value_list: &CreatePictureValueList) -> crate::result::UncheckedCookie {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_render_create_picture(connection.get_connection(), pid, drawable, format, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+/// The values of `change_picture`, every one that is set adds its bit to `value_mask`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ChangePictureValueList {
+    pub repeat: Option<u32>,
+    pub alphamap: Option<Picture>,
+    pub alphaxorigin: Option<i32>,
+    pub alphayorigin: Option<i32>,
+    pub clipxorigin: Option<i32>,
+    pub clipyorigin: Option<i32>,
+    pub clipmask: Option<super::xproto::Pixmap>,
+    pub graphicsexposure: Option<u32>,
+    pub subwindowmode: Option<u32>,
+    pub polyedge: Option<u32>,
+    pub polymode: Option<u32>,
+    pub dither: Option<super::xproto::Atom>,
+    pub componentalpha: Option<u32>,
+}
+
+impl ChangePictureValueList {
+    pub fn repeat(mut self, repeat: u32) -> Self {
+        self.repeat = Some(repeat);
+        self
+    }
+
+    pub fn alphamap(mut self, alphamap: Picture) -> Self {
+        self.alphamap = Some(alphamap);
+        self
+    }
+
+    pub fn alphaxorigin(mut self, alphaxorigin: i32) -> Self {
+        self.alphaxorigin = Some(alphaxorigin);
+        self
+    }
+
+    pub fn alphayorigin(mut self, alphayorigin: i32) -> Self {
+        self.alphayorigin = Some(alphayorigin);
+        self
+    }
+
+    pub fn clipxorigin(mut self, clipxorigin: i32) -> Self {
+        self.clipxorigin = Some(clipxorigin);
+        self
+    }
+
+    pub fn clipyorigin(mut self, clipyorigin: i32) -> Self {
+        self.clipyorigin = Some(clipyorigin);
+        self
+    }
+
+    pub fn clipmask(mut self, clipmask: super::xproto::Pixmap) -> Self {
+        self.clipmask = Some(clipmask);
+        self
+    }
+
+    pub fn graphicsexposure(mut self, graphicsexposure: u32) -> Self {
+        self.graphicsexposure = Some(graphicsexposure);
+        self
+    }
+
+    pub fn subwindowmode(mut self, subwindowmode: u32) -> Self {
+        self.subwindowmode = Some(subwindowmode);
+        self
+    }
+
+    pub fn polyedge(mut self, polyedge: u32) -> Self {
+        self.polyedge = Some(polyedge);
+        self
+    }
+
+    pub fn polymode(mut self, polymode: u32) -> Self {
+        self.polymode = Some(polymode);
+        self
+    }
+
+    pub fn dither(mut self, dither: super::xproto::Atom) -> Self {
+        self.dither = Some(dither);
+        self
+    }
+
+    pub fn componentalpha(mut self, componentalpha: u32) -> Self {
+        self.componentalpha = Some(componentalpha);
+        self
+    }
+
+    pub(crate) fn serialize(&self) -> (u32, Vec<u32>) {
+        let mut mask = 0;
+        let mut values = vec![];
+
+        if let Some(value) = self.repeat {
+            mask |= 0x1;
+            values.push(value);
+        }
+
+        if let Some(value) = self.alphamap {
+            mask |= 0x2;
+            values.push(value);
+        }
+
+        if let Some(value) = self.alphaxorigin {
+            mask |= 0x4;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.alphayorigin {
+            mask |= 0x8;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.clipxorigin {
+            mask |= 0x10;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.clipyorigin {
+            mask |= 0x20;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.clipmask {
+            mask |= 0x40;
+            values.push(value);
+        }
+
+        if let Some(value) = self.graphicsexposure {
+            mask |= 0x80;
+            values.push(value);
+        }
+
+        if let Some(value) = self.subwindowmode {
+            mask |= 0x100;
+            values.push(value);
+        }
+
+        if let Some(value) = self.polyedge {
+            mask |= 0x200;
+            values.push(value);
+        }
+
+        if let Some(value) = self.polymode {
+            mask |= 0x400;
+            values.push(value);
+        }
+
+        if let Some(value) = self.dither {
+            mask |= 0x800;
+            values.push(value);
+        }
+
+        if let Some(value) = self.componentalpha {
+            mask |= 0x1000;
+            values.push(value);
+        }
+
+        (mask, values)
+    }
+}
+
+pub fn change_picture<'a>(connection: &'a crate::connection::Connection, picture: Picture, value_list: &ChangePictureValueList) -> crate::result::Cookie<'a, crate::result::Void> {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_render_change_picture_checked(connection.get_connection(), picture, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_picture_unchecked<'a>(connection: &'a crate::connection::Connection, picture: Picture, value_list: &ChangePictureValueList) -> crate::result::UncheckedCookie {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_render_change_picture(connection.get_connection(), picture, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn set_picture_clip_rectangles<'a>(connection: &'a crate::connection::Connection, picture: Picture, clip_x_origin: i16, clip_y_origin: i16, rectangles: &[super::xproto::Rectangle]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_set_picture_clip_rectangles_checked(connection.get_connection(), picture, clip_x_origin, clip_y_origin, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_picture_clip_rectangles_unchecked<'a>(connection: &'a crate::connection::Connection, picture: Picture, clip_x_origin: i16, clip_y_origin: i16, rectangles: &[super::xproto::Rectangle]) -> crate::result::UncheckedCookie {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_set_picture_clip_rectangles(connection.get_connection(), picture, clip_x_origin, clip_y_origin, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn free_picture<'a>(connection: &'a crate::connection::Connection, picture: Picture) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_free_picture_checked(connection.get_connection(), picture) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn free_picture_unchecked<'a>(connection: &'a crate::connection::Connection, picture: Picture) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_free_picture(connection.get_connection(), picture) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn composite<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, mask: Picture, dst: Picture, src_x: i16, src_y: i16, mask_x: i16, mask_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_composite_checked(connection.get_connection(), op, src, mask, dst, src_x, src_y, mask_x, mask_y, dst_x, dst_y, width, height) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn composite_unchecked<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, mask: Picture, dst: Picture, src_x: i16, src_y: i16, mask_x: i16, mask_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_composite(connection.get_connection(), op, src, mask, dst, src_x, src_y, mask_x, mask_y, dst_x, dst_y, width, height) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn trapezoids<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, traps: &[Trapezoid]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let traps: Vec<xcb_system::xcb_render_trapezoid_t> = traps.iter().map(|&value| xcb_system::xcb_render_trapezoid_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_trapezoids_checked(connection.get_connection(), op, src, dst, mask_format, src_x, src_y, traps.len() as u32, traps.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn trapezoids_unchecked<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, traps: &[Trapezoid]) -> crate::result::UncheckedCookie {
+    let traps: Vec<xcb_system::xcb_render_trapezoid_t> = traps.iter().map(|&value| xcb_system::xcb_render_trapezoid_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_trapezoids(connection.get_connection(), op, src, dst, mask_format, src_x, src_y, traps.len() as u32, traps.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn triangles<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, triangles: &[Triangle]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let triangles: Vec<xcb_system::xcb_render_triangle_t> = triangles.iter().map(|&value| xcb_system::xcb_render_triangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_triangles_checked(connection.get_connection(), op, src, dst, mask_format, src_x, src_y, triangles.len() as u32, triangles.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn triangles_unchecked<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, triangles: &[Triangle]) -> crate::result::UncheckedCookie {
+    let triangles: Vec<xcb_system::xcb_render_triangle_t> = triangles.iter().map(|&value| xcb_system::xcb_render_triangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_triangles(connection.get_connection(), op, src, dst, mask_format, src_x, src_y, triangles.len() as u32, triangles.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn tri_strip<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &[Pointfix]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let points: Vec<xcb_system::xcb_render_pointfix_t> = points.iter().map(|&value| xcb_system::xcb_render_pointfix_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_tri_strip_checked(connection.get_connection(), op, src, dst, mask_format, src_x, src_y, points.len() as u32, points.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn tri_strip_unchecked<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &[Pointfix]) -> crate::result::UncheckedCookie {
+    let points: Vec<xcb_system::xcb_render_pointfix_t> = points.iter().map(|&value| xcb_system::xcb_render_pointfix_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_tri_strip(connection.get_connection(), op, src, dst, mask_format, src_x, src_y, points.len() as u32, points.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn tri_fan<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &[Pointfix]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let points: Vec<xcb_system::xcb_render_pointfix_t> = points.iter().map(|&value| xcb_system::xcb_render_pointfix_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_tri_fan_checked(connection.get_connection(), op, src, dst, mask_format, src_x, src_y, points.len() as u32, points.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn tri_fan_unchecked<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, src_x: i16, src_y: i16, points: &[Pointfix]) -> crate::result::UncheckedCookie {
+    let points: Vec<xcb_system::xcb_render_pointfix_t> = points.iter().map(|&value| xcb_system::xcb_render_pointfix_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_tri_fan(connection.get_connection(), op, src, dst, mask_format, src_x, src_y, points.len() as u32, points.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn create_glyph_set<'a>(connection: &'a crate::connection::Connection, gsid: Glyphset, format: Pictformat) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_create_glyph_set_checked(connection.get_connection(), gsid, format) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_glyph_set_unchecked<'a>(connection: &'a crate::connection::Connection, gsid: Glyphset, format: Pictformat) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_create_glyph_set(connection.get_connection(), gsid, format) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn reference_glyph_set<'a>(connection: &'a crate::connection::Connection, gsid: Glyphset, existing: Glyphset) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_reference_glyph_set_checked(connection.get_connection(), gsid, existing) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn reference_glyph_set_unchecked<'a>(connection: &'a crate::connection::Connection, gsid: Glyphset, existing: Glyphset) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_reference_glyph_set(connection.get_connection(), gsid, existing) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn free_glyph_set<'a>(connection: &'a crate::connection::Connection, glyphset: Glyphset) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_free_glyph_set_checked(connection.get_connection(), glyphset) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn free_glyph_set_unchecked<'a>(connection: &'a crate::connection::Connection, glyphset: Glyphset) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_free_glyph_set(connection.get_connection(), glyphset) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn add_glyphs<'a>(connection: &'a crate::connection::Connection, glyphset: Glyphset, glyphids: &[u32], glyphs: &[Glyphinfo], data: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    assert_eq!(glyphs.len(), glyphids.len());
+    let glyphs: Vec<xcb_system::xcb_render_glyphinfo_t> = glyphs.iter().map(|&value| xcb_system::xcb_render_glyphinfo_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_add_glyphs_checked(connection.get_connection(), glyphset, glyphids.len() as _, glyphids.as_ptr() as *const _, glyphs.as_ptr() as *const _, data.len() as u32, data.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn add_glyphs_unchecked<'a>(connection: &'a crate::connection::Connection, glyphset: Glyphset, glyphids: &[u32], glyphs: &[Glyphinfo], data: &[u8]) -> crate::result::UncheckedCookie {
+    assert_eq!(glyphs.len(), glyphids.len());
+    let glyphs: Vec<xcb_system::xcb_render_glyphinfo_t> = glyphs.iter().map(|&value| xcb_system::xcb_render_glyphinfo_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_add_glyphs(connection.get_connection(), glyphset, glyphids.len() as _, glyphids.as_ptr() as *const _, glyphs.as_ptr() as *const _, data.len() as u32, data.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn free_glyphs<'a>(connection: &'a crate::connection::Connection, glyphset: Glyphset, glyphs: &[Glyph]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_free_glyphs_checked(connection.get_connection(), glyphset, glyphs.len() as u32, glyphs.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn free_glyphs_unchecked<'a>(connection: &'a crate::connection::Connection, glyphset: Glyphset, glyphs: &[Glyph]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_free_glyphs(connection.get_connection(), glyphset, glyphs.len() as u32, glyphs.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn composite_glyphs_8<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_composite_glyphs_8_checked(connection.get_connection(), op, src, dst, mask_format, glyphset, src_x, src_y, glyphcmds.len() as u32, glyphcmds.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn composite_glyphs_8_unchecked<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_composite_glyphs_8(connection.get_connection(), op, src, dst, mask_format, glyphset, src_x, src_y, glyphcmds.len() as u32, glyphcmds.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn composite_glyphs_16<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_composite_glyphs_16_checked(connection.get_connection(), op, src, dst, mask_format, glyphset, src_x, src_y, glyphcmds.len() as u32, glyphcmds.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn composite_glyphs_16_unchecked<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_composite_glyphs_16(connection.get_connection(), op, src, dst, mask_format, glyphset, src_x, src_y, glyphcmds.len() as u32, glyphcmds.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn composite_glyphs_32<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_composite_glyphs_32_checked(connection.get_connection(), op, src, dst, mask_format, glyphset, src_x, src_y, glyphcmds.len() as u32, glyphcmds.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn composite_glyphs_32_unchecked<'a>(connection: &'a crate::connection::Connection, op: u8, src: Picture, dst: Picture, mask_format: Pictformat, glyphset: Glyphset, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_composite_glyphs_32(connection.get_connection(), op, src, dst, mask_format, glyphset, src_x, src_y, glyphcmds.len() as u32, glyphcmds.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn fill_rectangles<'a>(connection: &'a crate::connection::Connection, op: u8, dst: Picture, color: Color, rects: &[super::xproto::Rectangle]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let rects: Vec<xcb_system::xcb_rectangle_t> = rects.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_fill_rectangles_checked(connection.get_connection(), op, dst, xcb_system::xcb_render_color_t::from(color), rects.len() as u32, rects.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn fill_rectangles_unchecked<'a>(connection: &'a crate::connection::Connection, op: u8, dst: Picture, color: Color, rects: &[super::xproto::Rectangle]) -> crate::result::UncheckedCookie {
+    let rects: Vec<xcb_system::xcb_rectangle_t> = rects.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_fill_rectangles(connection.get_connection(), op, dst, xcb_system::xcb_render_color_t::from(color), rects.len() as u32, rects.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn create_cursor<'a>(connection: &'a crate::connection::Connection, cid: super::xproto::Cursor, source: Picture, x: u16, y: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_create_cursor_checked(connection.get_connection(), cid, source, x, y) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_cursor_unchecked<'a>(connection: &'a crate::connection::Connection, cid: super::xproto::Cursor, source: Picture, x: u16, y: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_create_cursor(connection.get_connection(), cid, source, x, y) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transform {
+    pub matrix11: Fixed,
+    pub matrix12: Fixed,
+    pub matrix13: Fixed,
+    pub matrix21: Fixed,
+    pub matrix22: Fixed,
+    pub matrix23: Fixed,
+    pub matrix31: Fixed,
+    pub matrix32: Fixed,
+    pub matrix33: Fixed,
+}
+
+impl From<xcb_system::xcb_render_transform_t> for Transform {
+    fn from(raw: xcb_system::xcb_render_transform_t) -> Self {
+        Self {
+            matrix11: raw.matrix11,
+            matrix12: raw.matrix12,
+            matrix13: raw.matrix13,
+            matrix21: raw.matrix21,
+            matrix22: raw.matrix22,
+            matrix23: raw.matrix23,
+            matrix31: raw.matrix31,
+            matrix32: raw.matrix32,
+            matrix33: raw.matrix33,
+        }
+    }
+}
+
+impl From<Transform> for xcb_system::xcb_render_transform_t {
+    fn from(value: Transform) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.matrix11 = value.matrix11;
+        raw.matrix12 = value.matrix12;
+        raw.matrix13 = value.matrix13;
+        raw.matrix21 = value.matrix21;
+        raw.matrix22 = value.matrix22;
+        raw.matrix23 = value.matrix23;
+        raw.matrix31 = value.matrix31;
+        raw.matrix32 = value.matrix32;
+        raw.matrix33 = value.matrix33;
+
+        raw
+    }
+}
+
+pub fn set_picture_transform<'a>(connection: &'a crate::connection::Connection, picture: Picture, transform: Transform) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_set_picture_transform_checked(connection.get_connection(), picture, xcb_system::xcb_render_transform_t::from(transform)) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_picture_transform_unchecked<'a>(connection: &'a crate::connection::Connection, picture: Picture, transform: Transform) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_set_picture_transform(connection.get_connection(), picture, xcb_system::xcb_render_transform_t::from(transform)) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+// QueryFilters isn't generated, STR has a variable size.
+
+pub fn set_picture_filter<'a>(connection: &'a crate::connection::Connection, picture: Picture, filter: &[u8], values: &[Fixed]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_set_picture_filter_checked(connection.get_connection(), picture, filter.len() as _, filter.as_ptr() as *const _, values.len() as u32, values.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_picture_filter_unchecked<'a>(connection: &'a crate::connection::Connection, picture: Picture, filter: &[u8], values: &[Fixed]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_set_picture_filter(connection.get_connection(), picture, filter.len() as _, filter.as_ptr() as *const _, values.len() as u32, values.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Animcursorelt {
+    pub cursor: super::xproto::Cursor,
+    pub delay: u32,
+}
+
+impl From<xcb_system::xcb_render_animcursorelt_t> for Animcursorelt {
+    fn from(raw: xcb_system::xcb_render_animcursorelt_t) -> Self {
+        Self {
+            cursor: raw.cursor,
+            delay: raw.delay,
+        }
+    }
+}
+
+impl From<Animcursorelt> for xcb_system::xcb_render_animcursorelt_t {
+    fn from(value: Animcursorelt) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.cursor = value.cursor;
+        raw.delay = value.delay;
+
+        raw
+    }
+}
+
+pub fn create_anim_cursor<'a>(connection: &'a crate::connection::Connection, cid: super::xproto::Cursor, cursors: &[Animcursorelt]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cursors: Vec<xcb_system::xcb_render_animcursorelt_t> = cursors.iter().map(|&value| xcb_system::xcb_render_animcursorelt_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_create_anim_cursor_checked(connection.get_connection(), cid, cursors.len() as u32, cursors.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_anim_cursor_unchecked<'a>(connection: &'a crate::connection::Connection, cid: super::xproto::Cursor, cursors: &[Animcursorelt]) -> crate::result::UncheckedCookie {
+    let cursors: Vec<xcb_system::xcb_render_animcursorelt_t> = cursors.iter().map(|&value| xcb_system::xcb_render_animcursorelt_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_create_anim_cursor(connection.get_connection(), cid, cursors.len() as u32, cursors.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Spanfix {
+    pub l: Fixed,
+    pub r: Fixed,
+    pub y: Fixed,
+}
+
+impl From<xcb_system::xcb_render_spanfix_t> for Spanfix {
+    fn from(raw: xcb_system::xcb_render_spanfix_t) -> Self {
+        Self {
+            l: raw.l,
+            r: raw.r,
+            y: raw.y,
+        }
+    }
+}
+
+impl From<Spanfix> for xcb_system::xcb_render_spanfix_t {
+    fn from(value: Spanfix) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.l = value.l;
+        raw.r = value.r;
+        raw.y = value.y;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Trap {
+    pub top: Spanfix,
+    pub bot: Spanfix,
+}
+
+impl From<xcb_system::xcb_render_trap_t> for Trap {
+    fn from(raw: xcb_system::xcb_render_trap_t) -> Self {
+        Self {
+            top: Spanfix::from(raw.top),
+            bot: Spanfix::from(raw.bot),
+        }
+    }
+}
+
+impl From<Trap> for xcb_system::xcb_render_trap_t {
+    fn from(value: Trap) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.top = xcb_system::xcb_render_spanfix_t::from(value.top);
+        raw.bot = xcb_system::xcb_render_spanfix_t::from(value.bot);
+
+        raw
+    }
+}
+
+pub fn add_traps<'a>(connection: &'a crate::connection::Connection, picture: Picture, x_off: i16, y_off: i16, traps: &[Trap]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let traps: Vec<xcb_system::xcb_render_trap_t> = traps.iter().map(|&value| xcb_system::xcb_render_trap_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_add_traps_checked(connection.get_connection(), picture, x_off, y_off, traps.len() as u32, traps.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn add_traps_unchecked<'a>(connection: &'a crate::connection::Connection, picture: Picture, x_off: i16, y_off: i16, traps: &[Trap]) -> crate::result::UncheckedCookie {
+    let traps: Vec<xcb_system::xcb_render_trap_t> = traps.iter().map(|&value| xcb_system::xcb_render_trap_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_add_traps(connection.get_connection(), picture, x_off, y_off, traps.len() as u32, traps.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn create_solid_fill<'a>(connection: &'a crate::connection::Connection, picture: Picture, color: Color) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_render_create_solid_fill_checked(connection.get_connection(), picture, xcb_system::xcb_render_color_t::from(color)) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_solid_fill_unchecked<'a>(connection: &'a crate::connection::Connection, picture: Picture, color: Color) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_render_create_solid_fill(connection.get_connection(), picture, xcb_system::xcb_render_color_t::from(color)) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn create_linear_gradient<'a>(connection: &'a crate::connection::Connection, picture: Picture, p1: Pointfix, p2: Pointfix, stops: &[Fixed], colors: &[Color]) -> crate::result::Cookie<'a, crate::result::Void> {
+    assert_eq!(colors.len(), stops.len());
+    let colors: Vec<xcb_system::xcb_render_color_t> = colors.iter().map(|&value| xcb_system::xcb_render_color_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_create_linear_gradient_checked(connection.get_connection(), picture, xcb_system::xcb_render_pointfix_t::from(p1), xcb_system::xcb_render_pointfix_t::from(p2), stops.len() as _, stops.as_ptr() as *const _, colors.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_linear_gradient_unchecked<'a>(connection: &'a crate::connection::Connection, picture: Picture, p1: Pointfix, p2: Pointfix, stops: &[Fixed], colors: &[Color]) -> crate::result::UncheckedCookie {
+    assert_eq!(colors.len(), stops.len());
+    let colors: Vec<xcb_system::xcb_render_color_t> = colors.iter().map(|&value| xcb_system::xcb_render_color_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_create_linear_gradient(connection.get_connection(), picture, xcb_system::xcb_render_pointfix_t::from(p1), xcb_system::xcb_render_pointfix_t::from(p2), stops.len() as _, stops.as_ptr() as *const _, colors.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn create_radial_gradient<'a>(connection: &'a crate::connection::Connection, picture: Picture, inner: Pointfix, outer: Pointfix, inner_radius: Fixed, outer_radius: Fixed, stops: &[Fixed], colors: &[Color]) -> crate::result::Cookie<'a, crate::result::Void> {
+    assert_eq!(colors.len(), stops.len());
+    let colors: Vec<xcb_system::xcb_render_color_t> = colors.iter().map(|&value| xcb_system::xcb_render_color_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_create_radial_gradient_checked(connection.get_connection(), picture, xcb_system::xcb_render_pointfix_t::from(inner), xcb_system::xcb_render_pointfix_t::from(outer), inner_radius, outer_radius, stops.len() as _, stops.as_ptr() as *const _, colors.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_radial_gradient_unchecked<'a>(connection: &'a crate::connection::Connection, picture: Picture, inner: Pointfix, outer: Pointfix, inner_radius: Fixed, outer_radius: Fixed, stops: &[Fixed], colors: &[Color]) -> crate::result::UncheckedCookie {
+    assert_eq!(colors.len(), stops.len());
+    let colors: Vec<xcb_system::xcb_render_color_t> = colors.iter().map(|&value| xcb_system::xcb_render_color_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_create_radial_gradient(connection.get_connection(), picture, xcb_system::xcb_render_pointfix_t::from(inner), xcb_system::xcb_render_pointfix_t::from(outer), inner_radius, outer_radius, stops.len() as _, stops.as_ptr() as *const _, colors.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn create_conical_gradient<'a>(connection: &'a crate::connection::Connection, picture: Picture, center: Pointfix, angle: Fixed, stops: &[Fixed], colors: &[Color]) -> crate::result::Cookie<'a, crate::result::Void> {
+    assert_eq!(colors.len(), stops.len());
+    let colors: Vec<xcb_system::xcb_render_color_t> = colors.iter().map(|&value| xcb_system::xcb_render_color_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_create_conical_gradient_checked(connection.get_connection(), picture, xcb_system::xcb_render_pointfix_t::from(center), angle, stops.len() as _, stops.as_ptr() as *const _, colors.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_conical_gradient_unchecked<'a>(connection: &'a crate::connection::Connection, picture: Picture, center: Pointfix, angle: Fixed, stops: &[Fixed], colors: &[Color]) -> crate::result::UncheckedCookie {
+    assert_eq!(colors.len(), stops.len());
+    let colors: Vec<xcb_system::xcb_render_color_t> = colors.iter().map(|&value| xcb_system::xcb_render_color_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_render_create_conical_gradient(connection.get_connection(), picture, xcb_system::xcb_render_pointfix_t::from(center), angle, stops.len() as _, stops.as_ptr() as *const _, colors.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
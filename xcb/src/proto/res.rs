@@ -0,0 +1,277 @@
+// Generated by xcb-codegen from res.xml, don't edit.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Client {
+    pub resource_base: u32,
+    pub resource_mask: u32,
+}
+
+impl From<xcb_system::xcb_res_client_t> for Client {
+    fn from(raw: xcb_system::xcb_res_client_t) -> Self {
+        Self {
+            resource_base: raw.resource_base,
+            resource_mask: raw.resource_mask,
+        }
+    }
+}
+
+impl From<Client> for xcb_system::xcb_res_client_t {
+    fn from(value: Client) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.resource_base = value.resource_base;
+        raw.resource_mask = value.resource_mask;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Type {
+    pub resource_type: super::xproto::Atom,
+    pub count: u32,
+}
+
+impl From<xcb_system::xcb_res_type_t> for Type {
+    fn from(raw: xcb_system::xcb_res_type_t) -> Self {
+        Self {
+            resource_type: raw.resource_type,
+            count: raw.count,
+        }
+    }
+}
+
+impl From<Type> for xcb_system::xcb_res_type_t {
+    fn from(value: Type) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.resource_type = value.resource_type;
+        raw.count = value.count;
+
+        raw
+    }
+}
+
+bitflags! {
+    pub struct ClientIdMask: u32 {
+        const CLIENT_XID = 0x1;
+        const LOCAL_CLIENT_PID = 0x2;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClientIdSpec {
+    pub client: u32,
+    pub mask: u32,
+}
+
+impl From<xcb_system::xcb_res_client_id_spec_t> for ClientIdSpec {
+    fn from(raw: xcb_system::xcb_res_client_id_spec_t) -> Self {
+        Self {
+            client: raw.client,
+            mask: raw.mask,
+        }
+    }
+}
+
+impl From<ClientIdSpec> for xcb_system::xcb_res_client_id_spec_t {
+    fn from(value: ClientIdSpec) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.client = value.client;
+        raw.mask = value.mask;
+
+        raw
+    }
+}
+
+// ClientIdValue isn't generated, value has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ResourceIdSpec {
+    pub resource: u32,
+    pub type_: u32,
+}
+
+impl From<xcb_system::xcb_res_resource_id_spec_t> for ResourceIdSpec {
+    fn from(raw: xcb_system::xcb_res_resource_id_spec_t) -> Self {
+        Self {
+            resource: raw.resource,
+            type_: raw.type_,
+        }
+    }
+}
+
+impl From<ResourceIdSpec> for xcb_system::xcb_res_resource_id_spec_t {
+    fn from(value: ResourceIdSpec) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.resource = value.resource;
+        raw.type_ = value.type_;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ResourceSizeSpec {
+    pub spec: ResourceIdSpec,
+    pub bytes: u32,
+    pub ref_count: u32,
+    pub use_count: u32,
+}
+
+impl From<xcb_system::xcb_res_resource_size_spec_t> for ResourceSizeSpec {
+    fn from(raw: xcb_system::xcb_res_resource_size_spec_t) -> Self {
+        Self {
+            spec: ResourceIdSpec::from(raw.spec),
+            bytes: raw.bytes,
+            ref_count: raw.ref_count,
+            use_count: raw.use_count,
+        }
+    }
+}
+
+impl From<ResourceSizeSpec> for xcb_system::xcb_res_resource_size_spec_t {
+    fn from(value: ResourceSizeSpec) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.spec = xcb_system::xcb_res_resource_id_spec_t::from(value.spec);
+        raw.bytes = value.bytes;
+        raw.ref_count = value.ref_count;
+        raw.use_count = value.use_count;
+
+        raw
+    }
+}
+
+// ResourceSizeValue isn't generated, cross_references has a variable length.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryVersionReply {
+    pub server_major: u16,
+    pub server_minor: u16,
+}
+
+pub struct QueryVersionRequest;
+
+impl crate::result::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_res_query_version_reply_t>(connection, sequence)?;
+
+        Ok(QueryVersionReply {
+            server_major: reply.server_major,
+            server_minor: reply.server_minor,
+        })
+    }
+}
+
+pub fn query_version<'a>(connection: &'a crate::connection::Connection, client_major: u8, client_minor: u8) -> crate::result::Cookie<'a, QueryVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_res_query_version(connection.get_connection(), client_major, client_minor) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryVersionRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryClientsReply {
+    pub num_clients: u32,
+    pub clients: Vec<Client>,
+}
+
+pub struct QueryClientsRequest;
+
+impl crate::result::Request for QueryClientsRequest {
+    type Reply = QueryClientsReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryClientsReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_res_query_clients_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { QueryClientsReply {
+            num_clients: reply.num_clients,
+            clients: std::slice::from_raw_parts(xcb_system::xcb_res_query_clients_clients(reply.as_ptr()) as *const xcb_system::xcb_res_client_t, xcb_system::xcb_res_query_clients_clients_length(reply.as_ptr()) as usize).iter().map(|&value| Client::from(value)).collect(),
+        } })
+    }
+}
+
+pub fn query_clients<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, QueryClientsRequest> {
+    let cookie = unsafe { xcb_system::xcb_res_query_clients(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryClientsRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryClientResourcesReply {
+    pub num_types: u32,
+    pub types: Vec<Type>,
+}
+
+pub struct QueryClientResourcesRequest;
+
+impl crate::result::Request for QueryClientResourcesRequest {
+    type Reply = QueryClientResourcesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryClientResourcesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_res_query_client_resources_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { QueryClientResourcesReply {
+            num_types: reply.num_types,
+            types: std::slice::from_raw_parts(xcb_system::xcb_res_query_client_resources_types(reply.as_ptr()) as *const xcb_system::xcb_res_type_t, xcb_system::xcb_res_query_client_resources_types_length(reply.as_ptr()) as usize).iter().map(|&value| Type::from(value)).collect(),
+        } })
+    }
+}
+
+pub fn query_client_resources<'a>(connection: &'a crate::connection::Connection, xid: u32) -> crate::result::Cookie<'a, QueryClientResourcesRequest> {
+    let cookie = unsafe { xcb_system::xcb_res_query_client_resources(connection.get_connection(), xid) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryClientResourcesRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryClientPixmapBytesReply {
+    pub bytes: u32,
+    pub bytes_overflow: u32,
+}
+
+pub struct QueryClientPixmapBytesRequest;
+
+impl crate::result::Request for QueryClientPixmapBytesRequest {
+    type Reply = QueryClientPixmapBytesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryClientPixmapBytesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_res_query_client_pixmap_bytes_reply_t>(connection, sequence)?;
+
+        Ok(QueryClientPixmapBytesReply {
+            bytes: reply.bytes,
+            bytes_overflow: reply.bytes_overflow,
+        })
+    }
+}
+
+pub fn query_client_pixmap_bytes<'a>(connection: &'a crate::connection::Connection, xid: u32) -> crate::result::Cookie<'a, QueryClientPixmapBytesRequest> {
+    let cookie = unsafe { xcb_system::xcb_res_query_client_pixmap_bytes(connection.get_connection(), xid) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryClientPixmapBytesRequest, connection)
+}
+
+// QueryClientIds isn't generated, ClientIdValue has a variable size.
+
+// QueryResourceBytes isn't generated, ResourceSizeValue has a variable size.
+
@@ -0,0 +1,368 @@
+// Generated by xcb-codegen from screensaver.xml, don't edit.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Kind(pub u32);
+
+impl Kind {
+    pub const BLANKED: Self = Self(0);
+    pub const INTERNAL: Self = Self(1);
+    pub const EXTERNAL: Self = Self(2);
+}
+
+bitflags! {
+    pub struct EventEnum: u32 {
+        const NOTIFY_MASK = 0x1;
+        const CYCLE_MASK = 0x2;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct State(pub u32);
+
+impl State {
+    pub const OFF: Self = Self(0);
+    pub const ON: Self = Self(1);
+    pub const CYCLE: Self = Self(2);
+    pub const DISABLED: Self = Self(3);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryVersionReply {
+    pub server_major_version: u16,
+    pub server_minor_version: u16,
+}
+
+pub struct QueryVersionRequest;
+
+impl crate::result::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_screensaver_query_version_reply_t>(connection, sequence)?;
+
+        Ok(QueryVersionReply {
+            server_major_version: reply.server_major_version,
+            server_minor_version: reply.server_minor_version,
+        })
+    }
+}
+
+pub fn query_version<'a>(connection: &'a crate::connection::Connection, client_major_version: u8, client_minor_version: u8) -> crate::result::Cookie<'a, QueryVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_screensaver_query_version(connection.get_connection(), client_major_version, client_minor_version) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryVersionRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryInfoReply {
+    pub state: u8,
+    pub saver_window: super::xproto::Window,
+    pub ms_until_server: u32,
+    pub ms_since_user_input: u32,
+    pub event_mask: u32,
+    pub kind: u8,
+}
+
+pub struct QueryInfoRequest;
+
+impl crate::result::Request for QueryInfoRequest {
+    type Reply = QueryInfoReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryInfoReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_screensaver_query_info_reply_t>(connection, sequence)?;
+
+        Ok(QueryInfoReply {
+            state: reply.state,
+            saver_window: reply.saver_window,
+            ms_until_server: reply.ms_until_server,
+            ms_since_user_input: reply.ms_since_user_input,
+            event_mask: reply.event_mask,
+            kind: reply.kind,
+        })
+    }
+}
+
+pub fn query_info<'a>(connection: &'a crate::connection::Connection, drawable: super::xproto::Drawable) -> crate::result::Cookie<'a, QueryInfoRequest> {
+    let cookie = unsafe { xcb_system::xcb_screensaver_query_info(connection.get_connection(), drawable) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryInfoRequest, connection)
+}
+
+pub fn select_input<'a>(connection: &'a crate::connection::Connection, drawable: super::xproto::Drawable, event_mask: u32) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_screensaver_select_input_checked(connection.get_connection(), drawable, event_mask) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn select_input_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: super::xproto::Drawable, event_mask: u32) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_screensaver_select_input(connection.get_connection(), drawable, event_mask) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+/// The values of `set_attributes`, every one that is set adds its bit to `value_mask`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SetAttributesValueList {
+    pub background_pixmap: Option<super::xproto::Pixmap>,
+    pub background_pixel: Option<u32>,
+    pub border_pixmap: Option<super::xproto::Pixmap>,
+    pub border_pixel: Option<u32>,
+    pub bit_gravity: Option<u32>,
+    pub win_gravity: Option<u32>,
+    pub backing_store: Option<u32>,
+    pub backing_planes: Option<u32>,
+    pub backing_pixel: Option<u32>,
+    pub override_redirect: Option<super::xproto::Bool32>,
+    pub save_under: Option<super::xproto::Bool32>,
+    pub event_mask: Option<u32>,
+    pub do_not_propogate_mask: Option<u32>,
+    pub colormap: Option<super::xproto::Colormap>,
+    pub cursor: Option<super::xproto::Cursor>,
+}
+
+impl SetAttributesValueList {
+    pub fn background_pixmap(mut self, background_pixmap: super::xproto::Pixmap) -> Self {
+        self.background_pixmap = Some(background_pixmap);
+        self
+    }
+
+    pub fn background_pixel(mut self, background_pixel: u32) -> Self {
+        self.background_pixel = Some(background_pixel);
+        self
+    }
+
+    pub fn border_pixmap(mut self, border_pixmap: super::xproto::Pixmap) -> Self {
+        self.border_pixmap = Some(border_pixmap);
+        self
+    }
+
+    pub fn border_pixel(mut self, border_pixel: u32) -> Self {
+        self.border_pixel = Some(border_pixel);
+        self
+    }
+
+    pub fn bit_gravity(mut self, bit_gravity: u32) -> Self {
+        self.bit_gravity = Some(bit_gravity);
+        self
+    }
+
+    pub fn win_gravity(mut self, win_gravity: u32) -> Self {
+        self.win_gravity = Some(win_gravity);
+        self
+    }
+
+    pub fn backing_store(mut self, backing_store: u32) -> Self {
+        self.backing_store = Some(backing_store);
+        self
+    }
+
+    pub fn backing_planes(mut self, backing_planes: u32) -> Self {
+        self.backing_planes = Some(backing_planes);
+        self
+    }
+
+    pub fn backing_pixel(mut self, backing_pixel: u32) -> Self {
+        self.backing_pixel = Some(backing_pixel);
+        self
+    }
+
+    pub fn override_redirect(mut self, override_redirect: super::xproto::Bool32) -> Self {
+        self.override_redirect = Some(override_redirect);
+        self
+    }
+
+    pub fn save_under(mut self, save_under: super::xproto::Bool32) -> Self {
+        self.save_under = Some(save_under);
+        self
+    }
+
+    pub fn event_mask(mut self, event_mask: u32) -> Self {
+        self.event_mask = Some(event_mask);
+        self
+    }
+
+    pub fn do_not_propogate_mask(mut self, do_not_propogate_mask: u32) -> Self {
+        self.do_not_propogate_mask = Some(do_not_propogate_mask);
+        self
+    }
+
+    pub fn colormap(mut self, colormap: super::xproto::Colormap) -> Self {
+        self.colormap = Some(colormap);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: super::xproto::Cursor) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub(crate) fn serialize(&self) -> (u32, Vec<u32>) {
+        let mut mask = 0;
+        let mut values = vec![];
+
+        if let Some(value) = self.background_pixmap {
+            mask |= 0x1;
+            values.push(value);
+        }
+
+        if let Some(value) = self.background_pixel {
+            mask |= 0x2;
+            values.push(value);
+        }
+
+        if let Some(value) = self.border_pixmap {
+            mask |= 0x4;
+            values.push(value);
+        }
+
+        if let Some(value) = self.border_pixel {
+            mask |= 0x8;
+            values.push(value);
+        }
+
+        if let Some(value) = self.bit_gravity {
+            mask |= 0x10;
+            values.push(value);
+        }
+
+        if let Some(value) = self.win_gravity {
+            mask |= 0x20;
+            values.push(value);
+        }
+
+        if let Some(value) = self.backing_store {
+            mask |= 0x40;
+            values.push(value);
+        }
+
+        if let Some(value) = self.backing_planes {
+            mask |= 0x80;
+            values.push(value);
+        }
+
+        if let Some(value) = self.backing_pixel {
+            mask |= 0x100;
+            values.push(value);
+        }
+
+        if let Some(value) = self.override_redirect {
+            mask |= 0x200;
+            values.push(value);
+        }
+
+        if let Some(value) = self.save_under {
+            mask |= 0x400;
+            values.push(value);
+        }
+
+        if let Some(value) = self.event_mask {
+            mask |= 0x800;
+            values.push(value);
+        }
+
+        if let Some(value) = self.do_not_propogate_mask {
+            mask |= 0x1000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.colormap {
+            mask |= 0x2000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.cursor {
+            mask |= 0x4000;
+            values.push(value);
+        }
+
+        (mask, values)
+    }
+}
+
+pub fn set_attributes<'a>(connection: &'a crate::connection::Connection, drawable: super::xproto::Drawable, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: u8, depth: u8, visual: super::xproto::Visualid, value_list: &SetAttributesValueList) -> crate::result::Cookie<'a, crate::result::Void> {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_screensaver_set_attributes_checked(connection.get_connection(), drawable, x, y, width, height, border_width, class, depth, visual, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_attributes_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: super::xproto::Drawable, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: u8, depth: u8, visual: super::xproto::Visualid, value_list: &SetAttributesValueList) -> crate::result::UncheckedCookie {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_screensaver_set_attributes(connection.get_connection(), drawable, x, y, width, height, border_width, class, depth, visual, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn unset_attributes<'a>(connection: &'a crate::connection::Connection, drawable: super::xproto::Drawable) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_screensaver_unset_attributes_checked(connection.get_connection(), drawable) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn unset_attributes_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: super::xproto::Drawable) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_screensaver_unset_attributes(connection.get_connection(), drawable) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn suspend<'a>(connection: &'a crate::connection::Connection, suspend: u32) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_screensaver_suspend_checked(connection.get_connection(), suspend) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn suspend_unchecked<'a>(connection: &'a crate::connection::Connection, suspend: u32) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_screensaver_suspend(connection.get_connection(), suspend) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NotifyEvent {
+    pub state: u8,
+    pub time: super::xproto::Timestamp,
+    pub root: super::xproto::Window,
+    pub window: super::xproto::Window,
+    pub kind: u8,
+    pub forced: bool,
+}
+
+impl From<xcb_system::xcb_screensaver_notify_event_t> for NotifyEvent {
+    fn from(raw: xcb_system::xcb_screensaver_notify_event_t) -> Self {
+        Self {
+            state: raw.state,
+            time: raw.time,
+            root: raw.root,
+            window: raw.window,
+            kind: raw.kind,
+            forced: raw.forced != 0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    Notify(NotifyEvent),
+}
+
+pub fn decode_event(event: &xcb_system::xcb_generic_event_t, first_event: u8) -> std::option::Option<Event> {
+    let raw = event as *const xcb_system::xcb_generic_event_t;
+
+    match (event.response_type & 0x7f).wrapping_sub(first_event) {
+        0 => Some(Event::Notify(
+            unsafe { *(raw as *const xcb_system::xcb_screensaver_notify_event_t) }.into(),
+        )),
+        _ => None,
+    }
+}
+
@@ -0,0 +1,273 @@
+// Generated by xcb-codegen from shape.xml, don't edit.
+
+pub type Op = u8;
+
+pub type Kind = u8;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct So(pub u32);
+
+impl So {
+    pub const SET: Self = Self(0);
+    pub const UNION: Self = Self(1);
+    pub const INTERSECT: Self = Self(2);
+    pub const SUBTRACT: Self = Self(3);
+    pub const INVERT: Self = Self(4);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Sk(pub u32);
+
+impl Sk {
+    pub const BOUNDING: Self = Self(0);
+    pub const CLIP: Self = Self(1);
+    pub const INPUT: Self = Self(2);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NotifyEvent {
+    pub shape_kind: Kind,
+    pub affected_window: super::xproto::Window,
+    pub extents_x: i16,
+    pub extents_y: i16,
+    pub extents_width: u16,
+    pub extents_height: u16,
+    pub server_time: super::xproto::Timestamp,
+    pub shaped: bool,
+}
+
+impl From<xcb_system::xcb_shape_notify_event_t> for NotifyEvent {
+    fn from(raw: xcb_system::xcb_shape_notify_event_t) -> Self {
+        Self {
+            shape_kind: raw.shape_kind,
+            affected_window: raw.affected_window,
+            extents_x: raw.extents_x,
+            extents_y: raw.extents_y,
+            extents_width: raw.extents_width,
+            extents_height: raw.extents_height,
+            server_time: raw.server_time,
+            shaped: raw.shaped != 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryVersionReply {
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+
+pub struct QueryVersionRequest;
+
+impl crate::result::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_shape_query_version_reply_t>(connection, sequence)?;
+
+        Ok(QueryVersionReply {
+            major_version: reply.major_version,
+            minor_version: reply.minor_version,
+        })
+    }
+}
+
+pub fn query_version<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, QueryVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_shape_query_version(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryVersionRequest, connection)
+}
+
+pub fn rectangles<'a>(connection: &'a crate::connection::Connection, operation: Op, destination_kind: Kind, ordering: u8, destination_window: super::xproto::Window, x_offset: i16, y_offset: i16, rectangles: &[super::xproto::Rectangle]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_shape_rectangles_checked(connection.get_connection(), operation, destination_kind, ordering, destination_window, x_offset, y_offset, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn rectangles_unchecked<'a>(connection: &'a crate::connection::Connection, operation: Op, destination_kind: Kind, ordering: u8, destination_window: super::xproto::Window, x_offset: i16, y_offset: i16, rectangles: &[super::xproto::Rectangle]) -> crate::result::UncheckedCookie {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_shape_rectangles(connection.get_connection(), operation, destination_kind, ordering, destination_window, x_offset, y_offset, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn mask<'a>(connection: &'a crate::connection::Connection, operation: Op, destination_kind: Kind, destination_window: super::xproto::Window, x_offset: i16, y_offset: i16, source_bitmap: super::xproto::Pixmap) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_shape_mask_checked(connection.get_connection(), operation, destination_kind, destination_window, x_offset, y_offset, source_bitmap) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn mask_unchecked<'a>(connection: &'a crate::connection::Connection, operation: Op, destination_kind: Kind, destination_window: super::xproto::Window, x_offset: i16, y_offset: i16, source_bitmap: super::xproto::Pixmap) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_shape_mask(connection.get_connection(), operation, destination_kind, destination_window, x_offset, y_offset, source_bitmap) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn combine<'a>(connection: &'a crate::connection::Connection, operation: Op, destination_kind: Kind, source_kind: Kind, destination_window: super::xproto::Window, x_offset: i16, y_offset: i16, source_window: super::xproto::Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_shape_combine_checked(connection.get_connection(), operation, destination_kind, source_kind, destination_window, x_offset, y_offset, source_window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn combine_unchecked<'a>(connection: &'a crate::connection::Connection, operation: Op, destination_kind: Kind, source_kind: Kind, destination_window: super::xproto::Window, x_offset: i16, y_offset: i16, source_window: super::xproto::Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_shape_combine(connection.get_connection(), operation, destination_kind, source_kind, destination_window, x_offset, y_offset, source_window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn offset<'a>(connection: &'a crate::connection::Connection, destination_kind: Kind, destination_window: super::xproto::Window, x_offset: i16, y_offset: i16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_shape_offset_checked(connection.get_connection(), destination_kind, destination_window, x_offset, y_offset) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn offset_unchecked<'a>(connection: &'a crate::connection::Connection, destination_kind: Kind, destination_window: super::xproto::Window, x_offset: i16, y_offset: i16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_shape_offset(connection.get_connection(), destination_kind, destination_window, x_offset, y_offset) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryExtentsReply {
+    pub bounding_shaped: bool,
+    pub clip_shaped: bool,
+    pub bounding_shape_extents_x: i16,
+    pub bounding_shape_extents_y: i16,
+    pub bounding_shape_extents_width: u16,
+    pub bounding_shape_extents_height: u16,
+    pub clip_shape_extents_x: i16,
+    pub clip_shape_extents_y: i16,
+    pub clip_shape_extents_width: u16,
+    pub clip_shape_extents_height: u16,
+}
+
+pub struct QueryExtentsRequest;
+
+impl crate::result::Request for QueryExtentsRequest {
+    type Reply = QueryExtentsReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryExtentsReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_shape_query_extents_reply_t>(connection, sequence)?;
+
+        Ok(QueryExtentsReply {
+            bounding_shaped: reply.bounding_shaped != 0,
+            clip_shaped: reply.clip_shaped != 0,
+            bounding_shape_extents_x: reply.bounding_shape_extents_x,
+            bounding_shape_extents_y: reply.bounding_shape_extents_y,
+            bounding_shape_extents_width: reply.bounding_shape_extents_width,
+            bounding_shape_extents_height: reply.bounding_shape_extents_height,
+            clip_shape_extents_x: reply.clip_shape_extents_x,
+            clip_shape_extents_y: reply.clip_shape_extents_y,
+            clip_shape_extents_width: reply.clip_shape_extents_width,
+            clip_shape_extents_height: reply.clip_shape_extents_height,
+        })
+    }
+}
+
+pub fn query_extents<'a>(connection: &'a crate::connection::Connection, destination_window: super::xproto::Window) -> crate::result::Cookie<'a, QueryExtentsRequest> {
+    let cookie = unsafe { xcb_system::xcb_shape_query_extents(connection.get_connection(), destination_window) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryExtentsRequest, connection)
+}
+
+pub fn select_input<'a>(connection: &'a crate::connection::Connection, destination_window: super::xproto::Window, enable: bool) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_shape_select_input_checked(connection.get_connection(), destination_window, u8::from(enable)) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn select_input_unchecked<'a>(connection: &'a crate::connection::Connection, destination_window: super::xproto::Window, enable: bool) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_shape_select_input(connection.get_connection(), destination_window, u8::from(enable)) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputSelectedReply {
+    pub enabled: bool,
+}
+
+pub struct InputSelectedRequest;
+
+impl crate::result::Request for InputSelectedRequest {
+    type Reply = InputSelectedReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<InputSelectedReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_shape_input_selected_reply_t>(connection, sequence)?;
+
+        Ok(InputSelectedReply {
+            enabled: reply.enabled != 0,
+        })
+    }
+}
+
+pub fn input_selected<'a>(connection: &'a crate::connection::Connection, destination_window: super::xproto::Window) -> crate::result::Cookie<'a, InputSelectedRequest> {
+    let cookie = unsafe { xcb_system::xcb_shape_input_selected(connection.get_connection(), destination_window) };
+
+    crate::result::Cookie::new(cookie.sequence, InputSelectedRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetRectanglesReply {
+    pub ordering: u8,
+    pub rectangles_len: u32,
+    pub rectangles: Vec<super::xproto::Rectangle>,
+}
+
+pub struct GetRectanglesRequest;
+
+impl crate::result::Request for GetRectanglesRequest {
+    type Reply = GetRectanglesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetRectanglesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_shape_get_rectangles_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetRectanglesReply {
+            ordering: reply.ordering,
+            rectangles_len: reply.rectangles_len,
+            rectangles: std::slice::from_raw_parts(xcb_system::xcb_shape_get_rectangles_rectangles(reply.as_ptr()) as *const xcb_system::xcb_rectangle_t, xcb_system::xcb_shape_get_rectangles_rectangles_length(reply.as_ptr()) as usize).iter().map(|&value| super::xproto::Rectangle::from(value)).collect(),
+        } })
+    }
+}
+
+pub fn get_rectangles<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, source_kind: Kind) -> crate::result::Cookie<'a, GetRectanglesRequest> {
+    let cookie = unsafe { xcb_system::xcb_shape_get_rectangles(connection.get_connection(), window, source_kind) };
+
+    crate::result::Cookie::new(cookie.sequence, GetRectanglesRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    Notify(NotifyEvent),
+}
+
+pub fn decode_event(event: &xcb_system::xcb_generic_event_t, first_event: u8) -> std::option::Option<Event> {
+    let raw = event as *const xcb_system::xcb_generic_event_t;
+
+    match (event.response_type & 0x7f).wrapping_sub(first_event) {
+        0 => Some(Event::Notify(
+            unsafe { *(raw as *const xcb_system::xcb_shape_notify_event_t) }.into(),
+        )),
+        _ => None,
+    }
+}
+
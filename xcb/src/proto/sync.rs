@@ -0,0 +1,507 @@
+// Generated by xcb-codegen from sync.xml, don't edit.
+
+pub type Alarm = u32;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Alarmstate(pub u32);
+
+impl Alarmstate {
+    pub const ACTIVE: Self = Self(0);
+    pub const INACTIVE: Self = Self(1);
+    pub const DESTROYED: Self = Self(2);
+}
+
+pub type Counter = u32;
+
+pub type Fence = u32;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Testtype(pub u32);
+
+impl Testtype {
+    pub const POSITIVE_TRANSITION: Self = Self(0);
+    pub const NEGATIVE_TRANSITION: Self = Self(1);
+    pub const POSITIVE_COMPARISON: Self = Self(2);
+    pub const NEGATIVE_COMPARISON: Self = Self(3);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Valuetype(pub u32);
+
+impl Valuetype {
+    pub const ABSOLUTE: Self = Self(0);
+    pub const RELATIVE: Self = Self(1);
+}
+
+bitflags! {
+    pub struct Ca: u32 {
+        const COUNTER = 0x1;
+        const VALUE_TYPE = 0x2;
+        const VALUE = 0x4;
+        const TEST_TYPE = 0x8;
+        const DELTA = 0x10;
+        const EVENTS = 0x20;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Int64 {
+    pub hi: i32,
+    pub lo: u32,
+}
+
+impl From<xcb_system::xcb_sync_int64_t> for Int64 {
+    fn from(raw: xcb_system::xcb_sync_int64_t) -> Self {
+        Self {
+            hi: raw.hi,
+            lo: raw.lo,
+        }
+    }
+}
+
+impl From<Int64> for xcb_system::xcb_sync_int64_t {
+    fn from(value: Int64) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.hi = value.hi;
+        raw.lo = value.lo;
+
+        raw
+    }
+}
+
+// SYSTEMCOUNTER isn't generated, name has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Trigger {
+    pub counter: Counter,
+    pub wait_type: u32,
+    pub wait_value: Int64,
+    pub test_type: u32,
+}
+
+impl From<xcb_system::xcb_sync_trigger_t> for Trigger {
+    fn from(raw: xcb_system::xcb_sync_trigger_t) -> Self {
+        Self {
+            counter: raw.counter,
+            wait_type: raw.wait_type,
+            wait_value: Int64::from(raw.wait_value),
+            test_type: raw.test_type,
+        }
+    }
+}
+
+impl From<Trigger> for xcb_system::xcb_sync_trigger_t {
+    fn from(value: Trigger) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.counter = value.counter;
+        raw.wait_type = value.wait_type;
+        raw.wait_value = xcb_system::xcb_sync_int64_t::from(value.wait_value);
+        raw.test_type = value.test_type;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Waitcondition {
+    pub trigger: Trigger,
+    pub event_threshold: Int64,
+}
+
+impl From<xcb_system::xcb_sync_waitcondition_t> for Waitcondition {
+    fn from(raw: xcb_system::xcb_sync_waitcondition_t) -> Self {
+        Self {
+            trigger: Trigger::from(raw.trigger),
+            event_threshold: Int64::from(raw.event_threshold),
+        }
+    }
+}
+
+impl From<Waitcondition> for xcb_system::xcb_sync_waitcondition_t {
+    fn from(value: Waitcondition) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.trigger = xcb_system::xcb_sync_trigger_t::from(value.trigger);
+        raw.event_threshold = xcb_system::xcb_sync_int64_t::from(value.event_threshold);
+
+        raw
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializeReply {
+    pub major_version: u8,
+    pub minor_version: u8,
+}
+
+pub struct InitializeRequest;
+
+impl crate::result::Request for InitializeRequest {
+    type Reply = InitializeReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<InitializeReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_sync_initialize_reply_t>(connection, sequence)?;
+
+        Ok(InitializeReply {
+            major_version: reply.major_version,
+            minor_version: reply.minor_version,
+        })
+    }
+}
+
+pub fn initialize<'a>(connection: &'a crate::connection::Connection, desired_major_version: u8, desired_minor_version: u8) -> crate::result::Cookie<'a, InitializeRequest> {
+    let cookie = unsafe { xcb_system::xcb_sync_initialize(connection.get_connection(), desired_major_version, desired_minor_version) };
+
+    crate::result::Cookie::new(cookie.sequence, InitializeRequest, connection)
+}
+
+// ListSystemCounters isn't generated, SYSTEMCOUNTER has a variable size.
+
+pub fn create_counter<'a>(connection: &'a crate::connection::Connection, id: Counter, initial_value: Int64) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_sync_create_counter_checked(connection.get_connection(), id, xcb_system::xcb_sync_int64_t::from(initial_value)) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_counter_unchecked<'a>(connection: &'a crate::connection::Connection, id: Counter, initial_value: Int64) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_sync_create_counter(connection.get_connection(), id, xcb_system::xcb_sync_int64_t::from(initial_value)) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn destroy_counter<'a>(connection: &'a crate::connection::Connection, counter: Counter) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_sync_destroy_counter_checked(connection.get_connection(), counter) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn destroy_counter_unchecked<'a>(connection: &'a crate::connection::Connection, counter: Counter) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_sync_destroy_counter(connection.get_connection(), counter) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryCounterReply {
+    pub counter_value: Int64,
+}
+
+pub struct QueryCounterRequest;
+
+impl crate::result::Request for QueryCounterRequest {
+    type Reply = QueryCounterReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryCounterReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_sync_query_counter_reply_t>(connection, sequence)?;
+
+        Ok(QueryCounterReply {
+            counter_value: Int64::from(reply.counter_value),
+        })
+    }
+}
+
+pub fn query_counter<'a>(connection: &'a crate::connection::Connection, counter: Counter) -> crate::result::Cookie<'a, QueryCounterRequest> {
+    let cookie = unsafe { xcb_system::xcb_sync_query_counter(connection.get_connection(), counter) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryCounterRequest, connection)
+}
+
+pub fn await_<'a>(connection: &'a crate::connection::Connection, wait_list: &[Waitcondition]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let wait_list: Vec<xcb_system::xcb_sync_waitcondition_t> = wait_list.iter().map(|&value| xcb_system::xcb_sync_waitcondition_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_sync_await_checked(connection.get_connection(), wait_list.len() as u32, wait_list.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn await__unchecked<'a>(connection: &'a crate::connection::Connection, wait_list: &[Waitcondition]) -> crate::result::UncheckedCookie {
+    let wait_list: Vec<xcb_system::xcb_sync_waitcondition_t> = wait_list.iter().map(|&value| xcb_system::xcb_sync_waitcondition_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_sync_await(connection.get_connection(), wait_list.len() as u32, wait_list.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn change_counter<'a>(connection: &'a crate::connection::Connection, counter: Counter, amount: Int64) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_sync_change_counter_checked(connection.get_connection(), counter, xcb_system::xcb_sync_int64_t::from(amount)) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_counter_unchecked<'a>(connection: &'a crate::connection::Connection, counter: Counter, amount: Int64) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_sync_change_counter(connection.get_connection(), counter, xcb_system::xcb_sync_int64_t::from(amount)) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn set_counter<'a>(connection: &'a crate::connection::Connection, counter: Counter, value: Int64) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_sync_set_counter_checked(connection.get_connection(), counter, xcb_system::xcb_sync_int64_t::from(value)) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_counter_unchecked<'a>(connection: &'a crate::connection::Connection, counter: Counter, value: Int64) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_sync_set_counter(connection.get_connection(), counter, xcb_system::xcb_sync_int64_t::from(value)) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+// CreateAlarm isn't generated, value_list has values of other sizes.
+
+// ChangeAlarm isn't generated, value_list has values of other sizes.
+
+pub fn destroy_alarm<'a>(connection: &'a crate::connection::Connection, alarm: Alarm) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_sync_destroy_alarm_checked(connection.get_connection(), alarm) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn destroy_alarm_unchecked<'a>(connection: &'a crate::connection::Connection, alarm: Alarm) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_sync_destroy_alarm(connection.get_connection(), alarm) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryAlarmReply {
+    pub trigger: Trigger,
+    pub delta: Int64,
+    pub events: bool,
+    pub state: u8,
+}
+
+pub struct QueryAlarmRequest;
+
+impl crate::result::Request for QueryAlarmRequest {
+    type Reply = QueryAlarmReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryAlarmReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_sync_query_alarm_reply_t>(connection, sequence)?;
+
+        Ok(QueryAlarmReply {
+            trigger: Trigger::from(reply.trigger),
+            delta: Int64::from(reply.delta),
+            events: reply.events != 0,
+            state: reply.state,
+        })
+    }
+}
+
+pub fn query_alarm<'a>(connection: &'a crate::connection::Connection, alarm: Alarm) -> crate::result::Cookie<'a, QueryAlarmRequest> {
+    let cookie = unsafe { xcb_system::xcb_sync_query_alarm(connection.get_connection(), alarm) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryAlarmRequest, connection)
+}
+
+pub fn set_priority<'a>(connection: &'a crate::connection::Connection, id: u32, priority: i32) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_sync_set_priority_checked(connection.get_connection(), id, priority) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_priority_unchecked<'a>(connection: &'a crate::connection::Connection, id: u32, priority: i32) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_sync_set_priority(connection.get_connection(), id, priority) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetPriorityReply {
+    pub priority: i32,
+}
+
+pub struct GetPriorityRequest;
+
+impl crate::result::Request for GetPriorityRequest {
+    type Reply = GetPriorityReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetPriorityReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_sync_get_priority_reply_t>(connection, sequence)?;
+
+        Ok(GetPriorityReply {
+            priority: reply.priority,
+        })
+    }
+}
+
+pub fn get_priority<'a>(connection: &'a crate::connection::Connection, id: u32) -> crate::result::Cookie<'a, GetPriorityRequest> {
+    let cookie = unsafe { xcb_system::xcb_sync_get_priority(connection.get_connection(), id) };
+
+    crate::result::Cookie::new(cookie.sequence, GetPriorityRequest, connection)
+}
+
+pub fn create_fence<'a>(connection: &'a crate::connection::Connection, drawable: super::xproto::Drawable, fence: Fence, initially_triggered: bool) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_sync_create_fence_checked(connection.get_connection(), drawable, fence, u8::from(initially_triggered)) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_fence_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: super::xproto::Drawable, fence: Fence, initially_triggered: bool) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_sync_create_fence(connection.get_connection(), drawable, fence, u8::from(initially_triggered)) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn trigger_fence<'a>(connection: &'a crate::connection::Connection, fence: Fence) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_sync_trigger_fence_checked(connection.get_connection(), fence) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn trigger_fence_unchecked<'a>(connection: &'a crate::connection::Connection, fence: Fence) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_sync_trigger_fence(connection.get_connection(), fence) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn reset_fence<'a>(connection: &'a crate::connection::Connection, fence: Fence) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_sync_reset_fence_checked(connection.get_connection(), fence) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn reset_fence_unchecked<'a>(connection: &'a crate::connection::Connection, fence: Fence) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_sync_reset_fence(connection.get_connection(), fence) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn destroy_fence<'a>(connection: &'a crate::connection::Connection, fence: Fence) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_sync_destroy_fence_checked(connection.get_connection(), fence) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn destroy_fence_unchecked<'a>(connection: &'a crate::connection::Connection, fence: Fence) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_sync_destroy_fence(connection.get_connection(), fence) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryFenceReply {
+    pub triggered: bool,
+}
+
+pub struct QueryFenceRequest;
+
+impl crate::result::Request for QueryFenceRequest {
+    type Reply = QueryFenceReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryFenceReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_sync_query_fence_reply_t>(connection, sequence)?;
+
+        Ok(QueryFenceReply {
+            triggered: reply.triggered != 0,
+        })
+    }
+}
+
+pub fn query_fence<'a>(connection: &'a crate::connection::Connection, fence: Fence) -> crate::result::Cookie<'a, QueryFenceRequest> {
+    let cookie = unsafe { xcb_system::xcb_sync_query_fence(connection.get_connection(), fence) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryFenceRequest, connection)
+}
+
+pub fn await_fence<'a>(connection: &'a crate::connection::Connection, fence_list: &[Fence]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_sync_await_fence_checked(connection.get_connection(), fence_list.len() as u32, fence_list.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn await_fence_unchecked<'a>(connection: &'a crate::connection::Connection, fence_list: &[Fence]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_sync_await_fence(connection.get_connection(), fence_list.len() as u32, fence_list.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CounterNotifyEvent {
+    pub kind: u8,
+    pub counter: Counter,
+    pub wait_value: Int64,
+    pub counter_value: Int64,
+    pub timestamp: super::xproto::Timestamp,
+    pub count: u16,
+    pub destroyed: bool,
+}
+
+impl From<xcb_system::xcb_sync_counter_notify_event_t> for CounterNotifyEvent {
+    fn from(raw: xcb_system::xcb_sync_counter_notify_event_t) -> Self {
+        Self {
+            kind: raw.kind,
+            counter: raw.counter,
+            wait_value: Int64::from(raw.wait_value),
+            counter_value: Int64::from(raw.counter_value),
+            timestamp: raw.timestamp,
+            count: raw.count,
+            destroyed: raw.destroyed != 0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AlarmNotifyEvent {
+    pub kind: u8,
+    pub alarm: Alarm,
+    pub counter_value: Int64,
+    pub alarm_value: Int64,
+    pub timestamp: super::xproto::Timestamp,
+    pub state: u8,
+}
+
+impl From<xcb_system::xcb_sync_alarm_notify_event_t> for AlarmNotifyEvent {
+    fn from(raw: xcb_system::xcb_sync_alarm_notify_event_t) -> Self {
+        Self {
+            kind: raw.kind,
+            alarm: raw.alarm,
+            counter_value: Int64::from(raw.counter_value),
+            alarm_value: Int64::from(raw.alarm_value),
+            timestamp: raw.timestamp,
+            state: raw.state,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    CounterNotify(CounterNotifyEvent),
+    AlarmNotify(AlarmNotifyEvent),
+}
+
+pub fn decode_event(event: &xcb_system::xcb_generic_event_t, first_event: u8) -> std::option::Option<Event> {
+    let raw = event as *const xcb_system::xcb_generic_event_t;
+
+    match (event.response_type & 0x7f).wrapping_sub(first_event) {
+        0 => Some(Event::CounterNotify(
+            unsafe { *(raw as *const xcb_system::xcb_sync_counter_notify_event_t) }.into(),
+        )),
+        1 => Some(Event::AlarmNotify(
+            unsafe { *(raw as *const xcb_system::xcb_sync_alarm_notify_event_t) }.into(),
+        )),
+        _ => None,
+    }
+}
+
@@ -0,0 +1,671 @@
+// Generated by xcb-codegen from xfixes.xml, don't edit.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryVersionReply {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+pub struct QueryVersionRequest;
+
+impl crate::result::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xfixes_query_version_reply_t>(connection, sequence)?;
+
+        Ok(QueryVersionReply {
+            major_version: reply.major_version,
+            minor_version: reply.minor_version,
+        })
+    }
+}
+
+pub fn query_version<'a>(connection: &'a crate::connection::Connection, client_major_version: u32, client_minor_version: u32) -> crate::result::Cookie<'a, QueryVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_query_version(connection.get_connection(), client_major_version, client_minor_version) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryVersionRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SaveSetMode(pub u32);
+
+impl SaveSetMode {
+    pub const INSERT: Self = Self(0);
+    pub const DELETE: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SaveSetTarget(pub u32);
+
+impl SaveSetTarget {
+    pub const NEAREST: Self = Self(0);
+    pub const ROOT: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SaveSetMapping(pub u32);
+
+impl SaveSetMapping {
+    pub const MAP: Self = Self(0);
+    pub const UNMAP: Self = Self(1);
+}
+
+pub fn change_save_set<'a>(connection: &'a crate::connection::Connection, mode: u8, target: u8, map: u8, window: super::xproto::Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_change_save_set_checked(connection.get_connection(), mode, target, map, window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_save_set_unchecked<'a>(connection: &'a crate::connection::Connection, mode: u8, target: u8, map: u8, window: super::xproto::Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_change_save_set(connection.get_connection(), mode, target, map, window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SelectionEvent(pub u32);
+
+impl SelectionEvent {
+    pub const SET_SELECTION_OWNER: Self = Self(0);
+    pub const SELECTION_WINDOW_DESTROY: Self = Self(1);
+    pub const SELECTION_CLIENT_CLOSE: Self = Self(2);
+}
+
+bitflags! {
+    pub struct SelectionEventMask: u32 {
+        const SET_SELECTION_OWNER = 0x1;
+        const SELECTION_WINDOW_DESTROY = 0x2;
+        const SELECTION_CLIENT_CLOSE = 0x4;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SelectionNotifyEvent {
+    pub subtype: u8,
+    pub window: super::xproto::Window,
+    pub owner: super::xproto::Window,
+    pub selection: super::xproto::Atom,
+    pub timestamp: super::xproto::Timestamp,
+    pub selection_timestamp: super::xproto::Timestamp,
+}
+
+impl From<xcb_system::xcb_xfixes_selection_notify_event_t> for SelectionNotifyEvent {
+    fn from(raw: xcb_system::xcb_xfixes_selection_notify_event_t) -> Self {
+        Self {
+            subtype: raw.subtype,
+            window: raw.window,
+            owner: raw.owner,
+            selection: raw.selection,
+            timestamp: raw.timestamp,
+            selection_timestamp: raw.selection_timestamp,
+        }
+    }
+}
+
+pub fn select_selection_input<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, selection: super::xproto::Atom, event_mask: u32) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_select_selection_input_checked(connection.get_connection(), window, selection, event_mask) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn select_selection_input_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, selection: super::xproto::Atom, event_mask: u32) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_select_selection_input(connection.get_connection(), window, selection, event_mask) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CursorNotify(pub u32);
+
+impl CursorNotify {
+    pub const DISPLAY_CURSOR: Self = Self(0);
+}
+
+bitflags! {
+    pub struct CursorNotifyMask: u32 {
+        const DISPLAY_CURSOR = 0x1;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CursorNotifyEvent {
+    pub subtype: u8,
+    pub window: super::xproto::Window,
+    pub cursor_serial: u32,
+    pub timestamp: super::xproto::Timestamp,
+    pub name: super::xproto::Atom,
+}
+
+impl From<xcb_system::xcb_xfixes_cursor_notify_event_t> for CursorNotifyEvent {
+    fn from(raw: xcb_system::xcb_xfixes_cursor_notify_event_t) -> Self {
+        Self {
+            subtype: raw.subtype,
+            window: raw.window,
+            cursor_serial: raw.cursor_serial,
+            timestamp: raw.timestamp,
+            name: raw.name,
+        }
+    }
+}
+
+pub fn select_cursor_input<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, event_mask: u32) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_select_cursor_input_checked(connection.get_connection(), window, event_mask) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn select_cursor_input_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, event_mask: u32) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_select_cursor_input(connection.get_connection(), window, event_mask) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetCursorImageReply {
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub xhot: u16,
+    pub yhot: u16,
+    pub cursor_serial: u32,
+    pub cursor_image: Vec<u32>,
+}
+
+pub struct GetCursorImageRequest;
+
+impl crate::result::Request for GetCursorImageRequest {
+    type Reply = GetCursorImageReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetCursorImageReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xfixes_get_cursor_image_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetCursorImageReply {
+            x: reply.x,
+            y: reply.y,
+            width: reply.width,
+            height: reply.height,
+            xhot: reply.xhot,
+            yhot: reply.yhot,
+            cursor_serial: reply.cursor_serial,
+            cursor_image: std::slice::from_raw_parts(xcb_system::xcb_xfixes_get_cursor_image_cursor_image(reply.as_ptr()) as *const u32, xcb_system::xcb_xfixes_get_cursor_image_cursor_image_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_cursor_image<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, GetCursorImageRequest> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_get_cursor_image(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, GetCursorImageRequest, connection)
+}
+
+pub type Region = u32;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RegionEnum(pub u32);
+
+impl RegionEnum {
+    pub const NONE: Self = Self(0);
+}
+
+pub fn create_region<'a>(connection: &'a crate::connection::Connection, region: Region, rectangles: &[super::xproto::Rectangle]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_xfixes_create_region_checked(connection.get_connection(), region, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_region_unchecked<'a>(connection: &'a crate::connection::Connection, region: Region, rectangles: &[super::xproto::Rectangle]) -> crate::result::UncheckedCookie {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_xfixes_create_region(connection.get_connection(), region, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn create_region_from_bitmap<'a>(connection: &'a crate::connection::Connection, region: Region, bitmap: super::xproto::Pixmap) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_create_region_from_bitmap_checked(connection.get_connection(), region, bitmap) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_region_from_bitmap_unchecked<'a>(connection: &'a crate::connection::Connection, region: Region, bitmap: super::xproto::Pixmap) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_create_region_from_bitmap(connection.get_connection(), region, bitmap) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn create_region_from_window<'a>(connection: &'a crate::connection::Connection, region: Region, window: super::xproto::Window, kind: super::shape::Kind) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_create_region_from_window_checked(connection.get_connection(), region, window, kind) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_region_from_window_unchecked<'a>(connection: &'a crate::connection::Connection, region: Region, window: super::xproto::Window, kind: super::shape::Kind) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_create_region_from_window(connection.get_connection(), region, window, kind) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn create_region_from_gc<'a>(connection: &'a crate::connection::Connection, region: Region, gc: super::xproto::Gcontext) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_create_region_from_gc_checked(connection.get_connection(), region, gc) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_region_from_gc_unchecked<'a>(connection: &'a crate::connection::Connection, region: Region, gc: super::xproto::Gcontext) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_create_region_from_gc(connection.get_connection(), region, gc) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn create_region_from_picture<'a>(connection: &'a crate::connection::Connection, region: Region, picture: super::render::Picture) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_create_region_from_picture_checked(connection.get_connection(), region, picture) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_region_from_picture_unchecked<'a>(connection: &'a crate::connection::Connection, region: Region, picture: super::render::Picture) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_create_region_from_picture(connection.get_connection(), region, picture) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn destroy_region<'a>(connection: &'a crate::connection::Connection, region: Region) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_destroy_region_checked(connection.get_connection(), region) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn destroy_region_unchecked<'a>(connection: &'a crate::connection::Connection, region: Region) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_destroy_region(connection.get_connection(), region) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn set_region<'a>(connection: &'a crate::connection::Connection, region: Region, rectangles: &[super::xproto::Rectangle]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_xfixes_set_region_checked(connection.get_connection(), region, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_region_unchecked<'a>(connection: &'a crate::connection::Connection, region: Region, rectangles: &[super::xproto::Rectangle]) -> crate::result::UncheckedCookie {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_xfixes_set_region(connection.get_connection(), region, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn copy_region<'a>(connection: &'a crate::connection::Connection, source: Region, destination: Region) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_copy_region_checked(connection.get_connection(), source, destination) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn copy_region_unchecked<'a>(connection: &'a crate::connection::Connection, source: Region, destination: Region) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_copy_region(connection.get_connection(), source, destination) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn union_region<'a>(connection: &'a crate::connection::Connection, source1: Region, source2: Region, destination: Region) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_union_region_checked(connection.get_connection(), source1, source2, destination) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn union_region_unchecked<'a>(connection: &'a crate::connection::Connection, source1: Region, source2: Region, destination: Region) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_union_region(connection.get_connection(), source1, source2, destination) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn intersect_region<'a>(connection: &'a crate::connection::Connection, source1: Region, source2: Region, destination: Region) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_intersect_region_checked(connection.get_connection(), source1, source2, destination) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn intersect_region_unchecked<'a>(connection: &'a crate::connection::Connection, source1: Region, source2: Region, destination: Region) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_intersect_region(connection.get_connection(), source1, source2, destination) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn subtract_region<'a>(connection: &'a crate::connection::Connection, source1: Region, source2: Region, destination: Region) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_subtract_region_checked(connection.get_connection(), source1, source2, destination) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn subtract_region_unchecked<'a>(connection: &'a crate::connection::Connection, source1: Region, source2: Region, destination: Region) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_subtract_region(connection.get_connection(), source1, source2, destination) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn invert_region<'a>(connection: &'a crate::connection::Connection, source: Region, bounds: super::xproto::Rectangle, destination: Region) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_invert_region_checked(connection.get_connection(), source, xcb_system::xcb_rectangle_t::from(bounds), destination) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn invert_region_unchecked<'a>(connection: &'a crate::connection::Connection, source: Region, bounds: super::xproto::Rectangle, destination: Region) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_invert_region(connection.get_connection(), source, xcb_system::xcb_rectangle_t::from(bounds), destination) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn translate_region<'a>(connection: &'a crate::connection::Connection, region: Region, dx: i16, dy: i16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_translate_region_checked(connection.get_connection(), region, dx, dy) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn translate_region_unchecked<'a>(connection: &'a crate::connection::Connection, region: Region, dx: i16, dy: i16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_translate_region(connection.get_connection(), region, dx, dy) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn region_extents<'a>(connection: &'a crate::connection::Connection, source: Region, destination: Region) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_region_extents_checked(connection.get_connection(), source, destination) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn region_extents_unchecked<'a>(connection: &'a crate::connection::Connection, source: Region, destination: Region) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_region_extents(connection.get_connection(), source, destination) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FetchRegionReply {
+    pub extents: super::xproto::Rectangle,
+    pub rectangles: Vec<super::xproto::Rectangle>,
+}
+
+pub struct FetchRegionRequest;
+
+impl crate::result::Request for FetchRegionRequest {
+    type Reply = FetchRegionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<FetchRegionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xfixes_fetch_region_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { FetchRegionReply {
+            extents: super::xproto::Rectangle::from(reply.extents),
+            rectangles: std::slice::from_raw_parts(xcb_system::xcb_xfixes_fetch_region_rectangles(reply.as_ptr()) as *const xcb_system::xcb_rectangle_t, xcb_system::xcb_xfixes_fetch_region_rectangles_length(reply.as_ptr()) as usize).iter().map(|&value| super::xproto::Rectangle::from(value)).collect(),
+        } })
+    }
+}
+
+pub fn fetch_region<'a>(connection: &'a crate::connection::Connection, region: Region) -> crate::result::Cookie<'a, FetchRegionRequest> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_fetch_region(connection.get_connection(), region) };
+
+    crate::result::Cookie::new(cookie.sequence, FetchRegionRequest, connection)
+}
+
+pub fn set_gc_clip_region<'a>(connection: &'a crate::connection::Connection, gc: super::xproto::Gcontext, region: Region, x_origin: i16, y_origin: i16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_set_gc_clip_region_checked(connection.get_connection(), gc, region, x_origin, y_origin) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_gc_clip_region_unchecked<'a>(connection: &'a crate::connection::Connection, gc: super::xproto::Gcontext, region: Region, x_origin: i16, y_origin: i16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_set_gc_clip_region(connection.get_connection(), gc, region, x_origin, y_origin) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn set_window_shape_region<'a>(connection: &'a crate::connection::Connection, dest: super::xproto::Window, dest_kind: super::shape::Kind, x_offset: i16, y_offset: i16, region: Region) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_set_window_shape_region_checked(connection.get_connection(), dest, dest_kind, x_offset, y_offset, region) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_window_shape_region_unchecked<'a>(connection: &'a crate::connection::Connection, dest: super::xproto::Window, dest_kind: super::shape::Kind, x_offset: i16, y_offset: i16, region: Region) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_set_window_shape_region(connection.get_connection(), dest, dest_kind, x_offset, y_offset, region) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn set_picture_clip_region<'a>(connection: &'a crate::connection::Connection, picture: super::render::Picture, region: Region, x_origin: i16, y_origin: i16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_set_picture_clip_region_checked(connection.get_connection(), picture, region, x_origin, y_origin) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_picture_clip_region_unchecked<'a>(connection: &'a crate::connection::Connection, picture: super::render::Picture, region: Region, x_origin: i16, y_origin: i16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_set_picture_clip_region(connection.get_connection(), picture, region, x_origin, y_origin) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn set_cursor_name<'a>(connection: &'a crate::connection::Connection, cursor: super::xproto::Cursor, name: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_set_cursor_name_checked(connection.get_connection(), cursor, name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_cursor_name_unchecked<'a>(connection: &'a crate::connection::Connection, cursor: super::xproto::Cursor, name: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_set_cursor_name(connection.get_connection(), cursor, name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetCursorNameReply {
+    pub atom: super::xproto::Atom,
+    pub nbytes: u16,
+    pub name: Vec<u8>,
+}
+
+pub struct GetCursorNameRequest;
+
+impl crate::result::Request for GetCursorNameRequest {
+    type Reply = GetCursorNameReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetCursorNameReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xfixes_get_cursor_name_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetCursorNameReply {
+            atom: reply.atom,
+            nbytes: reply.nbytes,
+            name: std::slice::from_raw_parts(xcb_system::xcb_xfixes_get_cursor_name_name(reply.as_ptr()) as *const u8, xcb_system::xcb_xfixes_get_cursor_name_name_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_cursor_name<'a>(connection: &'a crate::connection::Connection, cursor: super::xproto::Cursor) -> crate::result::Cookie<'a, GetCursorNameRequest> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_get_cursor_name(connection.get_connection(), cursor) };
+
+    crate::result::Cookie::new(cookie.sequence, GetCursorNameRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetCursorImageAndNameReply {
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub xhot: u16,
+    pub yhot: u16,
+    pub cursor_serial: u32,
+    pub cursor_atom: super::xproto::Atom,
+    pub nbytes: u16,
+    pub cursor_image: Vec<u32>,
+    pub name: Vec<u8>,
+}
+
+pub struct GetCursorImageAndNameRequest;
+
+impl crate::result::Request for GetCursorImageAndNameRequest {
+    type Reply = GetCursorImageAndNameReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetCursorImageAndNameReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xfixes_get_cursor_image_and_name_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetCursorImageAndNameReply {
+            x: reply.x,
+            y: reply.y,
+            width: reply.width,
+            height: reply.height,
+            xhot: reply.xhot,
+            yhot: reply.yhot,
+            cursor_serial: reply.cursor_serial,
+            cursor_atom: reply.cursor_atom,
+            nbytes: reply.nbytes,
+            cursor_image: std::slice::from_raw_parts(xcb_system::xcb_xfixes_get_cursor_image_and_name_cursor_image(reply.as_ptr()) as *const u32, xcb_system::xcb_xfixes_get_cursor_image_and_name_cursor_image_length(reply.as_ptr()) as usize).to_vec(),
+            name: std::slice::from_raw_parts(xcb_system::xcb_xfixes_get_cursor_image_and_name_name(reply.as_ptr()) as *const u8, xcb_system::xcb_xfixes_get_cursor_image_and_name_name_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_cursor_image_and_name<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, GetCursorImageAndNameRequest> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_get_cursor_image_and_name(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, GetCursorImageAndNameRequest, connection)
+}
+
+pub fn change_cursor<'a>(connection: &'a crate::connection::Connection, source: super::xproto::Cursor, destination: super::xproto::Cursor) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_change_cursor_checked(connection.get_connection(), source, destination) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_cursor_unchecked<'a>(connection: &'a crate::connection::Connection, source: super::xproto::Cursor, destination: super::xproto::Cursor) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_change_cursor(connection.get_connection(), source, destination) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn change_cursor_by_name<'a>(connection: &'a crate::connection::Connection, src: super::xproto::Cursor, name: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_change_cursor_by_name_checked(connection.get_connection(), src, name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_cursor_by_name_unchecked<'a>(connection: &'a crate::connection::Connection, src: super::xproto::Cursor, name: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_change_cursor_by_name(connection.get_connection(), src, name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn expand_region<'a>(connection: &'a crate::connection::Connection, source: Region, destination: Region, left: u16, right: u16, top: u16, bottom: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_expand_region_checked(connection.get_connection(), source, destination, left, right, top, bottom) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn expand_region_unchecked<'a>(connection: &'a crate::connection::Connection, source: Region, destination: Region, left: u16, right: u16, top: u16, bottom: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_expand_region(connection.get_connection(), source, destination, left, right, top, bottom) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn hide_cursor<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_hide_cursor_checked(connection.get_connection(), window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn hide_cursor_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_hide_cursor(connection.get_connection(), window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn show_cursor<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_show_cursor_checked(connection.get_connection(), window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn show_cursor_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_show_cursor(connection.get_connection(), window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub type Barrier = u32;
+
+bitflags! {
+    pub struct BarrierDirections: u32 {
+        const POSITIVE_X = 0x1;
+        const POSITIVE_Y = 0x2;
+        const NEGATIVE_X = 0x4;
+        const NEGATIVE_Y = 0x8;
+    }
+}
+
+pub fn create_pointer_barrier<'a>(connection: &'a crate::connection::Connection, barrier: Barrier, window: super::xproto::Window, x1: u16, y1: u16, x2: u16, y2: u16, directions: u32, devices: &[u16]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_create_pointer_barrier_checked(connection.get_connection(), barrier, window, x1, y1, x2, y2, directions, devices.len() as _, devices.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_pointer_barrier_unchecked<'a>(connection: &'a crate::connection::Connection, barrier: Barrier, window: super::xproto::Window, x1: u16, y1: u16, x2: u16, y2: u16, directions: u32, devices: &[u16]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_create_pointer_barrier(connection.get_connection(), barrier, window, x1, y1, x2, y2, directions, devices.len() as _, devices.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn delete_pointer_barrier<'a>(connection: &'a crate::connection::Connection, barrier: Barrier) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xfixes_delete_pointer_barrier_checked(connection.get_connection(), barrier) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn delete_pointer_barrier_unchecked<'a>(connection: &'a crate::connection::Connection, barrier: Barrier) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xfixes_delete_pointer_barrier(connection.get_connection(), barrier) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    SelectionNotify(SelectionNotifyEvent),
+    CursorNotify(CursorNotifyEvent),
+}
+
+pub fn decode_event(event: &xcb_system::xcb_generic_event_t, first_event: u8) -> std::option::Option<Event> {
+    let raw = event as *const xcb_system::xcb_generic_event_t;
+
+    match (event.response_type & 0x7f).wrapping_sub(first_event) {
+        0 => Some(Event::SelectionNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xfixes_selection_notify_event_t) }.into(),
+        )),
+        1 => Some(Event::CursorNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xfixes_cursor_notify_event_t) }.into(),
+        )),
+        _ => None,
+    }
+}
+
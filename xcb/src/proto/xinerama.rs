@@ -0,0 +1,222 @@
+// Generated by xcb-codegen from xinerama.xml, don't edit.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScreenInfo {
+    pub x_org: i16,
+    pub y_org: i16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl From<xcb_system::xcb_xinerama_screen_info_t> for ScreenInfo {
+    fn from(raw: xcb_system::xcb_xinerama_screen_info_t) -> Self {
+        Self {
+            x_org: raw.x_org,
+            y_org: raw.y_org,
+            width: raw.width,
+            height: raw.height,
+        }
+    }
+}
+
+impl From<ScreenInfo> for xcb_system::xcb_xinerama_screen_info_t {
+    fn from(value: ScreenInfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.x_org = value.x_org;
+        raw.y_org = value.y_org;
+        raw.width = value.width;
+        raw.height = value.height;
+
+        raw
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryVersionReply {
+    pub major: u16,
+    pub minor: u16,
+}
+
+pub struct QueryVersionRequest;
+
+impl crate::result::Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xinerama_query_version_reply_t>(connection, sequence)?;
+
+        Ok(QueryVersionReply {
+            major: reply.major,
+            minor: reply.minor,
+        })
+    }
+}
+
+pub fn query_version<'a>(connection: &'a crate::connection::Connection, major: u8, minor: u8) -> crate::result::Cookie<'a, QueryVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_xinerama_query_version(connection.get_connection(), major, minor) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryVersionRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetStateReply {
+    pub state: u8,
+    pub window: super::xproto::Window,
+}
+
+pub struct GetStateRequest;
+
+impl crate::result::Request for GetStateRequest {
+    type Reply = GetStateReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetStateReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xinerama_get_state_reply_t>(connection, sequence)?;
+
+        Ok(GetStateReply {
+            state: reply.state,
+            window: reply.window,
+        })
+    }
+}
+
+pub fn get_state<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, GetStateRequest> {
+    let cookie = unsafe { xcb_system::xcb_xinerama_get_state(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, GetStateRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetScreenCountReply {
+    pub screen_count: u8,
+    pub window: super::xproto::Window,
+}
+
+pub struct GetScreenCountRequest;
+
+impl crate::result::Request for GetScreenCountRequest {
+    type Reply = GetScreenCountReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetScreenCountReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xinerama_get_screen_count_reply_t>(connection, sequence)?;
+
+        Ok(GetScreenCountReply {
+            screen_count: reply.screen_count,
+            window: reply.window,
+        })
+    }
+}
+
+pub fn get_screen_count<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, GetScreenCountRequest> {
+    let cookie = unsafe { xcb_system::xcb_xinerama_get_screen_count(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, GetScreenCountRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetScreenSizeReply {
+    pub width: u32,
+    pub height: u32,
+    pub window: super::xproto::Window,
+    pub screen: u32,
+}
+
+pub struct GetScreenSizeRequest;
+
+impl crate::result::Request for GetScreenSizeRequest {
+    type Reply = GetScreenSizeReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetScreenSizeReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xinerama_get_screen_size_reply_t>(connection, sequence)?;
+
+        Ok(GetScreenSizeReply {
+            width: reply.width,
+            height: reply.height,
+            window: reply.window,
+            screen: reply.screen,
+        })
+    }
+}
+
+pub fn get_screen_size<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, screen: u32) -> crate::result::Cookie<'a, GetScreenSizeRequest> {
+    let cookie = unsafe { xcb_system::xcb_xinerama_get_screen_size(connection.get_connection(), window, screen) };
+
+    crate::result::Cookie::new(cookie.sequence, GetScreenSizeRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IsActiveReply {
+    pub state: u32,
+}
+
+pub struct IsActiveRequest;
+
+impl crate::result::Request for IsActiveRequest {
+    type Reply = IsActiveReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<IsActiveReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xinerama_is_active_reply_t>(connection, sequence)?;
+
+        Ok(IsActiveReply {
+            state: reply.state,
+        })
+    }
+}
+
+pub fn is_active<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, IsActiveRequest> {
+    let cookie = unsafe { xcb_system::xcb_xinerama_is_active(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, IsActiveRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryScreensReply {
+    pub number: u32,
+    pub screen_info: Vec<ScreenInfo>,
+}
+
+pub struct QueryScreensRequest;
+
+impl crate::result::Request for QueryScreensRequest {
+    type Reply = QueryScreensReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryScreensReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xinerama_query_screens_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { QueryScreensReply {
+            number: reply.number,
+            screen_info: std::slice::from_raw_parts(xcb_system::xcb_xinerama_query_screens_screen_info(reply.as_ptr()) as *const xcb_system::xcb_xinerama_screen_info_t, xcb_system::xcb_xinerama_query_screens_screen_info_length(reply.as_ptr()) as usize).iter().map(|&value| ScreenInfo::from(value)).collect(),
+        } })
+    }
+}
+
+pub fn query_screens<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, QueryScreensRequest> {
+    let cookie = unsafe { xcb_system::xcb_xinerama_query_screens(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryScreensRequest, connection)
+}
+
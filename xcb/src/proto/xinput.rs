@@ -0,0 +1,3125 @@
+// Generated by xcb-codegen from xinput.xml, don't edit.
+
+pub type EventClass = u32;
+
+pub type KeyCode = u8;
+
+pub type DeviceId = u16;
+
+pub type Fp1616 = i32;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Fp3232 {
+    pub integral: i32,
+    pub frac: u32,
+}
+
+impl From<xcb_system::xcb_input_fp3232_t> for Fp3232 {
+    fn from(raw: xcb_system::xcb_input_fp3232_t) -> Self {
+        Self {
+            integral: raw.integral,
+            frac: raw.frac,
+        }
+    }
+}
+
+impl From<Fp3232> for xcb_system::xcb_input_fp3232_t {
+    fn from(value: Fp3232) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.integral = value.integral;
+        raw.frac = value.frac;
+
+        raw
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetExtensionVersionReply {
+    pub xi_reply_type: u8,
+    pub server_major: u16,
+    pub server_minor: u16,
+    pub present: bool,
+}
+
+pub struct GetExtensionVersionRequest;
+
+impl crate::result::Request for GetExtensionVersionRequest {
+    type Reply = GetExtensionVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetExtensionVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_get_extension_version_reply_t>(connection, sequence)?;
+
+        Ok(GetExtensionVersionReply {
+            xi_reply_type: reply.xi_reply_type,
+            server_major: reply.server_major,
+            server_minor: reply.server_minor,
+            present: reply.present != 0,
+        })
+    }
+}
+
+pub fn get_extension_version<'a>(connection: &'a crate::connection::Connection, name: &[u8]) -> crate::result::Cookie<'a, GetExtensionVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_get_extension_version(connection.get_connection(), name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, GetExtensionVersionRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceUse(pub u32);
+
+impl DeviceUse {
+    pub const IS_X_POINTER: Self = Self(0);
+    pub const IS_X_KEYBOARD: Self = Self(1);
+    pub const IS_X_EXTENSION_DEVICE: Self = Self(2);
+    pub const IS_X_EXTENSION_KEYBOARD: Self = Self(3);
+    pub const IS_X_EXTENSION_POINTER: Self = Self(4);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InputClass(pub u32);
+
+impl InputClass {
+    pub const KEY: Self = Self(0);
+    pub const BUTTON: Self = Self(1);
+    pub const VALUATOR: Self = Self(2);
+    pub const FEEDBACK: Self = Self(3);
+    pub const PROXIMITY: Self = Self(4);
+    pub const FOCUS: Self = Self(5);
+    pub const OTHER: Self = Self(6);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValuatorMode(pub u32);
+
+impl ValuatorMode {
+    pub const RELATIVE: Self = Self(0);
+    pub const ABSOLUTE: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceInfo {
+    pub device_type: super::xproto::Atom,
+    pub device_id: u8,
+    pub num_class_info: u8,
+    pub device_use: u8,
+}
+
+impl From<xcb_system::xcb_input_device_info_t> for DeviceInfo {
+    fn from(raw: xcb_system::xcb_input_device_info_t) -> Self {
+        Self {
+            device_type: raw.device_type,
+            device_id: raw.device_id,
+            num_class_info: raw.num_class_info,
+            device_use: raw.device_use,
+        }
+    }
+}
+
+impl From<DeviceInfo> for xcb_system::xcb_input_device_info_t {
+    fn from(value: DeviceInfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.device_type = value.device_type;
+        raw.device_id = value.device_id;
+        raw.num_class_info = value.num_class_info;
+        raw.device_use = value.device_use;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KeyInfo {
+    pub class_id: u8,
+    pub len: u8,
+    pub min_keycode: KeyCode,
+    pub max_keycode: KeyCode,
+    pub num_keys: u16,
+}
+
+impl From<xcb_system::xcb_input_key_info_t> for KeyInfo {
+    fn from(raw: xcb_system::xcb_input_key_info_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            len: raw.len,
+            min_keycode: raw.min_keycode,
+            max_keycode: raw.max_keycode,
+            num_keys: raw.num_keys,
+        }
+    }
+}
+
+impl From<KeyInfo> for xcb_system::xcb_input_key_info_t {
+    fn from(value: KeyInfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.len = value.len;
+        raw.min_keycode = value.min_keycode;
+        raw.max_keycode = value.max_keycode;
+        raw.num_keys = value.num_keys;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ButtonInfo {
+    pub class_id: u8,
+    pub len: u8,
+    pub num_buttons: u16,
+}
+
+impl From<xcb_system::xcb_input_button_info_t> for ButtonInfo {
+    fn from(raw: xcb_system::xcb_input_button_info_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            len: raw.len,
+            num_buttons: raw.num_buttons,
+        }
+    }
+}
+
+impl From<ButtonInfo> for xcb_system::xcb_input_button_info_t {
+    fn from(value: ButtonInfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.len = value.len;
+        raw.num_buttons = value.num_buttons;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AxisInfo {
+    pub resolution: u32,
+    pub minimum: i32,
+    pub maximum: i32,
+}
+
+impl From<xcb_system::xcb_input_axis_info_t> for AxisInfo {
+    fn from(raw: xcb_system::xcb_input_axis_info_t) -> Self {
+        Self {
+            resolution: raw.resolution,
+            minimum: raw.minimum,
+            maximum: raw.maximum,
+        }
+    }
+}
+
+impl From<AxisInfo> for xcb_system::xcb_input_axis_info_t {
+    fn from(value: AxisInfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.resolution = value.resolution;
+        raw.minimum = value.minimum;
+        raw.maximum = value.maximum;
+
+        raw
+    }
+}
+
+// ValuatorInfo isn't generated, axes has a variable length.
+
+// InputInfo isn't generated, it has an case field.
+
+// DeviceName isn't generated, string has a variable length.
+
+// ListInputDevices isn't generated, InputInfo has a variable size.
+
+pub type EventTypeBase = u8;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InputClassInfo {
+    pub class_id: u8,
+    pub event_type_base: EventTypeBase,
+}
+
+impl From<xcb_system::xcb_input_input_class_info_t> for InputClassInfo {
+    fn from(raw: xcb_system::xcb_input_input_class_info_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            event_type_base: raw.event_type_base,
+        }
+    }
+}
+
+impl From<InputClassInfo> for xcb_system::xcb_input_input_class_info_t {
+    fn from(value: InputClassInfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.event_type_base = value.event_type_base;
+
+        raw
+    }
+}
+
+// OpenDevice isn't generated, it has alignment pads.
+
+pub fn close_device<'a>(connection: &'a crate::connection::Connection, device_id: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_close_device_checked(connection.get_connection(), device_id) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn close_device_unchecked<'a>(connection: &'a crate::connection::Connection, device_id: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_close_device(connection.get_connection(), device_id) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetDeviceModeReply {
+    pub xi_reply_type: u8,
+    pub status: u8,
+}
+
+pub struct SetDeviceModeRequest;
+
+impl crate::result::Request for SetDeviceModeRequest {
+    type Reply = SetDeviceModeReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<SetDeviceModeReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_set_device_mode_reply_t>(connection, sequence)?;
+
+        Ok(SetDeviceModeReply {
+            xi_reply_type: reply.xi_reply_type,
+            status: reply.status,
+        })
+    }
+}
+
+pub fn set_device_mode<'a>(connection: &'a crate::connection::Connection, device_id: u8, mode: u8) -> crate::result::Cookie<'a, SetDeviceModeRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_set_device_mode(connection.get_connection(), device_id, mode) };
+
+    crate::result::Cookie::new(cookie.sequence, SetDeviceModeRequest, connection)
+}
+
+pub fn select_extension_event<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, classes: &[EventClass]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_select_extension_event_checked(connection.get_connection(), window, classes.len() as _, classes.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn select_extension_event_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, classes: &[EventClass]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_select_extension_event(connection.get_connection(), window, classes.len() as _, classes.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetSelectedExtensionEventsReply {
+    pub xi_reply_type: u8,
+    pub num_this_classes: u16,
+    pub num_all_classes: u16,
+    pub this_classes: Vec<EventClass>,
+    pub all_classes: Vec<EventClass>,
+}
+
+pub struct GetSelectedExtensionEventsRequest;
+
+impl crate::result::Request for GetSelectedExtensionEventsRequest {
+    type Reply = GetSelectedExtensionEventsReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetSelectedExtensionEventsReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_get_selected_extension_events_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetSelectedExtensionEventsReply {
+            xi_reply_type: reply.xi_reply_type,
+            num_this_classes: reply.num_this_classes,
+            num_all_classes: reply.num_all_classes,
+            this_classes: std::slice::from_raw_parts(xcb_system::xcb_input_get_selected_extension_events_this_classes(reply.as_ptr()) as *const EventClass, xcb_system::xcb_input_get_selected_extension_events_this_classes_length(reply.as_ptr()) as usize).to_vec(),
+            all_classes: std::slice::from_raw_parts(xcb_system::xcb_input_get_selected_extension_events_all_classes(reply.as_ptr()) as *const EventClass, xcb_system::xcb_input_get_selected_extension_events_all_classes_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_selected_extension_events<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, GetSelectedExtensionEventsRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_get_selected_extension_events(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, GetSelectedExtensionEventsRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PropagateMode(pub u32);
+
+impl PropagateMode {
+    pub const ADD_TO_LIST: Self = Self(0);
+    pub const DELETE_FROM_LIST: Self = Self(1);
+}
+
+pub fn change_device_dont_propagate_list<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, mode: u8, classes: &[EventClass]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_change_device_dont_propagate_list_checked(connection.get_connection(), window, classes.len() as _, mode, classes.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_device_dont_propagate_list_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, mode: u8, classes: &[EventClass]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_change_device_dont_propagate_list(connection.get_connection(), window, classes.len() as _, mode, classes.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetDeviceDontPropagateListReply {
+    pub xi_reply_type: u8,
+    pub num_classes: u16,
+    pub classes: Vec<EventClass>,
+}
+
+pub struct GetDeviceDontPropagateListRequest;
+
+impl crate::result::Request for GetDeviceDontPropagateListRequest {
+    type Reply = GetDeviceDontPropagateListReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetDeviceDontPropagateListReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_get_device_dont_propagate_list_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetDeviceDontPropagateListReply {
+            xi_reply_type: reply.xi_reply_type,
+            num_classes: reply.num_classes,
+            classes: std::slice::from_raw_parts(xcb_system::xcb_input_get_device_dont_propagate_list_classes(reply.as_ptr()) as *const EventClass, xcb_system::xcb_input_get_device_dont_propagate_list_classes_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_device_dont_propagate_list<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, GetDeviceDontPropagateListRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_get_device_dont_propagate_list(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, GetDeviceDontPropagateListRequest, connection)
+}
+
+// DeviceTimeCoord isn't generated, axisvalues has a variable length.
+
+// GetDeviceMotionEvents isn't generated, DeviceTimeCoord has a variable size.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChangeKeyboardDeviceReply {
+    pub xi_reply_type: u8,
+    pub status: u8,
+}
+
+pub struct ChangeKeyboardDeviceRequest;
+
+impl crate::result::Request for ChangeKeyboardDeviceRequest {
+    type Reply = ChangeKeyboardDeviceReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<ChangeKeyboardDeviceReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_change_keyboard_device_reply_t>(connection, sequence)?;
+
+        Ok(ChangeKeyboardDeviceReply {
+            xi_reply_type: reply.xi_reply_type,
+            status: reply.status,
+        })
+    }
+}
+
+pub fn change_keyboard_device<'a>(connection: &'a crate::connection::Connection, device_id: u8) -> crate::result::Cookie<'a, ChangeKeyboardDeviceRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_change_keyboard_device(connection.get_connection(), device_id) };
+
+    crate::result::Cookie::new(cookie.sequence, ChangeKeyboardDeviceRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChangePointerDeviceReply {
+    pub xi_reply_type: u8,
+    pub status: u8,
+}
+
+pub struct ChangePointerDeviceRequest;
+
+impl crate::result::Request for ChangePointerDeviceRequest {
+    type Reply = ChangePointerDeviceReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<ChangePointerDeviceReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_change_pointer_device_reply_t>(connection, sequence)?;
+
+        Ok(ChangePointerDeviceReply {
+            xi_reply_type: reply.xi_reply_type,
+            status: reply.status,
+        })
+    }
+}
+
+pub fn change_pointer_device<'a>(connection: &'a crate::connection::Connection, x_axis: u8, y_axis: u8, device_id: u8) -> crate::result::Cookie<'a, ChangePointerDeviceRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_change_pointer_device(connection.get_connection(), x_axis, y_axis, device_id) };
+
+    crate::result::Cookie::new(cookie.sequence, ChangePointerDeviceRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrabDeviceReply {
+    pub xi_reply_type: u8,
+    pub status: u8,
+}
+
+pub struct GrabDeviceRequest;
+
+impl crate::result::Request for GrabDeviceRequest {
+    type Reply = GrabDeviceReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GrabDeviceReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_grab_device_reply_t>(connection, sequence)?;
+
+        Ok(GrabDeviceReply {
+            xi_reply_type: reply.xi_reply_type,
+            status: reply.status,
+        })
+    }
+}
+
+pub fn grab_device<'a>(connection: &'a crate::connection::Connection, grab_window: super::xproto::Window, time: super::xproto::Timestamp, this_device_mode: u8, other_device_mode: u8, owner_events: bool, device_id: u8, classes: &[EventClass]) -> crate::result::Cookie<'a, GrabDeviceRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_grab_device(connection.get_connection(), grab_window, time, classes.len() as _, this_device_mode, other_device_mode, u8::from(owner_events), device_id, classes.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, GrabDeviceRequest, connection)
+}
+
+pub fn ungrab_device<'a>(connection: &'a crate::connection::Connection, time: super::xproto::Timestamp, device_id: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_ungrab_device_checked(connection.get_connection(), time, device_id) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn ungrab_device_unchecked<'a>(connection: &'a crate::connection::Connection, time: super::xproto::Timestamp, device_id: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_ungrab_device(connection.get_connection(), time, device_id) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ModifierDevice(pub u32);
+
+impl ModifierDevice {
+    pub const USE_X_KEYBOARD: Self = Self(255);
+}
+
+pub fn grab_device_key<'a>(connection: &'a crate::connection::Connection, grab_window: super::xproto::Window, modifiers: u16, modifier_device: u8, grabbed_device: u8, key: u8, this_device_mode: u8, other_device_mode: u8, owner_events: bool, classes: &[EventClass]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_grab_device_key_checked(connection.get_connection(), grab_window, classes.len() as _, modifiers, modifier_device, grabbed_device, key, this_device_mode, other_device_mode, u8::from(owner_events), classes.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn grab_device_key_unchecked<'a>(connection: &'a crate::connection::Connection, grab_window: super::xproto::Window, modifiers: u16, modifier_device: u8, grabbed_device: u8, key: u8, this_device_mode: u8, other_device_mode: u8, owner_events: bool, classes: &[EventClass]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_grab_device_key(connection.get_connection(), grab_window, classes.len() as _, modifiers, modifier_device, grabbed_device, key, this_device_mode, other_device_mode, u8::from(owner_events), classes.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn ungrab_device_key<'a>(connection: &'a crate::connection::Connection, grabWindow: super::xproto::Window, modifiers: u16, modifier_device: u8, key: u8, grabbed_device: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_ungrab_device_key_checked(connection.get_connection(), grabWindow, modifiers, modifier_device, key, grabbed_device) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn ungrab_device_key_unchecked<'a>(connection: &'a crate::connection::Connection, grabWindow: super::xproto::Window, modifiers: u16, modifier_device: u8, key: u8, grabbed_device: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_ungrab_device_key(connection.get_connection(), grabWindow, modifiers, modifier_device, key, grabbed_device) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn grab_device_button<'a>(connection: &'a crate::connection::Connection, grab_window: super::xproto::Window, grabbed_device: u8, modifier_device: u8, modifiers: u16, this_device_mode: u8, other_device_mode: u8, button: u8, owner_events: bool, classes: &[EventClass]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_grab_device_button_checked(connection.get_connection(), grab_window, grabbed_device, modifier_device, classes.len() as _, modifiers, this_device_mode, other_device_mode, button, u8::from(owner_events), classes.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn grab_device_button_unchecked<'a>(connection: &'a crate::connection::Connection, grab_window: super::xproto::Window, grabbed_device: u8, modifier_device: u8, modifiers: u16, this_device_mode: u8, other_device_mode: u8, button: u8, owner_events: bool, classes: &[EventClass]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_grab_device_button(connection.get_connection(), grab_window, grabbed_device, modifier_device, classes.len() as _, modifiers, this_device_mode, other_device_mode, button, u8::from(owner_events), classes.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn ungrab_device_button<'a>(connection: &'a crate::connection::Connection, grab_window: super::xproto::Window, modifiers: u16, modifier_device: u8, button: u8, grabbed_device: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_ungrab_device_button_checked(connection.get_connection(), grab_window, modifiers, modifier_device, button, grabbed_device) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn ungrab_device_button_unchecked<'a>(connection: &'a crate::connection::Connection, grab_window: super::xproto::Window, modifiers: u16, modifier_device: u8, button: u8, grabbed_device: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_ungrab_device_button(connection.get_connection(), grab_window, modifiers, modifier_device, button, grabbed_device) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceInputMode(pub u32);
+
+impl DeviceInputMode {
+    pub const ASYNC_THIS_DEVICE: Self = Self(0);
+    pub const SYNC_THIS_DEVICE: Self = Self(1);
+    pub const REPLAY_THIS_DEVICE: Self = Self(2);
+    pub const ASYNC_OTHER_DEVICES: Self = Self(3);
+    pub const ASYNC_ALL: Self = Self(4);
+    pub const SYNC_ALL: Self = Self(5);
+}
+
+pub fn allow_device_events<'a>(connection: &'a crate::connection::Connection, time: super::xproto::Timestamp, mode: u8, device_id: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_allow_device_events_checked(connection.get_connection(), time, mode, device_id) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn allow_device_events_unchecked<'a>(connection: &'a crate::connection::Connection, time: super::xproto::Timestamp, mode: u8, device_id: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_allow_device_events(connection.get_connection(), time, mode, device_id) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetDeviceFocusReply {
+    pub xi_reply_type: u8,
+    pub focus: super::xproto::Window,
+    pub time: super::xproto::Timestamp,
+    pub revert_to: u8,
+}
+
+pub struct GetDeviceFocusRequest;
+
+impl crate::result::Request for GetDeviceFocusRequest {
+    type Reply = GetDeviceFocusReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetDeviceFocusReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_get_device_focus_reply_t>(connection, sequence)?;
+
+        Ok(GetDeviceFocusReply {
+            xi_reply_type: reply.xi_reply_type,
+            focus: reply.focus,
+            time: reply.time,
+            revert_to: reply.revert_to,
+        })
+    }
+}
+
+pub fn get_device_focus<'a>(connection: &'a crate::connection::Connection, device_id: u8) -> crate::result::Cookie<'a, GetDeviceFocusRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_get_device_focus(connection.get_connection(), device_id) };
+
+    crate::result::Cookie::new(cookie.sequence, GetDeviceFocusRequest, connection)
+}
+
+pub fn set_device_focus<'a>(connection: &'a crate::connection::Connection, focus: super::xproto::Window, time: super::xproto::Timestamp, revert_to: u8, device_id: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_set_device_focus_checked(connection.get_connection(), focus, time, revert_to, device_id) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_device_focus_unchecked<'a>(connection: &'a crate::connection::Connection, focus: super::xproto::Window, time: super::xproto::Timestamp, revert_to: u8, device_id: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_set_device_focus(connection.get_connection(), focus, time, revert_to, device_id) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FeedbackClass(pub u32);
+
+impl FeedbackClass {
+    pub const KEYBOARD: Self = Self(0);
+    pub const POINTER: Self = Self(1);
+    pub const STRING: Self = Self(2);
+    pub const INTEGER: Self = Self(3);
+    pub const LED: Self = Self(4);
+    pub const BELL: Self = Self(5);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KbdFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub pitch: u16,
+    pub duration: u16,
+    pub led_mask: u32,
+    pub led_values: u32,
+    pub global_auto_repeat: bool,
+    pub click: u8,
+    pub percent: u8,
+    pub auto_repeats: [u8; 32],
+}
+
+impl From<xcb_system::xcb_input_kbd_feedback_state_t> for KbdFeedbackState {
+    fn from(raw: xcb_system::xcb_input_kbd_feedback_state_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            feedback_id: raw.feedback_id,
+            len: raw.len,
+            pitch: raw.pitch,
+            duration: raw.duration,
+            led_mask: raw.led_mask,
+            led_values: raw.led_values,
+            global_auto_repeat: raw.global_auto_repeat != 0,
+            click: raw.click,
+            percent: raw.percent,
+            auto_repeats: raw.auto_repeats,
+        }
+    }
+}
+
+impl From<KbdFeedbackState> for xcb_system::xcb_input_kbd_feedback_state_t {
+    fn from(value: KbdFeedbackState) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.feedback_id = value.feedback_id;
+        raw.len = value.len;
+        raw.pitch = value.pitch;
+        raw.duration = value.duration;
+        raw.led_mask = value.led_mask;
+        raw.led_values = value.led_values;
+        raw.global_auto_repeat = u8::from(value.global_auto_repeat);
+        raw.click = value.click;
+        raw.percent = value.percent;
+        raw.auto_repeats = value.auto_repeats.map(|value| value);
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PtrFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub accel_num: u16,
+    pub accel_denom: u16,
+    pub threshold: u16,
+}
+
+impl From<xcb_system::xcb_input_ptr_feedback_state_t> for PtrFeedbackState {
+    fn from(raw: xcb_system::xcb_input_ptr_feedback_state_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            feedback_id: raw.feedback_id,
+            len: raw.len,
+            accel_num: raw.accel_num,
+            accel_denom: raw.accel_denom,
+            threshold: raw.threshold,
+        }
+    }
+}
+
+impl From<PtrFeedbackState> for xcb_system::xcb_input_ptr_feedback_state_t {
+    fn from(value: PtrFeedbackState) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.feedback_id = value.feedback_id;
+        raw.len = value.len;
+        raw.accel_num = value.accel_num;
+        raw.accel_denom = value.accel_denom;
+        raw.threshold = value.threshold;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IntegerFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub resolution: u32,
+    pub min_value: i32,
+    pub max_value: i32,
+}
+
+impl From<xcb_system::xcb_input_integer_feedback_state_t> for IntegerFeedbackState {
+    fn from(raw: xcb_system::xcb_input_integer_feedback_state_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            feedback_id: raw.feedback_id,
+            len: raw.len,
+            resolution: raw.resolution,
+            min_value: raw.min_value,
+            max_value: raw.max_value,
+        }
+    }
+}
+
+impl From<IntegerFeedbackState> for xcb_system::xcb_input_integer_feedback_state_t {
+    fn from(value: IntegerFeedbackState) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.feedback_id = value.feedback_id;
+        raw.len = value.len;
+        raw.resolution = value.resolution;
+        raw.min_value = value.min_value;
+        raw.max_value = value.max_value;
+
+        raw
+    }
+}
+
+// StringFeedbackState isn't generated, keysyms has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BellFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub percent: u8,
+    pub pitch: u16,
+    pub duration: u16,
+}
+
+impl From<xcb_system::xcb_input_bell_feedback_state_t> for BellFeedbackState {
+    fn from(raw: xcb_system::xcb_input_bell_feedback_state_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            feedback_id: raw.feedback_id,
+            len: raw.len,
+            percent: raw.percent,
+            pitch: raw.pitch,
+            duration: raw.duration,
+        }
+    }
+}
+
+impl From<BellFeedbackState> for xcb_system::xcb_input_bell_feedback_state_t {
+    fn from(value: BellFeedbackState) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.feedback_id = value.feedback_id;
+        raw.len = value.len;
+        raw.percent = value.percent;
+        raw.pitch = value.pitch;
+        raw.duration = value.duration;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LedFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub led_mask: u32,
+    pub led_values: u32,
+}
+
+impl From<xcb_system::xcb_input_led_feedback_state_t> for LedFeedbackState {
+    fn from(raw: xcb_system::xcb_input_led_feedback_state_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            feedback_id: raw.feedback_id,
+            len: raw.len,
+            led_mask: raw.led_mask,
+            led_values: raw.led_values,
+        }
+    }
+}
+
+impl From<LedFeedbackState> for xcb_system::xcb_input_led_feedback_state_t {
+    fn from(value: LedFeedbackState) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.feedback_id = value.feedback_id;
+        raw.len = value.len;
+        raw.led_mask = value.led_mask;
+        raw.led_values = value.led_values;
+
+        raw
+    }
+}
+
+// FeedbackState isn't generated, it has an case field.
+
+// GetFeedbackControl isn't generated, FeedbackState has a variable size.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KbdFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub key: KeyCode,
+    pub auto_repeat_mode: u8,
+    pub key_click_percent: i8,
+    pub bell_percent: i8,
+    pub bell_pitch: i16,
+    pub bell_duration: i16,
+    pub led_mask: u32,
+    pub led_values: u32,
+}
+
+impl From<xcb_system::xcb_input_kbd_feedback_ctl_t> for KbdFeedbackCtl {
+    fn from(raw: xcb_system::xcb_input_kbd_feedback_ctl_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            feedback_id: raw.feedback_id,
+            len: raw.len,
+            key: raw.key,
+            auto_repeat_mode: raw.auto_repeat_mode,
+            key_click_percent: raw.key_click_percent,
+            bell_percent: raw.bell_percent,
+            bell_pitch: raw.bell_pitch,
+            bell_duration: raw.bell_duration,
+            led_mask: raw.led_mask,
+            led_values: raw.led_values,
+        }
+    }
+}
+
+impl From<KbdFeedbackCtl> for xcb_system::xcb_input_kbd_feedback_ctl_t {
+    fn from(value: KbdFeedbackCtl) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.feedback_id = value.feedback_id;
+        raw.len = value.len;
+        raw.key = value.key;
+        raw.auto_repeat_mode = value.auto_repeat_mode;
+        raw.key_click_percent = value.key_click_percent;
+        raw.bell_percent = value.bell_percent;
+        raw.bell_pitch = value.bell_pitch;
+        raw.bell_duration = value.bell_duration;
+        raw.led_mask = value.led_mask;
+        raw.led_values = value.led_values;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PtrFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub num: i16,
+    pub denom: i16,
+    pub threshold: i16,
+}
+
+impl From<xcb_system::xcb_input_ptr_feedback_ctl_t> for PtrFeedbackCtl {
+    fn from(raw: xcb_system::xcb_input_ptr_feedback_ctl_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            feedback_id: raw.feedback_id,
+            len: raw.len,
+            num: raw.num,
+            denom: raw.denom,
+            threshold: raw.threshold,
+        }
+    }
+}
+
+impl From<PtrFeedbackCtl> for xcb_system::xcb_input_ptr_feedback_ctl_t {
+    fn from(value: PtrFeedbackCtl) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.feedback_id = value.feedback_id;
+        raw.len = value.len;
+        raw.num = value.num;
+        raw.denom = value.denom;
+        raw.threshold = value.threshold;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IntegerFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub int_to_display: i32,
+}
+
+impl From<xcb_system::xcb_input_integer_feedback_ctl_t> for IntegerFeedbackCtl {
+    fn from(raw: xcb_system::xcb_input_integer_feedback_ctl_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            feedback_id: raw.feedback_id,
+            len: raw.len,
+            int_to_display: raw.int_to_display,
+        }
+    }
+}
+
+impl From<IntegerFeedbackCtl> for xcb_system::xcb_input_integer_feedback_ctl_t {
+    fn from(value: IntegerFeedbackCtl) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.feedback_id = value.feedback_id;
+        raw.len = value.len;
+        raw.int_to_display = value.int_to_display;
+
+        raw
+    }
+}
+
+// StringFeedbackCtl isn't generated, keysyms has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BellFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub percent: i8,
+    pub pitch: i16,
+    pub duration: i16,
+}
+
+impl From<xcb_system::xcb_input_bell_feedback_ctl_t> for BellFeedbackCtl {
+    fn from(raw: xcb_system::xcb_input_bell_feedback_ctl_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            feedback_id: raw.feedback_id,
+            len: raw.len,
+            percent: raw.percent,
+            pitch: raw.pitch,
+            duration: raw.duration,
+        }
+    }
+}
+
+impl From<BellFeedbackCtl> for xcb_system::xcb_input_bell_feedback_ctl_t {
+    fn from(value: BellFeedbackCtl) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.feedback_id = value.feedback_id;
+        raw.len = value.len;
+        raw.percent = value.percent;
+        raw.pitch = value.pitch;
+        raw.duration = value.duration;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LedFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub led_mask: u32,
+    pub led_values: u32,
+}
+
+impl From<xcb_system::xcb_input_led_feedback_ctl_t> for LedFeedbackCtl {
+    fn from(raw: xcb_system::xcb_input_led_feedback_ctl_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            feedback_id: raw.feedback_id,
+            len: raw.len,
+            led_mask: raw.led_mask,
+            led_values: raw.led_values,
+        }
+    }
+}
+
+impl From<LedFeedbackCtl> for xcb_system::xcb_input_led_feedback_ctl_t {
+    fn from(value: LedFeedbackCtl) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.feedback_id = value.feedback_id;
+        raw.len = value.len;
+        raw.led_mask = value.led_mask;
+        raw.led_values = value.led_values;
+
+        raw
+    }
+}
+
+// FeedbackCtl isn't generated, it has an case field.
+
+bitflags! {
+    pub struct ChangeFeedbackControlMask: u32 {
+        const KEY_CLICK_PERCENT = 0x1;
+        const PERCENT = 0x2;
+        const PITCH = 0x4;
+        const DURATION = 0x8;
+        const LED = 0x10;
+        const LED_MODE = 0x20;
+        const KEY = 0x40;
+        const AUTO_REPEAT_MODE = 0x80;
+        const STRING = 0x1;
+        const INTEGER = 0x1;
+        const ACCEL_NUM = 0x1;
+        const ACCEL_DENOM = 0x2;
+        const THRESHOLD = 0x4;
+    }
+}
+
+// ChangeFeedbackControl isn't generated, FeedbackCtl has a variable size.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetDeviceKeyMappingReply {
+    pub xi_reply_type: u8,
+    pub keysyms_per_keycode: u8,
+    pub keysyms: Vec<super::xproto::Keysym>,
+}
+
+pub struct GetDeviceKeyMappingRequest;
+
+impl crate::result::Request for GetDeviceKeyMappingRequest {
+    type Reply = GetDeviceKeyMappingReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetDeviceKeyMappingReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_get_device_key_mapping_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetDeviceKeyMappingReply {
+            xi_reply_type: reply.xi_reply_type,
+            keysyms_per_keycode: reply.keysyms_per_keycode,
+            keysyms: std::slice::from_raw_parts(xcb_system::xcb_input_get_device_key_mapping_keysyms(reply.as_ptr()) as *const super::xproto::Keysym, xcb_system::xcb_input_get_device_key_mapping_keysyms_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_device_key_mapping<'a>(connection: &'a crate::connection::Connection, device_id: u8, first_keycode: KeyCode, count: u8) -> crate::result::Cookie<'a, GetDeviceKeyMappingRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_get_device_key_mapping(connection.get_connection(), device_id, first_keycode, count) };
+
+    crate::result::Cookie::new(cookie.sequence, GetDeviceKeyMappingRequest, connection)
+}
+
+pub fn change_device_key_mapping<'a>(connection: &'a crate::connection::Connection, device_id: u8, first_keycode: KeyCode, keysyms_per_keycode: u8, keycode_count: u8, keysyms: &[super::xproto::Keysym]) -> crate::result::Cookie<'a, crate::result::Void> {
+    assert!(keysyms.len() >= ((keycode_count as usize) * (keysyms_per_keycode as usize)) as usize);
+
+    let cookie = unsafe { xcb_system::xcb_input_change_device_key_mapping_checked(connection.get_connection(), device_id, first_keycode, keysyms_per_keycode, keycode_count, keysyms.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_device_key_mapping_unchecked<'a>(connection: &'a crate::connection::Connection, device_id: u8, first_keycode: KeyCode, keysyms_per_keycode: u8, keycode_count: u8, keysyms: &[super::xproto::Keysym]) -> crate::result::UncheckedCookie {
+    assert!(keysyms.len() >= ((keycode_count as usize) * (keysyms_per_keycode as usize)) as usize);
+
+    let cookie = unsafe { xcb_system::xcb_input_change_device_key_mapping(connection.get_connection(), device_id, first_keycode, keysyms_per_keycode, keycode_count, keysyms.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetDeviceModifierMappingReply {
+    pub xi_reply_type: u8,
+    pub keycodes_per_modifier: u8,
+    pub keymaps: Vec<u8>,
+}
+
+pub struct GetDeviceModifierMappingRequest;
+
+impl crate::result::Request for GetDeviceModifierMappingRequest {
+    type Reply = GetDeviceModifierMappingReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetDeviceModifierMappingReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_get_device_modifier_mapping_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetDeviceModifierMappingReply {
+            xi_reply_type: reply.xi_reply_type,
+            keycodes_per_modifier: reply.keycodes_per_modifier,
+            keymaps: std::slice::from_raw_parts(xcb_system::xcb_input_get_device_modifier_mapping_keymaps(reply.as_ptr()) as *const u8, xcb_system::xcb_input_get_device_modifier_mapping_keymaps_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_device_modifier_mapping<'a>(connection: &'a crate::connection::Connection, device_id: u8) -> crate::result::Cookie<'a, GetDeviceModifierMappingRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_get_device_modifier_mapping(connection.get_connection(), device_id) };
+
+    crate::result::Cookie::new(cookie.sequence, GetDeviceModifierMappingRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetDeviceModifierMappingReply {
+    pub xi_reply_type: u8,
+    pub status: u8,
+}
+
+pub struct SetDeviceModifierMappingRequest;
+
+impl crate::result::Request for SetDeviceModifierMappingRequest {
+    type Reply = SetDeviceModifierMappingReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<SetDeviceModifierMappingReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_set_device_modifier_mapping_reply_t>(connection, sequence)?;
+
+        Ok(SetDeviceModifierMappingReply {
+            xi_reply_type: reply.xi_reply_type,
+            status: reply.status,
+        })
+    }
+}
+
+pub fn set_device_modifier_mapping<'a>(connection: &'a crate::connection::Connection, device_id: u8, keycodes_per_modifier: u8, keymaps: &[u8]) -> crate::result::Cookie<'a, SetDeviceModifierMappingRequest> {
+    assert!(keymaps.len() >= ((keycodes_per_modifier as usize) * 8) as usize);
+
+    let cookie = unsafe { xcb_system::xcb_input_set_device_modifier_mapping(connection.get_connection(), device_id, keycodes_per_modifier, keymaps.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, SetDeviceModifierMappingRequest, connection)
+}
+
+// GetDeviceButtonMapping isn't generated, it has alignment pads.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetDeviceButtonMappingReply {
+    pub xi_reply_type: u8,
+    pub status: u8,
+}
+
+pub struct SetDeviceButtonMappingRequest;
+
+impl crate::result::Request for SetDeviceButtonMappingRequest {
+    type Reply = SetDeviceButtonMappingReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<SetDeviceButtonMappingReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_set_device_button_mapping_reply_t>(connection, sequence)?;
+
+        Ok(SetDeviceButtonMappingReply {
+            xi_reply_type: reply.xi_reply_type,
+            status: reply.status,
+        })
+    }
+}
+
+pub fn set_device_button_mapping<'a>(connection: &'a crate::connection::Connection, device_id: u8, map: &[u8]) -> crate::result::Cookie<'a, SetDeviceButtonMappingRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_set_device_button_mapping(connection.get_connection(), device_id, map.len() as _, map.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, SetDeviceButtonMappingRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KeyState {
+    pub class_id: u8,
+    pub len: u8,
+    pub num_keys: u8,
+    pub keys: [u8; 32],
+}
+
+impl From<xcb_system::xcb_input_key_state_t> for KeyState {
+    fn from(raw: xcb_system::xcb_input_key_state_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            len: raw.len,
+            num_keys: raw.num_keys,
+            keys: raw.keys,
+        }
+    }
+}
+
+impl From<KeyState> for xcb_system::xcb_input_key_state_t {
+    fn from(value: KeyState) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.len = value.len;
+        raw.num_keys = value.num_keys;
+        raw.keys = value.keys.map(|value| value);
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ButtonState {
+    pub class_id: u8,
+    pub len: u8,
+    pub num_buttons: u8,
+    pub buttons: [u8; 32],
+}
+
+impl From<xcb_system::xcb_input_button_state_t> for ButtonState {
+    fn from(raw: xcb_system::xcb_input_button_state_t) -> Self {
+        Self {
+            class_id: raw.class_id,
+            len: raw.len,
+            num_buttons: raw.num_buttons,
+            buttons: raw.buttons,
+        }
+    }
+}
+
+impl From<ButtonState> for xcb_system::xcb_input_button_state_t {
+    fn from(value: ButtonState) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.class_id = value.class_id;
+        raw.len = value.len;
+        raw.num_buttons = value.num_buttons;
+        raw.buttons = value.buttons.map(|value| value);
+
+        raw
+    }
+}
+
+bitflags! {
+    pub struct ValuatorStateModeMask: u32 {
+        const DEVICE_MODE_ABSOLUTE = 0x1;
+        const OUT_OF_PROXIMITY = 0x2;
+    }
+}
+
+// ValuatorState isn't generated, valuators has a variable length.
+
+// InputState isn't generated, it has an case field.
+
+// QueryDeviceState isn't generated, InputState has a variable size.
+
+pub fn device_bell<'a>(connection: &'a crate::connection::Connection, device_id: u8, feedback_id: u8, feedback_class: u8, percent: i8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_device_bell_checked(connection.get_connection(), device_id, feedback_id, feedback_class, percent) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn device_bell_unchecked<'a>(connection: &'a crate::connection::Connection, device_id: u8, feedback_id: u8, feedback_class: u8, percent: i8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_device_bell(connection.get_connection(), device_id, feedback_id, feedback_class, percent) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetDeviceValuatorsReply {
+    pub xi_reply_type: u8,
+    pub status: u8,
+}
+
+pub struct SetDeviceValuatorsRequest;
+
+impl crate::result::Request for SetDeviceValuatorsRequest {
+    type Reply = SetDeviceValuatorsReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<SetDeviceValuatorsReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_set_device_valuators_reply_t>(connection, sequence)?;
+
+        Ok(SetDeviceValuatorsReply {
+            xi_reply_type: reply.xi_reply_type,
+            status: reply.status,
+        })
+    }
+}
+
+pub fn set_device_valuators<'a>(connection: &'a crate::connection::Connection, device_id: u8, first_valuator: u8, valuators: &[i32]) -> crate::result::Cookie<'a, SetDeviceValuatorsRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_set_device_valuators(connection.get_connection(), device_id, first_valuator, valuators.len() as _, valuators.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, SetDeviceValuatorsRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceControl(pub u32);
+
+impl DeviceControl {
+    pub const RESOLUTION: Self = Self(1);
+    pub const ABS_CALIB: Self = Self(2);
+    pub const CORE: Self = Self(3);
+    pub const ENABLE: Self = Self(4);
+    pub const ABS_AREA: Self = Self(5);
+}
+
+// DeviceResolutionState isn't generated, resolution_values has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceAbsCalibState {
+    pub control_id: u16,
+    pub len: u16,
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+    pub flip_x: u32,
+    pub flip_y: u32,
+    pub rotation: u32,
+    pub button_threshold: u32,
+}
+
+impl From<xcb_system::xcb_input_device_abs_calib_state_t> for DeviceAbsCalibState {
+    fn from(raw: xcb_system::xcb_input_device_abs_calib_state_t) -> Self {
+        Self {
+            control_id: raw.control_id,
+            len: raw.len,
+            min_x: raw.min_x,
+            max_x: raw.max_x,
+            min_y: raw.min_y,
+            max_y: raw.max_y,
+            flip_x: raw.flip_x,
+            flip_y: raw.flip_y,
+            rotation: raw.rotation,
+            button_threshold: raw.button_threshold,
+        }
+    }
+}
+
+impl From<DeviceAbsCalibState> for xcb_system::xcb_input_device_abs_calib_state_t {
+    fn from(value: DeviceAbsCalibState) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.control_id = value.control_id;
+        raw.len = value.len;
+        raw.min_x = value.min_x;
+        raw.max_x = value.max_x;
+        raw.min_y = value.min_y;
+        raw.max_y = value.max_y;
+        raw.flip_x = value.flip_x;
+        raw.flip_y = value.flip_y;
+        raw.rotation = value.rotation;
+        raw.button_threshold = value.button_threshold;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceAbsAreaState {
+    pub control_id: u16,
+    pub len: u16,
+    pub offset_x: u32,
+    pub offset_y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub screen: u32,
+    pub following: u32,
+}
+
+impl From<xcb_system::xcb_input_device_abs_area_state_t> for DeviceAbsAreaState {
+    fn from(raw: xcb_system::xcb_input_device_abs_area_state_t) -> Self {
+        Self {
+            control_id: raw.control_id,
+            len: raw.len,
+            offset_x: raw.offset_x,
+            offset_y: raw.offset_y,
+            width: raw.width,
+            height: raw.height,
+            screen: raw.screen,
+            following: raw.following,
+        }
+    }
+}
+
+impl From<DeviceAbsAreaState> for xcb_system::xcb_input_device_abs_area_state_t {
+    fn from(value: DeviceAbsAreaState) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.control_id = value.control_id;
+        raw.len = value.len;
+        raw.offset_x = value.offset_x;
+        raw.offset_y = value.offset_y;
+        raw.width = value.width;
+        raw.height = value.height;
+        raw.screen = value.screen;
+        raw.following = value.following;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceCoreState {
+    pub control_id: u16,
+    pub len: u16,
+    pub status: u8,
+    pub iscore: u8,
+}
+
+impl From<xcb_system::xcb_input_device_core_state_t> for DeviceCoreState {
+    fn from(raw: xcb_system::xcb_input_device_core_state_t) -> Self {
+        Self {
+            control_id: raw.control_id,
+            len: raw.len,
+            status: raw.status,
+            iscore: raw.iscore,
+        }
+    }
+}
+
+impl From<DeviceCoreState> for xcb_system::xcb_input_device_core_state_t {
+    fn from(value: DeviceCoreState) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.control_id = value.control_id;
+        raw.len = value.len;
+        raw.status = value.status;
+        raw.iscore = value.iscore;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceEnableState {
+    pub control_id: u16,
+    pub len: u16,
+    pub enable: u8,
+}
+
+impl From<xcb_system::xcb_input_device_enable_state_t> for DeviceEnableState {
+    fn from(raw: xcb_system::xcb_input_device_enable_state_t) -> Self {
+        Self {
+            control_id: raw.control_id,
+            len: raw.len,
+            enable: raw.enable,
+        }
+    }
+}
+
+impl From<DeviceEnableState> for xcb_system::xcb_input_device_enable_state_t {
+    fn from(value: DeviceEnableState) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.control_id = value.control_id;
+        raw.len = value.len;
+        raw.enable = value.enable;
+
+        raw
+    }
+}
+
+// DeviceState isn't generated, it has an case field.
+
+// GetDeviceControl isn't generated, DeviceState has a variable size.
+
+// DeviceResolutionCtl isn't generated, resolution_values has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceAbsCalibCtl {
+    pub control_id: u16,
+    pub len: u16,
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+    pub flip_x: u32,
+    pub flip_y: u32,
+    pub rotation: u32,
+    pub button_threshold: u32,
+}
+
+impl From<xcb_system::xcb_input_device_abs_calib_ctl_t> for DeviceAbsCalibCtl {
+    fn from(raw: xcb_system::xcb_input_device_abs_calib_ctl_t) -> Self {
+        Self {
+            control_id: raw.control_id,
+            len: raw.len,
+            min_x: raw.min_x,
+            max_x: raw.max_x,
+            min_y: raw.min_y,
+            max_y: raw.max_y,
+            flip_x: raw.flip_x,
+            flip_y: raw.flip_y,
+            rotation: raw.rotation,
+            button_threshold: raw.button_threshold,
+        }
+    }
+}
+
+impl From<DeviceAbsCalibCtl> for xcb_system::xcb_input_device_abs_calib_ctl_t {
+    fn from(value: DeviceAbsCalibCtl) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.control_id = value.control_id;
+        raw.len = value.len;
+        raw.min_x = value.min_x;
+        raw.max_x = value.max_x;
+        raw.min_y = value.min_y;
+        raw.max_y = value.max_y;
+        raw.flip_x = value.flip_x;
+        raw.flip_y = value.flip_y;
+        raw.rotation = value.rotation;
+        raw.button_threshold = value.button_threshold;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceAbsAreaCtrl {
+    pub control_id: u16,
+    pub len: u16,
+    pub offset_x: u32,
+    pub offset_y: u32,
+    pub width: i32,
+    pub height: i32,
+    pub screen: i32,
+    pub following: u32,
+}
+
+impl From<xcb_system::xcb_input_device_abs_area_ctrl_t> for DeviceAbsAreaCtrl {
+    fn from(raw: xcb_system::xcb_input_device_abs_area_ctrl_t) -> Self {
+        Self {
+            control_id: raw.control_id,
+            len: raw.len,
+            offset_x: raw.offset_x,
+            offset_y: raw.offset_y,
+            width: raw.width,
+            height: raw.height,
+            screen: raw.screen,
+            following: raw.following,
+        }
+    }
+}
+
+impl From<DeviceAbsAreaCtrl> for xcb_system::xcb_input_device_abs_area_ctrl_t {
+    fn from(value: DeviceAbsAreaCtrl) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.control_id = value.control_id;
+        raw.len = value.len;
+        raw.offset_x = value.offset_x;
+        raw.offset_y = value.offset_y;
+        raw.width = value.width;
+        raw.height = value.height;
+        raw.screen = value.screen;
+        raw.following = value.following;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceCoreCtrl {
+    pub control_id: u16,
+    pub len: u16,
+    pub status: u8,
+}
+
+impl From<xcb_system::xcb_input_device_core_ctrl_t> for DeviceCoreCtrl {
+    fn from(raw: xcb_system::xcb_input_device_core_ctrl_t) -> Self {
+        Self {
+            control_id: raw.control_id,
+            len: raw.len,
+            status: raw.status,
+        }
+    }
+}
+
+impl From<DeviceCoreCtrl> for xcb_system::xcb_input_device_core_ctrl_t {
+    fn from(value: DeviceCoreCtrl) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.control_id = value.control_id;
+        raw.len = value.len;
+        raw.status = value.status;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceEnableCtrl {
+    pub control_id: u16,
+    pub len: u16,
+    pub enable: u8,
+}
+
+impl From<xcb_system::xcb_input_device_enable_ctrl_t> for DeviceEnableCtrl {
+    fn from(raw: xcb_system::xcb_input_device_enable_ctrl_t) -> Self {
+        Self {
+            control_id: raw.control_id,
+            len: raw.len,
+            enable: raw.enable,
+        }
+    }
+}
+
+impl From<DeviceEnableCtrl> for xcb_system::xcb_input_device_enable_ctrl_t {
+    fn from(value: DeviceEnableCtrl) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.control_id = value.control_id;
+        raw.len = value.len;
+        raw.enable = value.enable;
+
+        raw
+    }
+}
+
+// DeviceCtl isn't generated, it has an case field.
+
+// ChangeDeviceControl isn't generated, DeviceCtl has a variable size.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListDevicePropertiesReply {
+    pub xi_reply_type: u8,
+    pub num_atoms: u16,
+    pub atoms: Vec<super::xproto::Atom>,
+}
+
+pub struct ListDevicePropertiesRequest;
+
+impl crate::result::Request for ListDevicePropertiesRequest {
+    type Reply = ListDevicePropertiesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<ListDevicePropertiesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_list_device_properties_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { ListDevicePropertiesReply {
+            xi_reply_type: reply.xi_reply_type,
+            num_atoms: reply.num_atoms,
+            atoms: std::slice::from_raw_parts(xcb_system::xcb_input_list_device_properties_atoms(reply.as_ptr()) as *const super::xproto::Atom, xcb_system::xcb_input_list_device_properties_atoms_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn list_device_properties<'a>(connection: &'a crate::connection::Connection, device_id: u8) -> crate::result::Cookie<'a, ListDevicePropertiesRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_list_device_properties(connection.get_connection(), device_id) };
+
+    crate::result::Cookie::new(cookie.sequence, ListDevicePropertiesRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PropertyFormat(pub u32);
+
+impl PropertyFormat {
+    pub const PROPERTY_8_BITS: Self = Self(8);
+    pub const PROPERTY_16_BITS: Self = Self(16);
+    pub const PROPERTY_32_BITS: Self = Self(32);
+}
+
+// ChangeDeviceProperty isn't generated, it has an case field.
+
+pub fn delete_device_property<'a>(connection: &'a crate::connection::Connection, property: super::xproto::Atom, device_id: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_delete_device_property_checked(connection.get_connection(), property, device_id) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn delete_device_property_unchecked<'a>(connection: &'a crate::connection::Connection, property: super::xproto::Atom, device_id: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_delete_device_property(connection.get_connection(), property, device_id) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+// GetDeviceProperty isn't generated, it has an case field.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Device(pub u32);
+
+impl Device {
+    pub const ALL: Self = Self(0);
+    pub const ALL_MASTER: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GroupInfo {
+    pub base: u8,
+    pub latched: u8,
+    pub locked: u8,
+    pub effective: u8,
+}
+
+impl From<xcb_system::xcb_input_group_info_t> for GroupInfo {
+    fn from(raw: xcb_system::xcb_input_group_info_t) -> Self {
+        Self {
+            base: raw.base,
+            latched: raw.latched,
+            locked: raw.locked,
+            effective: raw.effective,
+        }
+    }
+}
+
+impl From<GroupInfo> for xcb_system::xcb_input_group_info_t {
+    fn from(value: GroupInfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.base = value.base;
+        raw.latched = value.latched;
+        raw.locked = value.locked;
+        raw.effective = value.effective;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ModifierInfo {
+    pub base: u32,
+    pub latched: u32,
+    pub locked: u32,
+    pub effective: u32,
+}
+
+impl From<xcb_system::xcb_input_modifier_info_t> for ModifierInfo {
+    fn from(raw: xcb_system::xcb_input_modifier_info_t) -> Self {
+        Self {
+            base: raw.base,
+            latched: raw.latched,
+            locked: raw.locked,
+            effective: raw.effective,
+        }
+    }
+}
+
+impl From<ModifierInfo> for xcb_system::xcb_input_modifier_info_t {
+    fn from(value: ModifierInfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.base = value.base;
+        raw.latched = value.latched;
+        raw.locked = value.locked;
+        raw.effective = value.effective;
+
+        raw
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct XIQueryPointerReply {
+    pub root: super::xproto::Window,
+    pub child: super::xproto::Window,
+    pub root_x: Fp1616,
+    pub root_y: Fp1616,
+    pub win_x: Fp1616,
+    pub win_y: Fp1616,
+    pub same_screen: bool,
+    pub buttons_len: u16,
+    pub mods: ModifierInfo,
+    pub group: GroupInfo,
+    pub buttons: Vec<u32>,
+}
+
+pub struct XIQueryPointerRequest;
+
+impl crate::result::Request for XIQueryPointerRequest {
+    type Reply = XIQueryPointerReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<XIQueryPointerReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_xi_query_pointer_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { XIQueryPointerReply {
+            root: reply.root,
+            child: reply.child,
+            root_x: reply.root_x,
+            root_y: reply.root_y,
+            win_x: reply.win_x,
+            win_y: reply.win_y,
+            same_screen: reply.same_screen != 0,
+            buttons_len: reply.buttons_len,
+            mods: ModifierInfo::from(reply.mods),
+            group: GroupInfo::from(reply.group),
+            buttons: std::slice::from_raw_parts(xcb_system::xcb_input_xi_query_pointer_buttons(reply.as_ptr()) as *const u32, xcb_system::xcb_input_xi_query_pointer_buttons_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn xi_query_pointer<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, deviceid: DeviceId) -> crate::result::Cookie<'a, XIQueryPointerRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_query_pointer(connection.get_connection(), window, deviceid) };
+
+    crate::result::Cookie::new(cookie.sequence, XIQueryPointerRequest, connection)
+}
+
+pub fn xi_warp_pointer<'a>(connection: &'a crate::connection::Connection, src_win: super::xproto::Window, dst_win: super::xproto::Window, src_x: Fp1616, src_y: Fp1616, src_width: u16, src_height: u16, dst_x: Fp1616, dst_y: Fp1616, deviceid: DeviceId) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_warp_pointer_checked(connection.get_connection(), src_win, dst_win, src_x, src_y, src_width, src_height, dst_x, dst_y, deviceid) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn xi_warp_pointer_unchecked<'a>(connection: &'a crate::connection::Connection, src_win: super::xproto::Window, dst_win: super::xproto::Window, src_x: Fp1616, src_y: Fp1616, src_width: u16, src_height: u16, dst_x: Fp1616, dst_y: Fp1616, deviceid: DeviceId) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_xi_warp_pointer(connection.get_connection(), src_win, dst_win, src_x, src_y, src_width, src_height, dst_x, dst_y, deviceid) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn xi_change_cursor<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, cursor: super::xproto::Cursor, deviceid: DeviceId) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_change_cursor_checked(connection.get_connection(), window, cursor, deviceid) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn xi_change_cursor_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, cursor: super::xproto::Cursor, deviceid: DeviceId) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_xi_change_cursor(connection.get_connection(), window, cursor, deviceid) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HierarchyChangeType(pub u32);
+
+impl HierarchyChangeType {
+    pub const ADD_MASTER: Self = Self(1);
+    pub const REMOVE_MASTER: Self = Self(2);
+    pub const ATTACH_SLAVE: Self = Self(3);
+    pub const DETACH_SLAVE: Self = Self(4);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ChangeMode(pub u32);
+
+impl ChangeMode {
+    pub const ATTACH: Self = Self(1);
+    pub const FLOAT: Self = Self(2);
+}
+
+// AddMaster isn't generated, name has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RemoveMaster {
+    pub type_: u16,
+    pub len: u16,
+    pub deviceid: DeviceId,
+    pub return_mode: u8,
+    pub return_pointer: DeviceId,
+    pub return_keyboard: DeviceId,
+}
+
+impl From<xcb_system::xcb_input_remove_master_t> for RemoveMaster {
+    fn from(raw: xcb_system::xcb_input_remove_master_t) -> Self {
+        Self {
+            type_: raw.type_,
+            len: raw.len,
+            deviceid: raw.deviceid,
+            return_mode: raw.return_mode,
+            return_pointer: raw.return_pointer,
+            return_keyboard: raw.return_keyboard,
+        }
+    }
+}
+
+impl From<RemoveMaster> for xcb_system::xcb_input_remove_master_t {
+    fn from(value: RemoveMaster) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.len = value.len;
+        raw.deviceid = value.deviceid;
+        raw.return_mode = value.return_mode;
+        raw.return_pointer = value.return_pointer;
+        raw.return_keyboard = value.return_keyboard;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AttachSlave {
+    pub type_: u16,
+    pub len: u16,
+    pub deviceid: DeviceId,
+    pub master: DeviceId,
+}
+
+impl From<xcb_system::xcb_input_attach_slave_t> for AttachSlave {
+    fn from(raw: xcb_system::xcb_input_attach_slave_t) -> Self {
+        Self {
+            type_: raw.type_,
+            len: raw.len,
+            deviceid: raw.deviceid,
+            master: raw.master,
+        }
+    }
+}
+
+impl From<AttachSlave> for xcb_system::xcb_input_attach_slave_t {
+    fn from(value: AttachSlave) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.len = value.len;
+        raw.deviceid = value.deviceid;
+        raw.master = value.master;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DetachSlave {
+    pub type_: u16,
+    pub len: u16,
+    pub deviceid: DeviceId,
+}
+
+impl From<xcb_system::xcb_input_detach_slave_t> for DetachSlave {
+    fn from(raw: xcb_system::xcb_input_detach_slave_t) -> Self {
+        Self {
+            type_: raw.type_,
+            len: raw.len,
+            deviceid: raw.deviceid,
+        }
+    }
+}
+
+impl From<DetachSlave> for xcb_system::xcb_input_detach_slave_t {
+    fn from(value: DetachSlave) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.len = value.len;
+        raw.deviceid = value.deviceid;
+
+        raw
+    }
+}
+
+// HierarchyChange isn't generated, it has an case field.
+
+// XIChangeHierarchy isn't generated, HierarchyChange has a variable size.
+
+pub fn xi_set_client_pointer<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, deviceid: DeviceId) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_set_client_pointer_checked(connection.get_connection(), window, deviceid) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn xi_set_client_pointer_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, deviceid: DeviceId) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_xi_set_client_pointer(connection.get_connection(), window, deviceid) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct XIGetClientPointerReply {
+    pub set: bool,
+    pub deviceid: DeviceId,
+}
+
+pub struct XIGetClientPointerRequest;
+
+impl crate::result::Request for XIGetClientPointerRequest {
+    type Reply = XIGetClientPointerReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<XIGetClientPointerReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_xi_get_client_pointer_reply_t>(connection, sequence)?;
+
+        Ok(XIGetClientPointerReply {
+            set: reply.set != 0,
+            deviceid: reply.deviceid,
+        })
+    }
+}
+
+pub fn xi_get_client_pointer<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window) -> crate::result::Cookie<'a, XIGetClientPointerRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_get_client_pointer(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, XIGetClientPointerRequest, connection)
+}
+
+bitflags! {
+    pub struct XIEventMask: u32 {
+        const DEVICE_CHANGED = 0x2;
+        const KEY_PRESS = 0x4;
+        const KEY_RELEASE = 0x8;
+        const BUTTON_PRESS = 0x10;
+        const BUTTON_RELEASE = 0x20;
+        const MOTION = 0x40;
+        const ENTER = 0x80;
+        const LEAVE = 0x100;
+        const FOCUS_IN = 0x200;
+        const FOCUS_OUT = 0x400;
+        const HIERARCHY = 0x800;
+        const PROPERTY = 0x1000;
+        const RAW_KEY_PRESS = 0x2000;
+        const RAW_KEY_RELEASE = 0x4000;
+        const RAW_BUTTON_PRESS = 0x8000;
+        const RAW_BUTTON_RELEASE = 0x10000;
+        const RAW_MOTION = 0x20000;
+        const TOUCH_BEGIN = 0x40000;
+        const TOUCH_UPDATE = 0x80000;
+        const TOUCH_END = 0x100000;
+        const TOUCH_OWNERSHIP = 0x200000;
+        const RAW_TOUCH_BEGIN = 0x400000;
+        const RAW_TOUCH_UPDATE = 0x800000;
+        const RAW_TOUCH_END = 0x1000000;
+        const BARRIER_HIT = 0x2000000;
+        const BARRIER_LEAVE = 0x4000000;
+    }
+}
+
+// EventMask isn't generated, mask has a variable length.
+
+// XISelectEvents isn't generated, EventMask has a variable size.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct XIQueryVersionReply {
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+
+pub struct XIQueryVersionRequest;
+
+impl crate::result::Request for XIQueryVersionRequest {
+    type Reply = XIQueryVersionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<XIQueryVersionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_xi_query_version_reply_t>(connection, sequence)?;
+
+        Ok(XIQueryVersionReply {
+            major_version: reply.major_version,
+            minor_version: reply.minor_version,
+        })
+    }
+}
+
+pub fn xi_query_version<'a>(connection: &'a crate::connection::Connection, major_version: u16, minor_version: u16) -> crate::result::Cookie<'a, XIQueryVersionRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_query_version(connection.get_connection(), major_version, minor_version) };
+
+    crate::result::Cookie::new(cookie.sequence, XIQueryVersionRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceClassType(pub u32);
+
+impl DeviceClassType {
+    pub const KEY: Self = Self(0);
+    pub const BUTTON: Self = Self(1);
+    pub const VALUATOR: Self = Self(2);
+    pub const SCROLL: Self = Self(3);
+    pub const TOUCH: Self = Self(8);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceType(pub u32);
+
+impl DeviceType {
+    pub const MASTER_POINTER: Self = Self(1);
+    pub const MASTER_KEYBOARD: Self = Self(2);
+    pub const SLAVE_POINTER: Self = Self(3);
+    pub const SLAVE_KEYBOARD: Self = Self(4);
+    pub const FLOATING_SLAVE: Self = Self(5);
+}
+
+bitflags! {
+    pub struct ScrollFlags: u32 {
+        const NO_EMULATION = 0x1;
+        const PREFERRED = 0x2;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ScrollType(pub u32);
+
+impl ScrollType {
+    pub const VERTICAL: Self = Self(1);
+    pub const HORIZONTAL: Self = Self(2);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TouchMode(pub u32);
+
+impl TouchMode {
+    pub const DIRECT: Self = Self(1);
+    pub const DEPENDENT: Self = Self(2);
+}
+
+// ButtonClass isn't generated, state has a variable length.
+
+// KeyClass isn't generated, keys has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScrollClass {
+    pub type_: u16,
+    pub len: u16,
+    pub sourceid: DeviceId,
+    pub number: u16,
+    pub scroll_type: u16,
+    pub flags: u32,
+    pub increment: Fp3232,
+}
+
+impl From<xcb_system::xcb_input_scroll_class_t> for ScrollClass {
+    fn from(raw: xcb_system::xcb_input_scroll_class_t) -> Self {
+        Self {
+            type_: raw.type_,
+            len: raw.len,
+            sourceid: raw.sourceid,
+            number: raw.number,
+            scroll_type: raw.scroll_type,
+            flags: raw.flags,
+            increment: Fp3232::from(raw.increment),
+        }
+    }
+}
+
+impl From<ScrollClass> for xcb_system::xcb_input_scroll_class_t {
+    fn from(value: ScrollClass) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.len = value.len;
+        raw.sourceid = value.sourceid;
+        raw.number = value.number;
+        raw.scroll_type = value.scroll_type;
+        raw.flags = value.flags;
+        raw.increment = xcb_system::xcb_input_fp3232_t::from(value.increment);
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TouchClass {
+    pub type_: u16,
+    pub len: u16,
+    pub sourceid: DeviceId,
+    pub mode: u8,
+    pub num_touches: u8,
+}
+
+impl From<xcb_system::xcb_input_touch_class_t> for TouchClass {
+    fn from(raw: xcb_system::xcb_input_touch_class_t) -> Self {
+        Self {
+            type_: raw.type_,
+            len: raw.len,
+            sourceid: raw.sourceid,
+            mode: raw.mode,
+            num_touches: raw.num_touches,
+        }
+    }
+}
+
+impl From<TouchClass> for xcb_system::xcb_input_touch_class_t {
+    fn from(value: TouchClass) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.len = value.len;
+        raw.sourceid = value.sourceid;
+        raw.mode = value.mode;
+        raw.num_touches = value.num_touches;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ValuatorClass {
+    pub type_: u16,
+    pub len: u16,
+    pub sourceid: DeviceId,
+    pub number: u16,
+    pub label: super::xproto::Atom,
+    pub min: Fp3232,
+    pub max: Fp3232,
+    pub value: Fp3232,
+    pub resolution: u32,
+    pub mode: u8,
+}
+
+impl From<xcb_system::xcb_input_valuator_class_t> for ValuatorClass {
+    fn from(raw: xcb_system::xcb_input_valuator_class_t) -> Self {
+        Self {
+            type_: raw.type_,
+            len: raw.len,
+            sourceid: raw.sourceid,
+            number: raw.number,
+            label: raw.label,
+            min: Fp3232::from(raw.min),
+            max: Fp3232::from(raw.max),
+            value: Fp3232::from(raw.value),
+            resolution: raw.resolution,
+            mode: raw.mode,
+        }
+    }
+}
+
+impl From<ValuatorClass> for xcb_system::xcb_input_valuator_class_t {
+    fn from(value: ValuatorClass) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.len = value.len;
+        raw.sourceid = value.sourceid;
+        raw.number = value.number;
+        raw.label = value.label;
+        raw.min = xcb_system::xcb_input_fp3232_t::from(value.min);
+        raw.max = xcb_system::xcb_input_fp3232_t::from(value.max);
+        raw.value = xcb_system::xcb_input_fp3232_t::from(value.value);
+        raw.resolution = value.resolution;
+        raw.mode = value.mode;
+
+        raw
+    }
+}
+
+// DeviceClass isn't generated, it has an case field.
+
+// XIDeviceInfo isn't generated, name has a variable length.
+
+// XIQueryDevice isn't generated, XIDeviceInfo has a variable size.
+
+pub fn xi_set_focus<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, time: super::xproto::Timestamp, deviceid: DeviceId) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_set_focus_checked(connection.get_connection(), window, time, deviceid) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn xi_set_focus_unchecked<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, time: super::xproto::Timestamp, deviceid: DeviceId) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_xi_set_focus(connection.get_connection(), window, time, deviceid) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct XIGetFocusReply {
+    pub focus: super::xproto::Window,
+}
+
+pub struct XIGetFocusRequest;
+
+impl crate::result::Request for XIGetFocusRequest {
+    type Reply = XIGetFocusReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<XIGetFocusReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_xi_get_focus_reply_t>(connection, sequence)?;
+
+        Ok(XIGetFocusReply {
+            focus: reply.focus,
+        })
+    }
+}
+
+pub fn xi_get_focus<'a>(connection: &'a crate::connection::Connection, deviceid: DeviceId) -> crate::result::Cookie<'a, XIGetFocusRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_get_focus(connection.get_connection(), deviceid) };
+
+    crate::result::Cookie::new(cookie.sequence, XIGetFocusRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GrabOwner(pub u32);
+
+impl GrabOwner {
+    pub const NO_OWNER: Self = Self(0);
+    pub const OWNER: Self = Self(1);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct XIGrabDeviceReply {
+    pub status: u8,
+}
+
+pub struct XIGrabDeviceRequest;
+
+impl crate::result::Request for XIGrabDeviceRequest {
+    type Reply = XIGrabDeviceReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<XIGrabDeviceReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_xi_grab_device_reply_t>(connection, sequence)?;
+
+        Ok(XIGrabDeviceReply {
+            status: reply.status,
+        })
+    }
+}
+
+pub fn xi_grab_device<'a>(connection: &'a crate::connection::Connection, window: super::xproto::Window, time: super::xproto::Timestamp, cursor: super::xproto::Cursor, deviceid: DeviceId, mode: u8, paired_device_mode: u8, owner_events: bool, mask: &[u32]) -> crate::result::Cookie<'a, XIGrabDeviceRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_grab_device(connection.get_connection(), window, time, cursor, deviceid, mode, paired_device_mode, u8::from(owner_events), mask.len() as _, mask.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, XIGrabDeviceRequest, connection)
+}
+
+pub fn xi_ungrab_device<'a>(connection: &'a crate::connection::Connection, time: super::xproto::Timestamp, deviceid: DeviceId) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_ungrab_device_checked(connection.get_connection(), time, deviceid) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn xi_ungrab_device_unchecked<'a>(connection: &'a crate::connection::Connection, time: super::xproto::Timestamp, deviceid: DeviceId) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_xi_ungrab_device(connection.get_connection(), time, deviceid) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EventMode(pub u32);
+
+impl EventMode {
+    pub const ASYNC_DEVICE: Self = Self(0);
+    pub const SYNC_DEVICE: Self = Self(1);
+    pub const REPLAY_DEVICE: Self = Self(2);
+    pub const ASYNC_PAIRED_DEVICE: Self = Self(3);
+    pub const ASYNC_PAIR: Self = Self(4);
+    pub const SYNC_PAIR: Self = Self(5);
+    pub const ACCEPT_TOUCH: Self = Self(6);
+    pub const REJECT_TOUCH: Self = Self(7);
+}
+
+pub fn xi_allow_events<'a>(connection: &'a crate::connection::Connection, time: super::xproto::Timestamp, deviceid: DeviceId, event_mode: u8, touchid: u32, grab_window: super::xproto::Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_allow_events_checked(connection.get_connection(), time, deviceid, event_mode, touchid, grab_window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn xi_allow_events_unchecked<'a>(connection: &'a crate::connection::Connection, time: super::xproto::Timestamp, deviceid: DeviceId, event_mode: u8, touchid: u32, grab_window: super::xproto::Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_xi_allow_events(connection.get_connection(), time, deviceid, event_mode, touchid, grab_window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GrabMode22(pub u32);
+
+impl GrabMode22 {
+    pub const SYNC: Self = Self(0);
+    pub const ASYNC: Self = Self(1);
+    pub const TOUCH: Self = Self(2);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GrabType(pub u32);
+
+impl GrabType {
+    pub const BUTTON: Self = Self(0);
+    pub const KEYCODE: Self = Self(1);
+    pub const ENTER: Self = Self(2);
+    pub const FOCUS_IN: Self = Self(3);
+    pub const TOUCH_BEGIN: Self = Self(4);
+}
+
+bitflags! {
+    pub struct ModifierMask: u32 {
+        const ANY = 0x80000000;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GrabModifierInfo {
+    pub modifiers: u32,
+    pub status: u8,
+}
+
+impl From<xcb_system::xcb_input_grab_modifier_info_t> for GrabModifierInfo {
+    fn from(raw: xcb_system::xcb_input_grab_modifier_info_t) -> Self {
+        Self {
+            modifiers: raw.modifiers,
+            status: raw.status,
+        }
+    }
+}
+
+impl From<GrabModifierInfo> for xcb_system::xcb_input_grab_modifier_info_t {
+    fn from(value: GrabModifierInfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.modifiers = value.modifiers;
+        raw.status = value.status;
+
+        raw
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct XIPassiveGrabDeviceReply {
+    pub num_modifiers: u16,
+    pub modifiers: Vec<GrabModifierInfo>,
+}
+
+pub struct XIPassiveGrabDeviceRequest;
+
+impl crate::result::Request for XIPassiveGrabDeviceRequest {
+    type Reply = XIPassiveGrabDeviceReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<XIPassiveGrabDeviceReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_xi_passive_grab_device_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { XIPassiveGrabDeviceReply {
+            num_modifiers: reply.num_modifiers,
+            modifiers: std::slice::from_raw_parts(xcb_system::xcb_input_xi_passive_grab_device_modifiers(reply.as_ptr()) as *const xcb_system::xcb_input_grab_modifier_info_t, xcb_system::xcb_input_xi_passive_grab_device_modifiers_length(reply.as_ptr()) as usize).iter().map(|&value| GrabModifierInfo::from(value)).collect(),
+        } })
+    }
+}
+
+pub fn xi_passive_grab_device<'a>(connection: &'a crate::connection::Connection, time: super::xproto::Timestamp, grab_window: super::xproto::Window, cursor: super::xproto::Cursor, detail: u32, deviceid: DeviceId, grab_type: u8, grab_mode: u8, paired_device_mode: u8, owner_events: bool, mask: &[u32], modifiers: &[u32]) -> crate::result::Cookie<'a, XIPassiveGrabDeviceRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_passive_grab_device(connection.get_connection(), time, grab_window, cursor, detail, deviceid, modifiers.len() as _, mask.len() as _, grab_type, grab_mode, paired_device_mode, u8::from(owner_events), mask.as_ptr() as *const _, modifiers.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, XIPassiveGrabDeviceRequest, connection)
+}
+
+pub fn xi_passive_ungrab_device<'a>(connection: &'a crate::connection::Connection, grab_window: super::xproto::Window, detail: u32, deviceid: DeviceId, grab_type: u8, modifiers: &[u32]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_passive_ungrab_device_checked(connection.get_connection(), grab_window, detail, deviceid, modifiers.len() as _, grab_type, modifiers.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn xi_passive_ungrab_device_unchecked<'a>(connection: &'a crate::connection::Connection, grab_window: super::xproto::Window, detail: u32, deviceid: DeviceId, grab_type: u8, modifiers: &[u32]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_xi_passive_ungrab_device(connection.get_connection(), grab_window, detail, deviceid, modifiers.len() as _, grab_type, modifiers.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct XIListPropertiesReply {
+    pub num_properties: u16,
+    pub properties: Vec<super::xproto::Atom>,
+}
+
+pub struct XIListPropertiesRequest;
+
+impl crate::result::Request for XIListPropertiesRequest {
+    type Reply = XIListPropertiesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<XIListPropertiesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_input_xi_list_properties_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { XIListPropertiesReply {
+            num_properties: reply.num_properties,
+            properties: std::slice::from_raw_parts(xcb_system::xcb_input_xi_list_properties_properties(reply.as_ptr()) as *const super::xproto::Atom, xcb_system::xcb_input_xi_list_properties_properties_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn xi_list_properties<'a>(connection: &'a crate::connection::Connection, deviceid: DeviceId) -> crate::result::Cookie<'a, XIListPropertiesRequest> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_list_properties(connection.get_connection(), deviceid) };
+
+    crate::result::Cookie::new(cookie.sequence, XIListPropertiesRequest, connection)
+}
+
+// XIChangeProperty isn't generated, it has an case field.
+
+pub fn xi_delete_property<'a>(connection: &'a crate::connection::Connection, deviceid: DeviceId, property: super::xproto::Atom) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_input_xi_delete_property_checked(connection.get_connection(), deviceid, property) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn xi_delete_property_unchecked<'a>(connection: &'a crate::connection::Connection, deviceid: DeviceId, property: super::xproto::Atom) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_input_xi_delete_property(connection.get_connection(), deviceid, property) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+// XIGetProperty isn't generated, it has an case field.
+
+// XIGetSelectedEvents isn't generated, EventMask has a variable size.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BarrierReleasePointerInfo {
+    pub deviceid: DeviceId,
+    pub barrier: super::xfixes::Barrier,
+    pub eventid: u32,
+}
+
+impl From<xcb_system::xcb_input_barrier_release_pointer_info_t> for BarrierReleasePointerInfo {
+    fn from(raw: xcb_system::xcb_input_barrier_release_pointer_info_t) -> Self {
+        Self {
+            deviceid: raw.deviceid,
+            barrier: raw.barrier,
+            eventid: raw.eventid,
+        }
+    }
+}
+
+impl From<BarrierReleasePointerInfo> for xcb_system::xcb_input_barrier_release_pointer_info_t {
+    fn from(value: BarrierReleasePointerInfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.deviceid = value.deviceid;
+        raw.barrier = value.barrier;
+        raw.eventid = value.eventid;
+
+        raw
+    }
+}
+
+pub fn xi_barrier_release_pointer<'a>(connection: &'a crate::connection::Connection, barriers: &[BarrierReleasePointerInfo]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let barriers: Vec<xcb_system::xcb_input_barrier_release_pointer_info_t> = barriers.iter().map(|&value| xcb_system::xcb_input_barrier_release_pointer_info_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_input_xi_barrier_release_pointer_checked(connection.get_connection(), barriers.len() as _, barriers.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn xi_barrier_release_pointer_unchecked<'a>(connection: &'a crate::connection::Connection, barriers: &[BarrierReleasePointerInfo]) -> crate::result::UncheckedCookie {
+    let barriers: Vec<xcb_system::xcb_input_barrier_release_pointer_info_t> = barriers.iter().map(|&value| xcb_system::xcb_input_barrier_release_pointer_info_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_input_xi_barrier_release_pointer(connection.get_connection(), barriers.len() as _, barriers.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceValuatorEvent {
+    pub device_id: u8,
+    pub device_state: u16,
+    pub num_valuators: u8,
+    pub first_valuator: u8,
+    pub valuators: [i32; 6],
+}
+
+impl From<xcb_system::xcb_input_device_valuator_event_t> for DeviceValuatorEvent {
+    fn from(raw: xcb_system::xcb_input_device_valuator_event_t) -> Self {
+        Self {
+            device_id: raw.device_id,
+            device_state: raw.device_state,
+            num_valuators: raw.num_valuators,
+            first_valuator: raw.first_valuator,
+            valuators: raw.valuators,
+        }
+    }
+}
+
+bitflags! {
+    pub struct MoreEventsMask: u32 {
+        const MORE_EVENTS = 0x80;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceKeyPressEvent {
+    pub detail: u8,
+    pub time: super::xproto::Timestamp,
+    pub root: super::xproto::Window,
+    pub event: super::xproto::Window,
+    pub child: super::xproto::Window,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub event_x: i16,
+    pub event_y: i16,
+    pub state: u16,
+    pub same_screen: bool,
+    pub device_id: u8,
+}
+
+impl From<xcb_system::xcb_input_device_key_press_event_t> for DeviceKeyPressEvent {
+    fn from(raw: xcb_system::xcb_input_device_key_press_event_t) -> Self {
+        Self {
+            detail: raw.detail,
+            time: raw.time,
+            root: raw.root,
+            event: raw.event,
+            child: raw.child,
+            root_x: raw.root_x,
+            root_y: raw.root_y,
+            event_x: raw.event_x,
+            event_y: raw.event_y,
+            state: raw.state,
+            same_screen: raw.same_screen != 0,
+            device_id: raw.device_id,
+        }
+    }
+}
+
+pub type DeviceKeyReleaseEvent = DeviceKeyPressEvent;
+
+pub type DeviceButtonPressEvent = DeviceKeyPressEvent;
+
+pub type DeviceButtonReleaseEvent = DeviceKeyPressEvent;
+
+pub type DeviceMotionNotifyEvent = DeviceKeyPressEvent;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceFocusInEvent {
+    pub detail: u8,
+    pub time: super::xproto::Timestamp,
+    pub window: super::xproto::Window,
+    pub mode: u8,
+    pub device_id: u8,
+}
+
+impl From<xcb_system::xcb_input_device_focus_in_event_t> for DeviceFocusInEvent {
+    fn from(raw: xcb_system::xcb_input_device_focus_in_event_t) -> Self {
+        Self {
+            detail: raw.detail,
+            time: raw.time,
+            window: raw.window,
+            mode: raw.mode,
+            device_id: raw.device_id,
+        }
+    }
+}
+
+pub type DeviceFocusOutEvent = DeviceFocusInEvent;
+
+pub type ProximityInEvent = DeviceKeyPressEvent;
+
+pub type ProximityOutEvent = DeviceKeyPressEvent;
+
+bitflags! {
+    pub struct ClassesReportedMask: u32 {
+        const OUT_OF_PROXIMITY = 0x80;
+        const DEVICE_MODE_ABSOLUTE = 0x40;
+        const REPORTING_VALUATORS = 0x4;
+        const REPORTING_BUTTONS = 0x2;
+        const REPORTING_KEYS = 0x1;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceStateNotifyEvent {
+    pub device_id: u8,
+    pub time: super::xproto::Timestamp,
+    pub num_keys: u8,
+    pub num_buttons: u8,
+    pub num_valuators: u8,
+    pub classes_reported: u8,
+    pub buttons: [u8; 4],
+    pub keys: [u8; 4],
+    pub valuators: [u32; 3],
+}
+
+impl From<xcb_system::xcb_input_device_state_notify_event_t> for DeviceStateNotifyEvent {
+    fn from(raw: xcb_system::xcb_input_device_state_notify_event_t) -> Self {
+        Self {
+            device_id: raw.device_id,
+            time: raw.time,
+            num_keys: raw.num_keys,
+            num_buttons: raw.num_buttons,
+            num_valuators: raw.num_valuators,
+            classes_reported: raw.classes_reported,
+            buttons: raw.buttons,
+            keys: raw.keys,
+            valuators: raw.valuators,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceMappingNotifyEvent {
+    pub device_id: u8,
+    pub request: u8,
+    pub first_keycode: KeyCode,
+    pub count: u8,
+    pub time: super::xproto::Timestamp,
+}
+
+impl From<xcb_system::xcb_input_device_mapping_notify_event_t> for DeviceMappingNotifyEvent {
+    fn from(raw: xcb_system::xcb_input_device_mapping_notify_event_t) -> Self {
+        Self {
+            device_id: raw.device_id,
+            request: raw.request,
+            first_keycode: raw.first_keycode,
+            count: raw.count,
+            time: raw.time,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ChangeDevice(pub u32);
+
+impl ChangeDevice {
+    pub const NEW_POINTER: Self = Self(0);
+    pub const NEW_KEYBOARD: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ChangeDeviceNotifyEvent {
+    pub device_id: u8,
+    pub time: super::xproto::Timestamp,
+    pub request: u8,
+}
+
+impl From<xcb_system::xcb_input_change_device_notify_event_t> for ChangeDeviceNotifyEvent {
+    fn from(raw: xcb_system::xcb_input_change_device_notify_event_t) -> Self {
+        Self {
+            device_id: raw.device_id,
+            time: raw.time,
+            request: raw.request,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceKeyStateNotifyEvent {
+    pub device_id: u8,
+    pub keys: [u8; 28],
+}
+
+impl From<xcb_system::xcb_input_device_key_state_notify_event_t> for DeviceKeyStateNotifyEvent {
+    fn from(raw: xcb_system::xcb_input_device_key_state_notify_event_t) -> Self {
+        Self {
+            device_id: raw.device_id,
+            keys: raw.keys,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceButtonStateNotifyEvent {
+    pub device_id: u8,
+    pub buttons: [u8; 28],
+}
+
+impl From<xcb_system::xcb_input_device_button_state_notify_event_t> for DeviceButtonStateNotifyEvent {
+    fn from(raw: xcb_system::xcb_input_device_button_state_notify_event_t) -> Self {
+        Self {
+            device_id: raw.device_id,
+            buttons: raw.buttons,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceChange(pub u32);
+
+impl DeviceChange {
+    pub const ADDED: Self = Self(0);
+    pub const REMOVED: Self = Self(1);
+    pub const ENABLED: Self = Self(2);
+    pub const DISABLED: Self = Self(3);
+    pub const UNRECOVERABLE: Self = Self(4);
+    pub const CONTROL_CHANGED: Self = Self(5);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DevicePresenceNotifyEvent {
+    pub time: super::xproto::Timestamp,
+    pub devchange: u8,
+    pub device_id: u8,
+    pub control: u16,
+}
+
+impl From<xcb_system::xcb_input_device_presence_notify_event_t> for DevicePresenceNotifyEvent {
+    fn from(raw: xcb_system::xcb_input_device_presence_notify_event_t) -> Self {
+        Self {
+            time: raw.time,
+            devchange: raw.devchange,
+            device_id: raw.device_id,
+            control: raw.control,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DevicePropertyNotifyEvent {
+    pub state: u8,
+    pub time: super::xproto::Timestamp,
+    pub property: super::xproto::Atom,
+    pub device_id: u8,
+}
+
+impl From<xcb_system::xcb_input_device_property_notify_event_t> for DevicePropertyNotifyEvent {
+    fn from(raw: xcb_system::xcb_input_device_property_notify_event_t) -> Self {
+        Self {
+            state: raw.state,
+            time: raw.time,
+            property: raw.property,
+            device_id: raw.device_id,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ChangeReason(pub u32);
+
+impl ChangeReason {
+    pub const SLAVE_SWITCH: Self = Self(1);
+    pub const DEVICE_CHANGE: Self = Self(2);
+}
+
+// DeviceChanged isn't generated, it's a generic event.
+
+bitflags! {
+    pub struct KeyEventFlags: u32 {
+        const KEY_REPEAT = 0x10000;
+    }
+}
+
+// KeyPress isn't generated, it's a generic event.
+
+// KeyRelease isn't generated, KeyPress isn't generated.
+
+bitflags! {
+    pub struct PointerEventFlags: u32 {
+        const POINTER_EMULATED = 0x10000;
+    }
+}
+
+// ButtonPress isn't generated, it's a generic event.
+
+// ButtonRelease isn't generated, ButtonPress isn't generated.
+
+// Motion isn't generated, ButtonPress isn't generated.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NotifyMode(pub u32);
+
+impl NotifyMode {
+    pub const NORMAL: Self = Self(0);
+    pub const GRAB: Self = Self(1);
+    pub const UNGRAB: Self = Self(2);
+    pub const WHILE_GRABBED: Self = Self(3);
+    pub const PASSIVE_GRAB: Self = Self(4);
+    pub const PASSIVE_UNGRAB: Self = Self(5);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NotifyDetail(pub u32);
+
+impl NotifyDetail {
+    pub const ANCESTOR: Self = Self(0);
+    pub const VIRTUAL: Self = Self(1);
+    pub const INFERIOR: Self = Self(2);
+    pub const NONLINEAR: Self = Self(3);
+    pub const NONLINEAR_VIRTUAL: Self = Self(4);
+    pub const POINTER: Self = Self(5);
+    pub const POINTER_ROOT: Self = Self(6);
+    pub const NONE: Self = Self(7);
+}
+
+// Enter isn't generated, it's a generic event.
+
+// Leave isn't generated, Enter isn't generated.
+
+// FocusIn isn't generated, Enter isn't generated.
+
+// FocusOut isn't generated, Enter isn't generated.
+
+bitflags! {
+    pub struct HierarchyMask: u32 {
+        const MASTER_ADDED = 0x1;
+        const MASTER_REMOVED = 0x2;
+        const SLAVE_ADDED = 0x4;
+        const SLAVE_REMOVED = 0x8;
+        const SLAVE_ATTACHED = 0x10;
+        const SLAVE_DETACHED = 0x20;
+        const DEVICE_ENABLED = 0x40;
+        const DEVICE_DISABLED = 0x80;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HierarchyInfo {
+    pub deviceid: DeviceId,
+    pub attachment: DeviceId,
+    pub type_: u8,
+    pub enabled: bool,
+    pub flags: u32,
+}
+
+impl From<xcb_system::xcb_input_hierarchy_info_t> for HierarchyInfo {
+    fn from(raw: xcb_system::xcb_input_hierarchy_info_t) -> Self {
+        Self {
+            deviceid: raw.deviceid,
+            attachment: raw.attachment,
+            type_: raw.type_,
+            enabled: raw.enabled != 0,
+            flags: raw.flags,
+        }
+    }
+}
+
+impl From<HierarchyInfo> for xcb_system::xcb_input_hierarchy_info_t {
+    fn from(value: HierarchyInfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.deviceid = value.deviceid;
+        raw.attachment = value.attachment;
+        raw.type_ = value.type_;
+        raw.enabled = u8::from(value.enabled);
+        raw.flags = value.flags;
+
+        raw
+    }
+}
+
+// Hierarchy isn't generated, it's a generic event.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PropertyFlag(pub u32);
+
+impl PropertyFlag {
+    pub const DELETED: Self = Self(0);
+    pub const CREATED: Self = Self(1);
+    pub const MODIFIED: Self = Self(2);
+}
+
+// Property isn't generated, it's a generic event.
+
+// RawKeyPress isn't generated, it's a generic event.
+
+// RawKeyRelease isn't generated, RawKeyPress isn't generated.
+
+// RawButtonPress isn't generated, it's a generic event.
+
+// RawButtonRelease isn't generated, RawButtonPress isn't generated.
+
+// RawMotion isn't generated, RawButtonPress isn't generated.
+
+bitflags! {
+    pub struct TouchEventFlags: u32 {
+        const TOUCH_PENDING_END = 0x10000;
+        const TOUCH_EMULATING_POINTER = 0x20000;
+    }
+}
+
+// TouchBegin isn't generated, it's a generic event.
+
+// TouchUpdate isn't generated, TouchBegin isn't generated.
+
+// TouchEnd isn't generated, TouchBegin isn't generated.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TouchOwnershipFlags(pub u32);
+
+impl TouchOwnershipFlags {
+    pub const NONE: Self = Self(0);
+}
+
+// TouchOwnership isn't generated, it's a generic event.
+
+// RawTouchBegin isn't generated, it's a generic event.
+
+// RawTouchUpdate isn't generated, RawTouchBegin isn't generated.
+
+// RawTouchEnd isn't generated, RawTouchBegin isn't generated.
+
+bitflags! {
+    pub struct BarrierFlags: u32 {
+        const POINTER_RELEASED = 0x1;
+        const DEVICE_IS_GRABBED = 0x2;
+    }
+}
+
+// BarrierHit isn't generated, it's a generic event.
+
+// BarrierLeave isn't generated, BarrierHit isn't generated.
+
+// SendExtensionEvent isn't generated, EventForSend is an unknown type.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    DeviceValuator(DeviceValuatorEvent),
+    DeviceKeyPress(DeviceKeyPressEvent),
+    DeviceKeyRelease(DeviceKeyReleaseEvent),
+    DeviceButtonPress(DeviceButtonPressEvent),
+    DeviceButtonRelease(DeviceButtonReleaseEvent),
+    DeviceMotionNotify(DeviceMotionNotifyEvent),
+    DeviceFocusIn(DeviceFocusInEvent),
+    DeviceFocusOut(DeviceFocusOutEvent),
+    ProximityIn(ProximityInEvent),
+    ProximityOut(ProximityOutEvent),
+    DeviceStateNotify(DeviceStateNotifyEvent),
+    DeviceMappingNotify(DeviceMappingNotifyEvent),
+    ChangeDeviceNotify(ChangeDeviceNotifyEvent),
+    DeviceKeyStateNotify(DeviceKeyStateNotifyEvent),
+    DeviceButtonStateNotify(DeviceButtonStateNotifyEvent),
+    DevicePresenceNotify(DevicePresenceNotifyEvent),
+    DevicePropertyNotify(DevicePropertyNotifyEvent),
+}
+
+pub fn decode_event(event: &xcb_system::xcb_generic_event_t, first_event: u8) -> std::option::Option<Event> {
+    let raw = event as *const xcb_system::xcb_generic_event_t;
+
+    match (event.response_type & 0x7f).wrapping_sub(first_event) {
+        0 => Some(Event::DeviceValuator(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_valuator_event_t) }.into(),
+        )),
+        1 => Some(Event::DeviceKeyPress(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_key_press_event_t) }.into(),
+        )),
+        2 => Some(Event::DeviceKeyRelease(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_key_release_event_t) }.into(),
+        )),
+        3 => Some(Event::DeviceButtonPress(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_button_press_event_t) }.into(),
+        )),
+        4 => Some(Event::DeviceButtonRelease(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_button_release_event_t) }.into(),
+        )),
+        5 => Some(Event::DeviceMotionNotify(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_motion_notify_event_t) }.into(),
+        )),
+        6 => Some(Event::DeviceFocusIn(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_focus_in_event_t) }.into(),
+        )),
+        7 => Some(Event::DeviceFocusOut(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_focus_out_event_t) }.into(),
+        )),
+        8 => Some(Event::ProximityIn(
+            unsafe { *(raw as *const xcb_system::xcb_input_proximity_in_event_t) }.into(),
+        )),
+        9 => Some(Event::ProximityOut(
+            unsafe { *(raw as *const xcb_system::xcb_input_proximity_out_event_t) }.into(),
+        )),
+        10 => Some(Event::DeviceStateNotify(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_state_notify_event_t) }.into(),
+        )),
+        11 => Some(Event::DeviceMappingNotify(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_mapping_notify_event_t) }.into(),
+        )),
+        12 => Some(Event::ChangeDeviceNotify(
+            unsafe { *(raw as *const xcb_system::xcb_input_change_device_notify_event_t) }.into(),
+        )),
+        13 => Some(Event::DeviceKeyStateNotify(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_key_state_notify_event_t) }.into(),
+        )),
+        14 => Some(Event::DeviceButtonStateNotify(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_button_state_notify_event_t) }.into(),
+        )),
+        15 => Some(Event::DevicePresenceNotify(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_presence_notify_event_t) }.into(),
+        )),
+        16 => Some(Event::DevicePropertyNotify(
+            unsafe { *(raw as *const xcb_system::xcb_input_device_property_notify_event_t) }.into(),
+        )),
+        _ => None,
+    }
+}
+
@@ -0,0 +1,2625 @@
+// Generated by xcb-codegen from xkb.xml, don't edit.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Const(pub u32);
+
+impl Const {
+    pub const MAX_LEGAL_KEY_CODE: Self = Self(255);
+    pub const PER_KEY_BIT_ARRAY_SIZE: Self = Self(32);
+    pub const KEY_NAME_LENGTH: Self = Self(4);
+}
+
+bitflags! {
+    pub struct EventType: u32 {
+        const NEW_KEYBOARD_NOTIFY = 0x1;
+        const MAP_NOTIFY = 0x2;
+        const STATE_NOTIFY = 0x4;
+        const CONTROLS_NOTIFY = 0x8;
+        const INDICATOR_STATE_NOTIFY = 0x10;
+        const INDICATOR_MAP_NOTIFY = 0x20;
+        const NAMES_NOTIFY = 0x40;
+        const COMPAT_MAP_NOTIFY = 0x80;
+        const BELL_NOTIFY = 0x100;
+        const ACTION_MESSAGE = 0x200;
+        const ACCESS_X_NOTIFY = 0x400;
+        const EXTENSION_DEVICE_NOTIFY = 0x800;
+    }
+}
+
+bitflags! {
+    pub struct NKNDetail: u32 {
+        const KEYCODES = 0x1;
+        const GEOMETRY = 0x2;
+        const DEVICE_ID = 0x4;
+    }
+}
+
+bitflags! {
+    pub struct AXNDetail: u32 {
+        const SK_PRESS = 0x1;
+        const SK_ACCEPT = 0x2;
+        const SK_REJECT = 0x4;
+        const SK_RELEASE = 0x8;
+        const BK_ACCEPT = 0x10;
+        const BK_REJECT = 0x20;
+        const AXK_WARNING = 0x40;
+    }
+}
+
+bitflags! {
+    pub struct MapPart: u32 {
+        const KEY_TYPES = 0x1;
+        const KEY_SYMS = 0x2;
+        const MODIFIER_MAP = 0x4;
+        const EXPLICIT_COMPONENTS = 0x8;
+        const KEY_ACTIONS = 0x10;
+        const KEY_BEHAVIORS = 0x20;
+        const VIRTUAL_MODS = 0x40;
+        const VIRTUAL_MOD_MAP = 0x80;
+    }
+}
+
+bitflags! {
+    pub struct SetMapFlags: u32 {
+        const RESIZE_TYPES = 0x1;
+        const RECOMPUTE_ACTIONS = 0x2;
+    }
+}
+
+bitflags! {
+    pub struct StatePart: u32 {
+        const MODIFIER_STATE = 0x1;
+        const MODIFIER_BASE = 0x2;
+        const MODIFIER_LATCH = 0x4;
+        const MODIFIER_LOCK = 0x8;
+        const GROUP_STATE = 0x10;
+        const GROUP_BASE = 0x20;
+        const GROUP_LATCH = 0x40;
+        const GROUP_LOCK = 0x80;
+        const COMPAT_STATE = 0x100;
+        const GRAB_MODS = 0x200;
+        const COMPAT_GRAB_MODS = 0x400;
+        const LOOKUP_MODS = 0x800;
+        const COMPAT_LOOKUP_MODS = 0x1000;
+        const POINTER_BUTTONS = 0x2000;
+    }
+}
+
+bitflags! {
+    pub struct BoolCtrl: u32 {
+        const REPEAT_KEYS = 0x1;
+        const SLOW_KEYS = 0x2;
+        const BOUNCE_KEYS = 0x4;
+        const STICKY_KEYS = 0x8;
+        const MOUSE_KEYS = 0x10;
+        const MOUSE_KEYS_ACCEL = 0x20;
+        const ACCESS_X_KEYS = 0x40;
+        const ACCESS_X_TIMEOUT_MASK = 0x80;
+        const ACCESS_X_FEEDBACK_MASK = 0x100;
+        const AUDIBLE_BELL_MASK = 0x200;
+        const OVERLAY_1_MASK = 0x400;
+        const OVERLAY_2_MASK = 0x800;
+        const IGNORE_GROUP_LOCK_MASK = 0x1000;
+    }
+}
+
+bitflags! {
+    pub struct Control: u32 {
+        const GROUPS_WRAP = 0x8000000;
+        const INTERNAL_MODS = 0x10000000;
+        const IGNORE_LOCK_MODS = 0x20000000;
+        const PER_KEY_REPEAT = 0x40000000;
+        const CONTROLS_ENABLED = 0x80000000;
+    }
+}
+
+bitflags! {
+    pub struct AXOption: u32 {
+        const SK_PRESS_FB = 0x1;
+        const SK_ACCEPT_FB = 0x2;
+        const FEATURE_FB = 0x4;
+        const SLOW_WARN_FB = 0x8;
+        const INDICATOR_FB = 0x10;
+        const STICKY_KEYS_FB = 0x20;
+        const TWO_KEYS = 0x40;
+        const LATCH_TO_LOCK = 0x80;
+        const SK_RELEASE_FB = 0x100;
+        const SK_REJECT_FB = 0x200;
+        const BK_REJECT_FB = 0x400;
+        const DUMB_BELL = 0x800;
+    }
+}
+
+pub type DeviceSpec = u16;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LedClassResult(pub u32);
+
+impl LedClassResult {
+    pub const KBD_FEEDBACK_CLASS: Self = Self(0);
+    pub const LED_FEEDBACK_CLASS: Self = Self(4);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LedClass(pub u32);
+
+impl LedClass {
+    pub const KBD_FEEDBACK_CLASS: Self = Self(0);
+    pub const LED_FEEDBACK_CLASS: Self = Self(4);
+    pub const DFLT_XI_CLASS: Self = Self(768);
+    pub const ALL_XI_CLASSES: Self = Self(1280);
+}
+
+pub type LedClassSpec = u16;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BellClassResult(pub u32);
+
+impl BellClassResult {
+    pub const KBD_FEEDBACK_CLASS: Self = Self(0);
+    pub const BELL_FEEDBACK_CLASS: Self = Self(5);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BellClass(pub u32);
+
+impl BellClass {
+    pub const KBD_FEEDBACK_CLASS: Self = Self(0);
+    pub const BELL_FEEDBACK_CLASS: Self = Self(5);
+    pub const DFLT_XI_CLASS: Self = Self(768);
+}
+
+pub type BellClassSpec = u16;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Id(pub u32);
+
+impl Id {
+    pub const USE_CORE_KBD: Self = Self(256);
+    pub const USE_CORE_PTR: Self = Self(512);
+    pub const DFLT_XI_CLASS: Self = Self(768);
+    pub const DFLT_XI_ID: Self = Self(1024);
+    pub const ALL_XI_CLASS: Self = Self(1280);
+    pub const ALL_XI_ID: Self = Self(1536);
+    pub const XI_NONE: Self = Self(65280);
+}
+
+pub type IDSpec = u16;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Group(pub u32);
+
+impl Group {
+    pub const GROUP_1: Self = Self(0);
+    pub const GROUP_2: Self = Self(1);
+    pub const GROUP_3: Self = Self(2);
+    pub const GROUP_4: Self = Self(3);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Groups(pub u32);
+
+impl Groups {
+    pub const ANY: Self = Self(254);
+    pub const ALL: Self = Self(255);
+}
+
+bitflags! {
+    pub struct SetOfGroup: u32 {
+        const GROUP_1 = 0x1;
+        const GROUP_2 = 0x2;
+        const GROUP_3 = 0x4;
+        const GROUP_4 = 0x8;
+    }
+}
+
+bitflags! {
+    pub struct SetOfGroups: u32 {
+        const ANY = 0x80;
+    }
+}
+
+bitflags! {
+    pub struct GroupsWrap: u32 {
+        const WRAP_INTO_RANGE = 0x0;
+        const CLAMP_INTO_RANGE = 0x40;
+        const REDIRECT_INTO_RANGE = 0x80;
+    }
+}
+
+bitflags! {
+    pub struct VModsHigh: u32 {
+        const V_15 = 0x80;
+        const V_14 = 0x40;
+        const V_13 = 0x20;
+        const V_12 = 0x10;
+        const V_11 = 0x8;
+        const V_10 = 0x4;
+        const V_9 = 0x2;
+        const V_8 = 0x1;
+    }
+}
+
+bitflags! {
+    pub struct VModsLow: u32 {
+        const V_7 = 0x80;
+        const V_6 = 0x40;
+        const V_5 = 0x20;
+        const V_4 = 0x10;
+        const V_3 = 0x8;
+        const V_2 = 0x4;
+        const V_1 = 0x2;
+        const V_0 = 0x1;
+    }
+}
+
+bitflags! {
+    pub struct VMod: u32 {
+        const V_15 = 0x8000;
+        const V_14 = 0x4000;
+        const V_13 = 0x2000;
+        const V_12 = 0x1000;
+        const V_11 = 0x800;
+        const V_10 = 0x400;
+        const V_9 = 0x200;
+        const V_8 = 0x100;
+        const V_7 = 0x80;
+        const V_6 = 0x40;
+        const V_5 = 0x20;
+        const V_4 = 0x10;
+        const V_3 = 0x8;
+        const V_2 = 0x4;
+        const V_1 = 0x2;
+        const V_0 = 0x1;
+    }
+}
+
+bitflags! {
+    pub struct Explicit: u32 {
+        const V_MOD_MAP = 0x80;
+        const BEHAVIOR = 0x40;
+        const AUTO_REPEAT = 0x20;
+        const INTERPRET = 0x10;
+        const KEY_TYPE_4 = 0x8;
+        const KEY_TYPE_3 = 0x4;
+        const KEY_TYPE_2 = 0x2;
+        const KEY_TYPE_1 = 0x1;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SymInterpretMatch(pub u32);
+
+impl SymInterpretMatch {
+    pub const NONE_OF: Self = Self(0);
+    pub const ANY_OF_OR_NONE: Self = Self(1);
+    pub const ANY_OF: Self = Self(2);
+    pub const ALL_OF: Self = Self(3);
+    pub const EXACTLY: Self = Self(4);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SymInterpMatch(pub u32);
+
+impl SymInterpMatch {
+    pub const LEVEL_ONE_ONLY: Self = Self(128);
+    pub const OP_MASK: Self = Self(127);
+}
+
+bitflags! {
+    pub struct IMFlag: u32 {
+        const NO_EXPLICIT = 0x80;
+        const NO_AUTOMATIC = 0x40;
+        const LED_DRIVES_KB = 0x20;
+    }
+}
+
+bitflags! {
+    pub struct IMModsWhich: u32 {
+        const USE_COMPAT = 0x10;
+        const USE_EFFECTIVE = 0x8;
+        const USE_LOCKED = 0x4;
+        const USE_LATCHED = 0x2;
+        const USE_BASE = 0x1;
+    }
+}
+
+bitflags! {
+    pub struct IMGroupsWhich: u32 {
+        const USE_COMPAT = 0x10;
+        const USE_EFFECTIVE = 0x8;
+        const USE_LOCKED = 0x4;
+        const USE_LATCHED = 0x2;
+        const USE_BASE = 0x1;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IndicatorMap {
+    pub flags: u8,
+    pub whichGroups: u8,
+    pub groups: u8,
+    pub whichMods: u8,
+    pub mods: u8,
+    pub realMods: u8,
+    pub vmods: u16,
+    pub ctrls: u32,
+}
+
+impl From<xcb_system::xcb_xkb_indicator_map_t> for IndicatorMap {
+    fn from(raw: xcb_system::xcb_xkb_indicator_map_t) -> Self {
+        Self {
+            flags: raw.flags,
+            whichGroups: raw.whichGroups,
+            groups: raw.groups,
+            whichMods: raw.whichMods,
+            mods: raw.mods,
+            realMods: raw.realMods,
+            vmods: raw.vmods,
+            ctrls: raw.ctrls,
+        }
+    }
+}
+
+impl From<IndicatorMap> for xcb_system::xcb_xkb_indicator_map_t {
+    fn from(value: IndicatorMap) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.flags = value.flags;
+        raw.whichGroups = value.whichGroups;
+        raw.groups = value.groups;
+        raw.whichMods = value.whichMods;
+        raw.mods = value.mods;
+        raw.realMods = value.realMods;
+        raw.vmods = value.vmods;
+        raw.ctrls = value.ctrls;
+
+        raw
+    }
+}
+
+bitflags! {
+    pub struct CMDetail: u32 {
+        const SYM_INTERP = 0x1;
+        const GROUP_COMPAT = 0x2;
+    }
+}
+
+bitflags! {
+    pub struct NameDetail: u32 {
+        const KEYCODES = 0x1;
+        const GEOMETRY = 0x2;
+        const SYMBOLS = 0x4;
+        const PHYS_SYMBOLS = 0x8;
+        const TYPES = 0x10;
+        const COMPAT = 0x20;
+        const KEY_TYPE_NAMES = 0x40;
+        const KT_LEVEL_NAMES = 0x80;
+        const INDICATOR_NAMES = 0x100;
+        const KEY_NAMES = 0x200;
+        const KEY_ALIASES = 0x400;
+        const VIRTUAL_MOD_NAMES = 0x800;
+        const GROUP_NAMES = 0x1000;
+        const RG_NAMES = 0x2000;
+    }
+}
+
+bitflags! {
+    pub struct GBNDetail: u32 {
+        const TYPES = 0x1;
+        const COMPAT_MAP = 0x2;
+        const CLIENT_SYMBOLS = 0x4;
+        const SERVER_SYMBOLS = 0x8;
+        const INDICATOR_MAPS = 0x10;
+        const KEY_NAMES = 0x20;
+        const GEOMETRY = 0x40;
+        const OTHER_NAMES = 0x80;
+    }
+}
+
+bitflags! {
+    pub struct XIFeature: u32 {
+        const KEYBOARDS = 0x1;
+        const BUTTON_ACTIONS = 0x2;
+        const INDICATOR_NAMES = 0x4;
+        const INDICATOR_MAPS = 0x8;
+        const INDICATOR_STATE = 0x10;
+    }
+}
+
+bitflags! {
+    pub struct PerClientFlag: u32 {
+        const DETECTABLE_AUTO_REPEAT = 0x1;
+        const GRABS_USE_XKB_STATE = 0x2;
+        const AUTO_RESET_CONTROLS = 0x4;
+        const LOOKUP_STATE_WHEN_GRABBED = 0x8;
+        const SEND_EVENT_USES_XKB_STATE = 0x10;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ModDef {
+    pub mask: u8,
+    pub realMods: u8,
+    pub vmods: u16,
+}
+
+impl From<xcb_system::xcb_xkb_mod_def_t> for ModDef {
+    fn from(raw: xcb_system::xcb_xkb_mod_def_t) -> Self {
+        Self {
+            mask: raw.mask,
+            realMods: raw.realMods,
+            vmods: raw.vmods,
+        }
+    }
+}
+
+impl From<ModDef> for xcb_system::xcb_xkb_mod_def_t {
+    fn from(value: ModDef) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.mask = value.mask;
+        raw.realMods = value.realMods;
+        raw.vmods = value.vmods;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KeyName {
+    pub name: [u8; 4],
+}
+
+impl From<xcb_system::xcb_xkb_key_name_t> for KeyName {
+    fn from(raw: xcb_system::xcb_xkb_key_name_t) -> Self {
+        Self {
+            name: raw.name.map(|value| value as u8),
+        }
+    }
+}
+
+impl From<KeyName> for xcb_system::xcb_xkb_key_name_t {
+    fn from(value: KeyName) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.name = value.name.map(|value| value as _);
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KeyAlias {
+    pub real: [u8; 4],
+    pub alias: [u8; 4],
+}
+
+impl From<xcb_system::xcb_xkb_key_alias_t> for KeyAlias {
+    fn from(raw: xcb_system::xcb_xkb_key_alias_t) -> Self {
+        Self {
+            real: raw.real.map(|value| value as u8),
+            alias: raw.alias.map(|value| value as u8),
+        }
+    }
+}
+
+impl From<KeyAlias> for xcb_system::xcb_xkb_key_alias_t {
+    fn from(value: KeyAlias) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.real = value.real.map(|value| value as _);
+        raw.alias = value.alias.map(|value| value as _);
+
+        raw
+    }
+}
+
+// CountedString16 isn't generated, string has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KTMapEntry {
+    pub active: bool,
+    pub mods_mask: u8,
+    pub level: u8,
+    pub mods_mods: u8,
+    pub mods_vmods: u16,
+}
+
+impl From<xcb_system::xcb_xkb_kt_map_entry_t> for KTMapEntry {
+    fn from(raw: xcb_system::xcb_xkb_kt_map_entry_t) -> Self {
+        Self {
+            active: raw.active != 0,
+            mods_mask: raw.mods_mask,
+            level: raw.level,
+            mods_mods: raw.mods_mods,
+            mods_vmods: raw.mods_vmods,
+        }
+    }
+}
+
+impl From<KTMapEntry> for xcb_system::xcb_xkb_kt_map_entry_t {
+    fn from(value: KTMapEntry) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.active = u8::from(value.active);
+        raw.mods_mask = value.mods_mask;
+        raw.level = value.level;
+        raw.mods_mods = value.mods_mods;
+        raw.mods_vmods = value.mods_vmods;
+
+        raw
+    }
+}
+
+// KeyType isn't generated, map has a variable length.
+
+// KeySymMap isn't generated, syms has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CommonBehavior {
+    pub type_: u8,
+    pub data: u8,
+}
+
+impl From<xcb_system::xcb_xkb_common_behavior_t> for CommonBehavior {
+    fn from(raw: xcb_system::xcb_xkb_common_behavior_t) -> Self {
+        Self {
+            type_: raw.type_,
+            data: raw.data,
+        }
+    }
+}
+
+impl From<CommonBehavior> for xcb_system::xcb_xkb_common_behavior_t {
+    fn from(value: CommonBehavior) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.data = value.data;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DefaultBehavior {
+    pub type_: u8,
+}
+
+impl From<xcb_system::xcb_xkb_default_behavior_t> for DefaultBehavior {
+    fn from(raw: xcb_system::xcb_xkb_default_behavior_t) -> Self {
+        Self {
+            type_: raw.type_,
+        }
+    }
+}
+
+impl From<DefaultBehavior> for xcb_system::xcb_xkb_default_behavior_t {
+    fn from(value: DefaultBehavior) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+
+        raw
+    }
+}
+
+pub type LockBehavior = DefaultBehavior;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RadioGroupBehavior {
+    pub type_: u8,
+    pub group: u8,
+}
+
+impl From<xcb_system::xcb_xkb_radio_group_behavior_t> for RadioGroupBehavior {
+    fn from(raw: xcb_system::xcb_xkb_radio_group_behavior_t) -> Self {
+        Self {
+            type_: raw.type_,
+            group: raw.group,
+        }
+    }
+}
+
+impl From<RadioGroupBehavior> for xcb_system::xcb_xkb_radio_group_behavior_t {
+    fn from(value: RadioGroupBehavior) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.group = value.group;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OverlayBehavior {
+    pub type_: u8,
+    pub key: super::xproto::Keycode,
+}
+
+impl From<xcb_system::xcb_xkb_overlay_behavior_t> for OverlayBehavior {
+    fn from(raw: xcb_system::xcb_xkb_overlay_behavior_t) -> Self {
+        Self {
+            type_: raw.type_,
+            key: raw.key,
+        }
+    }
+}
+
+impl From<OverlayBehavior> for xcb_system::xcb_xkb_overlay_behavior_t {
+    fn from(value: OverlayBehavior) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.key = value.key;
+
+        raw
+    }
+}
+
+pub type PermamentLockBehavior = LockBehavior;
+
+pub type PermamentRadioGroupBehavior = RadioGroupBehavior;
+
+pub type PermamentOverlayBehavior = OverlayBehavior;
+
+pub type Behavior = [u8; 2];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BehaviorType(pub u32);
+
+impl BehaviorType {
+    pub const DEFAULT: Self = Self(0);
+    pub const LOCK: Self = Self(1);
+    pub const RADIO_GROUP: Self = Self(2);
+    pub const OVERLAY_1: Self = Self(3);
+    pub const OVERLAY_2: Self = Self(4);
+    pub const PERMAMENT_LOCK: Self = Self(129);
+    pub const PERMAMENT_RADIO_GROUP: Self = Self(130);
+    pub const PERMAMENT_OVERLAY_1: Self = Self(131);
+    pub const PERMAMENT_OVERLAY_2: Self = Self(132);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetBehavior {
+    pub keycode: super::xproto::Keycode,
+    pub behavior: Behavior,
+}
+
+impl From<xcb_system::xcb_xkb_set_behavior_t> for SetBehavior {
+    fn from(raw: xcb_system::xcb_xkb_set_behavior_t) -> Self {
+        Self {
+            keycode: raw.keycode,
+            behavior: unsafe { std::mem::transmute::<xcb_system::xcb_xkb_behavior_t, Behavior>(raw.behavior) },
+        }
+    }
+}
+
+impl From<SetBehavior> for xcb_system::xcb_xkb_set_behavior_t {
+    fn from(value: SetBehavior) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.keycode = value.keycode;
+        raw.behavior = unsafe { std::mem::transmute::<Behavior, xcb_system::xcb_xkb_behavior_t>(value.behavior) };
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetExplicit {
+    pub keycode: super::xproto::Keycode,
+    pub explicit: u8,
+}
+
+impl From<xcb_system::xcb_xkb_set_explicit_t> for SetExplicit {
+    fn from(raw: xcb_system::xcb_xkb_set_explicit_t) -> Self {
+        Self {
+            keycode: raw.keycode,
+            explicit: raw.explicit,
+        }
+    }
+}
+
+impl From<SetExplicit> for xcb_system::xcb_xkb_set_explicit_t {
+    fn from(value: SetExplicit) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.keycode = value.keycode;
+        raw.explicit = value.explicit;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KeyModMap {
+    pub keycode: super::xproto::Keycode,
+    pub mods: u8,
+}
+
+impl From<xcb_system::xcb_xkb_key_mod_map_t> for KeyModMap {
+    fn from(raw: xcb_system::xcb_xkb_key_mod_map_t) -> Self {
+        Self {
+            keycode: raw.keycode,
+            mods: raw.mods,
+        }
+    }
+}
+
+impl From<KeyModMap> for xcb_system::xcb_xkb_key_mod_map_t {
+    fn from(value: KeyModMap) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.keycode = value.keycode;
+        raw.mods = value.mods;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KeyVModMap {
+    pub keycode: super::xproto::Keycode,
+    pub vmods: u16,
+}
+
+impl From<xcb_system::xcb_xkb_key_v_mod_map_t> for KeyVModMap {
+    fn from(raw: xcb_system::xcb_xkb_key_v_mod_map_t) -> Self {
+        Self {
+            keycode: raw.keycode,
+            vmods: raw.vmods,
+        }
+    }
+}
+
+impl From<KeyVModMap> for xcb_system::xcb_xkb_key_v_mod_map_t {
+    fn from(value: KeyVModMap) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.keycode = value.keycode;
+        raw.vmods = value.vmods;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KTSetMapEntry {
+    pub level: u8,
+    pub realMods: u8,
+    pub virtualMods: u16,
+}
+
+impl From<xcb_system::xcb_xkb_kt_set_map_entry_t> for KTSetMapEntry {
+    fn from(raw: xcb_system::xcb_xkb_kt_set_map_entry_t) -> Self {
+        Self {
+            level: raw.level,
+            realMods: raw.realMods,
+            virtualMods: raw.virtualMods,
+        }
+    }
+}
+
+impl From<KTSetMapEntry> for xcb_system::xcb_xkb_kt_set_map_entry_t {
+    fn from(value: KTSetMapEntry) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.level = value.level;
+        raw.realMods = value.realMods;
+        raw.virtualMods = value.virtualMods;
+
+        raw
+    }
+}
+
+// SetKeyType isn't generated, entries has a variable length.
+
+pub type String8 = u8;
+
+// Outline isn't generated, points has a variable length.
+
+// Shape isn't generated, outlines has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Key {
+    pub name: [String8; 4],
+    pub gap: i16,
+    pub shapeNdx: u8,
+    pub colorNdx: u8,
+}
+
+impl From<xcb_system::xcb_xkb_key_t> for Key {
+    fn from(raw: xcb_system::xcb_xkb_key_t) -> Self {
+        Self {
+            name: raw.name.map(|value| value as u8),
+            gap: raw.gap,
+            shapeNdx: raw.shapeNdx,
+            colorNdx: raw.colorNdx,
+        }
+    }
+}
+
+impl From<Key> for xcb_system::xcb_xkb_key_t {
+    fn from(value: Key) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.name = value.name.map(|value| value as _);
+        raw.gap = value.gap;
+        raw.shapeNdx = value.shapeNdx;
+        raw.colorNdx = value.colorNdx;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OverlayKey {
+    pub over: [String8; 4],
+    pub under: [String8; 4],
+}
+
+impl From<xcb_system::xcb_xkb_overlay_key_t> for OverlayKey {
+    fn from(raw: xcb_system::xcb_xkb_overlay_key_t) -> Self {
+        Self {
+            over: raw.over.map(|value| value as u8),
+            under: raw.under.map(|value| value as u8),
+        }
+    }
+}
+
+impl From<OverlayKey> for xcb_system::xcb_xkb_overlay_key_t {
+    fn from(value: OverlayKey) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.over = value.over.map(|value| value as _);
+        raw.under = value.under.map(|value| value as _);
+
+        raw
+    }
+}
+
+// OverlayRow isn't generated, keys has a variable length.
+
+// Overlay isn't generated, rows has a variable length.
+
+// Row isn't generated, keys has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DoodadType(pub u32);
+
+impl DoodadType {
+    pub const OUTLINE: Self = Self(1);
+    pub const SOLID: Self = Self(2);
+    pub const TEXT: Self = Self(3);
+    pub const INDICATOR: Self = Self(4);
+    pub const LOGO: Self = Self(5);
+}
+
+// Listing isn't generated, string has a variable length.
+
+// DeviceLedInfo isn't generated, names has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Error(pub u32);
+
+impl Error {
+    pub const BAD_DEVICE: Self = Self(255);
+    pub const BAD_CLASS: Self = Self(254);
+    pub const BAD_ID: Self = Self(253);
+}
+
+bitflags! {
+    pub struct Sa: u32 {
+        const CLEAR_LOCKS = 0x1;
+        const LATCH_TO_LOCK = 0x2;
+        const USE_MOD_MAP_MODS = 0x4;
+        const GROUP_ABSOLUTE = 0x4;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SAType(pub u32);
+
+impl SAType {
+    pub const NO_ACTION: Self = Self(0);
+    pub const SET_MODS: Self = Self(1);
+    pub const LATCH_MODS: Self = Self(2);
+    pub const LOCK_MODS: Self = Self(3);
+    pub const SET_GROUP: Self = Self(4);
+    pub const LATCH_GROUP: Self = Self(5);
+    pub const LOCK_GROUP: Self = Self(6);
+    pub const MOVE_PTR: Self = Self(7);
+    pub const PTR_BTN: Self = Self(8);
+    pub const LOCK_PTR_BTN: Self = Self(9);
+    pub const SET_PTR_DFLT: Self = Self(10);
+    pub const ISO_LOCK: Self = Self(11);
+    pub const TERMINATE: Self = Self(12);
+    pub const SWITCH_SCREEN: Self = Self(13);
+    pub const SET_CONTROLS: Self = Self(14);
+    pub const LOCK_CONTROLS: Self = Self(15);
+    pub const MESSAGE: Self = Self(16);
+    pub const REDIRECT_KEY: Self = Self(17);
+    pub const DEVICE_BTN: Self = Self(18);
+    pub const LOCK_DEVICE_BTN: Self = Self(19);
+    pub const DEVICE_VALUATOR: Self = Self(20);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SANoAction {
+    pub type_: u8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_no_action_t> for SANoAction {
+    fn from(raw: xcb_system::xcb_xkb_sa_no_action_t) -> Self {
+        Self {
+            type_: raw.type_,
+        }
+    }
+}
+
+impl From<SANoAction> for xcb_system::xcb_xkb_sa_no_action_t {
+    fn from(value: SANoAction) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SASetMods {
+    pub type_: u8,
+    pub flags: u8,
+    pub mask: u8,
+    pub realMods: u8,
+    pub vmodsHigh: u8,
+    pub vmodsLow: u8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_set_mods_t> for SASetMods {
+    fn from(raw: xcb_system::xcb_xkb_sa_set_mods_t) -> Self {
+        Self {
+            type_: raw.type_,
+            flags: raw.flags,
+            mask: raw.mask,
+            realMods: raw.realMods,
+            vmodsHigh: raw.vmodsHigh,
+            vmodsLow: raw.vmodsLow,
+        }
+    }
+}
+
+impl From<SASetMods> for xcb_system::xcb_xkb_sa_set_mods_t {
+    fn from(value: SASetMods) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.flags = value.flags;
+        raw.mask = value.mask;
+        raw.realMods = value.realMods;
+        raw.vmodsHigh = value.vmodsHigh;
+        raw.vmodsLow = value.vmodsLow;
+
+        raw
+    }
+}
+
+pub type SALatchMods = SASetMods;
+
+pub type SALockMods = SASetMods;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SASetGroup {
+    pub type_: u8,
+    pub flags: u8,
+    pub group: i8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_set_group_t> for SASetGroup {
+    fn from(raw: xcb_system::xcb_xkb_sa_set_group_t) -> Self {
+        Self {
+            type_: raw.type_,
+            flags: raw.flags,
+            group: raw.group,
+        }
+    }
+}
+
+impl From<SASetGroup> for xcb_system::xcb_xkb_sa_set_group_t {
+    fn from(value: SASetGroup) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.flags = value.flags;
+        raw.group = value.group;
+
+        raw
+    }
+}
+
+pub type SALatchGroup = SASetGroup;
+
+pub type SALockGroup = SASetGroup;
+
+bitflags! {
+    pub struct SAMovePtrFlag: u32 {
+        const NO_ACCELERATION = 0x1;
+        const MOVE_ABSOLUTE_X = 0x2;
+        const MOVE_ABSOLUTE_Y = 0x4;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SAMovePtr {
+    pub type_: u8,
+    pub flags: u8,
+    pub xHigh: i8,
+    pub xLow: u8,
+    pub yHigh: i8,
+    pub yLow: u8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_move_ptr_t> for SAMovePtr {
+    fn from(raw: xcb_system::xcb_xkb_sa_move_ptr_t) -> Self {
+        Self {
+            type_: raw.type_,
+            flags: raw.flags,
+            xHigh: raw.xHigh,
+            xLow: raw.xLow,
+            yHigh: raw.yHigh,
+            yLow: raw.yLow,
+        }
+    }
+}
+
+impl From<SAMovePtr> for xcb_system::xcb_xkb_sa_move_ptr_t {
+    fn from(value: SAMovePtr) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.flags = value.flags;
+        raw.xHigh = value.xHigh;
+        raw.xLow = value.xLow;
+        raw.yHigh = value.yHigh;
+        raw.yLow = value.yLow;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SAPtrBtn {
+    pub type_: u8,
+    pub flags: u8,
+    pub count: u8,
+    pub button: u8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_ptr_btn_t> for SAPtrBtn {
+    fn from(raw: xcb_system::xcb_xkb_sa_ptr_btn_t) -> Self {
+        Self {
+            type_: raw.type_,
+            flags: raw.flags,
+            count: raw.count,
+            button: raw.button,
+        }
+    }
+}
+
+impl From<SAPtrBtn> for xcb_system::xcb_xkb_sa_ptr_btn_t {
+    fn from(value: SAPtrBtn) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.flags = value.flags;
+        raw.count = value.count;
+        raw.button = value.button;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SALockPtrBtn {
+    pub type_: u8,
+    pub flags: u8,
+    pub button: u8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_lock_ptr_btn_t> for SALockPtrBtn {
+    fn from(raw: xcb_system::xcb_xkb_sa_lock_ptr_btn_t) -> Self {
+        Self {
+            type_: raw.type_,
+            flags: raw.flags,
+            button: raw.button,
+        }
+    }
+}
+
+impl From<SALockPtrBtn> for xcb_system::xcb_xkb_sa_lock_ptr_btn_t {
+    fn from(value: SALockPtrBtn) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.flags = value.flags;
+        raw.button = value.button;
+
+        raw
+    }
+}
+
+bitflags! {
+    pub struct SASetPtrDfltFlag: u32 {
+        const DFLT_BTN_ABSOLUTE = 0x4;
+        const AFFECT_DFLT_BUTTON = 0x1;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SASetPtrDflt {
+    pub type_: u8,
+    pub flags: u8,
+    pub affect: u8,
+    pub value: i8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_set_ptr_dflt_t> for SASetPtrDflt {
+    fn from(raw: xcb_system::xcb_xkb_sa_set_ptr_dflt_t) -> Self {
+        Self {
+            type_: raw.type_,
+            flags: raw.flags,
+            affect: raw.affect,
+            value: raw.value,
+        }
+    }
+}
+
+impl From<SASetPtrDflt> for xcb_system::xcb_xkb_sa_set_ptr_dflt_t {
+    fn from(value: SASetPtrDflt) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.flags = value.flags;
+        raw.affect = value.affect;
+        raw.value = value.value;
+
+        raw
+    }
+}
+
+bitflags! {
+    pub struct SAIsoLockFlag: u32 {
+        const NO_LOCK = 0x1;
+        const NO_UNLOCK = 0x2;
+        const USE_MOD_MAP_MODS = 0x4;
+        const GROUP_ABSOLUTE = 0x4;
+        const ISO_DFLT_IS_GROUP = 0x8;
+    }
+}
+
+bitflags! {
+    pub struct SAIsoLockNoAffect: u32 {
+        const CTRLS = 0x8;
+        const PTR = 0x10;
+        const GROUP = 0x20;
+        const MODS = 0x40;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SAIsoLock {
+    pub type_: u8,
+    pub flags: u8,
+    pub mask: u8,
+    pub realMods: u8,
+    pub group: i8,
+    pub affect: u8,
+    pub vmodsHigh: u8,
+    pub vmodsLow: u8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_iso_lock_t> for SAIsoLock {
+    fn from(raw: xcb_system::xcb_xkb_sa_iso_lock_t) -> Self {
+        Self {
+            type_: raw.type_,
+            flags: raw.flags,
+            mask: raw.mask,
+            realMods: raw.realMods,
+            group: raw.group,
+            affect: raw.affect,
+            vmodsHigh: raw.vmodsHigh,
+            vmodsLow: raw.vmodsLow,
+        }
+    }
+}
+
+impl From<SAIsoLock> for xcb_system::xcb_xkb_sa_iso_lock_t {
+    fn from(value: SAIsoLock) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.flags = value.flags;
+        raw.mask = value.mask;
+        raw.realMods = value.realMods;
+        raw.group = value.group;
+        raw.affect = value.affect;
+        raw.vmodsHigh = value.vmodsHigh;
+        raw.vmodsLow = value.vmodsLow;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SATerminate {
+    pub type_: u8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_terminate_t> for SATerminate {
+    fn from(raw: xcb_system::xcb_xkb_sa_terminate_t) -> Self {
+        Self {
+            type_: raw.type_,
+        }
+    }
+}
+
+impl From<SATerminate> for xcb_system::xcb_xkb_sa_terminate_t {
+    fn from(value: SATerminate) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+
+        raw
+    }
+}
+
+bitflags! {
+    pub struct SwitchScreenFlag: u32 {
+        const APPLICATION = 0x1;
+        const ABSOLUTE = 0x4;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SASwitchScreen {
+    pub type_: u8,
+    pub flags: u8,
+    pub newScreen: i8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_switch_screen_t> for SASwitchScreen {
+    fn from(raw: xcb_system::xcb_xkb_sa_switch_screen_t) -> Self {
+        Self {
+            type_: raw.type_,
+            flags: raw.flags,
+            newScreen: raw.newScreen,
+        }
+    }
+}
+
+impl From<SASwitchScreen> for xcb_system::xcb_xkb_sa_switch_screen_t {
+    fn from(value: SASwitchScreen) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.flags = value.flags;
+        raw.newScreen = value.newScreen;
+
+        raw
+    }
+}
+
+bitflags! {
+    pub struct BoolCtrlsHigh: u32 {
+        const ACCESS_X_FEEDBACK = 0x1;
+        const AUDIBLE_BELL = 0x2;
+        const OVERLAY_1 = 0x4;
+        const OVERLAY_2 = 0x8;
+        const IGNORE_GROUP_LOCK = 0x10;
+    }
+}
+
+bitflags! {
+    pub struct BoolCtrlsLow: u32 {
+        const REPEAT_KEYS = 0x1;
+        const SLOW_KEYS = 0x2;
+        const BOUNCE_KEYS = 0x4;
+        const STICKY_KEYS = 0x8;
+        const MOUSE_KEYS = 0x10;
+        const MOUSE_KEYS_ACCEL = 0x20;
+        const ACCESS_X_KEYS = 0x40;
+        const ACCESS_X_TIMEOUT = 0x80;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SASetControls {
+    pub type_: u8,
+    pub boolCtrlsHigh: u8,
+    pub boolCtrlsLow: u8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_set_controls_t> for SASetControls {
+    fn from(raw: xcb_system::xcb_xkb_sa_set_controls_t) -> Self {
+        Self {
+            type_: raw.type_,
+            boolCtrlsHigh: raw.boolCtrlsHigh,
+            boolCtrlsLow: raw.boolCtrlsLow,
+        }
+    }
+}
+
+impl From<SASetControls> for xcb_system::xcb_xkb_sa_set_controls_t {
+    fn from(value: SASetControls) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.boolCtrlsHigh = value.boolCtrlsHigh;
+        raw.boolCtrlsLow = value.boolCtrlsLow;
+
+        raw
+    }
+}
+
+pub type SALockControls = SASetControls;
+
+bitflags! {
+    pub struct ActionMessageFlag: u32 {
+        const ON_PRESS = 0x1;
+        const ON_RELEASE = 0x2;
+        const GEN_KEY_EVENT = 0x4;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SAActionMessage {
+    pub type_: u8,
+    pub flags: u8,
+    pub message: [u8; 6],
+}
+
+impl From<xcb_system::xcb_xkb_sa_action_message_t> for SAActionMessage {
+    fn from(raw: xcb_system::xcb_xkb_sa_action_message_t) -> Self {
+        Self {
+            type_: raw.type_,
+            flags: raw.flags,
+            message: raw.message,
+        }
+    }
+}
+
+impl From<SAActionMessage> for xcb_system::xcb_xkb_sa_action_message_t {
+    fn from(value: SAActionMessage) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.flags = value.flags;
+        raw.message = value.message.map(|value| value);
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SARedirectKey {
+    pub type_: u8,
+    pub newkey: super::xproto::Keycode,
+    pub mask: u8,
+    pub realModifiers: u8,
+    pub vmodsMaskHigh: u8,
+    pub vmodsMaskLow: u8,
+    pub vmodsHigh: u8,
+    pub vmodsLow: u8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_redirect_key_t> for SARedirectKey {
+    fn from(raw: xcb_system::xcb_xkb_sa_redirect_key_t) -> Self {
+        Self {
+            type_: raw.type_,
+            newkey: raw.newkey,
+            mask: raw.mask,
+            realModifiers: raw.realModifiers,
+            vmodsMaskHigh: raw.vmodsMaskHigh,
+            vmodsMaskLow: raw.vmodsMaskLow,
+            vmodsHigh: raw.vmodsHigh,
+            vmodsLow: raw.vmodsLow,
+        }
+    }
+}
+
+impl From<SARedirectKey> for xcb_system::xcb_xkb_sa_redirect_key_t {
+    fn from(value: SARedirectKey) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.newkey = value.newkey;
+        raw.mask = value.mask;
+        raw.realModifiers = value.realModifiers;
+        raw.vmodsMaskHigh = value.vmodsMaskHigh;
+        raw.vmodsMaskLow = value.vmodsMaskLow;
+        raw.vmodsHigh = value.vmodsHigh;
+        raw.vmodsLow = value.vmodsLow;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SADeviceBtn {
+    pub type_: u8,
+    pub flags: u8,
+    pub count: u8,
+    pub button: u8,
+    pub device: u8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_device_btn_t> for SADeviceBtn {
+    fn from(raw: xcb_system::xcb_xkb_sa_device_btn_t) -> Self {
+        Self {
+            type_: raw.type_,
+            flags: raw.flags,
+            count: raw.count,
+            button: raw.button,
+            device: raw.device,
+        }
+    }
+}
+
+impl From<SADeviceBtn> for xcb_system::xcb_xkb_sa_device_btn_t {
+    fn from(value: SADeviceBtn) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.flags = value.flags;
+        raw.count = value.count;
+        raw.button = value.button;
+        raw.device = value.device;
+
+        raw
+    }
+}
+
+bitflags! {
+    pub struct LockDeviceFlags: u32 {
+        const NO_LOCK = 0x1;
+        const NO_UNLOCK = 0x2;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SALockDeviceBtn {
+    pub type_: u8,
+    pub flags: u8,
+    pub button: u8,
+    pub device: u8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_lock_device_btn_t> for SALockDeviceBtn {
+    fn from(raw: xcb_system::xcb_xkb_sa_lock_device_btn_t) -> Self {
+        Self {
+            type_: raw.type_,
+            flags: raw.flags,
+            button: raw.button,
+            device: raw.device,
+        }
+    }
+}
+
+impl From<SALockDeviceBtn> for xcb_system::xcb_xkb_sa_lock_device_btn_t {
+    fn from(value: SALockDeviceBtn) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.flags = value.flags;
+        raw.button = value.button;
+        raw.device = value.device;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SAValWhat(pub u32);
+
+impl SAValWhat {
+    pub const IGNORE_VAL: Self = Self(0);
+    pub const SET_VAL_MIN: Self = Self(1);
+    pub const SET_VAL_CENTER: Self = Self(2);
+    pub const SET_VAL_MAX: Self = Self(3);
+    pub const SET_VAL_RELATIVE: Self = Self(4);
+    pub const SET_VAL_ABSOLUTE: Self = Self(5);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SADeviceValuator {
+    pub type_: u8,
+    pub device: u8,
+    pub val1what: u8,
+    pub val1index: u8,
+    pub val1value: u8,
+    pub val2what: u8,
+    pub val2index: u8,
+    pub val2value: u8,
+}
+
+impl From<xcb_system::xcb_xkb_sa_device_valuator_t> for SADeviceValuator {
+    fn from(raw: xcb_system::xcb_xkb_sa_device_valuator_t) -> Self {
+        Self {
+            type_: raw.type_,
+            device: raw.device,
+            val1what: raw.val1what,
+            val1index: raw.val1index,
+            val1value: raw.val1value,
+            val2what: raw.val2what,
+            val2index: raw.val2index,
+            val2value: raw.val2value,
+        }
+    }
+}
+
+impl From<SADeviceValuator> for xcb_system::xcb_xkb_sa_device_valuator_t {
+    fn from(value: SADeviceValuator) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.device = value.device;
+        raw.val1what = value.val1what;
+        raw.val1index = value.val1index;
+        raw.val1value = value.val1value;
+        raw.val2what = value.val2what;
+        raw.val2index = value.val2index;
+        raw.val2value = value.val2value;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SIAction {
+    pub type_: u8,
+    pub data: [u8; 7],
+}
+
+impl From<xcb_system::xcb_xkb_si_action_t> for SIAction {
+    fn from(raw: xcb_system::xcb_xkb_si_action_t) -> Self {
+        Self {
+            type_: raw.type_,
+            data: raw.data,
+        }
+    }
+}
+
+impl From<SIAction> for xcb_system::xcb_xkb_si_action_t {
+    fn from(value: SIAction) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.type_ = value.type_;
+        raw.data = value.data.map(|value| value);
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SymInterpret {
+    pub sym: super::xproto::Keysym,
+    pub mods: u8,
+    pub match_: u8,
+    pub virtualMod: u8,
+    pub flags: u8,
+    pub action: SIAction,
+}
+
+impl From<xcb_system::xcb_xkb_sym_interpret_t> for SymInterpret {
+    fn from(raw: xcb_system::xcb_xkb_sym_interpret_t) -> Self {
+        Self {
+            sym: raw.sym,
+            mods: raw.mods,
+            match_: raw.match_,
+            virtualMod: raw.virtualMod,
+            flags: raw.flags,
+            action: SIAction::from(raw.action),
+        }
+    }
+}
+
+impl From<SymInterpret> for xcb_system::xcb_xkb_sym_interpret_t {
+    fn from(value: SymInterpret) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.sym = value.sym;
+        raw.mods = value.mods;
+        raw.match_ = value.match_;
+        raw.virtualMod = value.virtualMod;
+        raw.flags = value.flags;
+        raw.action = xcb_system::xcb_xkb_si_action_t::from(value.action);
+
+        raw
+    }
+}
+
+pub type Action = [u8; 8];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseExtensionReply {
+    pub supported: bool,
+    pub serverMajor: u16,
+    pub serverMinor: u16,
+}
+
+pub struct UseExtensionRequest;
+
+impl crate::result::Request for UseExtensionRequest {
+    type Reply = UseExtensionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<UseExtensionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xkb_use_extension_reply_t>(connection, sequence)?;
+
+        Ok(UseExtensionReply {
+            supported: reply.supported != 0,
+            serverMajor: reply.serverMajor,
+            serverMinor: reply.serverMinor,
+        })
+    }
+}
+
+pub fn use_extension<'a>(connection: &'a crate::connection::Connection, wantedMajor: u16, wantedMinor: u16) -> crate::result::Cookie<'a, UseExtensionRequest> {
+    let cookie = unsafe { xcb_system::xcb_xkb_use_extension(connection.get_connection(), wantedMajor, wantedMinor) };
+
+    crate::result::Cookie::new(cookie.sequence, UseExtensionRequest, connection)
+}
+
+// SelectEvents isn't generated, the mask of details isn't a plain field.
+
+pub fn bell<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, bellClass: BellClassSpec, bellID: IDSpec, percent: i8, forceSound: bool, eventOnly: bool, pitch: i16, duration: i16, name: super::xproto::Atom, window: super::xproto::Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xkb_bell_checked(connection.get_connection(), deviceSpec, bellClass, bellID, percent, u8::from(forceSound), u8::from(eventOnly), pitch, duration, name, window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn bell_unchecked<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, bellClass: BellClassSpec, bellID: IDSpec, percent: i8, forceSound: bool, eventOnly: bool, pitch: i16, duration: i16, name: super::xproto::Atom, window: super::xproto::Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xkb_bell(connection.get_connection(), deviceSpec, bellClass, bellID, percent, u8::from(forceSound), u8::from(eventOnly), pitch, duration, name, window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetStateReply {
+    pub deviceID: u8,
+    pub mods: u8,
+    pub baseMods: u8,
+    pub latchedMods: u8,
+    pub lockedMods: u8,
+    pub group: u8,
+    pub lockedGroup: u8,
+    pub baseGroup: i16,
+    pub latchedGroup: i16,
+    pub compatState: u8,
+    pub grabMods: u8,
+    pub compatGrabMods: u8,
+    pub lookupMods: u8,
+    pub compatLookupMods: u8,
+    pub ptrBtnState: u16,
+}
+
+pub struct GetStateRequest;
+
+impl crate::result::Request for GetStateRequest {
+    type Reply = GetStateReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetStateReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xkb_get_state_reply_t>(connection, sequence)?;
+
+        Ok(GetStateReply {
+            deviceID: reply.deviceID,
+            mods: reply.mods,
+            baseMods: reply.baseMods,
+            latchedMods: reply.latchedMods,
+            lockedMods: reply.lockedMods,
+            group: reply.group,
+            lockedGroup: reply.lockedGroup,
+            baseGroup: reply.baseGroup,
+            latchedGroup: reply.latchedGroup,
+            compatState: reply.compatState,
+            grabMods: reply.grabMods,
+            compatGrabMods: reply.compatGrabMods,
+            lookupMods: reply.lookupMods,
+            compatLookupMods: reply.compatLookupMods,
+            ptrBtnState: reply.ptrBtnState,
+        })
+    }
+}
+
+pub fn get_state<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec) -> crate::result::Cookie<'a, GetStateRequest> {
+    let cookie = unsafe { xcb_system::xcb_xkb_get_state(connection.get_connection(), deviceSpec) };
+
+    crate::result::Cookie::new(cookie.sequence, GetStateRequest, connection)
+}
+
+pub fn latch_lock_state<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, affectModLocks: u8, modLocks: u8, lockGroup: bool, groupLock: u8, affectModLatches: u8, latchGroup: bool, groupLatch: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xkb_latch_lock_state_checked(connection.get_connection(), deviceSpec, affectModLocks, modLocks, u8::from(lockGroup), groupLock, affectModLatches, u8::from(latchGroup), groupLatch) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn latch_lock_state_unchecked<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, affectModLocks: u8, modLocks: u8, lockGroup: bool, groupLock: u8, affectModLatches: u8, latchGroup: bool, groupLatch: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xkb_latch_lock_state(connection.get_connection(), deviceSpec, affectModLocks, modLocks, u8::from(lockGroup), groupLock, affectModLatches, u8::from(latchGroup), groupLatch) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetControlsReply {
+    pub deviceID: u8,
+    pub mouseKeysDfltBtn: u8,
+    pub numGroups: u8,
+    pub groupsWrap: u8,
+    pub internalModsMask: u8,
+    pub ignoreLockModsMask: u8,
+    pub internalModsRealMods: u8,
+    pub ignoreLockModsRealMods: u8,
+    pub internalModsVmods: u16,
+    pub ignoreLockModsVmods: u16,
+    pub repeatDelay: u16,
+    pub repeatInterval: u16,
+    pub slowKeysDelay: u16,
+    pub debounceDelay: u16,
+    pub mouseKeysDelay: u16,
+    pub mouseKeysInterval: u16,
+    pub mouseKeysTimeToMax: u16,
+    pub mouseKeysMaxSpeed: u16,
+    pub mouseKeysCurve: i16,
+    pub accessXOption: u16,
+    pub accessXTimeout: u16,
+    pub accessXTimeoutOptionsMask: u16,
+    pub accessXTimeoutOptionsValues: u16,
+    pub accessXTimeoutMask: u32,
+    pub accessXTimeoutValues: u32,
+    pub enabledControls: u32,
+    pub perKeyRepeat: [u8; 32],
+}
+
+pub struct GetControlsRequest;
+
+impl crate::result::Request for GetControlsRequest {
+    type Reply = GetControlsReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetControlsReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xkb_get_controls_reply_t>(connection, sequence)?;
+
+        Ok(GetControlsReply {
+            deviceID: reply.deviceID,
+            mouseKeysDfltBtn: reply.mouseKeysDfltBtn,
+            numGroups: reply.numGroups,
+            groupsWrap: reply.groupsWrap,
+            internalModsMask: reply.internalModsMask,
+            ignoreLockModsMask: reply.ignoreLockModsMask,
+            internalModsRealMods: reply.internalModsRealMods,
+            ignoreLockModsRealMods: reply.ignoreLockModsRealMods,
+            internalModsVmods: reply.internalModsVmods,
+            ignoreLockModsVmods: reply.ignoreLockModsVmods,
+            repeatDelay: reply.repeatDelay,
+            repeatInterval: reply.repeatInterval,
+            slowKeysDelay: reply.slowKeysDelay,
+            debounceDelay: reply.debounceDelay,
+            mouseKeysDelay: reply.mouseKeysDelay,
+            mouseKeysInterval: reply.mouseKeysInterval,
+            mouseKeysTimeToMax: reply.mouseKeysTimeToMax,
+            mouseKeysMaxSpeed: reply.mouseKeysMaxSpeed,
+            mouseKeysCurve: reply.mouseKeysCurve,
+            accessXOption: reply.accessXOption,
+            accessXTimeout: reply.accessXTimeout,
+            accessXTimeoutOptionsMask: reply.accessXTimeoutOptionsMask,
+            accessXTimeoutOptionsValues: reply.accessXTimeoutOptionsValues,
+            accessXTimeoutMask: reply.accessXTimeoutMask,
+            accessXTimeoutValues: reply.accessXTimeoutValues,
+            enabledControls: reply.enabledControls,
+            perKeyRepeat: reply.perKeyRepeat,
+        })
+    }
+}
+
+pub fn get_controls<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec) -> crate::result::Cookie<'a, GetControlsRequest> {
+    let cookie = unsafe { xcb_system::xcb_xkb_get_controls(connection.get_connection(), deviceSpec) };
+
+    crate::result::Cookie::new(cookie.sequence, GetControlsRequest, connection)
+}
+
+pub fn set_controls<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, affectInternalRealMods: u8, internalRealMods: u8, affectIgnoreLockRealMods: u8, ignoreLockRealMods: u8, affectInternalVirtualMods: u16, internalVirtualMods: u16, affectIgnoreLockVirtualMods: u16, ignoreLockVirtualMods: u16, mouseKeysDfltBtn: u8, groupsWrap: u8, accessXOptions: u16, affectEnabledControls: u32, enabledControls: u32, changeControls: u32, repeatDelay: u16, repeatInterval: u16, slowKeysDelay: u16, debounceDelay: u16, mouseKeysDelay: u16, mouseKeysInterval: u16, mouseKeysTimeToMax: u16, mouseKeysMaxSpeed: u16, mouseKeysCurve: i16, accessXTimeout: u16, accessXTimeoutMask: u32, accessXTimeoutValues: u32, accessXTimeoutOptionsMask: u16, accessXTimeoutOptionsValues: u16, perKeyRepeat: &[u8; 32]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xkb_set_controls_checked(connection.get_connection(), deviceSpec, affectInternalRealMods, internalRealMods, affectIgnoreLockRealMods, ignoreLockRealMods, affectInternalVirtualMods, internalVirtualMods, affectIgnoreLockVirtualMods, ignoreLockVirtualMods, mouseKeysDfltBtn, groupsWrap, accessXOptions, affectEnabledControls, enabledControls, changeControls, repeatDelay, repeatInterval, slowKeysDelay, debounceDelay, mouseKeysDelay, mouseKeysInterval, mouseKeysTimeToMax, mouseKeysMaxSpeed, mouseKeysCurve, accessXTimeout, accessXTimeoutMask, accessXTimeoutValues, accessXTimeoutOptionsMask, accessXTimeoutOptionsValues, perKeyRepeat.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_controls_unchecked<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, affectInternalRealMods: u8, internalRealMods: u8, affectIgnoreLockRealMods: u8, ignoreLockRealMods: u8, affectInternalVirtualMods: u16, internalVirtualMods: u16, affectIgnoreLockVirtualMods: u16, ignoreLockVirtualMods: u16, mouseKeysDfltBtn: u8, groupsWrap: u8, accessXOptions: u16, affectEnabledControls: u32, enabledControls: u32, changeControls: u32, repeatDelay: u16, repeatInterval: u16, slowKeysDelay: u16, debounceDelay: u16, mouseKeysDelay: u16, mouseKeysInterval: u16, mouseKeysTimeToMax: u16, mouseKeysMaxSpeed: u16, mouseKeysCurve: i16, accessXTimeout: u16, accessXTimeoutMask: u32, accessXTimeoutValues: u32, accessXTimeoutOptionsMask: u16, accessXTimeoutOptionsValues: u16, perKeyRepeat: &[u8; 32]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xkb_set_controls(connection.get_connection(), deviceSpec, affectInternalRealMods, internalRealMods, affectIgnoreLockRealMods, ignoreLockRealMods, affectInternalVirtualMods, internalVirtualMods, affectIgnoreLockVirtualMods, ignoreLockVirtualMods, mouseKeysDfltBtn, groupsWrap, accessXOptions, affectEnabledControls, enabledControls, changeControls, repeatDelay, repeatInterval, slowKeysDelay, debounceDelay, mouseKeysDelay, mouseKeysInterval, mouseKeysTimeToMax, mouseKeysMaxSpeed, mouseKeysCurve, accessXTimeout, accessXTimeoutMask, accessXTimeoutValues, accessXTimeoutOptionsMask, accessXTimeoutOptionsValues, perKeyRepeat.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+// GetMap isn't generated, map is a switch.
+
+// SetMap isn't generated, values has cases of more than one value.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetCompatMapReply {
+    pub deviceID: u8,
+    pub groupsRtrn: u8,
+    pub firstSIRtrn: u16,
+    pub nSIRtrn: u16,
+    pub nTotalSI: u16,
+    pub si_rtrn: Vec<SymInterpret>,
+    pub group_rtrn: Vec<ModDef>,
+}
+
+pub struct GetCompatMapRequest;
+
+impl crate::result::Request for GetCompatMapRequest {
+    type Reply = GetCompatMapReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetCompatMapReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xkb_get_compat_map_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetCompatMapReply {
+            deviceID: reply.deviceID,
+            groupsRtrn: reply.groupsRtrn,
+            firstSIRtrn: reply.firstSIRtrn,
+            nSIRtrn: reply.nSIRtrn,
+            nTotalSI: reply.nTotalSI,
+            si_rtrn: std::slice::from_raw_parts(xcb_system::xcb_xkb_get_compat_map_si_rtrn(reply.as_ptr()) as *const xcb_system::xcb_xkb_sym_interpret_t, xcb_system::xcb_xkb_get_compat_map_si_rtrn_length(reply.as_ptr()) as usize).iter().map(|&value| SymInterpret::from(value)).collect(),
+            group_rtrn: std::slice::from_raw_parts(xcb_system::xcb_xkb_get_compat_map_group_rtrn(reply.as_ptr()) as *const xcb_system::xcb_xkb_mod_def_t, xcb_system::xcb_xkb_get_compat_map_group_rtrn_length(reply.as_ptr()) as usize).iter().map(|&value| ModDef::from(value)).collect(),
+        } })
+    }
+}
+
+pub fn get_compat_map<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, groups: u8, getAllSI: bool, firstSI: u16, nSI: u16) -> crate::result::Cookie<'a, GetCompatMapRequest> {
+    let cookie = unsafe { xcb_system::xcb_xkb_get_compat_map(connection.get_connection(), deviceSpec, groups, u8::from(getAllSI), firstSI, nSI) };
+
+    crate::result::Cookie::new(cookie.sequence, GetCompatMapRequest, connection)
+}
+
+pub fn set_compat_map<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, recomputeActions: bool, truncateSI: bool, groups: u8, firstSI: u16, si: &[SymInterpret], groupMaps: &[ModDef]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let si: Vec<xcb_system::xcb_xkb_sym_interpret_t> = si.iter().map(|&value| xcb_system::xcb_xkb_sym_interpret_t::from(value)).collect();
+    assert!(groupMaps.len() >= ((groups as usize).count_ones() as usize) as usize);
+    let groupMaps: Vec<xcb_system::xcb_xkb_mod_def_t> = groupMaps.iter().map(|&value| xcb_system::xcb_xkb_mod_def_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_xkb_set_compat_map_checked(connection.get_connection(), deviceSpec, u8::from(recomputeActions), u8::from(truncateSI), groups, firstSI, si.len() as _, si.as_ptr() as *const _, groupMaps.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_compat_map_unchecked<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, recomputeActions: bool, truncateSI: bool, groups: u8, firstSI: u16, si: &[SymInterpret], groupMaps: &[ModDef]) -> crate::result::UncheckedCookie {
+    let si: Vec<xcb_system::xcb_xkb_sym_interpret_t> = si.iter().map(|&value| xcb_system::xcb_xkb_sym_interpret_t::from(value)).collect();
+    assert!(groupMaps.len() >= ((groups as usize).count_ones() as usize) as usize);
+    let groupMaps: Vec<xcb_system::xcb_xkb_mod_def_t> = groupMaps.iter().map(|&value| xcb_system::xcb_xkb_mod_def_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_xkb_set_compat_map(connection.get_connection(), deviceSpec, u8::from(recomputeActions), u8::from(truncateSI), groups, firstSI, si.len() as _, si.as_ptr() as *const _, groupMaps.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetIndicatorStateReply {
+    pub deviceID: u8,
+    pub state: u32,
+}
+
+pub struct GetIndicatorStateRequest;
+
+impl crate::result::Request for GetIndicatorStateRequest {
+    type Reply = GetIndicatorStateReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetIndicatorStateReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xkb_get_indicator_state_reply_t>(connection, sequence)?;
+
+        Ok(GetIndicatorStateReply {
+            deviceID: reply.deviceID,
+            state: reply.state,
+        })
+    }
+}
+
+pub fn get_indicator_state<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec) -> crate::result::Cookie<'a, GetIndicatorStateRequest> {
+    let cookie = unsafe { xcb_system::xcb_xkb_get_indicator_state(connection.get_connection(), deviceSpec) };
+
+    crate::result::Cookie::new(cookie.sequence, GetIndicatorStateRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetIndicatorMapReply {
+    pub deviceID: u8,
+    pub which: u32,
+    pub realIndicators: u32,
+    pub nIndicators: u8,
+    pub maps: Vec<IndicatorMap>,
+}
+
+pub struct GetIndicatorMapRequest;
+
+impl crate::result::Request for GetIndicatorMapRequest {
+    type Reply = GetIndicatorMapReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetIndicatorMapReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xkb_get_indicator_map_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetIndicatorMapReply {
+            deviceID: reply.deviceID,
+            which: reply.which,
+            realIndicators: reply.realIndicators,
+            nIndicators: reply.nIndicators,
+            maps: std::slice::from_raw_parts(xcb_system::xcb_xkb_get_indicator_map_maps(reply.as_ptr()) as *const xcb_system::xcb_xkb_indicator_map_t, xcb_system::xcb_xkb_get_indicator_map_maps_length(reply.as_ptr()) as usize).iter().map(|&value| IndicatorMap::from(value)).collect(),
+        } })
+    }
+}
+
+pub fn get_indicator_map<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, which: u32) -> crate::result::Cookie<'a, GetIndicatorMapRequest> {
+    let cookie = unsafe { xcb_system::xcb_xkb_get_indicator_map(connection.get_connection(), deviceSpec, which) };
+
+    crate::result::Cookie::new(cookie.sequence, GetIndicatorMapRequest, connection)
+}
+
+pub fn set_indicator_map<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, which: u32, maps: &[IndicatorMap]) -> crate::result::Cookie<'a, crate::result::Void> {
+    assert!(maps.len() >= ((which as usize).count_ones() as usize) as usize);
+    let maps: Vec<xcb_system::xcb_xkb_indicator_map_t> = maps.iter().map(|&value| xcb_system::xcb_xkb_indicator_map_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_xkb_set_indicator_map_checked(connection.get_connection(), deviceSpec, which, maps.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_indicator_map_unchecked<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, which: u32, maps: &[IndicatorMap]) -> crate::result::UncheckedCookie {
+    assert!(maps.len() >= ((which as usize).count_ones() as usize) as usize);
+    let maps: Vec<xcb_system::xcb_xkb_indicator_map_t> = maps.iter().map(|&value| xcb_system::xcb_xkb_indicator_map_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_xkb_set_indicator_map(connection.get_connection(), deviceSpec, which, maps.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetNamedIndicatorReply {
+    pub deviceID: u8,
+    pub indicator: super::xproto::Atom,
+    pub found: bool,
+    pub on: bool,
+    pub realIndicator: bool,
+    pub ndx: u8,
+    pub map_flags: u8,
+    pub map_whichGroups: u8,
+    pub map_groups: u8,
+    pub map_whichMods: u8,
+    pub map_mods: u8,
+    pub map_realMods: u8,
+    pub map_vmod: u16,
+    pub map_ctrls: u32,
+    pub supported: bool,
+}
+
+pub struct GetNamedIndicatorRequest;
+
+impl crate::result::Request for GetNamedIndicatorRequest {
+    type Reply = GetNamedIndicatorReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetNamedIndicatorReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xkb_get_named_indicator_reply_t>(connection, sequence)?;
+
+        Ok(GetNamedIndicatorReply {
+            deviceID: reply.deviceID,
+            indicator: reply.indicator,
+            found: reply.found != 0,
+            on: reply.on != 0,
+            realIndicator: reply.realIndicator != 0,
+            ndx: reply.ndx,
+            map_flags: reply.map_flags,
+            map_whichGroups: reply.map_whichGroups,
+            map_groups: reply.map_groups,
+            map_whichMods: reply.map_whichMods,
+            map_mods: reply.map_mods,
+            map_realMods: reply.map_realMods,
+            map_vmod: reply.map_vmod,
+            map_ctrls: reply.map_ctrls,
+            supported: reply.supported != 0,
+        })
+    }
+}
+
+pub fn get_named_indicator<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, ledClass: LedClassSpec, ledID: IDSpec, indicator: super::xproto::Atom) -> crate::result::Cookie<'a, GetNamedIndicatorRequest> {
+    let cookie = unsafe { xcb_system::xcb_xkb_get_named_indicator(connection.get_connection(), deviceSpec, ledClass, ledID, indicator) };
+
+    crate::result::Cookie::new(cookie.sequence, GetNamedIndicatorRequest, connection)
+}
+
+pub fn set_named_indicator<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, ledClass: LedClassSpec, ledID: IDSpec, indicator: super::xproto::Atom, setState: bool, on: bool, setMap: bool, createMap: bool, map_flags: u8, map_whichGroups: u8, map_groups: u8, map_whichMods: u8, map_realMods: u8, map_vmods: u16, map_ctrls: u32) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_xkb_set_named_indicator_checked(connection.get_connection(), deviceSpec, ledClass, ledID, indicator, u8::from(setState), u8::from(on), u8::from(setMap), u8::from(createMap), map_flags, map_whichGroups, map_groups, map_whichMods, map_realMods, map_vmods, map_ctrls) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_named_indicator_unchecked<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, ledClass: LedClassSpec, ledID: IDSpec, indicator: super::xproto::Atom, setState: bool, on: bool, setMap: bool, createMap: bool, map_flags: u8, map_whichGroups: u8, map_groups: u8, map_whichMods: u8, map_realMods: u8, map_vmods: u16, map_ctrls: u32) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_xkb_set_named_indicator(connection.get_connection(), deviceSpec, ledClass, ledID, indicator, u8::from(setState), u8::from(on), u8::from(setMap), u8::from(createMap), map_flags, map_whichGroups, map_groups, map_whichMods, map_realMods, map_vmods, map_ctrls) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+// GetNames isn't generated, valueList is a switch.
+
+// SetNames isn't generated, values has cases of more than one value.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PerClientFlagsReply {
+    pub deviceID: u8,
+    pub supported: u32,
+    pub value: u32,
+    pub autoCtrls: u32,
+    pub autoCtrlsValues: u32,
+}
+
+pub struct PerClientFlagsRequest;
+
+impl crate::result::Request for PerClientFlagsRequest {
+    type Reply = PerClientFlagsReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<PerClientFlagsReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xkb_per_client_flags_reply_t>(connection, sequence)?;
+
+        Ok(PerClientFlagsReply {
+            deviceID: reply.deviceID,
+            supported: reply.supported,
+            value: reply.value,
+            autoCtrls: reply.autoCtrls,
+            autoCtrlsValues: reply.autoCtrlsValues,
+        })
+    }
+}
+
+pub fn per_client_flags<'a>(connection: &'a crate::connection::Connection, deviceSpec: DeviceSpec, change: u32, value: u32, ctrlsToChange: u32, autoCtrls: u32, autoCtrlsValues: u32) -> crate::result::Cookie<'a, PerClientFlagsRequest> {
+    let cookie = unsafe { xcb_system::xcb_xkb_per_client_flags(connection.get_connection(), deviceSpec, change, value, ctrlsToChange, autoCtrls, autoCtrlsValues) };
+
+    crate::result::Cookie::new(cookie.sequence, PerClientFlagsRequest, connection)
+}
+
+// ListComponents isn't generated, Listing has a variable size.
+
+// GetKbdByName isn't generated, replies is a switch.
+
+// GetDeviceInfo isn't generated, it has alignment pads.
+
+// SetDeviceInfo isn't generated, btnActions is a list of unions.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetDebuggingFlagsReply {
+    pub currentFlags: u32,
+    pub currentCtrls: u32,
+    pub supportedFlags: u32,
+    pub supportedCtrls: u32,
+}
+
+pub struct SetDebuggingFlagsRequest;
+
+impl crate::result::Request for SetDebuggingFlagsRequest {
+    type Reply = SetDebuggingFlagsReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<SetDebuggingFlagsReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_xkb_set_debugging_flags_reply_t>(connection, sequence)?;
+
+        Ok(SetDebuggingFlagsReply {
+            currentFlags: reply.currentFlags,
+            currentCtrls: reply.currentCtrls,
+            supportedFlags: reply.supportedFlags,
+            supportedCtrls: reply.supportedCtrls,
+        })
+    }
+}
+
+pub fn set_debugging_flags<'a>(connection: &'a crate::connection::Connection, affectFlags: u32, flags: u32, affectCtrls: u32, ctrls: u32, message: &[String8]) -> crate::result::Cookie<'a, SetDebuggingFlagsRequest> {
+    let cookie = unsafe { xcb_system::xcb_xkb_set_debugging_flags(connection.get_connection(), message.len() as _, affectFlags, flags, affectCtrls, ctrls, message.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, SetDebuggingFlagsRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NewKeyboardNotifyEvent {
+    pub xkbType: u8,
+    pub time: super::xproto::Timestamp,
+    pub deviceID: u8,
+    pub oldDeviceID: u8,
+    pub minKeyCode: super::xproto::Keycode,
+    pub maxKeyCode: super::xproto::Keycode,
+    pub oldMinKeyCode: super::xproto::Keycode,
+    pub oldMaxKeyCode: super::xproto::Keycode,
+    pub requestMajor: u8,
+    pub requestMinor: u8,
+    pub changed: u16,
+}
+
+impl From<xcb_system::xcb_xkb_new_keyboard_notify_event_t> for NewKeyboardNotifyEvent {
+    fn from(raw: xcb_system::xcb_xkb_new_keyboard_notify_event_t) -> Self {
+        Self {
+            xkbType: raw.xkbType,
+            time: raw.time,
+            deviceID: raw.deviceID,
+            oldDeviceID: raw.oldDeviceID,
+            minKeyCode: raw.minKeyCode,
+            maxKeyCode: raw.maxKeyCode,
+            oldMinKeyCode: raw.oldMinKeyCode,
+            oldMaxKeyCode: raw.oldMaxKeyCode,
+            requestMajor: raw.requestMajor,
+            requestMinor: raw.requestMinor,
+            changed: raw.changed,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MapNotifyEvent {
+    pub xkbType: u8,
+    pub time: super::xproto::Timestamp,
+    pub deviceID: u8,
+    pub ptrBtnActions: u8,
+    pub changed: u16,
+    pub minKeyCode: super::xproto::Keycode,
+    pub maxKeyCode: super::xproto::Keycode,
+    pub firstType: u8,
+    pub nTypes: u8,
+    pub firstKeySym: super::xproto::Keycode,
+    pub nKeySyms: u8,
+    pub firstKeyAct: super::xproto::Keycode,
+    pub nKeyActs: u8,
+    pub firstKeyBehavior: super::xproto::Keycode,
+    pub nKeyBehavior: u8,
+    pub firstKeyExplicit: super::xproto::Keycode,
+    pub nKeyExplicit: u8,
+    pub firstModMapKey: super::xproto::Keycode,
+    pub nModMapKeys: u8,
+    pub firstVModMapKey: super::xproto::Keycode,
+    pub nVModMapKeys: u8,
+    pub virtualMods: u16,
+}
+
+impl From<xcb_system::xcb_xkb_map_notify_event_t> for MapNotifyEvent {
+    fn from(raw: xcb_system::xcb_xkb_map_notify_event_t) -> Self {
+        Self {
+            xkbType: raw.xkbType,
+            time: raw.time,
+            deviceID: raw.deviceID,
+            ptrBtnActions: raw.ptrBtnActions,
+            changed: raw.changed,
+            minKeyCode: raw.minKeyCode,
+            maxKeyCode: raw.maxKeyCode,
+            firstType: raw.firstType,
+            nTypes: raw.nTypes,
+            firstKeySym: raw.firstKeySym,
+            nKeySyms: raw.nKeySyms,
+            firstKeyAct: raw.firstKeyAct,
+            nKeyActs: raw.nKeyActs,
+            firstKeyBehavior: raw.firstKeyBehavior,
+            nKeyBehavior: raw.nKeyBehavior,
+            firstKeyExplicit: raw.firstKeyExplicit,
+            nKeyExplicit: raw.nKeyExplicit,
+            firstModMapKey: raw.firstModMapKey,
+            nModMapKeys: raw.nModMapKeys,
+            firstVModMapKey: raw.firstVModMapKey,
+            nVModMapKeys: raw.nVModMapKeys,
+            virtualMods: raw.virtualMods,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StateNotifyEvent {
+    pub xkbType: u8,
+    pub time: super::xproto::Timestamp,
+    pub deviceID: u8,
+    pub mods: u8,
+    pub baseMods: u8,
+    pub latchedMods: u8,
+    pub lockedMods: u8,
+    pub group: u8,
+    pub baseGroup: i16,
+    pub latchedGroup: i16,
+    pub lockedGroup: u8,
+    pub compatState: u8,
+    pub grabMods: u8,
+    pub compatGrabMods: u8,
+    pub lookupMods: u8,
+    pub compatLoockupMods: u8,
+    pub ptrBtnState: u16,
+    pub changed: u16,
+    pub keycode: super::xproto::Keycode,
+    pub eventType: u8,
+    pub requestMajor: u8,
+    pub requestMinor: u8,
+}
+
+impl From<xcb_system::xcb_xkb_state_notify_event_t> for StateNotifyEvent {
+    fn from(raw: xcb_system::xcb_xkb_state_notify_event_t) -> Self {
+        Self {
+            xkbType: raw.xkbType,
+            time: raw.time,
+            deviceID: raw.deviceID,
+            mods: raw.mods,
+            baseMods: raw.baseMods,
+            latchedMods: raw.latchedMods,
+            lockedMods: raw.lockedMods,
+            group: raw.group,
+            baseGroup: raw.baseGroup,
+            latchedGroup: raw.latchedGroup,
+            lockedGroup: raw.lockedGroup,
+            compatState: raw.compatState,
+            grabMods: raw.grabMods,
+            compatGrabMods: raw.compatGrabMods,
+            lookupMods: raw.lookupMods,
+            compatLoockupMods: raw.compatLoockupMods,
+            ptrBtnState: raw.ptrBtnState,
+            changed: raw.changed,
+            keycode: raw.keycode,
+            eventType: raw.eventType,
+            requestMajor: raw.requestMajor,
+            requestMinor: raw.requestMinor,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ControlsNotifyEvent {
+    pub xkbType: u8,
+    pub time: super::xproto::Timestamp,
+    pub deviceID: u8,
+    pub numGroups: u8,
+    pub changedControls: u32,
+    pub enabledControls: u32,
+    pub enabledControlChanges: u32,
+    pub keycode: super::xproto::Keycode,
+    pub eventType: u8,
+    pub requestMajor: u8,
+    pub requestMinor: u8,
+}
+
+impl From<xcb_system::xcb_xkb_controls_notify_event_t> for ControlsNotifyEvent {
+    fn from(raw: xcb_system::xcb_xkb_controls_notify_event_t) -> Self {
+        Self {
+            xkbType: raw.xkbType,
+            time: raw.time,
+            deviceID: raw.deviceID,
+            numGroups: raw.numGroups,
+            changedControls: raw.changedControls,
+            enabledControls: raw.enabledControls,
+            enabledControlChanges: raw.enabledControlChanges,
+            keycode: raw.keycode,
+            eventType: raw.eventType,
+            requestMajor: raw.requestMajor,
+            requestMinor: raw.requestMinor,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IndicatorStateNotifyEvent {
+    pub xkbType: u8,
+    pub time: super::xproto::Timestamp,
+    pub deviceID: u8,
+    pub state: u32,
+    pub stateChanged: u32,
+}
+
+impl From<xcb_system::xcb_xkb_indicator_state_notify_event_t> for IndicatorStateNotifyEvent {
+    fn from(raw: xcb_system::xcb_xkb_indicator_state_notify_event_t) -> Self {
+        Self {
+            xkbType: raw.xkbType,
+            time: raw.time,
+            deviceID: raw.deviceID,
+            state: raw.state,
+            stateChanged: raw.stateChanged,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IndicatorMapNotifyEvent {
+    pub xkbType: u8,
+    pub time: super::xproto::Timestamp,
+    pub deviceID: u8,
+    pub state: u32,
+    pub mapChanged: u32,
+}
+
+impl From<xcb_system::xcb_xkb_indicator_map_notify_event_t> for IndicatorMapNotifyEvent {
+    fn from(raw: xcb_system::xcb_xkb_indicator_map_notify_event_t) -> Self {
+        Self {
+            xkbType: raw.xkbType,
+            time: raw.time,
+            deviceID: raw.deviceID,
+            state: raw.state,
+            mapChanged: raw.mapChanged,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NamesNotifyEvent {
+    pub xkbType: u8,
+    pub time: super::xproto::Timestamp,
+    pub deviceID: u8,
+    pub changed: u16,
+    pub firstType: u8,
+    pub nTypes: u8,
+    pub firstLevelName: u8,
+    pub nLevelNames: u8,
+    pub nRadioGroups: u8,
+    pub nKeyAliases: u8,
+    pub changedGroupNames: u8,
+    pub changedVirtualMods: u16,
+    pub firstKey: super::xproto::Keycode,
+    pub nKeys: u8,
+    pub changedIndicators: u32,
+}
+
+impl From<xcb_system::xcb_xkb_names_notify_event_t> for NamesNotifyEvent {
+    fn from(raw: xcb_system::xcb_xkb_names_notify_event_t) -> Self {
+        Self {
+            xkbType: raw.xkbType,
+            time: raw.time,
+            deviceID: raw.deviceID,
+            changed: raw.changed,
+            firstType: raw.firstType,
+            nTypes: raw.nTypes,
+            firstLevelName: raw.firstLevelName,
+            nLevelNames: raw.nLevelNames,
+            nRadioGroups: raw.nRadioGroups,
+            nKeyAliases: raw.nKeyAliases,
+            changedGroupNames: raw.changedGroupNames,
+            changedVirtualMods: raw.changedVirtualMods,
+            firstKey: raw.firstKey,
+            nKeys: raw.nKeys,
+            changedIndicators: raw.changedIndicators,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CompatMapNotifyEvent {
+    pub xkbType: u8,
+    pub time: super::xproto::Timestamp,
+    pub deviceID: u8,
+    pub changedGroups: u8,
+    pub firstSI: u16,
+    pub nSI: u16,
+    pub nTotalSI: u16,
+}
+
+impl From<xcb_system::xcb_xkb_compat_map_notify_event_t> for CompatMapNotifyEvent {
+    fn from(raw: xcb_system::xcb_xkb_compat_map_notify_event_t) -> Self {
+        Self {
+            xkbType: raw.xkbType,
+            time: raw.time,
+            deviceID: raw.deviceID,
+            changedGroups: raw.changedGroups,
+            firstSI: raw.firstSI,
+            nSI: raw.nSI,
+            nTotalSI: raw.nTotalSI,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BellNotifyEvent {
+    pub xkbType: u8,
+    pub time: super::xproto::Timestamp,
+    pub deviceID: u8,
+    pub bellClass: u8,
+    pub bellID: u8,
+    pub percent: u8,
+    pub pitch: u16,
+    pub duration: u16,
+    pub name: super::xproto::Atom,
+    pub window: super::xproto::Window,
+    pub eventOnly: bool,
+}
+
+impl From<xcb_system::xcb_xkb_bell_notify_event_t> for BellNotifyEvent {
+    fn from(raw: xcb_system::xcb_xkb_bell_notify_event_t) -> Self {
+        Self {
+            xkbType: raw.xkbType,
+            time: raw.time,
+            deviceID: raw.deviceID,
+            bellClass: raw.bellClass,
+            bellID: raw.bellID,
+            percent: raw.percent,
+            pitch: raw.pitch,
+            duration: raw.duration,
+            name: raw.name,
+            window: raw.window,
+            eventOnly: raw.eventOnly != 0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ActionMessageEvent {
+    pub xkbType: u8,
+    pub time: super::xproto::Timestamp,
+    pub deviceID: u8,
+    pub keycode: super::xproto::Keycode,
+    pub press: bool,
+    pub keyEventFollows: bool,
+    pub mods: u8,
+    pub group: u8,
+    pub message: [String8; 8],
+}
+
+impl From<xcb_system::xcb_xkb_action_message_event_t> for ActionMessageEvent {
+    fn from(raw: xcb_system::xcb_xkb_action_message_event_t) -> Self {
+        Self {
+            xkbType: raw.xkbType,
+            time: raw.time,
+            deviceID: raw.deviceID,
+            keycode: raw.keycode,
+            press: raw.press != 0,
+            keyEventFollows: raw.keyEventFollows != 0,
+            mods: raw.mods,
+            group: raw.group,
+            message: raw.message.map(|value| value as u8),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AccessXNotifyEvent {
+    pub xkbType: u8,
+    pub time: super::xproto::Timestamp,
+    pub deviceID: u8,
+    pub keycode: super::xproto::Keycode,
+    pub detailt: u16,
+    pub slowKeysDelay: u16,
+    pub debounceDelay: u16,
+}
+
+impl From<xcb_system::xcb_xkb_access_x_notify_event_t> for AccessXNotifyEvent {
+    fn from(raw: xcb_system::xcb_xkb_access_x_notify_event_t) -> Self {
+        Self {
+            xkbType: raw.xkbType,
+            time: raw.time,
+            deviceID: raw.deviceID,
+            keycode: raw.keycode,
+            detailt: raw.detailt,
+            slowKeysDelay: raw.slowKeysDelay,
+            debounceDelay: raw.debounceDelay,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ExtensionDeviceNotifyEvent {
+    pub xkbType: u8,
+    pub time: super::xproto::Timestamp,
+    pub deviceID: u8,
+    pub reason: u16,
+    pub ledClass: u16,
+    pub ledID: u16,
+    pub ledsDefined: u32,
+    pub ledState: u32,
+    pub firstButton: u8,
+    pub nButtons: u8,
+    pub supported: u16,
+    pub unsupported: u16,
+}
+
+impl From<xcb_system::xcb_xkb_extension_device_notify_event_t> for ExtensionDeviceNotifyEvent {
+    fn from(raw: xcb_system::xcb_xkb_extension_device_notify_event_t) -> Self {
+        Self {
+            xkbType: raw.xkbType,
+            time: raw.time,
+            deviceID: raw.deviceID,
+            reason: raw.reason,
+            ledClass: raw.ledClass,
+            ledID: raw.ledID,
+            ledsDefined: raw.ledsDefined,
+            ledState: raw.ledState,
+            firstButton: raw.firstButton,
+            nButtons: raw.nButtons,
+            supported: raw.supported,
+            unsupported: raw.unsupported,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    NewKeyboardNotify(NewKeyboardNotifyEvent),
+    MapNotify(MapNotifyEvent),
+    StateNotify(StateNotifyEvent),
+    ControlsNotify(ControlsNotifyEvent),
+    IndicatorStateNotify(IndicatorStateNotifyEvent),
+    IndicatorMapNotify(IndicatorMapNotifyEvent),
+    NamesNotify(NamesNotifyEvent),
+    CompatMapNotify(CompatMapNotifyEvent),
+    BellNotify(BellNotifyEvent),
+    ActionMessage(ActionMessageEvent),
+    AccessXNotify(AccessXNotifyEvent),
+    ExtensionDeviceNotify(ExtensionDeviceNotifyEvent),
+}
+
+pub fn decode_event(event: &xcb_system::xcb_generic_event_t, first_event: u8) -> std::option::Option<Event> {
+    let raw = event as *const xcb_system::xcb_generic_event_t;
+
+    match (event.response_type & 0x7f).wrapping_sub(first_event) {
+        0 => Some(Event::NewKeyboardNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xkb_new_keyboard_notify_event_t) }.into(),
+        )),
+        1 => Some(Event::MapNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xkb_map_notify_event_t) }.into(),
+        )),
+        2 => Some(Event::StateNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xkb_state_notify_event_t) }.into(),
+        )),
+        3 => Some(Event::ControlsNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xkb_controls_notify_event_t) }.into(),
+        )),
+        4 => Some(Event::IndicatorStateNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xkb_indicator_state_notify_event_t) }.into(),
+        )),
+        5 => Some(Event::IndicatorMapNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xkb_indicator_map_notify_event_t) }.into(),
+        )),
+        6 => Some(Event::NamesNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xkb_names_notify_event_t) }.into(),
+        )),
+        7 => Some(Event::CompatMapNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xkb_compat_map_notify_event_t) }.into(),
+        )),
+        8 => Some(Event::BellNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xkb_bell_notify_event_t) }.into(),
+        )),
+        9 => Some(Event::ActionMessage(
+            unsafe { *(raw as *const xcb_system::xcb_xkb_action_message_event_t) }.into(),
+        )),
+        10 => Some(Event::AccessXNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xkb_access_x_notify_event_t) }.into(),
+        )),
+        11 => Some(Event::ExtensionDeviceNotify(
+            unsafe { *(raw as *const xcb_system::xcb_xkb_extension_device_notify_event_t) }.into(),
+        )),
+        _ => None,
+    }
+}
+
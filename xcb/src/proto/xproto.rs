@@ -0,0 +1,5348 @@
+// Generated by xcb-codegen from xproto.xml, don't edit.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Char2b {
+    pub byte1: u8,
+    pub byte2: u8,
+}
+
+impl From<xcb_system::xcb_char2b_t> for Char2b {
+    fn from(raw: xcb_system::xcb_char2b_t) -> Self {
+        Self {
+            byte1: raw.byte1,
+            byte2: raw.byte2,
+        }
+    }
+}
+
+impl From<Char2b> for xcb_system::xcb_char2b_t {
+    fn from(value: Char2b) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.byte1 = value.byte1;
+        raw.byte2 = value.byte2;
+
+        raw
+    }
+}
+
+pub type Window = u32;
+
+pub type Pixmap = u32;
+
+pub type Cursor = u32;
+
+pub type Font = u32;
+
+pub type Gcontext = u32;
+
+pub type Colormap = u32;
+
+pub type Atom = u32;
+
+pub type Drawable = u32;
+
+pub type Fontable = u32;
+
+pub type Bool32 = u32;
+
+pub type Visualid = u32;
+
+pub type Timestamp = u32;
+
+pub type Keysym = u32;
+
+pub type Keycode = u8;
+
+pub type Keycode32 = u32;
+
+pub type Button = u8;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Point {
+    pub x: i16,
+    pub y: i16,
+}
+
+impl From<xcb_system::xcb_point_t> for Point {
+    fn from(raw: xcb_system::xcb_point_t) -> Self {
+        Self {
+            x: raw.x,
+            y: raw.y,
+        }
+    }
+}
+
+impl From<Point> for xcb_system::xcb_point_t {
+    fn from(value: Point) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.x = value.x;
+        raw.y = value.y;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rectangle {
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl From<xcb_system::xcb_rectangle_t> for Rectangle {
+    fn from(raw: xcb_system::xcb_rectangle_t) -> Self {
+        Self {
+            x: raw.x,
+            y: raw.y,
+            width: raw.width,
+            height: raw.height,
+        }
+    }
+}
+
+impl From<Rectangle> for xcb_system::xcb_rectangle_t {
+    fn from(value: Rectangle) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.x = value.x;
+        raw.y = value.y;
+        raw.width = value.width;
+        raw.height = value.height;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Arc {
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub angle1: i16,
+    pub angle2: i16,
+}
+
+impl From<xcb_system::xcb_arc_t> for Arc {
+    fn from(raw: xcb_system::xcb_arc_t) -> Self {
+        Self {
+            x: raw.x,
+            y: raw.y,
+            width: raw.width,
+            height: raw.height,
+            angle1: raw.angle1,
+            angle2: raw.angle2,
+        }
+    }
+}
+
+impl From<Arc> for xcb_system::xcb_arc_t {
+    fn from(value: Arc) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.x = value.x;
+        raw.y = value.y;
+        raw.width = value.width;
+        raw.height = value.height;
+        raw.angle1 = value.angle1;
+        raw.angle2 = value.angle2;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Format {
+    pub depth: u8,
+    pub bits_per_pixel: u8,
+    pub scanline_pad: u8,
+}
+
+impl From<xcb_system::xcb_format_t> for Format {
+    fn from(raw: xcb_system::xcb_format_t) -> Self {
+        Self {
+            depth: raw.depth,
+            bits_per_pixel: raw.bits_per_pixel,
+            scanline_pad: raw.scanline_pad,
+        }
+    }
+}
+
+impl From<Format> for xcb_system::xcb_format_t {
+    fn from(value: Format) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.depth = value.depth;
+        raw.bits_per_pixel = value.bits_per_pixel;
+        raw.scanline_pad = value.scanline_pad;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VisualClass(pub u32);
+
+impl VisualClass {
+    pub const STATIC_GRAY: Self = Self(0);
+    pub const GRAY_SCALE: Self = Self(1);
+    pub const STATIC_COLOR: Self = Self(2);
+    pub const PSEUDO_COLOR: Self = Self(3);
+    pub const TRUE_COLOR: Self = Self(4);
+    pub const DIRECT_COLOR: Self = Self(5);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Visualtype {
+    pub visual_id: Visualid,
+    pub class: u8,
+    pub bits_per_rgb_value: u8,
+    pub colormap_entries: u16,
+    pub red_mask: u32,
+    pub green_mask: u32,
+    pub blue_mask: u32,
+}
+
+impl From<xcb_system::xcb_visualtype_t> for Visualtype {
+    fn from(raw: xcb_system::xcb_visualtype_t) -> Self {
+        Self {
+            visual_id: raw.visual_id,
+            class: raw._class,
+            bits_per_rgb_value: raw.bits_per_rgb_value,
+            colormap_entries: raw.colormap_entries,
+            red_mask: raw.red_mask,
+            green_mask: raw.green_mask,
+            blue_mask: raw.blue_mask,
+        }
+    }
+}
+
+impl From<Visualtype> for xcb_system::xcb_visualtype_t {
+    fn from(value: Visualtype) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.visual_id = value.visual_id;
+        raw._class = value.class;
+        raw.bits_per_rgb_value = value.bits_per_rgb_value;
+        raw.colormap_entries = value.colormap_entries;
+        raw.red_mask = value.red_mask;
+        raw.green_mask = value.green_mask;
+        raw.blue_mask = value.blue_mask;
+
+        raw
+    }
+}
+
+// DEPTH isn't generated, visuals has a variable length.
+
+bitflags! {
+    pub struct EventMask: u32 {
+        const NO_EVENT = 0x0;
+        const KEY_PRESS = 0x1;
+        const KEY_RELEASE = 0x2;
+        const BUTTON_PRESS = 0x4;
+        const BUTTON_RELEASE = 0x8;
+        const ENTER_WINDOW = 0x10;
+        const LEAVE_WINDOW = 0x20;
+        const POINTER_MOTION = 0x40;
+        const POINTER_MOTION_HINT = 0x80;
+        const BUTTON_1_MOTION = 0x100;
+        const BUTTON_2_MOTION = 0x200;
+        const BUTTON_3_MOTION = 0x400;
+        const BUTTON_4_MOTION = 0x800;
+        const BUTTON_5_MOTION = 0x1000;
+        const BUTTON_MOTION = 0x2000;
+        const KEYMAP_STATE = 0x4000;
+        const EXPOSURE = 0x8000;
+        const VISIBILITY_CHANGE = 0x10000;
+        const STRUCTURE_NOTIFY = 0x20000;
+        const RESIZE_REDIRECT = 0x40000;
+        const SUBSTRUCTURE_NOTIFY = 0x80000;
+        const SUBSTRUCTURE_REDIRECT = 0x100000;
+        const FOCUS_CHANGE = 0x200000;
+        const PROPERTY_CHANGE = 0x400000;
+        const COLOR_MAP_CHANGE = 0x800000;
+        const OWNER_GRAB_BUTTON = 0x1000000;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BackingStore(pub u32);
+
+impl BackingStore {
+    pub const NOT_USEFUL: Self = Self(0);
+    pub const WHEN_MAPPED: Self = Self(1);
+    pub const ALWAYS: Self = Self(2);
+}
+
+// SCREEN isn't generated, allowed_depths has a variable length.
+
+// SetupRequest isn't generated, authorization_protocol_name has a variable length.
+
+// SetupFailed isn't generated, reason has a variable length.
+
+// SetupAuthenticate isn't generated, reason has a variable length.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ImageOrder(pub u32);
+
+impl ImageOrder {
+    pub const LSB_FIRST: Self = Self(0);
+    pub const MSB_FIRST: Self = Self(1);
+}
+
+// Setup isn't generated, vendor has a variable length.
+
+bitflags! {
+    pub struct ModMask: u32 {
+        const SHIFT = 0x1;
+        const LOCK = 0x2;
+        const CONTROL = 0x4;
+        const MOD_1 = 0x8;
+        const MOD_2 = 0x10;
+        const MOD_3 = 0x20;
+        const MOD_4 = 0x40;
+        const MOD_5 = 0x80;
+        const ANY = 0x8000;
+    }
+}
+
+bitflags! {
+    pub struct KeyButMask: u32 {
+        const SHIFT = 0x1;
+        const LOCK = 0x2;
+        const CONTROL = 0x4;
+        const MOD_1 = 0x8;
+        const MOD_2 = 0x10;
+        const MOD_3 = 0x20;
+        const MOD_4 = 0x40;
+        const MOD_5 = 0x80;
+        const BUTTON_1 = 0x100;
+        const BUTTON_2 = 0x200;
+        const BUTTON_3 = 0x400;
+        const BUTTON_4 = 0x800;
+        const BUTTON_5 = 0x1000;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WindowEnum(pub u32);
+
+impl WindowEnum {
+    pub const NONE: Self = Self(0);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KeyPressEvent {
+    pub detail: Keycode,
+    pub time: Timestamp,
+    pub root: Window,
+    pub event: Window,
+    pub child: Window,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub event_x: i16,
+    pub event_y: i16,
+    pub state: u16,
+    pub same_screen: bool,
+}
+
+impl From<xcb_system::xcb_key_press_event_t> for KeyPressEvent {
+    fn from(raw: xcb_system::xcb_key_press_event_t) -> Self {
+        Self {
+            detail: raw.detail,
+            time: raw.time,
+            root: raw.root,
+            event: raw.event,
+            child: raw.child,
+            root_x: raw.root_x,
+            root_y: raw.root_y,
+            event_x: raw.event_x,
+            event_y: raw.event_y,
+            state: raw.state,
+            same_screen: raw.same_screen != 0,
+        }
+    }
+}
+
+pub type KeyReleaseEvent = KeyPressEvent;
+
+bitflags! {
+    pub struct ButtonMask: u32 {
+        const BUTTON_1 = 0x100;
+        const BUTTON_2 = 0x200;
+        const BUTTON_3 = 0x400;
+        const BUTTON_4 = 0x800;
+        const BUTTON_5 = 0x1000;
+        const ANY = 0x8000;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ButtonPressEvent {
+    pub detail: Button,
+    pub time: Timestamp,
+    pub root: Window,
+    pub event: Window,
+    pub child: Window,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub event_x: i16,
+    pub event_y: i16,
+    pub state: u16,
+    pub same_screen: bool,
+}
+
+impl From<xcb_system::xcb_button_press_event_t> for ButtonPressEvent {
+    fn from(raw: xcb_system::xcb_button_press_event_t) -> Self {
+        Self {
+            detail: raw.detail,
+            time: raw.time,
+            root: raw.root,
+            event: raw.event,
+            child: raw.child,
+            root_x: raw.root_x,
+            root_y: raw.root_y,
+            event_x: raw.event_x,
+            event_y: raw.event_y,
+            state: raw.state,
+            same_screen: raw.same_screen != 0,
+        }
+    }
+}
+
+pub type ButtonReleaseEvent = ButtonPressEvent;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Motion(pub u32);
+
+impl Motion {
+    pub const NORMAL: Self = Self(0);
+    pub const HINT: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MotionNotifyEvent {
+    pub detail: u8,
+    pub time: Timestamp,
+    pub root: Window,
+    pub event: Window,
+    pub child: Window,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub event_x: i16,
+    pub event_y: i16,
+    pub state: u16,
+    pub same_screen: bool,
+}
+
+impl From<xcb_system::xcb_motion_notify_event_t> for MotionNotifyEvent {
+    fn from(raw: xcb_system::xcb_motion_notify_event_t) -> Self {
+        Self {
+            detail: raw.detail,
+            time: raw.time,
+            root: raw.root,
+            event: raw.event,
+            child: raw.child,
+            root_x: raw.root_x,
+            root_y: raw.root_y,
+            event_x: raw.event_x,
+            event_y: raw.event_y,
+            state: raw.state,
+            same_screen: raw.same_screen != 0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NotifyDetail(pub u32);
+
+impl NotifyDetail {
+    pub const ANCESTOR: Self = Self(0);
+    pub const VIRTUAL: Self = Self(1);
+    pub const INFERIOR: Self = Self(2);
+    pub const NONLINEAR: Self = Self(3);
+    pub const NONLINEAR_VIRTUAL: Self = Self(4);
+    pub const POINTER: Self = Self(5);
+    pub const POINTER_ROOT: Self = Self(6);
+    pub const NONE: Self = Self(7);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NotifyMode(pub u32);
+
+impl NotifyMode {
+    pub const NORMAL: Self = Self(0);
+    pub const GRAB: Self = Self(1);
+    pub const UNGRAB: Self = Self(2);
+    pub const WHILE_GRABBED: Self = Self(3);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EnterNotifyEvent {
+    pub detail: u8,
+    pub time: Timestamp,
+    pub root: Window,
+    pub event: Window,
+    pub child: Window,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub event_x: i16,
+    pub event_y: i16,
+    pub state: u16,
+    pub mode: u8,
+    pub same_screen_focus: u8,
+}
+
+impl From<xcb_system::xcb_enter_notify_event_t> for EnterNotifyEvent {
+    fn from(raw: xcb_system::xcb_enter_notify_event_t) -> Self {
+        Self {
+            detail: raw.detail,
+            time: raw.time,
+            root: raw.root,
+            event: raw.event,
+            child: raw.child,
+            root_x: raw.root_x,
+            root_y: raw.root_y,
+            event_x: raw.event_x,
+            event_y: raw.event_y,
+            state: raw.state,
+            mode: raw.mode,
+            same_screen_focus: raw.same_screen_focus,
+        }
+    }
+}
+
+pub type LeaveNotifyEvent = EnterNotifyEvent;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FocusInEvent {
+    pub detail: u8,
+    pub event: Window,
+    pub mode: u8,
+}
+
+impl From<xcb_system::xcb_focus_in_event_t> for FocusInEvent {
+    fn from(raw: xcb_system::xcb_focus_in_event_t) -> Self {
+        Self {
+            detail: raw.detail,
+            event: raw.event,
+            mode: raw.mode,
+        }
+    }
+}
+
+pub type FocusOutEvent = FocusInEvent;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KeymapNotifyEvent {
+    pub keys: [u8; 31],
+}
+
+impl From<xcb_system::xcb_keymap_notify_event_t> for KeymapNotifyEvent {
+    fn from(raw: xcb_system::xcb_keymap_notify_event_t) -> Self {
+        Self {
+            keys: raw.keys,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ExposeEvent {
+    pub window: Window,
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+    pub count: u16,
+}
+
+impl From<xcb_system::xcb_expose_event_t> for ExposeEvent {
+    fn from(raw: xcb_system::xcb_expose_event_t) -> Self {
+        Self {
+            window: raw.window,
+            x: raw.x,
+            y: raw.y,
+            width: raw.width,
+            height: raw.height,
+            count: raw.count,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GraphicsExposureEvent {
+    pub drawable: Drawable,
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+    pub minor_opcode: u16,
+    pub count: u16,
+    pub major_opcode: u8,
+}
+
+impl From<xcb_system::xcb_graphics_exposure_event_t> for GraphicsExposureEvent {
+    fn from(raw: xcb_system::xcb_graphics_exposure_event_t) -> Self {
+        Self {
+            drawable: raw.drawable,
+            x: raw.x,
+            y: raw.y,
+            width: raw.width,
+            height: raw.height,
+            minor_opcode: raw.minor_opcode,
+            count: raw.count,
+            major_opcode: raw.major_opcode,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NoExposureEvent {
+    pub drawable: Drawable,
+    pub minor_opcode: u16,
+    pub major_opcode: u8,
+}
+
+impl From<xcb_system::xcb_no_exposure_event_t> for NoExposureEvent {
+    fn from(raw: xcb_system::xcb_no_exposure_event_t) -> Self {
+        Self {
+            drawable: raw.drawable,
+            minor_opcode: raw.minor_opcode,
+            major_opcode: raw.major_opcode,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Visibility(pub u32);
+
+impl Visibility {
+    pub const UNOBSCURED: Self = Self(0);
+    pub const PARTIALLY_OBSCURED: Self = Self(1);
+    pub const FULLY_OBSCURED: Self = Self(2);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VisibilityNotifyEvent {
+    pub window: Window,
+    pub state: u8,
+}
+
+impl From<xcb_system::xcb_visibility_notify_event_t> for VisibilityNotifyEvent {
+    fn from(raw: xcb_system::xcb_visibility_notify_event_t) -> Self {
+        Self {
+            window: raw.window,
+            state: raw.state,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CreateNotifyEvent {
+    pub parent: Window,
+    pub window: Window,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub border_width: u16,
+    pub override_redirect: bool,
+}
+
+impl From<xcb_system::xcb_create_notify_event_t> for CreateNotifyEvent {
+    fn from(raw: xcb_system::xcb_create_notify_event_t) -> Self {
+        Self {
+            parent: raw.parent,
+            window: raw.window,
+            x: raw.x,
+            y: raw.y,
+            width: raw.width,
+            height: raw.height,
+            border_width: raw.border_width,
+            override_redirect: raw.override_redirect != 0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DestroyNotifyEvent {
+    pub event: Window,
+    pub window: Window,
+}
+
+impl From<xcb_system::xcb_destroy_notify_event_t> for DestroyNotifyEvent {
+    fn from(raw: xcb_system::xcb_destroy_notify_event_t) -> Self {
+        Self {
+            event: raw.event,
+            window: raw.window,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UnmapNotifyEvent {
+    pub event: Window,
+    pub window: Window,
+    pub from_configure: bool,
+}
+
+impl From<xcb_system::xcb_unmap_notify_event_t> for UnmapNotifyEvent {
+    fn from(raw: xcb_system::xcb_unmap_notify_event_t) -> Self {
+        Self {
+            event: raw.event,
+            window: raw.window,
+            from_configure: raw.from_configure != 0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MapNotifyEvent {
+    pub event: Window,
+    pub window: Window,
+    pub override_redirect: bool,
+}
+
+impl From<xcb_system::xcb_map_notify_event_t> for MapNotifyEvent {
+    fn from(raw: xcb_system::xcb_map_notify_event_t) -> Self {
+        Self {
+            event: raw.event,
+            window: raw.window,
+            override_redirect: raw.override_redirect != 0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MapRequestEvent {
+    pub parent: Window,
+    pub window: Window,
+}
+
+impl From<xcb_system::xcb_map_request_event_t> for MapRequestEvent {
+    fn from(raw: xcb_system::xcb_map_request_event_t) -> Self {
+        Self {
+            parent: raw.parent,
+            window: raw.window,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ReparentNotifyEvent {
+    pub event: Window,
+    pub window: Window,
+    pub parent: Window,
+    pub x: i16,
+    pub y: i16,
+    pub override_redirect: bool,
+}
+
+impl From<xcb_system::xcb_reparent_notify_event_t> for ReparentNotifyEvent {
+    fn from(raw: xcb_system::xcb_reparent_notify_event_t) -> Self {
+        Self {
+            event: raw.event,
+            window: raw.window,
+            parent: raw.parent,
+            x: raw.x,
+            y: raw.y,
+            override_redirect: raw.override_redirect != 0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ConfigureNotifyEvent {
+    pub event: Window,
+    pub window: Window,
+    pub above_sibling: Window,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub border_width: u16,
+    pub override_redirect: bool,
+}
+
+impl From<xcb_system::xcb_configure_notify_event_t> for ConfigureNotifyEvent {
+    fn from(raw: xcb_system::xcb_configure_notify_event_t) -> Self {
+        Self {
+            event: raw.event,
+            window: raw.window,
+            above_sibling: raw.above_sibling,
+            x: raw.x,
+            y: raw.y,
+            width: raw.width,
+            height: raw.height,
+            border_width: raw.border_width,
+            override_redirect: raw.override_redirect != 0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ConfigureRequestEvent {
+    pub stack_mode: u8,
+    pub parent: Window,
+    pub window: Window,
+    pub sibling: Window,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub border_width: u16,
+    pub value_mask: u16,
+}
+
+impl From<xcb_system::xcb_configure_request_event_t> for ConfigureRequestEvent {
+    fn from(raw: xcb_system::xcb_configure_request_event_t) -> Self {
+        Self {
+            stack_mode: raw.stack_mode,
+            parent: raw.parent,
+            window: raw.window,
+            sibling: raw.sibling,
+            x: raw.x,
+            y: raw.y,
+            width: raw.width,
+            height: raw.height,
+            border_width: raw.border_width,
+            value_mask: raw.value_mask,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GravityNotifyEvent {
+    pub event: Window,
+    pub window: Window,
+    pub x: i16,
+    pub y: i16,
+}
+
+impl From<xcb_system::xcb_gravity_notify_event_t> for GravityNotifyEvent {
+    fn from(raw: xcb_system::xcb_gravity_notify_event_t) -> Self {
+        Self {
+            event: raw.event,
+            window: raw.window,
+            x: raw.x,
+            y: raw.y,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ResizeRequestEvent {
+    pub window: Window,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl From<xcb_system::xcb_resize_request_event_t> for ResizeRequestEvent {
+    fn from(raw: xcb_system::xcb_resize_request_event_t) -> Self {
+        Self {
+            window: raw.window,
+            width: raw.width,
+            height: raw.height,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Place(pub u32);
+
+impl Place {
+    pub const ON_TOP: Self = Self(0);
+    pub const ON_BOTTOM: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CirculateNotifyEvent {
+    pub event: Window,
+    pub window: Window,
+    pub place: u8,
+}
+
+impl From<xcb_system::xcb_circulate_notify_event_t> for CirculateNotifyEvent {
+    fn from(raw: xcb_system::xcb_circulate_notify_event_t) -> Self {
+        Self {
+            event: raw.event,
+            window: raw.window,
+            place: raw.place,
+        }
+    }
+}
+
+pub type CirculateRequestEvent = CirculateNotifyEvent;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Property(pub u32);
+
+impl Property {
+    pub const NEW_VALUE: Self = Self(0);
+    pub const DELETE: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PropertyNotifyEvent {
+    pub window: Window,
+    pub atom: Atom,
+    pub time: Timestamp,
+    pub state: u8,
+}
+
+impl From<xcb_system::xcb_property_notify_event_t> for PropertyNotifyEvent {
+    fn from(raw: xcb_system::xcb_property_notify_event_t) -> Self {
+        Self {
+            window: raw.window,
+            atom: raw.atom,
+            time: raw.time,
+            state: raw.state,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SelectionClearEvent {
+    pub time: Timestamp,
+    pub owner: Window,
+    pub selection: Atom,
+}
+
+impl From<xcb_system::xcb_selection_clear_event_t> for SelectionClearEvent {
+    fn from(raw: xcb_system::xcb_selection_clear_event_t) -> Self {
+        Self {
+            time: raw.time,
+            owner: raw.owner,
+            selection: raw.selection,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Time(pub u32);
+
+impl Time {
+    pub const CURRENT_TIME: Self = Self(0);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AtomEnum(pub u32);
+
+impl AtomEnum {
+    pub const NONE: Self = Self(0);
+    pub const ANY: Self = Self(0);
+    pub const PRIMARY: Self = Self(1);
+    pub const SECONDARY: Self = Self(2);
+    pub const ARC: Self = Self(3);
+    pub const ATOM: Self = Self(4);
+    pub const BITMAP: Self = Self(5);
+    pub const CARDINAL: Self = Self(6);
+    pub const COLORMAP: Self = Self(7);
+    pub const CURSOR: Self = Self(8);
+    pub const CUT_BUFFER0: Self = Self(9);
+    pub const CUT_BUFFER1: Self = Self(10);
+    pub const CUT_BUFFER2: Self = Self(11);
+    pub const CUT_BUFFER3: Self = Self(12);
+    pub const CUT_BUFFER4: Self = Self(13);
+    pub const CUT_BUFFER5: Self = Self(14);
+    pub const CUT_BUFFER6: Self = Self(15);
+    pub const CUT_BUFFER7: Self = Self(16);
+    pub const DRAWABLE: Self = Self(17);
+    pub const FONT: Self = Self(18);
+    pub const INTEGER: Self = Self(19);
+    pub const PIXMAP: Self = Self(20);
+    pub const POINT: Self = Self(21);
+    pub const RECTANGLE: Self = Self(22);
+    pub const RESOURCE_MANAGER: Self = Self(23);
+    pub const RGB_COLOR_MAP: Self = Self(24);
+    pub const RGB_BEST_MAP: Self = Self(25);
+    pub const RGB_BLUE_MAP: Self = Self(26);
+    pub const RGB_DEFAULT_MAP: Self = Self(27);
+    pub const RGB_GRAY_MAP: Self = Self(28);
+    pub const RGB_GREEN_MAP: Self = Self(29);
+    pub const RGB_RED_MAP: Self = Self(30);
+    pub const STRING: Self = Self(31);
+    pub const VISUALID: Self = Self(32);
+    pub const WINDOW: Self = Self(33);
+    pub const WM_COMMAND: Self = Self(34);
+    pub const WM_HINTS: Self = Self(35);
+    pub const WM_CLIENT_MACHINE: Self = Self(36);
+    pub const WM_ICON_NAME: Self = Self(37);
+    pub const WM_ICON_SIZE: Self = Self(38);
+    pub const WM_NAME: Self = Self(39);
+    pub const WM_NORMAL_HINTS: Self = Self(40);
+    pub const WM_SIZE_HINTS: Self = Self(41);
+    pub const WM_ZOOM_HINTS: Self = Self(42);
+    pub const MIN_SPACE: Self = Self(43);
+    pub const NORM_SPACE: Self = Self(44);
+    pub const MAX_SPACE: Self = Self(45);
+    pub const END_SPACE: Self = Self(46);
+    pub const SUPERSCRIPT_X: Self = Self(47);
+    pub const SUPERSCRIPT_Y: Self = Self(48);
+    pub const SUBSCRIPT_X: Self = Self(49);
+    pub const SUBSCRIPT_Y: Self = Self(50);
+    pub const UNDERLINE_POSITION: Self = Self(51);
+    pub const UNDERLINE_THICKNESS: Self = Self(52);
+    pub const STRIKEOUT_ASCENT: Self = Self(53);
+    pub const STRIKEOUT_DESCENT: Self = Self(54);
+    pub const ITALIC_ANGLE: Self = Self(55);
+    pub const X_HEIGHT: Self = Self(56);
+    pub const QUAD_WIDTH: Self = Self(57);
+    pub const WEIGHT: Self = Self(58);
+    pub const POINT_SIZE: Self = Self(59);
+    pub const RESOLUTION: Self = Self(60);
+    pub const COPYRIGHT: Self = Self(61);
+    pub const NOTICE: Self = Self(62);
+    pub const FONT_NAME: Self = Self(63);
+    pub const FAMILY_NAME: Self = Self(64);
+    pub const FULL_NAME: Self = Self(65);
+    pub const CAP_HEIGHT: Self = Self(66);
+    pub const WM_CLASS: Self = Self(67);
+    pub const WM_TRANSIENT_FOR: Self = Self(68);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SelectionRequestEvent {
+    pub time: Timestamp,
+    pub owner: Window,
+    pub requestor: Window,
+    pub selection: Atom,
+    pub target: Atom,
+    pub property: Atom,
+}
+
+impl From<xcb_system::xcb_selection_request_event_t> for SelectionRequestEvent {
+    fn from(raw: xcb_system::xcb_selection_request_event_t) -> Self {
+        Self {
+            time: raw.time,
+            owner: raw.owner,
+            requestor: raw.requestor,
+            selection: raw.selection,
+            target: raw.target,
+            property: raw.property,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SelectionNotifyEvent {
+    pub time: Timestamp,
+    pub requestor: Window,
+    pub selection: Atom,
+    pub target: Atom,
+    pub property: Atom,
+}
+
+impl From<xcb_system::xcb_selection_notify_event_t> for SelectionNotifyEvent {
+    fn from(raw: xcb_system::xcb_selection_notify_event_t) -> Self {
+        Self {
+            time: raw.time,
+            requestor: raw.requestor,
+            selection: raw.selection,
+            target: raw.target,
+            property: raw.property,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColormapState(pub u32);
+
+impl ColormapState {
+    pub const UNINSTALLED: Self = Self(0);
+    pub const INSTALLED: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColormapEnum(pub u32);
+
+impl ColormapEnum {
+    pub const NONE: Self = Self(0);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ColormapNotifyEvent {
+    pub window: Window,
+    pub colormap: Colormap,
+    pub new: bool,
+    pub state: u8,
+}
+
+impl From<xcb_system::xcb_colormap_notify_event_t> for ColormapNotifyEvent {
+    fn from(raw: xcb_system::xcb_colormap_notify_event_t) -> Self {
+        Self {
+            window: raw.window,
+            colormap: raw.colormap,
+            new: raw._new != 0,
+            state: raw.state,
+        }
+    }
+}
+
+pub type ClientMessageData = [u8; 20];
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClientMessageEvent {
+    pub format: u8,
+    pub window: Window,
+    pub type_: Atom,
+    pub data: ClientMessageData,
+}
+
+impl From<xcb_system::xcb_client_message_event_t> for ClientMessageEvent {
+    fn from(raw: xcb_system::xcb_client_message_event_t) -> Self {
+        Self {
+            format: raw.format,
+            window: raw.window,
+            type_: raw.type_,
+            data: unsafe { std::mem::transmute::<xcb_system::xcb_client_message_data_t, ClientMessageData>(raw.data) },
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Mapping(pub u32);
+
+impl Mapping {
+    pub const MODIFIER: Self = Self(0);
+    pub const KEYBOARD: Self = Self(1);
+    pub const POINTER: Self = Self(2);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MappingNotifyEvent {
+    pub request: u8,
+    pub first_keycode: Keycode,
+    pub count: u8,
+}
+
+impl From<xcb_system::xcb_mapping_notify_event_t> for MappingNotifyEvent {
+    fn from(raw: xcb_system::xcb_mapping_notify_event_t) -> Self {
+        Self {
+            request: raw.request,
+            first_keycode: raw.first_keycode,
+            count: raw.count,
+        }
+    }
+}
+
+// GeGeneric isn't generated, it's a generic event.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WindowClass(pub u32);
+
+impl WindowClass {
+    pub const COPY_FROM_PARENT: Self = Self(0);
+    pub const INPUT_OUTPUT: Self = Self(1);
+    pub const INPUT_ONLY: Self = Self(2);
+}
+
+bitflags! {
+    pub struct Cw: u32 {
+        const BACK_PIXMAP = 0x1;
+        const BACK_PIXEL = 0x2;
+        const BORDER_PIXMAP = 0x4;
+        const BORDER_PIXEL = 0x8;
+        const BIT_GRAVITY = 0x10;
+        const WIN_GRAVITY = 0x20;
+        const BACKING_STORE = 0x40;
+        const BACKING_PLANES = 0x80;
+        const BACKING_PIXEL = 0x100;
+        const OVERRIDE_REDIRECT = 0x200;
+        const SAVE_UNDER = 0x400;
+        const EVENT_MASK = 0x800;
+        const DONT_PROPAGATE = 0x1000;
+        const COLORMAP = 0x2000;
+        const CURSOR = 0x4000;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BackPixmap(pub u32);
+
+impl BackPixmap {
+    pub const NONE: Self = Self(0);
+    pub const PARENT_RELATIVE: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Gravity(pub u32);
+
+impl Gravity {
+    pub const BIT_FORGET: Self = Self(0);
+    pub const WIN_UNMAP: Self = Self(0);
+    pub const NORTH_WEST: Self = Self(1);
+    pub const NORTH: Self = Self(2);
+    pub const NORTH_EAST: Self = Self(3);
+    pub const WEST: Self = Self(4);
+    pub const CENTER: Self = Self(5);
+    pub const EAST: Self = Self(6);
+    pub const SOUTH_WEST: Self = Self(7);
+    pub const SOUTH: Self = Self(8);
+    pub const SOUTH_EAST: Self = Self(9);
+    pub const STATIC: Self = Self(10);
+}
+
+/// The values of `create_window`, every one that is set adds its bit to `value_mask`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct CreateWindowValueList {
+    pub background_pixmap: Option<Pixmap>,
+    pub background_pixel: Option<u32>,
+    pub border_pixmap: Option<Pixmap>,
+    pub border_pixel: Option<u32>,
+    pub bit_gravity: Option<u32>,
+    pub win_gravity: Option<u32>,
+    pub backing_store: Option<u32>,
+    pub backing_planes: Option<u32>,
+    pub backing_pixel: Option<u32>,
+    pub override_redirect: Option<Bool32>,
+    pub save_under: Option<Bool32>,
+    pub event_mask: Option<u32>,
+    pub do_not_propogate_mask: Option<u32>,
+    pub colormap: Option<Colormap>,
+    pub cursor: Option<Cursor>,
+}
+
+impl CreateWindowValueList {
+    pub fn background_pixmap(mut self, background_pixmap: Pixmap) -> Self {
+        self.background_pixmap = Some(background_pixmap);
+        self
+    }
+
+    pub fn background_pixel(mut self, background_pixel: u32) -> Self {
+        self.background_pixel = Some(background_pixel);
+        self
+    }
+
+    pub fn border_pixmap(mut self, border_pixmap: Pixmap) -> Self {
+        self.border_pixmap = Some(border_pixmap);
+        self
+    }
+
+    pub fn border_pixel(mut self, border_pixel: u32) -> Self {
+        self.border_pixel = Some(border_pixel);
+        self
+    }
+
+    pub fn bit_gravity(mut self, bit_gravity: u32) -> Self {
+        self.bit_gravity = Some(bit_gravity);
+        self
+    }
+
+    pub fn win_gravity(mut self, win_gravity: u32) -> Self {
+        self.win_gravity = Some(win_gravity);
+        self
+    }
+
+    pub fn backing_store(mut self, backing_store: u32) -> Self {
+        self.backing_store = Some(backing_store);
+        self
+    }
+
+    pub fn backing_planes(mut self, backing_planes: u32) -> Self {
+        self.backing_planes = Some(backing_planes);
+        self
+    }
+
+    pub fn backing_pixel(mut self, backing_pixel: u32) -> Self {
+        self.backing_pixel = Some(backing_pixel);
+        self
+    }
+
+    pub fn override_redirect(mut self, override_redirect: Bool32) -> Self {
+        self.override_redirect = Some(override_redirect);
+        self
+    }
+
+    pub fn save_under(mut self, save_under: Bool32) -> Self {
+        self.save_under = Some(save_under);
+        self
+    }
+
+    pub fn event_mask(mut self, event_mask: u32) -> Self {
+        self.event_mask = Some(event_mask);
+        self
+    }
+
+    pub fn do_not_propogate_mask(mut self, do_not_propogate_mask: u32) -> Self {
+        self.do_not_propogate_mask = Some(do_not_propogate_mask);
+        self
+    }
+
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = Some(colormap);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub(crate) fn serialize(&self) -> (u32, Vec<u32>) {
+        let mut mask = 0;
+        let mut values = vec![];
+
+        if let Some(value) = self.background_pixmap {
+            mask |= 0x1;
+            values.push(value);
+        }
+
+        if let Some(value) = self.background_pixel {
+            mask |= 0x2;
+            values.push(value);
+        }
+
+        if let Some(value) = self.border_pixmap {
+            mask |= 0x4;
+            values.push(value);
+        }
+
+        if let Some(value) = self.border_pixel {
+            mask |= 0x8;
+            values.push(value);
+        }
+
+        if let Some(value) = self.bit_gravity {
+            mask |= 0x10;
+            values.push(value);
+        }
+
+        if let Some(value) = self.win_gravity {
+            mask |= 0x20;
+            values.push(value);
+        }
+
+        if let Some(value) = self.backing_store {
+            mask |= 0x40;
+            values.push(value);
+        }
+
+        if let Some(value) = self.backing_planes {
+            mask |= 0x80;
+            values.push(value);
+        }
+
+        if let Some(value) = self.backing_pixel {
+            mask |= 0x100;
+            values.push(value);
+        }
+
+        if let Some(value) = self.override_redirect {
+            mask |= 0x200;
+            values.push(value);
+        }
+
+        if let Some(value) = self.save_under {
+            mask |= 0x400;
+            values.push(value);
+        }
+
+        if let Some(value) = self.event_mask {
+            mask |= 0x800;
+            values.push(value);
+        }
+
+        if let Some(value) = self.do_not_propogate_mask {
+            mask |= 0x1000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.colormap {
+            mask |= 0x2000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.cursor {
+            mask |= 0x4000;
+            values.push(value);
+        }
+
+        (mask, values)
+    }
+}
+
+pub fn create_window<'a>(connection: &'a crate::connection::Connection, depth: u8, wid: Window, parent: Window, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: u16, visual: Visualid, value_list: &CreateWindowValueList) -> crate::result::Cookie<'a, crate::result::Void> {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_create_window_checked(connection.get_connection(), depth, wid, parent, x, y, width, height, border_width, class, visual, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_window_unchecked<'a>(connection: &'a crate::connection::Connection, depth: u8, wid: Window, parent: Window, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: u16, visual: Visualid, value_list: &CreateWindowValueList) -> crate::result::UncheckedCookie {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_create_window(connection.get_connection(), depth, wid, parent, x, y, width, height, border_width, class, visual, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+/// The values of `change_window_attributes`, every one that is set adds its bit to `value_mask`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ChangeWindowAttributesValueList {
+    pub background_pixmap: Option<Pixmap>,
+    pub background_pixel: Option<u32>,
+    pub border_pixmap: Option<Pixmap>,
+    pub border_pixel: Option<u32>,
+    pub bit_gravity: Option<u32>,
+    pub win_gravity: Option<u32>,
+    pub backing_store: Option<u32>,
+    pub backing_planes: Option<u32>,
+    pub backing_pixel: Option<u32>,
+    pub override_redirect: Option<Bool32>,
+    pub save_under: Option<Bool32>,
+    pub event_mask: Option<u32>,
+    pub do_not_propogate_mask: Option<u32>,
+    pub colormap: Option<Colormap>,
+    pub cursor: Option<Cursor>,
+}
+
+impl ChangeWindowAttributesValueList {
+    pub fn background_pixmap(mut self, background_pixmap: Pixmap) -> Self {
+        self.background_pixmap = Some(background_pixmap);
+        self
+    }
+
+    pub fn background_pixel(mut self, background_pixel: u32) -> Self {
+        self.background_pixel = Some(background_pixel);
+        self
+    }
+
+    pub fn border_pixmap(mut self, border_pixmap: Pixmap) -> Self {
+        self.border_pixmap = Some(border_pixmap);
+        self
+    }
+
+    pub fn border_pixel(mut self, border_pixel: u32) -> Self {
+        self.border_pixel = Some(border_pixel);
+        self
+    }
+
+    pub fn bit_gravity(mut self, bit_gravity: u32) -> Self {
+        self.bit_gravity = Some(bit_gravity);
+        self
+    }
+
+    pub fn win_gravity(mut self, win_gravity: u32) -> Self {
+        self.win_gravity = Some(win_gravity);
+        self
+    }
+
+    pub fn backing_store(mut self, backing_store: u32) -> Self {
+        self.backing_store = Some(backing_store);
+        self
+    }
+
+    pub fn backing_planes(mut self, backing_planes: u32) -> Self {
+        self.backing_planes = Some(backing_planes);
+        self
+    }
+
+    pub fn backing_pixel(mut self, backing_pixel: u32) -> Self {
+        self.backing_pixel = Some(backing_pixel);
+        self
+    }
+
+    pub fn override_redirect(mut self, override_redirect: Bool32) -> Self {
+        self.override_redirect = Some(override_redirect);
+        self
+    }
+
+    pub fn save_under(mut self, save_under: Bool32) -> Self {
+        self.save_under = Some(save_under);
+        self
+    }
+
+    pub fn event_mask(mut self, event_mask: u32) -> Self {
+        self.event_mask = Some(event_mask);
+        self
+    }
+
+    pub fn do_not_propogate_mask(mut self, do_not_propogate_mask: u32) -> Self {
+        self.do_not_propogate_mask = Some(do_not_propogate_mask);
+        self
+    }
+
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = Some(colormap);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub(crate) fn serialize(&self) -> (u32, Vec<u32>) {
+        let mut mask = 0;
+        let mut values = vec![];
+
+        if let Some(value) = self.background_pixmap {
+            mask |= 0x1;
+            values.push(value);
+        }
+
+        if let Some(value) = self.background_pixel {
+            mask |= 0x2;
+            values.push(value);
+        }
+
+        if let Some(value) = self.border_pixmap {
+            mask |= 0x4;
+            values.push(value);
+        }
+
+        if let Some(value) = self.border_pixel {
+            mask |= 0x8;
+            values.push(value);
+        }
+
+        if let Some(value) = self.bit_gravity {
+            mask |= 0x10;
+            values.push(value);
+        }
+
+        if let Some(value) = self.win_gravity {
+            mask |= 0x20;
+            values.push(value);
+        }
+
+        if let Some(value) = self.backing_store {
+            mask |= 0x40;
+            values.push(value);
+        }
+
+        if let Some(value) = self.backing_planes {
+            mask |= 0x80;
+            values.push(value);
+        }
+
+        if let Some(value) = self.backing_pixel {
+            mask |= 0x100;
+            values.push(value);
+        }
+
+        if let Some(value) = self.override_redirect {
+            mask |= 0x200;
+            values.push(value);
+        }
+
+        if let Some(value) = self.save_under {
+            mask |= 0x400;
+            values.push(value);
+        }
+
+        if let Some(value) = self.event_mask {
+            mask |= 0x800;
+            values.push(value);
+        }
+
+        if let Some(value) = self.do_not_propogate_mask {
+            mask |= 0x1000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.colormap {
+            mask |= 0x2000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.cursor {
+            mask |= 0x4000;
+            values.push(value);
+        }
+
+        (mask, values)
+    }
+}
+
+pub fn change_window_attributes<'a>(connection: &'a crate::connection::Connection, window: Window, value_list: &ChangeWindowAttributesValueList) -> crate::result::Cookie<'a, crate::result::Void> {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_change_window_attributes_checked(connection.get_connection(), window, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_window_attributes_unchecked<'a>(connection: &'a crate::connection::Connection, window: Window, value_list: &ChangeWindowAttributesValueList) -> crate::result::UncheckedCookie {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_change_window_attributes(connection.get_connection(), window, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MapState(pub u32);
+
+impl MapState {
+    pub const UNMAPPED: Self = Self(0);
+    pub const UNVIEWABLE: Self = Self(1);
+    pub const VIEWABLE: Self = Self(2);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetWindowAttributesReply {
+    pub backing_store: u8,
+    pub visual: Visualid,
+    pub class: u16,
+    pub bit_gravity: u8,
+    pub win_gravity: u8,
+    pub backing_planes: u32,
+    pub backing_pixel: u32,
+    pub save_under: bool,
+    pub map_is_installed: bool,
+    pub map_state: u8,
+    pub override_redirect: bool,
+    pub colormap: Colormap,
+    pub all_event_masks: u32,
+    pub your_event_mask: u32,
+    pub do_not_propagate_mask: u16,
+}
+
+pub struct GetWindowAttributesRequest;
+
+impl crate::result::Request for GetWindowAttributesRequest {
+    type Reply = GetWindowAttributesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetWindowAttributesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_window_attributes_reply_t>(connection, sequence)?;
+
+        Ok(GetWindowAttributesReply {
+            backing_store: reply.backing_store,
+            visual: reply.visual,
+            class: reply._class,
+            bit_gravity: reply.bit_gravity,
+            win_gravity: reply.win_gravity,
+            backing_planes: reply.backing_planes,
+            backing_pixel: reply.backing_pixel,
+            save_under: reply.save_under != 0,
+            map_is_installed: reply.map_is_installed != 0,
+            map_state: reply.map_state,
+            override_redirect: reply.override_redirect != 0,
+            colormap: reply.colormap,
+            all_event_masks: reply.all_event_masks,
+            your_event_mask: reply.your_event_mask,
+            do_not_propagate_mask: reply.do_not_propagate_mask,
+        })
+    }
+}
+
+pub fn get_window_attributes<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::Cookie<'a, GetWindowAttributesRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_window_attributes(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, GetWindowAttributesRequest, connection)
+}
+
+pub fn destroy_window<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_destroy_window_checked(connection.get_connection(), window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn destroy_window_unchecked<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_destroy_window(connection.get_connection(), window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn destroy_subwindows<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_destroy_subwindows_checked(connection.get_connection(), window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn destroy_subwindows_unchecked<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_destroy_subwindows(connection.get_connection(), window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SetMode(pub u32);
+
+impl SetMode {
+    pub const INSERT: Self = Self(0);
+    pub const DELETE: Self = Self(1);
+}
+
+pub fn change_save_set<'a>(connection: &'a crate::connection::Connection, mode: u8, window: Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_change_save_set_checked(connection.get_connection(), mode, window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_save_set_unchecked<'a>(connection: &'a crate::connection::Connection, mode: u8, window: Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_change_save_set(connection.get_connection(), mode, window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn reparent_window<'a>(connection: &'a crate::connection::Connection, window: Window, parent: Window, x: i16, y: i16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_reparent_window_checked(connection.get_connection(), window, parent, x, y) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn reparent_window_unchecked<'a>(connection: &'a crate::connection::Connection, window: Window, parent: Window, x: i16, y: i16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_reparent_window(connection.get_connection(), window, parent, x, y) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn map_window<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_map_window_checked(connection.get_connection(), window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn map_window_unchecked<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_map_window(connection.get_connection(), window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn map_subwindows<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_map_subwindows_checked(connection.get_connection(), window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn map_subwindows_unchecked<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_map_subwindows(connection.get_connection(), window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn unmap_window<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_unmap_window_checked(connection.get_connection(), window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn unmap_window_unchecked<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_unmap_window(connection.get_connection(), window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn unmap_subwindows<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_unmap_subwindows_checked(connection.get_connection(), window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn unmap_subwindows_unchecked<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_unmap_subwindows(connection.get_connection(), window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+bitflags! {
+    pub struct ConfigWindow: u32 {
+        const X = 0x1;
+        const Y = 0x2;
+        const WIDTH = 0x4;
+        const HEIGHT = 0x8;
+        const BORDER_WIDTH = 0x10;
+        const SIBLING = 0x20;
+        const STACK_MODE = 0x40;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StackMode(pub u32);
+
+impl StackMode {
+    pub const ABOVE: Self = Self(0);
+    pub const BELOW: Self = Self(1);
+    pub const TOP_IF: Self = Self(2);
+    pub const BOTTOM_IF: Self = Self(3);
+    pub const OPPOSITE: Self = Self(4);
+}
+
+/// The values of `configure_window`, every one that is set adds its bit to `value_mask`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ConfigureWindowValueList {
+    pub x: Option<i32>,
+    pub y: Option<i32>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub border_width: Option<u32>,
+    pub sibling: Option<Window>,
+    pub stack_mode: Option<u32>,
+}
+
+impl ConfigureWindowValueList {
+    pub fn x(mut self, x: i32) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    pub fn y(mut self, y: i32) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: u32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn border_width(mut self, border_width: u32) -> Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    pub fn sibling(mut self, sibling: Window) -> Self {
+        self.sibling = Some(sibling);
+        self
+    }
+
+    pub fn stack_mode(mut self, stack_mode: u32) -> Self {
+        self.stack_mode = Some(stack_mode);
+        self
+    }
+
+    pub(crate) fn serialize(&self) -> (u16, Vec<u32>) {
+        let mut mask = 0;
+        let mut values = vec![];
+
+        if let Some(value) = self.x {
+            mask |= 0x1;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.y {
+            mask |= 0x2;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.width {
+            mask |= 0x4;
+            values.push(value);
+        }
+
+        if let Some(value) = self.height {
+            mask |= 0x8;
+            values.push(value);
+        }
+
+        if let Some(value) = self.border_width {
+            mask |= 0x10;
+            values.push(value);
+        }
+
+        if let Some(value) = self.sibling {
+            mask |= 0x20;
+            values.push(value);
+        }
+
+        if let Some(value) = self.stack_mode {
+            mask |= 0x40;
+            values.push(value);
+        }
+
+        (mask, values)
+    }
+}
+
+pub fn configure_window<'a>(connection: &'a crate::connection::Connection, window: Window, value_list: &ConfigureWindowValueList) -> crate::result::Cookie<'a, crate::result::Void> {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_configure_window_checked(connection.get_connection(), window, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn configure_window_unchecked<'a>(connection: &'a crate::connection::Connection, window: Window, value_list: &ConfigureWindowValueList) -> crate::result::UncheckedCookie {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_configure_window(connection.get_connection(), window, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Circulate(pub u32);
+
+impl Circulate {
+    pub const RAISE_LOWEST: Self = Self(0);
+    pub const LOWER_HIGHEST: Self = Self(1);
+}
+
+pub fn circulate_window<'a>(connection: &'a crate::connection::Connection, direction: u8, window: Window) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_circulate_window_checked(connection.get_connection(), direction, window) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn circulate_window_unchecked<'a>(connection: &'a crate::connection::Connection, direction: u8, window: Window) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_circulate_window(connection.get_connection(), direction, window) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetGeometryReply {
+    pub depth: u8,
+    pub root: Window,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub border_width: u16,
+}
+
+pub struct GetGeometryRequest;
+
+impl crate::result::Request for GetGeometryRequest {
+    type Reply = GetGeometryReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetGeometryReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_geometry_reply_t>(connection, sequence)?;
+
+        Ok(GetGeometryReply {
+            depth: reply.depth,
+            root: reply.root,
+            x: reply.x,
+            y: reply.y,
+            width: reply.width,
+            height: reply.height,
+            border_width: reply.border_width,
+        })
+    }
+}
+
+pub fn get_geometry<'a>(connection: &'a crate::connection::Connection, drawable: Drawable) -> crate::result::Cookie<'a, GetGeometryRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_geometry(connection.get_connection(), drawable) };
+
+    crate::result::Cookie::new(cookie.sequence, GetGeometryRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryTreeReply {
+    pub root: Window,
+    pub parent: Window,
+    pub children_len: u16,
+    pub children: Vec<Window>,
+}
+
+pub struct QueryTreeRequest;
+
+impl crate::result::Request for QueryTreeRequest {
+    type Reply = QueryTreeReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryTreeReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_query_tree_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { QueryTreeReply {
+            root: reply.root,
+            parent: reply.parent,
+            children_len: reply.children_len,
+            children: std::slice::from_raw_parts(xcb_system::xcb_query_tree_children(reply.as_ptr()) as *const Window, xcb_system::xcb_query_tree_children_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn query_tree<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::Cookie<'a, QueryTreeRequest> {
+    let cookie = unsafe { xcb_system::xcb_query_tree(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryTreeRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InternAtomReply {
+    pub atom: Atom,
+}
+
+pub struct InternAtomRequest;
+
+impl crate::result::Request for InternAtomRequest {
+    type Reply = InternAtomReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<InternAtomReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_intern_atom_reply_t>(connection, sequence)?;
+
+        Ok(InternAtomReply {
+            atom: reply.atom,
+        })
+    }
+}
+
+pub fn intern_atom<'a>(connection: &'a crate::connection::Connection, only_if_exists: bool, name: &[u8]) -> crate::result::Cookie<'a, InternAtomRequest> {
+    let cookie = unsafe { xcb_system::xcb_intern_atom(connection.get_connection(), u8::from(only_if_exists), name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, InternAtomRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetAtomNameReply {
+    pub name_len: u16,
+    pub name: Vec<u8>,
+}
+
+pub struct GetAtomNameRequest;
+
+impl crate::result::Request for GetAtomNameRequest {
+    type Reply = GetAtomNameReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetAtomNameReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_atom_name_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetAtomNameReply {
+            name_len: reply.name_len,
+            name: std::slice::from_raw_parts(xcb_system::xcb_get_atom_name_name(reply.as_ptr()) as *const u8, xcb_system::xcb_get_atom_name_name_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_atom_name<'a>(connection: &'a crate::connection::Connection, atom: Atom) -> crate::result::Cookie<'a, GetAtomNameRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_atom_name(connection.get_connection(), atom) };
+
+    crate::result::Cookie::new(cookie.sequence, GetAtomNameRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PropMode(pub u32);
+
+impl PropMode {
+    pub const REPLACE: Self = Self(0);
+    pub const PREPEND: Self = Self(1);
+    pub const APPEND: Self = Self(2);
+}
+
+pub fn change_property<'a>(connection: &'a crate::connection::Connection, mode: u8, window: Window, property: Atom, type_: Atom, format: u8, data_len: u32, data: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    assert!(data.len() >= ((data_len as usize) * ((format as usize) / 8)) as usize);
+
+    let cookie = unsafe { xcb_system::xcb_change_property_checked(connection.get_connection(), mode, window, property, type_, format, data_len, data.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_property_unchecked<'a>(connection: &'a crate::connection::Connection, mode: u8, window: Window, property: Atom, type_: Atom, format: u8, data_len: u32, data: &[u8]) -> crate::result::UncheckedCookie {
+    assert!(data.len() >= ((data_len as usize) * ((format as usize) / 8)) as usize);
+
+    let cookie = unsafe { xcb_system::xcb_change_property(connection.get_connection(), mode, window, property, type_, format, data_len, data.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn delete_property<'a>(connection: &'a crate::connection::Connection, window: Window, property: Atom) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_delete_property_checked(connection.get_connection(), window, property) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn delete_property_unchecked<'a>(connection: &'a crate::connection::Connection, window: Window, property: Atom) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_delete_property(connection.get_connection(), window, property) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GetPropertyType(pub u32);
+
+impl GetPropertyType {
+    pub const ANY: Self = Self(0);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetPropertyReply {
+    pub format: u8,
+    pub type_: Atom,
+    pub bytes_after: u32,
+    pub value_len: u32,
+    pub value: Vec<u8>,
+}
+
+pub struct GetPropertyRequest;
+
+impl crate::result::Request for GetPropertyRequest {
+    type Reply = GetPropertyReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetPropertyReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_property_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetPropertyReply {
+            format: reply.format,
+            type_: reply.type_,
+            bytes_after: reply.bytes_after,
+            value_len: reply.value_len,
+            value: std::slice::from_raw_parts(xcb_system::xcb_get_property_value(reply.as_ptr()) as *const u8, xcb_system::xcb_get_property_value_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_property<'a>(connection: &'a crate::connection::Connection, delete: bool, window: Window, property: Atom, type_: Atom, long_offset: u32, long_length: u32) -> crate::result::Cookie<'a, GetPropertyRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_property(connection.get_connection(), u8::from(delete), window, property, type_, long_offset, long_length) };
+
+    crate::result::Cookie::new(cookie.sequence, GetPropertyRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListPropertiesReply {
+    pub atoms_len: u16,
+    pub atoms: Vec<Atom>,
+}
+
+pub struct ListPropertiesRequest;
+
+impl crate::result::Request for ListPropertiesRequest {
+    type Reply = ListPropertiesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<ListPropertiesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_list_properties_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { ListPropertiesReply {
+            atoms_len: reply.atoms_len,
+            atoms: std::slice::from_raw_parts(xcb_system::xcb_list_properties_atoms(reply.as_ptr()) as *const Atom, xcb_system::xcb_list_properties_atoms_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn list_properties<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::Cookie<'a, ListPropertiesRequest> {
+    let cookie = unsafe { xcb_system::xcb_list_properties(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, ListPropertiesRequest, connection)
+}
+
+pub fn set_selection_owner<'a>(connection: &'a crate::connection::Connection, owner: Window, selection: Atom, time: Timestamp) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_set_selection_owner_checked(connection.get_connection(), owner, selection, time) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_selection_owner_unchecked<'a>(connection: &'a crate::connection::Connection, owner: Window, selection: Atom, time: Timestamp) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_set_selection_owner(connection.get_connection(), owner, selection, time) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetSelectionOwnerReply {
+    pub owner: Window,
+}
+
+pub struct GetSelectionOwnerRequest;
+
+impl crate::result::Request for GetSelectionOwnerRequest {
+    type Reply = GetSelectionOwnerReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetSelectionOwnerReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_selection_owner_reply_t>(connection, sequence)?;
+
+        Ok(GetSelectionOwnerReply {
+            owner: reply.owner,
+        })
+    }
+}
+
+pub fn get_selection_owner<'a>(connection: &'a crate::connection::Connection, selection: Atom) -> crate::result::Cookie<'a, GetSelectionOwnerRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_selection_owner(connection.get_connection(), selection) };
+
+    crate::result::Cookie::new(cookie.sequence, GetSelectionOwnerRequest, connection)
+}
+
+pub fn convert_selection<'a>(connection: &'a crate::connection::Connection, requestor: Window, selection: Atom, target: Atom, property: Atom, time: Timestamp) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_convert_selection_checked(connection.get_connection(), requestor, selection, target, property, time) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn convert_selection_unchecked<'a>(connection: &'a crate::connection::Connection, requestor: Window, selection: Atom, target: Atom, property: Atom, time: Timestamp) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_convert_selection(connection.get_connection(), requestor, selection, target, property, time) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SendEventDest(pub u32);
+
+impl SendEventDest {
+    pub const POINTER_WINDOW: Self = Self(0);
+    pub const ITEM_FOCUS: Self = Self(1);
+}
+
+pub fn send_event<'a>(connection: &'a crate::connection::Connection, propagate: bool, destination: Window, event_mask: u32, event: &[u8; 32]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_send_event_checked(connection.get_connection(), u8::from(propagate), destination, event_mask, event.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn send_event_unchecked<'a>(connection: &'a crate::connection::Connection, propagate: bool, destination: Window, event_mask: u32, event: &[u8; 32]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_send_event(connection.get_connection(), u8::from(propagate), destination, event_mask, event.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GrabMode(pub u32);
+
+impl GrabMode {
+    pub const SYNC: Self = Self(0);
+    pub const ASYNC: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GrabStatus(pub u32);
+
+impl GrabStatus {
+    pub const SUCCESS: Self = Self(0);
+    pub const ALREADY_GRABBED: Self = Self(1);
+    pub const INVALID_TIME: Self = Self(2);
+    pub const NOT_VIEWABLE: Self = Self(3);
+    pub const FROZEN: Self = Self(4);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CursorEnum(pub u32);
+
+impl CursorEnum {
+    pub const NONE: Self = Self(0);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrabPointerReply {
+    pub status: u8,
+}
+
+pub struct GrabPointerRequest;
+
+impl crate::result::Request for GrabPointerRequest {
+    type Reply = GrabPointerReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GrabPointerReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_grab_pointer_reply_t>(connection, sequence)?;
+
+        Ok(GrabPointerReply {
+            status: reply.status,
+        })
+    }
+}
+
+pub fn grab_pointer<'a>(connection: &'a crate::connection::Connection, owner_events: bool, grab_window: Window, event_mask: u16, pointer_mode: u8, keyboard_mode: u8, confine_to: Window, cursor: Cursor, time: Timestamp) -> crate::result::Cookie<'a, GrabPointerRequest> {
+    let cookie = unsafe { xcb_system::xcb_grab_pointer(connection.get_connection(), u8::from(owner_events), grab_window, event_mask, pointer_mode, keyboard_mode, confine_to, cursor, time) };
+
+    crate::result::Cookie::new(cookie.sequence, GrabPointerRequest, connection)
+}
+
+pub fn ungrab_pointer<'a>(connection: &'a crate::connection::Connection, time: Timestamp) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_ungrab_pointer_checked(connection.get_connection(), time) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn ungrab_pointer_unchecked<'a>(connection: &'a crate::connection::Connection, time: Timestamp) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_ungrab_pointer(connection.get_connection(), time) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ButtonIndex(pub u32);
+
+impl ButtonIndex {
+    pub const ANY: Self = Self(0);
+    pub const BUTTON_1: Self = Self(1);
+    pub const BUTTON_2: Self = Self(2);
+    pub const BUTTON_3: Self = Self(3);
+    pub const BUTTON_4: Self = Self(4);
+    pub const BUTTON_5: Self = Self(5);
+}
+
+pub fn grab_button<'a>(connection: &'a crate::connection::Connection, owner_events: bool, grab_window: Window, event_mask: u16, pointer_mode: u8, keyboard_mode: u8, confine_to: Window, cursor: Cursor, button: u8, modifiers: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_grab_button_checked(connection.get_connection(), u8::from(owner_events), grab_window, event_mask, pointer_mode, keyboard_mode, confine_to, cursor, button, modifiers) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn grab_button_unchecked<'a>(connection: &'a crate::connection::Connection, owner_events: bool, grab_window: Window, event_mask: u16, pointer_mode: u8, keyboard_mode: u8, confine_to: Window, cursor: Cursor, button: u8, modifiers: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_grab_button(connection.get_connection(), u8::from(owner_events), grab_window, event_mask, pointer_mode, keyboard_mode, confine_to, cursor, button, modifiers) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn ungrab_button<'a>(connection: &'a crate::connection::Connection, button: u8, grab_window: Window, modifiers: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_ungrab_button_checked(connection.get_connection(), button, grab_window, modifiers) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn ungrab_button_unchecked<'a>(connection: &'a crate::connection::Connection, button: u8, grab_window: Window, modifiers: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_ungrab_button(connection.get_connection(), button, grab_window, modifiers) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn change_active_pointer_grab<'a>(connection: &'a crate::connection::Connection, cursor: Cursor, time: Timestamp, event_mask: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_change_active_pointer_grab_checked(connection.get_connection(), cursor, time, event_mask) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_active_pointer_grab_unchecked<'a>(connection: &'a crate::connection::Connection, cursor: Cursor, time: Timestamp, event_mask: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_change_active_pointer_grab(connection.get_connection(), cursor, time, event_mask) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrabKeyboardReply {
+    pub status: u8,
+}
+
+pub struct GrabKeyboardRequest;
+
+impl crate::result::Request for GrabKeyboardRequest {
+    type Reply = GrabKeyboardReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GrabKeyboardReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_grab_keyboard_reply_t>(connection, sequence)?;
+
+        Ok(GrabKeyboardReply {
+            status: reply.status,
+        })
+    }
+}
+
+pub fn grab_keyboard<'a>(connection: &'a crate::connection::Connection, owner_events: bool, grab_window: Window, time: Timestamp, pointer_mode: u8, keyboard_mode: u8) -> crate::result::Cookie<'a, GrabKeyboardRequest> {
+    let cookie = unsafe { xcb_system::xcb_grab_keyboard(connection.get_connection(), u8::from(owner_events), grab_window, time, pointer_mode, keyboard_mode) };
+
+    crate::result::Cookie::new(cookie.sequence, GrabKeyboardRequest, connection)
+}
+
+pub fn ungrab_keyboard<'a>(connection: &'a crate::connection::Connection, time: Timestamp) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_ungrab_keyboard_checked(connection.get_connection(), time) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn ungrab_keyboard_unchecked<'a>(connection: &'a crate::connection::Connection, time: Timestamp) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_ungrab_keyboard(connection.get_connection(), time) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grab(pub u32);
+
+impl Grab {
+    pub const ANY: Self = Self(0);
+}
+
+pub fn grab_key<'a>(connection: &'a crate::connection::Connection, owner_events: bool, grab_window: Window, modifiers: u16, key: Keycode, pointer_mode: u8, keyboard_mode: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_grab_key_checked(connection.get_connection(), u8::from(owner_events), grab_window, modifiers, key, pointer_mode, keyboard_mode) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn grab_key_unchecked<'a>(connection: &'a crate::connection::Connection, owner_events: bool, grab_window: Window, modifiers: u16, key: Keycode, pointer_mode: u8, keyboard_mode: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_grab_key(connection.get_connection(), u8::from(owner_events), grab_window, modifiers, key, pointer_mode, keyboard_mode) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn ungrab_key<'a>(connection: &'a crate::connection::Connection, key: Keycode, grab_window: Window, modifiers: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_ungrab_key_checked(connection.get_connection(), key, grab_window, modifiers) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn ungrab_key_unchecked<'a>(connection: &'a crate::connection::Connection, key: Keycode, grab_window: Window, modifiers: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_ungrab_key(connection.get_connection(), key, grab_window, modifiers) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Allow(pub u32);
+
+impl Allow {
+    pub const ASYNC_POINTER: Self = Self(0);
+    pub const SYNC_POINTER: Self = Self(1);
+    pub const REPLAY_POINTER: Self = Self(2);
+    pub const ASYNC_KEYBOARD: Self = Self(3);
+    pub const SYNC_KEYBOARD: Self = Self(4);
+    pub const REPLAY_KEYBOARD: Self = Self(5);
+    pub const ASYNC_BOTH: Self = Self(6);
+    pub const SYNC_BOTH: Self = Self(7);
+}
+
+pub fn allow_events<'a>(connection: &'a crate::connection::Connection, mode: u8, time: Timestamp) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_allow_events_checked(connection.get_connection(), mode, time) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn allow_events_unchecked<'a>(connection: &'a crate::connection::Connection, mode: u8, time: Timestamp) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_allow_events(connection.get_connection(), mode, time) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn grab_server<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_grab_server_checked(connection.get_connection()) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn grab_server_unchecked<'a>(connection: &'a crate::connection::Connection) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_grab_server(connection.get_connection()) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn ungrab_server<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_ungrab_server_checked(connection.get_connection()) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn ungrab_server_unchecked<'a>(connection: &'a crate::connection::Connection) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_ungrab_server(connection.get_connection()) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryPointerReply {
+    pub same_screen: bool,
+    pub root: Window,
+    pub child: Window,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub win_x: i16,
+    pub win_y: i16,
+    pub mask: u16,
+}
+
+pub struct QueryPointerRequest;
+
+impl crate::result::Request for QueryPointerRequest {
+    type Reply = QueryPointerReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryPointerReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_query_pointer_reply_t>(connection, sequence)?;
+
+        Ok(QueryPointerReply {
+            same_screen: reply.same_screen != 0,
+            root: reply.root,
+            child: reply.child,
+            root_x: reply.root_x,
+            root_y: reply.root_y,
+            win_x: reply.win_x,
+            win_y: reply.win_y,
+            mask: reply.mask,
+        })
+    }
+}
+
+pub fn query_pointer<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::Cookie<'a, QueryPointerRequest> {
+    let cookie = unsafe { xcb_system::xcb_query_pointer(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryPointerRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Timecoord {
+    pub time: Timestamp,
+    pub x: i16,
+    pub y: i16,
+}
+
+impl From<xcb_system::xcb_timecoord_t> for Timecoord {
+    fn from(raw: xcb_system::xcb_timecoord_t) -> Self {
+        Self {
+            time: raw.time,
+            x: raw.x,
+            y: raw.y,
+        }
+    }
+}
+
+impl From<Timecoord> for xcb_system::xcb_timecoord_t {
+    fn from(value: Timecoord) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.time = value.time;
+        raw.x = value.x;
+        raw.y = value.y;
+
+        raw
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetMotionEventsReply {
+    pub events_len: u32,
+    pub events: Vec<Timecoord>,
+}
+
+pub struct GetMotionEventsRequest;
+
+impl crate::result::Request for GetMotionEventsRequest {
+    type Reply = GetMotionEventsReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetMotionEventsReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_motion_events_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetMotionEventsReply {
+            events_len: reply.events_len,
+            events: std::slice::from_raw_parts(xcb_system::xcb_get_motion_events_events(reply.as_ptr()) as *const xcb_system::xcb_timecoord_t, xcb_system::xcb_get_motion_events_events_length(reply.as_ptr()) as usize).iter().map(|&value| Timecoord::from(value)).collect(),
+        } })
+    }
+}
+
+pub fn get_motion_events<'a>(connection: &'a crate::connection::Connection, window: Window, start: Timestamp, stop: Timestamp) -> crate::result::Cookie<'a, GetMotionEventsRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_motion_events(connection.get_connection(), window, start, stop) };
+
+    crate::result::Cookie::new(cookie.sequence, GetMotionEventsRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TranslateCoordinatesReply {
+    pub same_screen: bool,
+    pub child: Window,
+    pub dst_x: i16,
+    pub dst_y: i16,
+}
+
+pub struct TranslateCoordinatesRequest;
+
+impl crate::result::Request for TranslateCoordinatesRequest {
+    type Reply = TranslateCoordinatesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<TranslateCoordinatesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_translate_coordinates_reply_t>(connection, sequence)?;
+
+        Ok(TranslateCoordinatesReply {
+            same_screen: reply.same_screen != 0,
+            child: reply.child,
+            dst_x: reply.dst_x,
+            dst_y: reply.dst_y,
+        })
+    }
+}
+
+pub fn translate_coordinates<'a>(connection: &'a crate::connection::Connection, src_window: Window, dst_window: Window, src_x: i16, src_y: i16) -> crate::result::Cookie<'a, TranslateCoordinatesRequest> {
+    let cookie = unsafe { xcb_system::xcb_translate_coordinates(connection.get_connection(), src_window, dst_window, src_x, src_y) };
+
+    crate::result::Cookie::new(cookie.sequence, TranslateCoordinatesRequest, connection)
+}
+
+pub fn warp_pointer<'a>(connection: &'a crate::connection::Connection, src_window: Window, dst_window: Window, src_x: i16, src_y: i16, src_width: u16, src_height: u16, dst_x: i16, dst_y: i16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_warp_pointer_checked(connection.get_connection(), src_window, dst_window, src_x, src_y, src_width, src_height, dst_x, dst_y) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn warp_pointer_unchecked<'a>(connection: &'a crate::connection::Connection, src_window: Window, dst_window: Window, src_x: i16, src_y: i16, src_width: u16, src_height: u16, dst_x: i16, dst_y: i16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_warp_pointer(connection.get_connection(), src_window, dst_window, src_x, src_y, src_width, src_height, dst_x, dst_y) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InputFocus(pub u32);
+
+impl InputFocus {
+    pub const NONE: Self = Self(0);
+    pub const POINTER_ROOT: Self = Self(1);
+    pub const PARENT: Self = Self(2);
+    pub const FOLLOW_KEYBOARD: Self = Self(3);
+}
+
+pub fn set_input_focus<'a>(connection: &'a crate::connection::Connection, revert_to: u8, focus: Window, time: Timestamp) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_set_input_focus_checked(connection.get_connection(), revert_to, focus, time) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_input_focus_unchecked<'a>(connection: &'a crate::connection::Connection, revert_to: u8, focus: Window, time: Timestamp) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_set_input_focus(connection.get_connection(), revert_to, focus, time) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetInputFocusReply {
+    pub revert_to: u8,
+    pub focus: Window,
+}
+
+pub struct GetInputFocusRequest;
+
+impl crate::result::Request for GetInputFocusRequest {
+    type Reply = GetInputFocusReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetInputFocusReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_input_focus_reply_t>(connection, sequence)?;
+
+        Ok(GetInputFocusReply {
+            revert_to: reply.revert_to,
+            focus: reply.focus,
+        })
+    }
+}
+
+pub fn get_input_focus<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, GetInputFocusRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_input_focus(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, GetInputFocusRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryKeymapReply {
+    pub keys: [u8; 32],
+}
+
+pub struct QueryKeymapRequest;
+
+impl crate::result::Request for QueryKeymapRequest {
+    type Reply = QueryKeymapReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryKeymapReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_query_keymap_reply_t>(connection, sequence)?;
+
+        Ok(QueryKeymapReply {
+            keys: reply.keys,
+        })
+    }
+}
+
+pub fn query_keymap<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, QueryKeymapRequest> {
+    let cookie = unsafe { xcb_system::xcb_query_keymap(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryKeymapRequest, connection)
+}
+
+pub fn open_font<'a>(connection: &'a crate::connection::Connection, fid: Font, name: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_open_font_checked(connection.get_connection(), fid, name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn open_font_unchecked<'a>(connection: &'a crate::connection::Connection, fid: Font, name: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_open_font(connection.get_connection(), fid, name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn close_font<'a>(connection: &'a crate::connection::Connection, font: Font) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_close_font_checked(connection.get_connection(), font) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn close_font_unchecked<'a>(connection: &'a crate::connection::Connection, font: Font) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_close_font(connection.get_connection(), font) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FontDraw(pub u32);
+
+impl FontDraw {
+    pub const LEFT_TO_RIGHT: Self = Self(0);
+    pub const RIGHT_TO_LEFT: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Fontprop {
+    pub name: Atom,
+    pub value: u32,
+}
+
+impl From<xcb_system::xcb_fontprop_t> for Fontprop {
+    fn from(raw: xcb_system::xcb_fontprop_t) -> Self {
+        Self {
+            name: raw.name,
+            value: raw.value,
+        }
+    }
+}
+
+impl From<Fontprop> for xcb_system::xcb_fontprop_t {
+    fn from(value: Fontprop) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.name = value.name;
+        raw.value = value.value;
+
+        raw
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Charinfo {
+    pub left_side_bearing: i16,
+    pub right_side_bearing: i16,
+    pub character_width: i16,
+    pub ascent: i16,
+    pub descent: i16,
+    pub attributes: u16,
+}
+
+impl From<xcb_system::xcb_charinfo_t> for Charinfo {
+    fn from(raw: xcb_system::xcb_charinfo_t) -> Self {
+        Self {
+            left_side_bearing: raw.left_side_bearing,
+            right_side_bearing: raw.right_side_bearing,
+            character_width: raw.character_width,
+            ascent: raw.ascent,
+            descent: raw.descent,
+            attributes: raw.attributes,
+        }
+    }
+}
+
+impl From<Charinfo> for xcb_system::xcb_charinfo_t {
+    fn from(value: Charinfo) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.left_side_bearing = value.left_side_bearing;
+        raw.right_side_bearing = value.right_side_bearing;
+        raw.character_width = value.character_width;
+        raw.ascent = value.ascent;
+        raw.descent = value.descent;
+        raw.attributes = value.attributes;
+
+        raw
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryFontReply {
+    pub min_bounds: Charinfo,
+    pub max_bounds: Charinfo,
+    pub min_char_or_byte2: u16,
+    pub max_char_or_byte2: u16,
+    pub default_char: u16,
+    pub properties_len: u16,
+    pub draw_direction: u8,
+    pub min_byte1: u8,
+    pub max_byte1: u8,
+    pub all_chars_exist: bool,
+    pub font_ascent: i16,
+    pub font_descent: i16,
+    pub char_infos_len: u32,
+    pub properties: Vec<Fontprop>,
+    pub char_infos: Vec<Charinfo>,
+}
+
+pub struct QueryFontRequest;
+
+impl crate::result::Request for QueryFontRequest {
+    type Reply = QueryFontReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryFontReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_query_font_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { QueryFontReply {
+            min_bounds: Charinfo::from(reply.min_bounds),
+            max_bounds: Charinfo::from(reply.max_bounds),
+            min_char_or_byte2: reply.min_char_or_byte2,
+            max_char_or_byte2: reply.max_char_or_byte2,
+            default_char: reply.default_char,
+            properties_len: reply.properties_len,
+            draw_direction: reply.draw_direction,
+            min_byte1: reply.min_byte1,
+            max_byte1: reply.max_byte1,
+            all_chars_exist: reply.all_chars_exist != 0,
+            font_ascent: reply.font_ascent,
+            font_descent: reply.font_descent,
+            char_infos_len: reply.char_infos_len,
+            properties: std::slice::from_raw_parts(xcb_system::xcb_query_font_properties(reply.as_ptr()) as *const xcb_system::xcb_fontprop_t, xcb_system::xcb_query_font_properties_length(reply.as_ptr()) as usize).iter().map(|&value| Fontprop::from(value)).collect(),
+            char_infos: std::slice::from_raw_parts(xcb_system::xcb_query_font_char_infos(reply.as_ptr()) as *const xcb_system::xcb_charinfo_t, xcb_system::xcb_query_font_char_infos_length(reply.as_ptr()) as usize).iter().map(|&value| Charinfo::from(value)).collect(),
+        } })
+    }
+}
+
+pub fn query_font<'a>(connection: &'a crate::connection::Connection, font: Fontable) -> crate::result::Cookie<'a, QueryFontRequest> {
+    let cookie = unsafe { xcb_system::xcb_query_font(connection.get_connection(), font) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryFontRequest, connection)
+}
+
+// QueryTextExtents isn't generated, it has an exprfield field.
+
+// STR isn't generated, name has a variable length.
+
+// ListFonts isn't generated, STR has a variable size.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListFontsWithInfoReply {
+    pub name_len: u8,
+    pub min_bounds: Charinfo,
+    pub max_bounds: Charinfo,
+    pub min_char_or_byte2: u16,
+    pub max_char_or_byte2: u16,
+    pub default_char: u16,
+    pub properties_len: u16,
+    pub draw_direction: u8,
+    pub min_byte1: u8,
+    pub max_byte1: u8,
+    pub all_chars_exist: bool,
+    pub font_ascent: i16,
+    pub font_descent: i16,
+    pub replies_hint: u32,
+    pub properties: Vec<Fontprop>,
+    pub name: Vec<u8>,
+}
+
+pub struct ListFontsWithInfoRequest;
+
+impl crate::result::Request for ListFontsWithInfoRequest {
+    type Reply = ListFontsWithInfoReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<ListFontsWithInfoReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_list_fonts_with_info_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { ListFontsWithInfoReply {
+            name_len: reply.name_len,
+            min_bounds: Charinfo::from(reply.min_bounds),
+            max_bounds: Charinfo::from(reply.max_bounds),
+            min_char_or_byte2: reply.min_char_or_byte2,
+            max_char_or_byte2: reply.max_char_or_byte2,
+            default_char: reply.default_char,
+            properties_len: reply.properties_len,
+            draw_direction: reply.draw_direction,
+            min_byte1: reply.min_byte1,
+            max_byte1: reply.max_byte1,
+            all_chars_exist: reply.all_chars_exist != 0,
+            font_ascent: reply.font_ascent,
+            font_descent: reply.font_descent,
+            replies_hint: reply.replies_hint,
+            properties: std::slice::from_raw_parts(xcb_system::xcb_list_fonts_with_info_properties(reply.as_ptr()) as *const xcb_system::xcb_fontprop_t, xcb_system::xcb_list_fonts_with_info_properties_length(reply.as_ptr()) as usize).iter().map(|&value| Fontprop::from(value)).collect(),
+            name: std::slice::from_raw_parts(xcb_system::xcb_list_fonts_with_info_name(reply.as_ptr()) as *const u8, xcb_system::xcb_list_fonts_with_info_name_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn list_fonts_with_info<'a>(connection: &'a crate::connection::Connection, max_names: u16, pattern: &[u8]) -> crate::result::Cookie<'a, ListFontsWithInfoRequest> {
+    let cookie = unsafe { xcb_system::xcb_list_fonts_with_info(connection.get_connection(), max_names, pattern.len() as _, pattern.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, ListFontsWithInfoRequest, connection)
+}
+
+// SetFontPath isn't generated, STR has a variable size.
+
+// GetFontPath isn't generated, STR has a variable size.
+
+pub fn create_pixmap<'a>(connection: &'a crate::connection::Connection, depth: u8, pid: Pixmap, drawable: Drawable, width: u16, height: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_create_pixmap_checked(connection.get_connection(), depth, pid, drawable, width, height) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_pixmap_unchecked<'a>(connection: &'a crate::connection::Connection, depth: u8, pid: Pixmap, drawable: Drawable, width: u16, height: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_create_pixmap(connection.get_connection(), depth, pid, drawable, width, height) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn free_pixmap<'a>(connection: &'a crate::connection::Connection, pixmap: Pixmap) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_free_pixmap_checked(connection.get_connection(), pixmap) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn free_pixmap_unchecked<'a>(connection: &'a crate::connection::Connection, pixmap: Pixmap) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_free_pixmap(connection.get_connection(), pixmap) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+bitflags! {
+    pub struct Gc: u32 {
+        const FUNCTION = 0x1;
+        const PLANE_MASK = 0x2;
+        const FOREGROUND = 0x4;
+        const BACKGROUND = 0x8;
+        const LINE_WIDTH = 0x10;
+        const LINE_STYLE = 0x20;
+        const CAP_STYLE = 0x40;
+        const JOIN_STYLE = 0x80;
+        const FILL_STYLE = 0x100;
+        const FILL_RULE = 0x200;
+        const TILE = 0x400;
+        const STIPPLE = 0x800;
+        const TILE_STIPPLE_ORIGIN_X = 0x1000;
+        const TILE_STIPPLE_ORIGIN_Y = 0x2000;
+        const FONT = 0x4000;
+        const SUBWINDOW_MODE = 0x8000;
+        const GRAPHICS_EXPOSURES = 0x10000;
+        const CLIP_ORIGIN_X = 0x20000;
+        const CLIP_ORIGIN_Y = 0x40000;
+        const CLIP_MASK = 0x80000;
+        const DASH_OFFSET = 0x100000;
+        const DASH_LIST = 0x200000;
+        const ARC_MODE = 0x400000;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Gx(pub u32);
+
+impl Gx {
+    pub const CLEAR: Self = Self(0);
+    pub const AND: Self = Self(1);
+    pub const AND_REVERSE: Self = Self(2);
+    pub const COPY: Self = Self(3);
+    pub const AND_INVERTED: Self = Self(4);
+    pub const NOOP: Self = Self(5);
+    pub const XOR: Self = Self(6);
+    pub const OR: Self = Self(7);
+    pub const NOR: Self = Self(8);
+    pub const EQUIV: Self = Self(9);
+    pub const INVERT: Self = Self(10);
+    pub const OR_REVERSE: Self = Self(11);
+    pub const COPY_INVERTED: Self = Self(12);
+    pub const OR_INVERTED: Self = Self(13);
+    pub const NAND: Self = Self(14);
+    pub const SET: Self = Self(15);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LineStyle(pub u32);
+
+impl LineStyle {
+    pub const SOLID: Self = Self(0);
+    pub const ON_OFF_DASH: Self = Self(1);
+    pub const DOUBLE_DASH: Self = Self(2);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CapStyle(pub u32);
+
+impl CapStyle {
+    pub const NOT_LAST: Self = Self(0);
+    pub const BUTT: Self = Self(1);
+    pub const ROUND: Self = Self(2);
+    pub const PROJECTING: Self = Self(3);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct JoinStyle(pub u32);
+
+impl JoinStyle {
+    pub const MITER: Self = Self(0);
+    pub const ROUND: Self = Self(1);
+    pub const BEVEL: Self = Self(2);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FillStyle(pub u32);
+
+impl FillStyle {
+    pub const SOLID: Self = Self(0);
+    pub const TILED: Self = Self(1);
+    pub const STIPPLED: Self = Self(2);
+    pub const OPAQUE_STIPPLED: Self = Self(3);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FillRule(pub u32);
+
+impl FillRule {
+    pub const EVEN_ODD: Self = Self(0);
+    pub const WINDING: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SubwindowMode(pub u32);
+
+impl SubwindowMode {
+    pub const CLIP_BY_CHILDREN: Self = Self(0);
+    pub const INCLUDE_INFERIORS: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ArcMode(pub u32);
+
+impl ArcMode {
+    pub const CHORD: Self = Self(0);
+    pub const PIE_SLICE: Self = Self(1);
+}
+
+/// The values of `create_gc`, every one that is set adds its bit to `value_mask`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct CreateGCValueList {
+    pub function: Option<u32>,
+    pub plane_mask: Option<u32>,
+    pub foreground: Option<u32>,
+    pub background: Option<u32>,
+    pub line_width: Option<u32>,
+    pub line_style: Option<u32>,
+    pub cap_style: Option<u32>,
+    pub join_style: Option<u32>,
+    pub fill_style: Option<u32>,
+    pub fill_rule: Option<u32>,
+    pub tile: Option<Pixmap>,
+    pub stipple: Option<Pixmap>,
+    pub tile_stipple_x_origin: Option<i32>,
+    pub tile_stipple_y_origin: Option<i32>,
+    pub font: Option<Font>,
+    pub subwindow_mode: Option<u32>,
+    pub graphics_exposures: Option<Bool32>,
+    pub clip_x_origin: Option<i32>,
+    pub clip_y_origin: Option<i32>,
+    pub clip_mask: Option<Pixmap>,
+    pub dash_offset: Option<u32>,
+    pub dashes: Option<u32>,
+    pub arc_mode: Option<u32>,
+}
+
+impl CreateGCValueList {
+    pub fn function(mut self, function: u32) -> Self {
+        self.function = Some(function);
+        self
+    }
+
+    pub fn plane_mask(mut self, plane_mask: u32) -> Self {
+        self.plane_mask = Some(plane_mask);
+        self
+    }
+
+    pub fn foreground(mut self, foreground: u32) -> Self {
+        self.foreground = Some(foreground);
+        self
+    }
+
+    pub fn background(mut self, background: u32) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    pub fn line_width(mut self, line_width: u32) -> Self {
+        self.line_width = Some(line_width);
+        self
+    }
+
+    pub fn line_style(mut self, line_style: u32) -> Self {
+        self.line_style = Some(line_style);
+        self
+    }
+
+    pub fn cap_style(mut self, cap_style: u32) -> Self {
+        self.cap_style = Some(cap_style);
+        self
+    }
+
+    pub fn join_style(mut self, join_style: u32) -> Self {
+        self.join_style = Some(join_style);
+        self
+    }
+
+    pub fn fill_style(mut self, fill_style: u32) -> Self {
+        self.fill_style = Some(fill_style);
+        self
+    }
+
+    pub fn fill_rule(mut self, fill_rule: u32) -> Self {
+        self.fill_rule = Some(fill_rule);
+        self
+    }
+
+    pub fn tile(mut self, tile: Pixmap) -> Self {
+        self.tile = Some(tile);
+        self
+    }
+
+    pub fn stipple(mut self, stipple: Pixmap) -> Self {
+        self.stipple = Some(stipple);
+        self
+    }
+
+    pub fn tile_stipple_x_origin(mut self, tile_stipple_x_origin: i32) -> Self {
+        self.tile_stipple_x_origin = Some(tile_stipple_x_origin);
+        self
+    }
+
+    pub fn tile_stipple_y_origin(mut self, tile_stipple_y_origin: i32) -> Self {
+        self.tile_stipple_y_origin = Some(tile_stipple_y_origin);
+        self
+    }
+
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    pub fn subwindow_mode(mut self, subwindow_mode: u32) -> Self {
+        self.subwindow_mode = Some(subwindow_mode);
+        self
+    }
+
+    pub fn graphics_exposures(mut self, graphics_exposures: Bool32) -> Self {
+        self.graphics_exposures = Some(graphics_exposures);
+        self
+    }
+
+    pub fn clip_x_origin(mut self, clip_x_origin: i32) -> Self {
+        self.clip_x_origin = Some(clip_x_origin);
+        self
+    }
+
+    pub fn clip_y_origin(mut self, clip_y_origin: i32) -> Self {
+        self.clip_y_origin = Some(clip_y_origin);
+        self
+    }
+
+    pub fn clip_mask(mut self, clip_mask: Pixmap) -> Self {
+        self.clip_mask = Some(clip_mask);
+        self
+    }
+
+    pub fn dash_offset(mut self, dash_offset: u32) -> Self {
+        self.dash_offset = Some(dash_offset);
+        self
+    }
+
+    pub fn dashes(mut self, dashes: u32) -> Self {
+        self.dashes = Some(dashes);
+        self
+    }
+
+    pub fn arc_mode(mut self, arc_mode: u32) -> Self {
+        self.arc_mode = Some(arc_mode);
+        self
+    }
+
+    pub(crate) fn serialize(&self) -> (u32, Vec<u32>) {
+        let mut mask = 0;
+        let mut values = vec![];
+
+        if let Some(value) = self.function {
+            mask |= 0x1;
+            values.push(value);
+        }
+
+        if let Some(value) = self.plane_mask {
+            mask |= 0x2;
+            values.push(value);
+        }
+
+        if let Some(value) = self.foreground {
+            mask |= 0x4;
+            values.push(value);
+        }
+
+        if let Some(value) = self.background {
+            mask |= 0x8;
+            values.push(value);
+        }
+
+        if let Some(value) = self.line_width {
+            mask |= 0x10;
+            values.push(value);
+        }
+
+        if let Some(value) = self.line_style {
+            mask |= 0x20;
+            values.push(value);
+        }
+
+        if let Some(value) = self.cap_style {
+            mask |= 0x40;
+            values.push(value);
+        }
+
+        if let Some(value) = self.join_style {
+            mask |= 0x80;
+            values.push(value);
+        }
+
+        if let Some(value) = self.fill_style {
+            mask |= 0x100;
+            values.push(value);
+        }
+
+        if let Some(value) = self.fill_rule {
+            mask |= 0x200;
+            values.push(value);
+        }
+
+        if let Some(value) = self.tile {
+            mask |= 0x400;
+            values.push(value);
+        }
+
+        if let Some(value) = self.stipple {
+            mask |= 0x800;
+            values.push(value);
+        }
+
+        if let Some(value) = self.tile_stipple_x_origin {
+            mask |= 0x1000;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.tile_stipple_y_origin {
+            mask |= 0x2000;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.font {
+            mask |= 0x4000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.subwindow_mode {
+            mask |= 0x8000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.graphics_exposures {
+            mask |= 0x10000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.clip_x_origin {
+            mask |= 0x20000;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.clip_y_origin {
+            mask |= 0x40000;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.clip_mask {
+            mask |= 0x80000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.dash_offset {
+            mask |= 0x100000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.dashes {
+            mask |= 0x200000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.arc_mode {
+            mask |= 0x400000;
+            values.push(value);
+        }
+
+        (mask, values)
+    }
+}
+
+pub fn create_gc<'a>(connection: &'a crate::connection::Connection, cid: Gcontext, drawable: Drawable, value_list: &CreateGCValueList) -> crate::result::Cookie<'a, crate::result::Void> {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_create_gc_checked(connection.get_connection(), cid, drawable, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_gc_unchecked<'a>(connection: &'a crate::connection::Connection, cid: Gcontext, drawable: Drawable, value_list: &CreateGCValueList) -> crate::result::UncheckedCookie {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_create_gc(connection.get_connection(), cid, drawable, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+/// The values of `change_gc`, every one that is set adds its bit to `value_mask`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ChangeGCValueList {
+    pub function: Option<u32>,
+    pub plane_mask: Option<u32>,
+    pub foreground: Option<u32>,
+    pub background: Option<u32>,
+    pub line_width: Option<u32>,
+    pub line_style: Option<u32>,
+    pub cap_style: Option<u32>,
+    pub join_style: Option<u32>,
+    pub fill_style: Option<u32>,
+    pub fill_rule: Option<u32>,
+    pub tile: Option<Pixmap>,
+    pub stipple: Option<Pixmap>,
+    pub tile_stipple_x_origin: Option<i32>,
+    pub tile_stipple_y_origin: Option<i32>,
+    pub font: Option<Font>,
+    pub subwindow_mode: Option<u32>,
+    pub graphics_exposures: Option<Bool32>,
+    pub clip_x_origin: Option<i32>,
+    pub clip_y_origin: Option<i32>,
+    pub clip_mask: Option<Pixmap>,
+    pub dash_offset: Option<u32>,
+    pub dashes: Option<u32>,
+    pub arc_mode: Option<u32>,
+}
+
+impl ChangeGCValueList {
+    pub fn function(mut self, function: u32) -> Self {
+        self.function = Some(function);
+        self
+    }
+
+    pub fn plane_mask(mut self, plane_mask: u32) -> Self {
+        self.plane_mask = Some(plane_mask);
+        self
+    }
+
+    pub fn foreground(mut self, foreground: u32) -> Self {
+        self.foreground = Some(foreground);
+        self
+    }
+
+    pub fn background(mut self, background: u32) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    pub fn line_width(mut self, line_width: u32) -> Self {
+        self.line_width = Some(line_width);
+        self
+    }
+
+    pub fn line_style(mut self, line_style: u32) -> Self {
+        self.line_style = Some(line_style);
+        self
+    }
+
+    pub fn cap_style(mut self, cap_style: u32) -> Self {
+        self.cap_style = Some(cap_style);
+        self
+    }
+
+    pub fn join_style(mut self, join_style: u32) -> Self {
+        self.join_style = Some(join_style);
+        self
+    }
+
+    pub fn fill_style(mut self, fill_style: u32) -> Self {
+        self.fill_style = Some(fill_style);
+        self
+    }
+
+    pub fn fill_rule(mut self, fill_rule: u32) -> Self {
+        self.fill_rule = Some(fill_rule);
+        self
+    }
+
+    pub fn tile(mut self, tile: Pixmap) -> Self {
+        self.tile = Some(tile);
+        self
+    }
+
+    pub fn stipple(mut self, stipple: Pixmap) -> Self {
+        self.stipple = Some(stipple);
+        self
+    }
+
+    pub fn tile_stipple_x_origin(mut self, tile_stipple_x_origin: i32) -> Self {
+        self.tile_stipple_x_origin = Some(tile_stipple_x_origin);
+        self
+    }
+
+    pub fn tile_stipple_y_origin(mut self, tile_stipple_y_origin: i32) -> Self {
+        self.tile_stipple_y_origin = Some(tile_stipple_y_origin);
+        self
+    }
+
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    pub fn subwindow_mode(mut self, subwindow_mode: u32) -> Self {
+        self.subwindow_mode = Some(subwindow_mode);
+        self
+    }
+
+    pub fn graphics_exposures(mut self, graphics_exposures: Bool32) -> Self {
+        self.graphics_exposures = Some(graphics_exposures);
+        self
+    }
+
+    pub fn clip_x_origin(mut self, clip_x_origin: i32) -> Self {
+        self.clip_x_origin = Some(clip_x_origin);
+        self
+    }
+
+    pub fn clip_y_origin(mut self, clip_y_origin: i32) -> Self {
+        self.clip_y_origin = Some(clip_y_origin);
+        self
+    }
+
+    pub fn clip_mask(mut self, clip_mask: Pixmap) -> Self {
+        self.clip_mask = Some(clip_mask);
+        self
+    }
+
+    pub fn dash_offset(mut self, dash_offset: u32) -> Self {
+        self.dash_offset = Some(dash_offset);
+        self
+    }
+
+    pub fn dashes(mut self, dashes: u32) -> Self {
+        self.dashes = Some(dashes);
+        self
+    }
+
+    pub fn arc_mode(mut self, arc_mode: u32) -> Self {
+        self.arc_mode = Some(arc_mode);
+        self
+    }
+
+    pub(crate) fn serialize(&self) -> (u32, Vec<u32>) {
+        let mut mask = 0;
+        let mut values = vec![];
+
+        if let Some(value) = self.function {
+            mask |= 0x1;
+            values.push(value);
+        }
+
+        if let Some(value) = self.plane_mask {
+            mask |= 0x2;
+            values.push(value);
+        }
+
+        if let Some(value) = self.foreground {
+            mask |= 0x4;
+            values.push(value);
+        }
+
+        if let Some(value) = self.background {
+            mask |= 0x8;
+            values.push(value);
+        }
+
+        if let Some(value) = self.line_width {
+            mask |= 0x10;
+            values.push(value);
+        }
+
+        if let Some(value) = self.line_style {
+            mask |= 0x20;
+            values.push(value);
+        }
+
+        if let Some(value) = self.cap_style {
+            mask |= 0x40;
+            values.push(value);
+        }
+
+        if let Some(value) = self.join_style {
+            mask |= 0x80;
+            values.push(value);
+        }
+
+        if let Some(value) = self.fill_style {
+            mask |= 0x100;
+            values.push(value);
+        }
+
+        if let Some(value) = self.fill_rule {
+            mask |= 0x200;
+            values.push(value);
+        }
+
+        if let Some(value) = self.tile {
+            mask |= 0x400;
+            values.push(value);
+        }
+
+        if let Some(value) = self.stipple {
+            mask |= 0x800;
+            values.push(value);
+        }
+
+        if let Some(value) = self.tile_stipple_x_origin {
+            mask |= 0x1000;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.tile_stipple_y_origin {
+            mask |= 0x2000;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.font {
+            mask |= 0x4000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.subwindow_mode {
+            mask |= 0x8000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.graphics_exposures {
+            mask |= 0x10000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.clip_x_origin {
+            mask |= 0x20000;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.clip_y_origin {
+            mask |= 0x40000;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.clip_mask {
+            mask |= 0x80000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.dash_offset {
+            mask |= 0x100000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.dashes {
+            mask |= 0x200000;
+            values.push(value);
+        }
+
+        if let Some(value) = self.arc_mode {
+            mask |= 0x400000;
+            values.push(value);
+        }
+
+        (mask, values)
+    }
+}
+
+pub fn change_gc<'a>(connection: &'a crate::connection::Connection, gc: Gcontext, value_list: &ChangeGCValueList) -> crate::result::Cookie<'a, crate::result::Void> {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_change_gc_checked(connection.get_connection(), gc, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_gc_unchecked<'a>(connection: &'a crate::connection::Connection, gc: Gcontext, value_list: &ChangeGCValueList) -> crate::result::UncheckedCookie {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_change_gc(connection.get_connection(), gc, value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn copy_gc<'a>(connection: &'a crate::connection::Connection, src_gc: Gcontext, dst_gc: Gcontext, value_mask: u32) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_copy_gc_checked(connection.get_connection(), src_gc, dst_gc, value_mask) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn copy_gc_unchecked<'a>(connection: &'a crate::connection::Connection, src_gc: Gcontext, dst_gc: Gcontext, value_mask: u32) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_copy_gc(connection.get_connection(), src_gc, dst_gc, value_mask) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn set_dashes<'a>(connection: &'a crate::connection::Connection, gc: Gcontext, dash_offset: u16, dashes: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_set_dashes_checked(connection.get_connection(), gc, dash_offset, dashes.len() as _, dashes.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_dashes_unchecked<'a>(connection: &'a crate::connection::Connection, gc: Gcontext, dash_offset: u16, dashes: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_set_dashes(connection.get_connection(), gc, dash_offset, dashes.len() as _, dashes.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ClipOrdering(pub u32);
+
+impl ClipOrdering {
+    pub const UNSORTED: Self = Self(0);
+    pub const Y_SORTED: Self = Self(1);
+    pub const YX_SORTED: Self = Self(2);
+    pub const YX_BANDED: Self = Self(3);
+}
+
+pub fn set_clip_rectangles<'a>(connection: &'a crate::connection::Connection, ordering: u8, gc: Gcontext, clip_x_origin: i16, clip_y_origin: i16, rectangles: &[Rectangle]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_set_clip_rectangles_checked(connection.get_connection(), ordering, gc, clip_x_origin, clip_y_origin, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_clip_rectangles_unchecked<'a>(connection: &'a crate::connection::Connection, ordering: u8, gc: Gcontext, clip_x_origin: i16, clip_y_origin: i16, rectangles: &[Rectangle]) -> crate::result::UncheckedCookie {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_set_clip_rectangles(connection.get_connection(), ordering, gc, clip_x_origin, clip_y_origin, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn free_gc<'a>(connection: &'a crate::connection::Connection, gc: Gcontext) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_free_gc_checked(connection.get_connection(), gc) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn free_gc_unchecked<'a>(connection: &'a crate::connection::Connection, gc: Gcontext) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_free_gc(connection.get_connection(), gc) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn clear_area<'a>(connection: &'a crate::connection::Connection, exposures: bool, window: Window, x: i16, y: i16, width: u16, height: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_clear_area_checked(connection.get_connection(), u8::from(exposures), window, x, y, width, height) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn clear_area_unchecked<'a>(connection: &'a crate::connection::Connection, exposures: bool, window: Window, x: i16, y: i16, width: u16, height: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_clear_area(connection.get_connection(), u8::from(exposures), window, x, y, width, height) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn copy_area<'a>(connection: &'a crate::connection::Connection, src_drawable: Drawable, dst_drawable: Drawable, gc: Gcontext, src_x: i16, src_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_copy_area_checked(connection.get_connection(), src_drawable, dst_drawable, gc, src_x, src_y, dst_x, dst_y, width, height) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn copy_area_unchecked<'a>(connection: &'a crate::connection::Connection, src_drawable: Drawable, dst_drawable: Drawable, gc: Gcontext, src_x: i16, src_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_copy_area(connection.get_connection(), src_drawable, dst_drawable, gc, src_x, src_y, dst_x, dst_y, width, height) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn copy_plane<'a>(connection: &'a crate::connection::Connection, src_drawable: Drawable, dst_drawable: Drawable, gc: Gcontext, src_x: i16, src_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16, bit_plane: u32) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_copy_plane_checked(connection.get_connection(), src_drawable, dst_drawable, gc, src_x, src_y, dst_x, dst_y, width, height, bit_plane) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn copy_plane_unchecked<'a>(connection: &'a crate::connection::Connection, src_drawable: Drawable, dst_drawable: Drawable, gc: Gcontext, src_x: i16, src_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16, bit_plane: u32) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_copy_plane(connection.get_connection(), src_drawable, dst_drawable, gc, src_x, src_y, dst_x, dst_y, width, height, bit_plane) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CoordMode(pub u32);
+
+impl CoordMode {
+    pub const ORIGIN: Self = Self(0);
+    pub const PREVIOUS: Self = Self(1);
+}
+
+pub fn poly_point<'a>(connection: &'a crate::connection::Connection, coordinate_mode: u8, drawable: Drawable, gc: Gcontext, points: &[Point]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let points: Vec<xcb_system::xcb_point_t> = points.iter().map(|&value| xcb_system::xcb_point_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_point_checked(connection.get_connection(), coordinate_mode, drawable, gc, points.len() as u32, points.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn poly_point_unchecked<'a>(connection: &'a crate::connection::Connection, coordinate_mode: u8, drawable: Drawable, gc: Gcontext, points: &[Point]) -> crate::result::UncheckedCookie {
+    let points: Vec<xcb_system::xcb_point_t> = points.iter().map(|&value| xcb_system::xcb_point_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_point(connection.get_connection(), coordinate_mode, drawable, gc, points.len() as u32, points.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn poly_line<'a>(connection: &'a crate::connection::Connection, coordinate_mode: u8, drawable: Drawable, gc: Gcontext, points: &[Point]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let points: Vec<xcb_system::xcb_point_t> = points.iter().map(|&value| xcb_system::xcb_point_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_line_checked(connection.get_connection(), coordinate_mode, drawable, gc, points.len() as u32, points.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn poly_line_unchecked<'a>(connection: &'a crate::connection::Connection, coordinate_mode: u8, drawable: Drawable, gc: Gcontext, points: &[Point]) -> crate::result::UncheckedCookie {
+    let points: Vec<xcb_system::xcb_point_t> = points.iter().map(|&value| xcb_system::xcb_point_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_line(connection.get_connection(), coordinate_mode, drawable, gc, points.len() as u32, points.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Segment {
+    pub x1: i16,
+    pub y1: i16,
+    pub x2: i16,
+    pub y2: i16,
+}
+
+impl From<xcb_system::xcb_segment_t> for Segment {
+    fn from(raw: xcb_system::xcb_segment_t) -> Self {
+        Self {
+            x1: raw.x1,
+            y1: raw.y1,
+            x2: raw.x2,
+            y2: raw.y2,
+        }
+    }
+}
+
+impl From<Segment> for xcb_system::xcb_segment_t {
+    fn from(value: Segment) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.x1 = value.x1;
+        raw.y1 = value.y1;
+        raw.x2 = value.x2;
+        raw.y2 = value.y2;
+
+        raw
+    }
+}
+
+pub fn poly_segment<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, segments: &[Segment]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let segments: Vec<xcb_system::xcb_segment_t> = segments.iter().map(|&value| xcb_system::xcb_segment_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_segment_checked(connection.get_connection(), drawable, gc, segments.len() as u32, segments.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn poly_segment_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, segments: &[Segment]) -> crate::result::UncheckedCookie {
+    let segments: Vec<xcb_system::xcb_segment_t> = segments.iter().map(|&value| xcb_system::xcb_segment_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_segment(connection.get_connection(), drawable, gc, segments.len() as u32, segments.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn poly_rectangle<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, rectangles: &[Rectangle]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_rectangle_checked(connection.get_connection(), drawable, gc, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn poly_rectangle_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, rectangles: &[Rectangle]) -> crate::result::UncheckedCookie {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_rectangle(connection.get_connection(), drawable, gc, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn poly_arc<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, arcs: &[Arc]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let arcs: Vec<xcb_system::xcb_arc_t> = arcs.iter().map(|&value| xcb_system::xcb_arc_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_arc_checked(connection.get_connection(), drawable, gc, arcs.len() as u32, arcs.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn poly_arc_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, arcs: &[Arc]) -> crate::result::UncheckedCookie {
+    let arcs: Vec<xcb_system::xcb_arc_t> = arcs.iter().map(|&value| xcb_system::xcb_arc_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_arc(connection.get_connection(), drawable, gc, arcs.len() as u32, arcs.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PolyShape(pub u32);
+
+impl PolyShape {
+    pub const COMPLEX: Self = Self(0);
+    pub const NONCONVEX: Self = Self(1);
+    pub const CONVEX: Self = Self(2);
+}
+
+pub fn fill_poly<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, shape: u8, coordinate_mode: u8, points: &[Point]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let points: Vec<xcb_system::xcb_point_t> = points.iter().map(|&value| xcb_system::xcb_point_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_fill_poly_checked(connection.get_connection(), drawable, gc, shape, coordinate_mode, points.len() as u32, points.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn fill_poly_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, shape: u8, coordinate_mode: u8, points: &[Point]) -> crate::result::UncheckedCookie {
+    let points: Vec<xcb_system::xcb_point_t> = points.iter().map(|&value| xcb_system::xcb_point_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_fill_poly(connection.get_connection(), drawable, gc, shape, coordinate_mode, points.len() as u32, points.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn poly_fill_rectangle<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, rectangles: &[Rectangle]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_fill_rectangle_checked(connection.get_connection(), drawable, gc, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn poly_fill_rectangle_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, rectangles: &[Rectangle]) -> crate::result::UncheckedCookie {
+    let rectangles: Vec<xcb_system::xcb_rectangle_t> = rectangles.iter().map(|&value| xcb_system::xcb_rectangle_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_fill_rectangle(connection.get_connection(), drawable, gc, rectangles.len() as u32, rectangles.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn poly_fill_arc<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, arcs: &[Arc]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let arcs: Vec<xcb_system::xcb_arc_t> = arcs.iter().map(|&value| xcb_system::xcb_arc_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_fill_arc_checked(connection.get_connection(), drawable, gc, arcs.len() as u32, arcs.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn poly_fill_arc_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, arcs: &[Arc]) -> crate::result::UncheckedCookie {
+    let arcs: Vec<xcb_system::xcb_arc_t> = arcs.iter().map(|&value| xcb_system::xcb_arc_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_poly_fill_arc(connection.get_connection(), drawable, gc, arcs.len() as u32, arcs.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ImageFormat(pub u32);
+
+impl ImageFormat {
+    pub const XY_BITMAP: Self = Self(0);
+    pub const XY_PIXMAP: Self = Self(1);
+    pub const Z_PIXMAP: Self = Self(2);
+}
+
+pub fn put_image<'a>(connection: &'a crate::connection::Connection, format: u8, drawable: Drawable, gc: Gcontext, width: u16, height: u16, dst_x: i16, dst_y: i16, left_pad: u8, depth: u8, data: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_put_image_checked(connection.get_connection(), format, drawable, gc, width, height, dst_x, dst_y, left_pad, depth, data.len() as u32, data.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn put_image_unchecked<'a>(connection: &'a crate::connection::Connection, format: u8, drawable: Drawable, gc: Gcontext, width: u16, height: u16, dst_x: i16, dst_y: i16, left_pad: u8, depth: u8, data: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_put_image(connection.get_connection(), format, drawable, gc, width, height, dst_x, dst_y, left_pad, depth, data.len() as u32, data.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetImageReply {
+    pub depth: u8,
+    pub visual: Visualid,
+    pub data: Vec<u8>,
+}
+
+pub struct GetImageRequest;
+
+impl crate::result::Request for GetImageRequest {
+    type Reply = GetImageReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetImageReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_image_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetImageReply {
+            depth: reply.depth,
+            visual: reply.visual,
+            data: std::slice::from_raw_parts(xcb_system::xcb_get_image_data(reply.as_ptr()) as *const u8, xcb_system::xcb_get_image_data_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_image<'a>(connection: &'a crate::connection::Connection, format: u8, drawable: Drawable, x: i16, y: i16, width: u16, height: u16, plane_mask: u32) -> crate::result::Cookie<'a, GetImageRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_image(connection.get_connection(), format, drawable, x, y, width, height, plane_mask) };
+
+    crate::result::Cookie::new(cookie.sequence, GetImageRequest, connection)
+}
+
+pub fn poly_text_8<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, x: i16, y: i16, items: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_poly_text_8_checked(connection.get_connection(), drawable, gc, x, y, items.len() as u32, items.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn poly_text_8_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, x: i16, y: i16, items: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_poly_text_8(connection.get_connection(), drawable, gc, x, y, items.len() as u32, items.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn poly_text_16<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, x: i16, y: i16, items: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_poly_text_16_checked(connection.get_connection(), drawable, gc, x, y, items.len() as u32, items.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn poly_text_16_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, x: i16, y: i16, items: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_poly_text_16(connection.get_connection(), drawable, gc, x, y, items.len() as u32, items.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn image_text_8<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, x: i16, y: i16, string: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_image_text_8_checked(connection.get_connection(), string.len() as _, drawable, gc, x, y, string.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn image_text_8_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, x: i16, y: i16, string: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_image_text_8(connection.get_connection(), string.len() as _, drawable, gc, x, y, string.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn image_text_16<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, x: i16, y: i16, string: &[Char2b]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let string: Vec<xcb_system::xcb_char2b_t> = string.iter().map(|&value| xcb_system::xcb_char2b_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_image_text_16_checked(connection.get_connection(), string.len() as _, drawable, gc, x, y, string.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn image_text_16_unchecked<'a>(connection: &'a crate::connection::Connection, drawable: Drawable, gc: Gcontext, x: i16, y: i16, string: &[Char2b]) -> crate::result::UncheckedCookie {
+    let string: Vec<xcb_system::xcb_char2b_t> = string.iter().map(|&value| xcb_system::xcb_char2b_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_image_text_16(connection.get_connection(), string.len() as _, drawable, gc, x, y, string.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColormapAlloc(pub u32);
+
+impl ColormapAlloc {
+    pub const NONE: Self = Self(0);
+    pub const ALL: Self = Self(1);
+}
+
+pub fn create_colormap<'a>(connection: &'a crate::connection::Connection, alloc: u8, mid: Colormap, window: Window, visual: Visualid) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_create_colormap_checked(connection.get_connection(), alloc, mid, window, visual) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_colormap_unchecked<'a>(connection: &'a crate::connection::Connection, alloc: u8, mid: Colormap, window: Window, visual: Visualid) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_create_colormap(connection.get_connection(), alloc, mid, window, visual) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn free_colormap<'a>(connection: &'a crate::connection::Connection, cmap: Colormap) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_free_colormap_checked(connection.get_connection(), cmap) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn free_colormap_unchecked<'a>(connection: &'a crate::connection::Connection, cmap: Colormap) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_free_colormap(connection.get_connection(), cmap) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn copy_colormap_and_free<'a>(connection: &'a crate::connection::Connection, mid: Colormap, src_cmap: Colormap) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_copy_colormap_and_free_checked(connection.get_connection(), mid, src_cmap) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn copy_colormap_and_free_unchecked<'a>(connection: &'a crate::connection::Connection, mid: Colormap, src_cmap: Colormap) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_copy_colormap_and_free(connection.get_connection(), mid, src_cmap) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn install_colormap<'a>(connection: &'a crate::connection::Connection, cmap: Colormap) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_install_colormap_checked(connection.get_connection(), cmap) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn install_colormap_unchecked<'a>(connection: &'a crate::connection::Connection, cmap: Colormap) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_install_colormap(connection.get_connection(), cmap) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn uninstall_colormap<'a>(connection: &'a crate::connection::Connection, cmap: Colormap) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_uninstall_colormap_checked(connection.get_connection(), cmap) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn uninstall_colormap_unchecked<'a>(connection: &'a crate::connection::Connection, cmap: Colormap) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_uninstall_colormap(connection.get_connection(), cmap) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListInstalledColormapsReply {
+    pub cmaps_len: u16,
+    pub cmaps: Vec<Colormap>,
+}
+
+pub struct ListInstalledColormapsRequest;
+
+impl crate::result::Request for ListInstalledColormapsRequest {
+    type Reply = ListInstalledColormapsReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<ListInstalledColormapsReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_list_installed_colormaps_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { ListInstalledColormapsReply {
+            cmaps_len: reply.cmaps_len,
+            cmaps: std::slice::from_raw_parts(xcb_system::xcb_list_installed_colormaps_cmaps(reply.as_ptr()) as *const Colormap, xcb_system::xcb_list_installed_colormaps_cmaps_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn list_installed_colormaps<'a>(connection: &'a crate::connection::Connection, window: Window) -> crate::result::Cookie<'a, ListInstalledColormapsRequest> {
+    let cookie = unsafe { xcb_system::xcb_list_installed_colormaps(connection.get_connection(), window) };
+
+    crate::result::Cookie::new(cookie.sequence, ListInstalledColormapsRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllocColorReply {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub pixel: u32,
+}
+
+pub struct AllocColorRequest;
+
+impl crate::result::Request for AllocColorRequest {
+    type Reply = AllocColorReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<AllocColorReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_alloc_color_reply_t>(connection, sequence)?;
+
+        Ok(AllocColorReply {
+            red: reply.red,
+            green: reply.green,
+            blue: reply.blue,
+            pixel: reply.pixel,
+        })
+    }
+}
+
+pub fn alloc_color<'a>(connection: &'a crate::connection::Connection, cmap: Colormap, red: u16, green: u16, blue: u16) -> crate::result::Cookie<'a, AllocColorRequest> {
+    let cookie = unsafe { xcb_system::xcb_alloc_color(connection.get_connection(), cmap, red, green, blue) };
+
+    crate::result::Cookie::new(cookie.sequence, AllocColorRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllocNamedColorReply {
+    pub pixel: u32,
+    pub exact_red: u16,
+    pub exact_green: u16,
+    pub exact_blue: u16,
+    pub visual_red: u16,
+    pub visual_green: u16,
+    pub visual_blue: u16,
+}
+
+pub struct AllocNamedColorRequest;
+
+impl crate::result::Request for AllocNamedColorRequest {
+    type Reply = AllocNamedColorReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<AllocNamedColorReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_alloc_named_color_reply_t>(connection, sequence)?;
+
+        Ok(AllocNamedColorReply {
+            pixel: reply.pixel,
+            exact_red: reply.exact_red,
+            exact_green: reply.exact_green,
+            exact_blue: reply.exact_blue,
+            visual_red: reply.visual_red,
+            visual_green: reply.visual_green,
+            visual_blue: reply.visual_blue,
+        })
+    }
+}
+
+pub fn alloc_named_color<'a>(connection: &'a crate::connection::Connection, cmap: Colormap, name: &[u8]) -> crate::result::Cookie<'a, AllocNamedColorRequest> {
+    let cookie = unsafe { xcb_system::xcb_alloc_named_color(connection.get_connection(), cmap, name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, AllocNamedColorRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllocColorCellsReply {
+    pub pixels_len: u16,
+    pub masks_len: u16,
+    pub pixels: Vec<u32>,
+    pub masks: Vec<u32>,
+}
+
+pub struct AllocColorCellsRequest;
+
+impl crate::result::Request for AllocColorCellsRequest {
+    type Reply = AllocColorCellsReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<AllocColorCellsReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_alloc_color_cells_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { AllocColorCellsReply {
+            pixels_len: reply.pixels_len,
+            masks_len: reply.masks_len,
+            pixels: std::slice::from_raw_parts(xcb_system::xcb_alloc_color_cells_pixels(reply.as_ptr()) as *const u32, xcb_system::xcb_alloc_color_cells_pixels_length(reply.as_ptr()) as usize).to_vec(),
+            masks: std::slice::from_raw_parts(xcb_system::xcb_alloc_color_cells_masks(reply.as_ptr()) as *const u32, xcb_system::xcb_alloc_color_cells_masks_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn alloc_color_cells<'a>(connection: &'a crate::connection::Connection, contiguous: bool, cmap: Colormap, colors: u16, planes: u16) -> crate::result::Cookie<'a, AllocColorCellsRequest> {
+    let cookie = unsafe { xcb_system::xcb_alloc_color_cells(connection.get_connection(), u8::from(contiguous), cmap, colors, planes) };
+
+    crate::result::Cookie::new(cookie.sequence, AllocColorCellsRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllocColorPlanesReply {
+    pub pixels_len: u16,
+    pub red_mask: u32,
+    pub green_mask: u32,
+    pub blue_mask: u32,
+    pub pixels: Vec<u32>,
+}
+
+pub struct AllocColorPlanesRequest;
+
+impl crate::result::Request for AllocColorPlanesRequest {
+    type Reply = AllocColorPlanesReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<AllocColorPlanesReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_alloc_color_planes_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { AllocColorPlanesReply {
+            pixels_len: reply.pixels_len,
+            red_mask: reply.red_mask,
+            green_mask: reply.green_mask,
+            blue_mask: reply.blue_mask,
+            pixels: std::slice::from_raw_parts(xcb_system::xcb_alloc_color_planes_pixels(reply.as_ptr()) as *const u32, xcb_system::xcb_alloc_color_planes_pixels_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn alloc_color_planes<'a>(connection: &'a crate::connection::Connection, contiguous: bool, cmap: Colormap, colors: u16, reds: u16, greens: u16, blues: u16) -> crate::result::Cookie<'a, AllocColorPlanesRequest> {
+    let cookie = unsafe { xcb_system::xcb_alloc_color_planes(connection.get_connection(), u8::from(contiguous), cmap, colors, reds, greens, blues) };
+
+    crate::result::Cookie::new(cookie.sequence, AllocColorPlanesRequest, connection)
+}
+
+pub fn free_colors<'a>(connection: &'a crate::connection::Connection, cmap: Colormap, plane_mask: u32, pixels: &[u32]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_free_colors_checked(connection.get_connection(), cmap, plane_mask, pixels.len() as u32, pixels.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn free_colors_unchecked<'a>(connection: &'a crate::connection::Connection, cmap: Colormap, plane_mask: u32, pixels: &[u32]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_free_colors(connection.get_connection(), cmap, plane_mask, pixels.len() as u32, pixels.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+bitflags! {
+    pub struct ColorFlag: u32 {
+        const RED = 0x1;
+        const GREEN = 0x2;
+        const BLUE = 0x4;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Coloritem {
+    pub pixel: u32,
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub flags: u8,
+}
+
+impl From<xcb_system::xcb_coloritem_t> for Coloritem {
+    fn from(raw: xcb_system::xcb_coloritem_t) -> Self {
+        Self {
+            pixel: raw.pixel,
+            red: raw.red,
+            green: raw.green,
+            blue: raw.blue,
+            flags: raw.flags,
+        }
+    }
+}
+
+impl From<Coloritem> for xcb_system::xcb_coloritem_t {
+    fn from(value: Coloritem) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.pixel = value.pixel;
+        raw.red = value.red;
+        raw.green = value.green;
+        raw.blue = value.blue;
+        raw.flags = value.flags;
+
+        raw
+    }
+}
+
+pub fn store_colors<'a>(connection: &'a crate::connection::Connection, cmap: Colormap, items: &[Coloritem]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let items: Vec<xcb_system::xcb_coloritem_t> = items.iter().map(|&value| xcb_system::xcb_coloritem_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_store_colors_checked(connection.get_connection(), cmap, items.len() as u32, items.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn store_colors_unchecked<'a>(connection: &'a crate::connection::Connection, cmap: Colormap, items: &[Coloritem]) -> crate::result::UncheckedCookie {
+    let items: Vec<xcb_system::xcb_coloritem_t> = items.iter().map(|&value| xcb_system::xcb_coloritem_t::from(value)).collect();
+
+    let cookie = unsafe { xcb_system::xcb_store_colors(connection.get_connection(), cmap, items.len() as u32, items.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn store_named_color<'a>(connection: &'a crate::connection::Connection, flags: u8, cmap: Colormap, pixel: u32, name: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_store_named_color_checked(connection.get_connection(), flags, cmap, pixel, name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn store_named_color_unchecked<'a>(connection: &'a crate::connection::Connection, flags: u8, cmap: Colormap, pixel: u32, name: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_store_named_color(connection.get_connection(), flags, cmap, pixel, name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rgb {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+}
+
+impl From<xcb_system::xcb_rgb_t> for Rgb {
+    fn from(raw: xcb_system::xcb_rgb_t) -> Self {
+        Self {
+            red: raw.red,
+            green: raw.green,
+            blue: raw.blue,
+        }
+    }
+}
+
+impl From<Rgb> for xcb_system::xcb_rgb_t {
+    fn from(value: Rgb) -> Self {
+        let mut raw: Self = unsafe { std::mem::zeroed() };
+
+        raw.red = value.red;
+        raw.green = value.green;
+        raw.blue = value.blue;
+
+        raw
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryColorsReply {
+    pub colors_len: u16,
+    pub colors: Vec<Rgb>,
+}
+
+pub struct QueryColorsRequest;
+
+impl crate::result::Request for QueryColorsRequest {
+    type Reply = QueryColorsReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryColorsReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_query_colors_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { QueryColorsReply {
+            colors_len: reply.colors_len,
+            colors: std::slice::from_raw_parts(xcb_system::xcb_query_colors_colors(reply.as_ptr()) as *const xcb_system::xcb_rgb_t, xcb_system::xcb_query_colors_colors_length(reply.as_ptr()) as usize).iter().map(|&value| Rgb::from(value)).collect(),
+        } })
+    }
+}
+
+pub fn query_colors<'a>(connection: &'a crate::connection::Connection, cmap: Colormap, pixels: &[u32]) -> crate::result::Cookie<'a, QueryColorsRequest> {
+    let cookie = unsafe { xcb_system::xcb_query_colors(connection.get_connection(), cmap, pixels.len() as u32, pixels.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryColorsRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LookupColorReply {
+    pub exact_red: u16,
+    pub exact_green: u16,
+    pub exact_blue: u16,
+    pub visual_red: u16,
+    pub visual_green: u16,
+    pub visual_blue: u16,
+}
+
+pub struct LookupColorRequest;
+
+impl crate::result::Request for LookupColorRequest {
+    type Reply = LookupColorReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<LookupColorReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_lookup_color_reply_t>(connection, sequence)?;
+
+        Ok(LookupColorReply {
+            exact_red: reply.exact_red,
+            exact_green: reply.exact_green,
+            exact_blue: reply.exact_blue,
+            visual_red: reply.visual_red,
+            visual_green: reply.visual_green,
+            visual_blue: reply.visual_blue,
+        })
+    }
+}
+
+pub fn lookup_color<'a>(connection: &'a crate::connection::Connection, cmap: Colormap, name: &[u8]) -> crate::result::Cookie<'a, LookupColorRequest> {
+    let cookie = unsafe { xcb_system::xcb_lookup_color(connection.get_connection(), cmap, name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, LookupColorRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PixmapEnum(pub u32);
+
+impl PixmapEnum {
+    pub const NONE: Self = Self(0);
+}
+
+pub fn create_cursor<'a>(connection: &'a crate::connection::Connection, cid: Cursor, source: Pixmap, mask: Pixmap, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16, x: u16, y: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_create_cursor_checked(connection.get_connection(), cid, source, mask, fore_red, fore_green, fore_blue, back_red, back_green, back_blue, x, y) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_cursor_unchecked<'a>(connection: &'a crate::connection::Connection, cid: Cursor, source: Pixmap, mask: Pixmap, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16, x: u16, y: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_create_cursor(connection.get_connection(), cid, source, mask, fore_red, fore_green, fore_blue, back_red, back_green, back_blue, x, y) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FontEnum(pub u32);
+
+impl FontEnum {
+    pub const NONE: Self = Self(0);
+}
+
+pub fn create_glyph_cursor<'a>(connection: &'a crate::connection::Connection, cid: Cursor, source_font: Font, mask_font: Font, source_char: u16, mask_char: u16, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_create_glyph_cursor_checked(connection.get_connection(), cid, source_font, mask_font, source_char, mask_char, fore_red, fore_green, fore_blue, back_red, back_green, back_blue) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn create_glyph_cursor_unchecked<'a>(connection: &'a crate::connection::Connection, cid: Cursor, source_font: Font, mask_font: Font, source_char: u16, mask_char: u16, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_create_glyph_cursor(connection.get_connection(), cid, source_font, mask_font, source_char, mask_char, fore_red, fore_green, fore_blue, back_red, back_green, back_blue) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn free_cursor<'a>(connection: &'a crate::connection::Connection, cursor: Cursor) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_free_cursor_checked(connection.get_connection(), cursor) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn free_cursor_unchecked<'a>(connection: &'a crate::connection::Connection, cursor: Cursor) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_free_cursor(connection.get_connection(), cursor) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn recolor_cursor<'a>(connection: &'a crate::connection::Connection, cursor: Cursor, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_recolor_cursor_checked(connection.get_connection(), cursor, fore_red, fore_green, fore_blue, back_red, back_green, back_blue) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn recolor_cursor_unchecked<'a>(connection: &'a crate::connection::Connection, cursor: Cursor, fore_red: u16, fore_green: u16, fore_blue: u16, back_red: u16, back_green: u16, back_blue: u16) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_recolor_cursor(connection.get_connection(), cursor, fore_red, fore_green, fore_blue, back_red, back_green, back_blue) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct QueryShapeOf(pub u32);
+
+impl QueryShapeOf {
+    pub const LARGEST_CURSOR: Self = Self(0);
+    pub const FASTEST_TILE: Self = Self(1);
+    pub const FASTEST_STIPPLE: Self = Self(2);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryBestSizeReply {
+    pub width: u16,
+    pub height: u16,
+}
+
+pub struct QueryBestSizeRequest;
+
+impl crate::result::Request for QueryBestSizeRequest {
+    type Reply = QueryBestSizeReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryBestSizeReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_query_best_size_reply_t>(connection, sequence)?;
+
+        Ok(QueryBestSizeReply {
+            width: reply.width,
+            height: reply.height,
+        })
+    }
+}
+
+pub fn query_best_size<'a>(connection: &'a crate::connection::Connection, class: u8, drawable: Drawable, width: u16, height: u16) -> crate::result::Cookie<'a, QueryBestSizeRequest> {
+    let cookie = unsafe { xcb_system::xcb_query_best_size(connection.get_connection(), class, drawable, width, height) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryBestSizeRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryExtensionReply {
+    pub present: bool,
+    pub major_opcode: u8,
+    pub first_event: u8,
+    pub first_error: u8,
+}
+
+pub struct QueryExtensionRequest;
+
+impl crate::result::Request for QueryExtensionRequest {
+    type Reply = QueryExtensionReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<QueryExtensionReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_query_extension_reply_t>(connection, sequence)?;
+
+        Ok(QueryExtensionReply {
+            present: reply.present != 0,
+            major_opcode: reply.major_opcode,
+            first_event: reply.first_event,
+            first_error: reply.first_error,
+        })
+    }
+}
+
+pub fn query_extension<'a>(connection: &'a crate::connection::Connection, name: &[u8]) -> crate::result::Cookie<'a, QueryExtensionRequest> {
+    let cookie = unsafe { xcb_system::xcb_query_extension(connection.get_connection(), name.len() as _, name.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, QueryExtensionRequest, connection)
+}
+
+// ListExtensions isn't generated, STR has a variable size.
+
+pub fn change_keyboard_mapping<'a>(connection: &'a crate::connection::Connection, keycode_count: u8, first_keycode: Keycode, keysyms_per_keycode: u8, keysyms: &[Keysym]) -> crate::result::Cookie<'a, crate::result::Void> {
+    assert!(keysyms.len() >= ((keycode_count as usize) * (keysyms_per_keycode as usize)) as usize);
+
+    let cookie = unsafe { xcb_system::xcb_change_keyboard_mapping_checked(connection.get_connection(), keycode_count, first_keycode, keysyms_per_keycode, keysyms.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_keyboard_mapping_unchecked<'a>(connection: &'a crate::connection::Connection, keycode_count: u8, first_keycode: Keycode, keysyms_per_keycode: u8, keysyms: &[Keysym]) -> crate::result::UncheckedCookie {
+    assert!(keysyms.len() >= ((keycode_count as usize) * (keysyms_per_keycode as usize)) as usize);
+
+    let cookie = unsafe { xcb_system::xcb_change_keyboard_mapping(connection.get_connection(), keycode_count, first_keycode, keysyms_per_keycode, keysyms.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetKeyboardMappingReply {
+    pub keysyms_per_keycode: u8,
+    pub keysyms: Vec<Keysym>,
+}
+
+pub struct GetKeyboardMappingRequest;
+
+impl crate::result::Request for GetKeyboardMappingRequest {
+    type Reply = GetKeyboardMappingReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetKeyboardMappingReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_keyboard_mapping_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetKeyboardMappingReply {
+            keysyms_per_keycode: reply.keysyms_per_keycode,
+            keysyms: std::slice::from_raw_parts(xcb_system::xcb_get_keyboard_mapping_keysyms(reply.as_ptr()) as *const Keysym, xcb_system::xcb_get_keyboard_mapping_keysyms_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_keyboard_mapping<'a>(connection: &'a crate::connection::Connection, first_keycode: Keycode, count: u8) -> crate::result::Cookie<'a, GetKeyboardMappingRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_keyboard_mapping(connection.get_connection(), first_keycode, count) };
+
+    crate::result::Cookie::new(cookie.sequence, GetKeyboardMappingRequest, connection)
+}
+
+bitflags! {
+    pub struct Kb: u32 {
+        const KEY_CLICK_PERCENT = 0x1;
+        const BELL_PERCENT = 0x2;
+        const BELL_PITCH = 0x4;
+        const BELL_DURATION = 0x8;
+        const LED = 0x10;
+        const LED_MODE = 0x20;
+        const KEY = 0x40;
+        const AUTO_REPEAT_MODE = 0x80;
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LedMode(pub u32);
+
+impl LedMode {
+    pub const OFF: Self = Self(0);
+    pub const ON: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AutoRepeatMode(pub u32);
+
+impl AutoRepeatMode {
+    pub const OFF: Self = Self(0);
+    pub const ON: Self = Self(1);
+    pub const DEFAULT: Self = Self(2);
+}
+
+/// The values of `change_keyboard_control`, every one that is set adds its bit to `value_mask`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ChangeKeyboardControlValueList {
+    pub key_click_percent: Option<i32>,
+    pub bell_percent: Option<i32>,
+    pub bell_pitch: Option<i32>,
+    pub bell_duration: Option<i32>,
+    pub led: Option<u32>,
+    pub led_mode: Option<u32>,
+    pub key: Option<Keycode32>,
+    pub auto_repeat_mode: Option<u32>,
+}
+
+impl ChangeKeyboardControlValueList {
+    pub fn key_click_percent(mut self, key_click_percent: i32) -> Self {
+        self.key_click_percent = Some(key_click_percent);
+        self
+    }
+
+    pub fn bell_percent(mut self, bell_percent: i32) -> Self {
+        self.bell_percent = Some(bell_percent);
+        self
+    }
+
+    pub fn bell_pitch(mut self, bell_pitch: i32) -> Self {
+        self.bell_pitch = Some(bell_pitch);
+        self
+    }
+
+    pub fn bell_duration(mut self, bell_duration: i32) -> Self {
+        self.bell_duration = Some(bell_duration);
+        self
+    }
+
+    pub fn led(mut self, led: u32) -> Self {
+        self.led = Some(led);
+        self
+    }
+
+    pub fn led_mode(mut self, led_mode: u32) -> Self {
+        self.led_mode = Some(led_mode);
+        self
+    }
+
+    pub fn key(mut self, key: Keycode32) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    pub fn auto_repeat_mode(mut self, auto_repeat_mode: u32) -> Self {
+        self.auto_repeat_mode = Some(auto_repeat_mode);
+        self
+    }
+
+    pub(crate) fn serialize(&self) -> (u32, Vec<u32>) {
+        let mut mask = 0;
+        let mut values = vec![];
+
+        if let Some(value) = self.key_click_percent {
+            mask |= 0x1;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.bell_percent {
+            mask |= 0x2;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.bell_pitch {
+            mask |= 0x4;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.bell_duration {
+            mask |= 0x8;
+            values.push(value as u32);
+        }
+
+        if let Some(value) = self.led {
+            mask |= 0x10;
+            values.push(value);
+        }
+
+        if let Some(value) = self.led_mode {
+            mask |= 0x20;
+            values.push(value);
+        }
+
+        if let Some(value) = self.key {
+            mask |= 0x40;
+            values.push(value);
+        }
+
+        if let Some(value) = self.auto_repeat_mode {
+            mask |= 0x80;
+            values.push(value);
+        }
+
+        (mask, values)
+    }
+}
+
+pub fn change_keyboard_control<'a>(connection: &'a crate::connection::Connection, value_list: &ChangeKeyboardControlValueList) -> crate::result::Cookie<'a, crate::result::Void> {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_change_keyboard_control_checked(connection.get_connection(), value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_keyboard_control_unchecked<'a>(connection: &'a crate::connection::Connection, value_list: &ChangeKeyboardControlValueList) -> crate::result::UncheckedCookie {
+    let (value_mask, value_list) = value_list.serialize();
+
+    let cookie = unsafe { xcb_system::xcb_change_keyboard_control(connection.get_connection(), value_mask, value_list.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetKeyboardControlReply {
+    pub global_auto_repeat: u8,
+    pub led_mask: u32,
+    pub key_click_percent: u8,
+    pub bell_percent: u8,
+    pub bell_pitch: u16,
+    pub bell_duration: u16,
+    pub auto_repeats: [u8; 32],
+}
+
+pub struct GetKeyboardControlRequest;
+
+impl crate::result::Request for GetKeyboardControlRequest {
+    type Reply = GetKeyboardControlReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetKeyboardControlReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_keyboard_control_reply_t>(connection, sequence)?;
+
+        Ok(GetKeyboardControlReply {
+            global_auto_repeat: reply.global_auto_repeat,
+            led_mask: reply.led_mask,
+            key_click_percent: reply.key_click_percent,
+            bell_percent: reply.bell_percent,
+            bell_pitch: reply.bell_pitch,
+            bell_duration: reply.bell_duration,
+            auto_repeats: reply.auto_repeats,
+        })
+    }
+}
+
+pub fn get_keyboard_control<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, GetKeyboardControlRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_keyboard_control(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, GetKeyboardControlRequest, connection)
+}
+
+pub fn bell<'a>(connection: &'a crate::connection::Connection, percent: i8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_bell_checked(connection.get_connection(), percent) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn bell_unchecked<'a>(connection: &'a crate::connection::Connection, percent: i8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_bell(connection.get_connection(), percent) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn change_pointer_control<'a>(connection: &'a crate::connection::Connection, acceleration_numerator: i16, acceleration_denominator: i16, threshold: i16, do_acceleration: bool, do_threshold: bool) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_change_pointer_control_checked(connection.get_connection(), acceleration_numerator, acceleration_denominator, threshold, u8::from(do_acceleration), u8::from(do_threshold)) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_pointer_control_unchecked<'a>(connection: &'a crate::connection::Connection, acceleration_numerator: i16, acceleration_denominator: i16, threshold: i16, do_acceleration: bool, do_threshold: bool) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_change_pointer_control(connection.get_connection(), acceleration_numerator, acceleration_denominator, threshold, u8::from(do_acceleration), u8::from(do_threshold)) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetPointerControlReply {
+    pub acceleration_numerator: u16,
+    pub acceleration_denominator: u16,
+    pub threshold: u16,
+}
+
+pub struct GetPointerControlRequest;
+
+impl crate::result::Request for GetPointerControlRequest {
+    type Reply = GetPointerControlReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetPointerControlReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_pointer_control_reply_t>(connection, sequence)?;
+
+        Ok(GetPointerControlReply {
+            acceleration_numerator: reply.acceleration_numerator,
+            acceleration_denominator: reply.acceleration_denominator,
+            threshold: reply.threshold,
+        })
+    }
+}
+
+pub fn get_pointer_control<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, GetPointerControlRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_pointer_control(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, GetPointerControlRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Blanking(pub u32);
+
+impl Blanking {
+    pub const NOT_PREFERRED: Self = Self(0);
+    pub const PREFERRED: Self = Self(1);
+    pub const DEFAULT: Self = Self(2);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Exposures(pub u32);
+
+impl Exposures {
+    pub const NOT_ALLOWED: Self = Self(0);
+    pub const ALLOWED: Self = Self(1);
+    pub const DEFAULT: Self = Self(2);
+}
+
+pub fn set_screen_saver<'a>(connection: &'a crate::connection::Connection, timeout: i16, interval: i16, prefer_blanking: u8, allow_exposures: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_set_screen_saver_checked(connection.get_connection(), timeout, interval, prefer_blanking, allow_exposures) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_screen_saver_unchecked<'a>(connection: &'a crate::connection::Connection, timeout: i16, interval: i16, prefer_blanking: u8, allow_exposures: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_set_screen_saver(connection.get_connection(), timeout, interval, prefer_blanking, allow_exposures) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetScreenSaverReply {
+    pub timeout: u16,
+    pub interval: u16,
+    pub prefer_blanking: u8,
+    pub allow_exposures: u8,
+}
+
+pub struct GetScreenSaverRequest;
+
+impl crate::result::Request for GetScreenSaverRequest {
+    type Reply = GetScreenSaverReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetScreenSaverReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_screen_saver_reply_t>(connection, sequence)?;
+
+        Ok(GetScreenSaverReply {
+            timeout: reply.timeout,
+            interval: reply.interval,
+            prefer_blanking: reply.prefer_blanking,
+            allow_exposures: reply.allow_exposures,
+        })
+    }
+}
+
+pub fn get_screen_saver<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, GetScreenSaverRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_screen_saver(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, GetScreenSaverRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HostMode(pub u32);
+
+impl HostMode {
+    pub const INSERT: Self = Self(0);
+    pub const DELETE: Self = Self(1);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Family(pub u32);
+
+impl Family {
+    pub const INTERNET: Self = Self(0);
+    pub const DE_CNET: Self = Self(1);
+    pub const CHAOS: Self = Self(2);
+    pub const SERVER_INTERPRETED: Self = Self(5);
+    pub const INTERNET_6: Self = Self(6);
+}
+
+pub fn change_hosts<'a>(connection: &'a crate::connection::Connection, mode: u8, family: u8, address: &[u8]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_change_hosts_checked(connection.get_connection(), mode, family, address.len() as _, address.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn change_hosts_unchecked<'a>(connection: &'a crate::connection::Connection, mode: u8, family: u8, address: &[u8]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_change_hosts(connection.get_connection(), mode, family, address.len() as _, address.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+// HOST isn't generated, address has a variable length.
+
+// ListHosts isn't generated, HOST has a variable size.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AccessControl(pub u32);
+
+impl AccessControl {
+    pub const DISABLE: Self = Self(0);
+    pub const ENABLE: Self = Self(1);
+}
+
+pub fn set_access_control<'a>(connection: &'a crate::connection::Connection, mode: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_set_access_control_checked(connection.get_connection(), mode) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_access_control_unchecked<'a>(connection: &'a crate::connection::Connection, mode: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_set_access_control(connection.get_connection(), mode) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CloseDown(pub u32);
+
+impl CloseDown {
+    pub const DESTROY_ALL: Self = Self(0);
+    pub const RETAIN_PERMANENT: Self = Self(1);
+    pub const RETAIN_TEMPORARY: Self = Self(2);
+}
+
+pub fn set_close_down_mode<'a>(connection: &'a crate::connection::Connection, mode: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_set_close_down_mode_checked(connection.get_connection(), mode) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn set_close_down_mode_unchecked<'a>(connection: &'a crate::connection::Connection, mode: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_set_close_down_mode(connection.get_connection(), mode) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Kill(pub u32);
+
+impl Kill {
+    pub const ALL_TEMPORARY: Self = Self(0);
+}
+
+pub fn kill_client<'a>(connection: &'a crate::connection::Connection, resource: u32) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_kill_client_checked(connection.get_connection(), resource) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn kill_client_unchecked<'a>(connection: &'a crate::connection::Connection, resource: u32) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_kill_client(connection.get_connection(), resource) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+pub fn rotate_properties<'a>(connection: &'a crate::connection::Connection, window: Window, delta: i16, atoms: &[Atom]) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_rotate_properties_checked(connection.get_connection(), window, atoms.len() as _, delta, atoms.as_ptr() as *const _) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn rotate_properties_unchecked<'a>(connection: &'a crate::connection::Connection, window: Window, delta: i16, atoms: &[Atom]) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_rotate_properties(connection.get_connection(), window, atoms.len() as _, delta, atoms.as_ptr() as *const _) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ScreenSaver(pub u32);
+
+impl ScreenSaver {
+    pub const RESET: Self = Self(0);
+    pub const ACTIVE: Self = Self(1);
+}
+
+pub fn force_screen_saver<'a>(connection: &'a crate::connection::Connection, mode: u8) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_force_screen_saver_checked(connection.get_connection(), mode) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn force_screen_saver_unchecked<'a>(connection: &'a crate::connection::Connection, mode: u8) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_force_screen_saver(connection.get_connection(), mode) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MappingStatus(pub u32);
+
+impl MappingStatus {
+    pub const SUCCESS: Self = Self(0);
+    pub const BUSY: Self = Self(1);
+    pub const FAILURE: Self = Self(2);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetPointerMappingReply {
+    pub status: u8,
+}
+
+pub struct SetPointerMappingRequest;
+
+impl crate::result::Request for SetPointerMappingRequest {
+    type Reply = SetPointerMappingReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<SetPointerMappingReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_set_pointer_mapping_reply_t>(connection, sequence)?;
+
+        Ok(SetPointerMappingReply {
+            status: reply.status,
+        })
+    }
+}
+
+pub fn set_pointer_mapping<'a>(connection: &'a crate::connection::Connection, map: &[u8]) -> crate::result::Cookie<'a, SetPointerMappingRequest> {
+    let cookie = unsafe { xcb_system::xcb_set_pointer_mapping(connection.get_connection(), map.len() as _, map.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, SetPointerMappingRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetPointerMappingReply {
+    pub map_len: u8,
+    pub map: Vec<u8>,
+}
+
+pub struct GetPointerMappingRequest;
+
+impl crate::result::Request for GetPointerMappingRequest {
+    type Reply = GetPointerMappingReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetPointerMappingReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_pointer_mapping_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetPointerMappingReply {
+            map_len: reply.map_len,
+            map: std::slice::from_raw_parts(xcb_system::xcb_get_pointer_mapping_map(reply.as_ptr()) as *const u8, xcb_system::xcb_get_pointer_mapping_map_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_pointer_mapping<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, GetPointerMappingRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_pointer_mapping(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, GetPointerMappingRequest, connection)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MapIndex(pub u32);
+
+impl MapIndex {
+    pub const SHIFT: Self = Self(0);
+    pub const LOCK: Self = Self(1);
+    pub const CONTROL: Self = Self(2);
+    pub const MAP_1: Self = Self(3);
+    pub const MAP_2: Self = Self(4);
+    pub const MAP_3: Self = Self(5);
+    pub const MAP_4: Self = Self(6);
+    pub const MAP_5: Self = Self(7);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetModifierMappingReply {
+    pub status: u8,
+}
+
+pub struct SetModifierMappingRequest;
+
+impl crate::result::Request for SetModifierMappingRequest {
+    type Reply = SetModifierMappingReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<SetModifierMappingReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_set_modifier_mapping_reply_t>(connection, sequence)?;
+
+        Ok(SetModifierMappingReply {
+            status: reply.status,
+        })
+    }
+}
+
+pub fn set_modifier_mapping<'a>(connection: &'a crate::connection::Connection, keycodes_per_modifier: u8, keycodes: &[Keycode]) -> crate::result::Cookie<'a, SetModifierMappingRequest> {
+    assert!(keycodes.len() >= ((keycodes_per_modifier as usize) * 8) as usize);
+
+    let cookie = unsafe { xcb_system::xcb_set_modifier_mapping(connection.get_connection(), keycodes_per_modifier, keycodes.as_ptr() as *const _) };
+
+    crate::result::Cookie::new(cookie.sequence, SetModifierMappingRequest, connection)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetModifierMappingReply {
+    pub keycodes_per_modifier: u8,
+    pub keycodes: Vec<Keycode>,
+}
+
+pub struct GetModifierMappingRequest;
+
+impl crate::result::Request for GetModifierMappingRequest {
+    type Reply = GetModifierMappingReply;
+
+    fn wait(
+        self,
+        connection: &crate::connection::Connection,
+        sequence: crate::result::Sequence,
+    ) -> Result<GetModifierMappingReply, crate::result::Error> {
+        let reply = crate::result::wait_for_reply::<xcb_system::xcb_get_modifier_mapping_reply_t>(connection, sequence)?;
+
+        Ok(unsafe { GetModifierMappingReply {
+            keycodes_per_modifier: reply.keycodes_per_modifier,
+            keycodes: std::slice::from_raw_parts(xcb_system::xcb_get_modifier_mapping_keycodes(reply.as_ptr()) as *const Keycode, xcb_system::xcb_get_modifier_mapping_keycodes_length(reply.as_ptr()) as usize).to_vec(),
+        } })
+    }
+}
+
+pub fn get_modifier_mapping<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, GetModifierMappingRequest> {
+    let cookie = unsafe { xcb_system::xcb_get_modifier_mapping(connection.get_connection()) };
+
+    crate::result::Cookie::new(cookie.sequence, GetModifierMappingRequest, connection)
+}
+
+pub fn no_operation<'a>(connection: &'a crate::connection::Connection) -> crate::result::Cookie<'a, crate::result::Void> {
+    let cookie = unsafe { xcb_system::xcb_no_operation_checked(connection.get_connection()) };
+
+    crate::result::Cookie::new_void(cookie, connection)
+}
+
+pub fn no_operation_unchecked<'a>(connection: &'a crate::connection::Connection) -> crate::result::UncheckedCookie {
+    let cookie = unsafe { xcb_system::xcb_no_operation(connection.get_connection()) };
+
+    crate::result::UncheckedCookie::new(cookie)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    KeyPress(KeyPressEvent),
+    KeyRelease(KeyReleaseEvent),
+    ButtonPress(ButtonPressEvent),
+    ButtonRelease(ButtonReleaseEvent),
+    MotionNotify(MotionNotifyEvent),
+    EnterNotify(EnterNotifyEvent),
+    LeaveNotify(LeaveNotifyEvent),
+    FocusIn(FocusInEvent),
+    FocusOut(FocusOutEvent),
+    KeymapNotify(KeymapNotifyEvent),
+    Expose(ExposeEvent),
+    GraphicsExposure(GraphicsExposureEvent),
+    NoExposure(NoExposureEvent),
+    VisibilityNotify(VisibilityNotifyEvent),
+    CreateNotify(CreateNotifyEvent),
+    DestroyNotify(DestroyNotifyEvent),
+    UnmapNotify(UnmapNotifyEvent),
+    MapNotify(MapNotifyEvent),
+    MapRequest(MapRequestEvent),
+    ReparentNotify(ReparentNotifyEvent),
+    ConfigureNotify(ConfigureNotifyEvent),
+    ConfigureRequest(ConfigureRequestEvent),
+    GravityNotify(GravityNotifyEvent),
+    ResizeRequest(ResizeRequestEvent),
+    CirculateNotify(CirculateNotifyEvent),
+    CirculateRequest(CirculateRequestEvent),
+    PropertyNotify(PropertyNotifyEvent),
+    SelectionClear(SelectionClearEvent),
+    SelectionRequest(SelectionRequestEvent),
+    SelectionNotify(SelectionNotifyEvent),
+    ColormapNotify(ColormapNotifyEvent),
+    ClientMessage(ClientMessageEvent),
+    MappingNotify(MappingNotifyEvent),
+}
+
+pub fn decode_event(event: &xcb_system::xcb_generic_event_t) -> std::option::Option<Event> {
+    let raw = event as *const xcb_system::xcb_generic_event_t;
+
+    match event.response_type & 0x7f {
+        2 => Some(Event::KeyPress(
+            unsafe { *(raw as *const xcb_system::xcb_key_press_event_t) }.into(),
+        )),
+        3 => Some(Event::KeyRelease(
+            unsafe { *(raw as *const xcb_system::xcb_key_release_event_t) }.into(),
+        )),
+        4 => Some(Event::ButtonPress(
+            unsafe { *(raw as *const xcb_system::xcb_button_press_event_t) }.into(),
+        )),
+        5 => Some(Event::ButtonRelease(
+            unsafe { *(raw as *const xcb_system::xcb_button_release_event_t) }.into(),
+        )),
+        6 => Some(Event::MotionNotify(
+            unsafe { *(raw as *const xcb_system::xcb_motion_notify_event_t) }.into(),
+        )),
+        7 => Some(Event::EnterNotify(
+            unsafe { *(raw as *const xcb_system::xcb_enter_notify_event_t) }.into(),
+        )),
+        8 => Some(Event::LeaveNotify(
+            unsafe { *(raw as *const xcb_system::xcb_leave_notify_event_t) }.into(),
+        )),
+        9 => Some(Event::FocusIn(
+            unsafe { *(raw as *const xcb_system::xcb_focus_in_event_t) }.into(),
+        )),
+        10 => Some(Event::FocusOut(
+            unsafe { *(raw as *const xcb_system::xcb_focus_out_event_t) }.into(),
+        )),
+        11 => Some(Event::KeymapNotify(
+            unsafe { *(raw as *const xcb_system::xcb_keymap_notify_event_t) }.into(),
+        )),
+        12 => Some(Event::Expose(
+            unsafe { *(raw as *const xcb_system::xcb_expose_event_t) }.into(),
+        )),
+        13 => Some(Event::GraphicsExposure(
+            unsafe { *(raw as *const xcb_system::xcb_graphics_exposure_event_t) }.into(),
+        )),
+        14 => Some(Event::NoExposure(
+            unsafe { *(raw as *const xcb_system::xcb_no_exposure_event_t) }.into(),
+        )),
+        15 => Some(Event::VisibilityNotify(
+            unsafe { *(raw as *const xcb_system::xcb_visibility_notify_event_t) }.into(),
+        )),
+        16 => Some(Event::CreateNotify(
+            unsafe { *(raw as *const xcb_system::xcb_create_notify_event_t) }.into(),
+        )),
+        17 => Some(Event::DestroyNotify(
+            unsafe { *(raw as *const xcb_system::xcb_destroy_notify_event_t) }.into(),
+        )),
+        18 => Some(Event::UnmapNotify(
+            unsafe { *(raw as *const xcb_system::xcb_unmap_notify_event_t) }.into(),
+        )),
+        19 => Some(Event::MapNotify(
+            unsafe { *(raw as *const xcb_system::xcb_map_notify_event_t) }.into(),
+        )),
+        20 => Some(Event::MapRequest(
+            unsafe { *(raw as *const xcb_system::xcb_map_request_event_t) }.into(),
+        )),
+        21 => Some(Event::ReparentNotify(
+            unsafe { *(raw as *const xcb_system::xcb_reparent_notify_event_t) }.into(),
+        )),
+        22 => Some(Event::ConfigureNotify(
+            unsafe { *(raw as *const xcb_system::xcb_configure_notify_event_t) }.into(),
+        )),
+        23 => Some(Event::ConfigureRequest(
+            unsafe { *(raw as *const xcb_system::xcb_configure_request_event_t) }.into(),
+        )),
+        24 => Some(Event::GravityNotify(
+            unsafe { *(raw as *const xcb_system::xcb_gravity_notify_event_t) }.into(),
+        )),
+        25 => Some(Event::ResizeRequest(
+            unsafe { *(raw as *const xcb_system::xcb_resize_request_event_t) }.into(),
+        )),
+        26 => Some(Event::CirculateNotify(
+            unsafe { *(raw as *const xcb_system::xcb_circulate_notify_event_t) }.into(),
+        )),
+        27 => Some(Event::CirculateRequest(
+            unsafe { *(raw as *const xcb_system::xcb_circulate_request_event_t) }.into(),
+        )),
+        28 => Some(Event::PropertyNotify(
+            unsafe { *(raw as *const xcb_system::xcb_property_notify_event_t) }.into(),
+        )),
+        29 => Some(Event::SelectionClear(
+            unsafe { *(raw as *const xcb_system::xcb_selection_clear_event_t) }.into(),
+        )),
+        30 => Some(Event::SelectionRequest(
+            unsafe { *(raw as *const xcb_system::xcb_selection_request_event_t) }.into(),
+        )),
+        31 => Some(Event::SelectionNotify(
+            unsafe { *(raw as *const xcb_system::xcb_selection_notify_event_t) }.into(),
+        )),
+        32 => Some(Event::ColormapNotify(
+            unsafe { *(raw as *const xcb_system::xcb_colormap_notify_event_t) }.into(),
+        )),
+        33 => Some(Event::ClientMessage(
+            unsafe { *(raw as *const xcb_system::xcb_client_message_event_t) }.into(),
+        )),
+        34 => Some(Event::MappingNotify(
+            unsafe { *(raw as *const xcb_system::xcb_mapping_notify_event_t) }.into(),
+        )),
+        _ => None,
+    }
+}
+
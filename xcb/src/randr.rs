@@ -0,0 +1,243 @@
+use crate::connection::Connection;
+use crate::extension::Extension;
+use crate::proto::randr;
+use crate::result::{wait_for_reply, Cookie, Error, Request, Sequence, Void};
+use crate::window::Window;
+use crate::Rectangle;
+use xcb_system::xcb_randr_get_monitors_reply_t;
+
+pub use crate::proto::randr::NotifyMask;
+
+#[derive(Debug, Clone)]
+pub struct Monitor {
+    // The atom naming the monitor, usually after its first output.
+    pub name: u32,
+    pub primary: bool,
+    // Created by the server for an output, rather than by a client with SetMonitor.
+    pub automatic: bool,
+    pub rectangle: Rectangle,
+    pub width_in_millimeters: u32,
+    pub height_in_millimeters: u32,
+    pub outputs: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScreenResources {
+    pub timestamp: u32,
+    // Passed on to the CRTC and output queries, so they fail if the configuration changed
+    // in between.
+    pub config_timestamp: u32,
+    pub crtcs: Vec<u32>,
+    pub outputs: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CrtcInfo {
+    pub rectangle: Rectangle,
+    // `None` when the CRTC is disabled.
+    pub mode: Option<u32>,
+    pub rotation: u16,
+    pub outputs: Vec<u32>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OutputConnection {
+    Connected,
+    Disconnected,
+    Unknown,
+}
+
+impl OutputConnection {
+    fn from_raw(raw: u8) -> Self {
+        match u32::from(raw) {
+            xcb_system::xcb_randr_connection_t_XCB_RANDR_CONNECTION_CONNECTED => {
+                OutputConnection::Connected
+            }
+            xcb_system::xcb_randr_connection_t_XCB_RANDR_CONNECTION_DISCONNECTED => {
+                OutputConnection::Disconnected
+            }
+            _ => OutputConnection::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OutputInfo {
+    pub name: String,
+    pub crtc: Option<u32>,
+    pub connection: OutputConnection,
+    pub width_in_millimeters: u32,
+    pub height_in_millimeters: u32,
+}
+
+// The event code of RRScreenChangeNotify, or `None` if the server doesn't support RandR.
+pub fn screen_change_notify_event(connection: &Connection) -> Option<u32> {
+    connection
+        .extension_data(Extension::RandR)
+        .map(|info| u32::from(info.first_event) + xcb_system::XCB_RANDR_SCREEN_CHANGE_NOTIFY)
+}
+
+pub struct QueryVersion(randr::QueryVersionRequest);
+
+impl Request for QueryVersion {
+    type Reply = (u32, u32);
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<(u32, u32), Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        Ok((reply.major_version, reply.minor_version))
+    }
+}
+
+// Monitors need RandR 1.5, CRTCs and outputs 1.2.
+pub fn query_version(connection: &Connection) -> Cookie<'_, QueryVersion> {
+    randr::query_version(connection, 1, 5).wrap(QueryVersion)
+}
+
+pub struct GetMonitors;
+
+impl Request for GetMonitors {
+    type Reply = Vec<Monitor>;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<Vec<Monitor>, Error> {
+        let reply = wait_for_reply::<xcb_randr_get_monitors_reply_t>(connection, sequence)?;
+
+        let mut monitors = vec![];
+        let mut iterator =
+            unsafe { xcb_system::xcb_randr_get_monitors_monitors_iterator(reply.as_ptr()) };
+
+        while iterator.rem > 0 {
+            let info = unsafe { *iterator.data };
+            let outputs = unsafe {
+                std::slice::from_raw_parts(
+                    xcb_system::xcb_randr_monitor_info_outputs(iterator.data),
+                    xcb_system::xcb_randr_monitor_info_outputs_length(iterator.data) as usize,
+                )
+            }
+            .to_vec();
+
+            monitors.push(Monitor {
+                name: info.name,
+                primary: info.primary != 0,
+                automatic: info.automatic != 0,
+                rectangle: Rectangle {
+                    x: info.x,
+                    y: info.y,
+                    width: info.width,
+                    height: info.height,
+                },
+                width_in_millimeters: info.width_in_millimeters,
+                height_in_millimeters: info.height_in_millimeters,
+                outputs,
+            });
+
+            unsafe { xcb_system::xcb_randr_monitor_info_next(&mut iterator) };
+        }
+
+        Ok(monitors)
+    }
+}
+
+pub fn get_monitors<'a>(
+    connection: &'a Connection,
+    window: &dyn Window,
+    active_only: bool,
+) -> Cookie<'a, GetMonitors> {
+    let cookie = unsafe {
+        xcb_system::xcb_randr_get_monitors(
+            connection.get_connection(),
+            window.id(),
+            active_only as u8,
+        )
+    };
+
+    Cookie::new(cookie.sequence, GetMonitors, connection)
+}
+
+pub struct GetScreenResources(randr::GetScreenResourcesCurrentRequest);
+
+impl Request for GetScreenResources {
+    type Reply = ScreenResources;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<ScreenResources, Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        Ok(ScreenResources {
+            timestamp: reply.timestamp,
+            config_timestamp: reply.config_timestamp,
+            crtcs: reply.crtcs,
+            outputs: reply.outputs,
+        })
+    }
+}
+
+pub fn get_screen_resources<'a>(
+    connection: &'a Connection,
+    window: &dyn Window,
+) -> Cookie<'a, GetScreenResources> {
+    randr::get_screen_resources_current(connection, window.id()).wrap(GetScreenResources)
+}
+
+pub struct GetCrtcInfo(randr::GetCrtcInfoRequest);
+
+impl Request for GetCrtcInfo {
+    type Reply = CrtcInfo;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<CrtcInfo, Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        Ok(CrtcInfo {
+            rectangle: Rectangle {
+                x: reply.x,
+                y: reply.y,
+                width: reply.width,
+                height: reply.height,
+            },
+            mode: Some(reply.mode).filter(|mode| *mode != xcb_system::XCB_NONE),
+            rotation: reply.rotation,
+            outputs: reply.outputs,
+        })
+    }
+}
+
+pub fn get_crtc_info(
+    connection: &Connection,
+    crtc: u32,
+    config_timestamp: u32,
+) -> Cookie<'_, GetCrtcInfo> {
+    randr::get_crtc_info(connection, crtc, config_timestamp).wrap(GetCrtcInfo)
+}
+
+pub struct GetOutputInfo(randr::GetOutputInfoRequest);
+
+impl Request for GetOutputInfo {
+    type Reply = OutputInfo;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<OutputInfo, Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        Ok(OutputInfo {
+            name: String::from_utf8_lossy(&reply.name).into_owned(),
+            crtc: Some(reply.crtc).filter(|crtc| *crtc != xcb_system::XCB_NONE),
+            connection: OutputConnection::from_raw(reply.connection),
+            width_in_millimeters: reply.mm_width,
+            height_in_millimeters: reply.mm_height,
+        })
+    }
+}
+
+pub fn get_output_info(
+    connection: &Connection,
+    output: u32,
+    config_timestamp: u32,
+) -> Cookie<'_, GetOutputInfo> {
+    randr::get_output_info(connection, output, config_timestamp).wrap(GetOutputInfo)
+}
+
+pub fn select_input<'a>(
+    connection: &'a Connection,
+    window: &dyn Window,
+    events: NotifyMask,
+) -> Cookie<'a, Void> {
+    randr::select_input(connection, window.id(), events.bits() as u16)
+}
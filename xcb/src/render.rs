@@ -0,0 +1,388 @@
+use crate::color::Color;
+use crate::connection::Connection;
+use crate::drawable::Drawable;
+use crate::proto::{render, xproto};
+use crate::result::{wait_for_reply, Cookie, Error, Request, Sequence, Void};
+use crate::Rectangle;
+use xcb_system::{
+    xcb_render_glyphset_t, xcb_render_pictformat_t, xcb_render_picture_t,
+    xcb_render_query_pict_formats_reply_t,
+};
+
+const MAXIMUM_GLYPHS_PER_ELEMENT: usize = 254;
+
+#[derive(Debug, Copy, Clone)]
+pub enum PictOp {
+    Clear,
+    Src,
+    Over,
+}
+
+impl PictOp {
+    fn raw(self) -> u8 {
+        (match self {
+            PictOp::Clear => render::PictOp::CLEAR,
+            PictOp::Src => render::PictOp::SRC,
+            PictOp::Over => render::PictOp::OVER,
+        })
+        .0 as u8
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StandardFormat {
+    Argb32,
+    Rgb24,
+    A8,
+    A1,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct RenderColor {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub alpha: u16,
+}
+
+impl From<Color> for RenderColor {
+    fn from(color: Color) -> Self {
+        RenderColor {
+            red: color.red,
+            green: color.green,
+            blue: color.blue,
+            alpha: 0xffff,
+        }
+    }
+}
+
+impl From<RenderColor> for render::Color {
+    fn from(color: RenderColor) -> Self {
+        render::Color {
+            red: color.red,
+            green: color.green,
+            blue: color.blue,
+            alpha: color.alpha,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DirectFormat {
+    pub red_shift: u16,
+    pub red_mask: u16,
+    pub green_shift: u16,
+    pub green_mask: u16,
+    pub blue_shift: u16,
+    pub blue_mask: u16,
+    pub alpha_shift: u16,
+    pub alpha_mask: u16,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct PictureFormat {
+    pub id: xcb_render_pictformat_t,
+    pub depth: u8,
+    pub direct: Option<DirectFormat>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PictureFormats {
+    formats: Vec<PictureFormat>,
+    visuals: Vec<(xcb_system::xcb_visualid_t, xcb_render_pictformat_t)>,
+}
+
+impl PictureFormats {
+    pub fn find_standard(&self, standard: StandardFormat) -> Option<PictureFormat> {
+        let (depth, alpha, red, green, blue) = match standard {
+            StandardFormat::Argb32 => (32, (24, 0xff), (16, 0xff), (8, 0xff), (0, 0xff)),
+            StandardFormat::Rgb24 => (24, (0, 0), (16, 0xff), (8, 0xff), (0, 0xff)),
+            StandardFormat::A8 => (8, (0, 0xff), (0, 0), (0, 0), (0, 0)),
+            StandardFormat::A1 => (1, (0, 0x1), (0, 0), (0, 0), (0, 0)),
+        };
+
+        self.formats
+            .iter()
+            .find(|format| match format.direct {
+                Some(direct) => {
+                    format.depth == depth
+                        && (direct.alpha_mask == 0 || direct.alpha_shift == alpha.0)
+                        && direct.alpha_mask == alpha.1
+                        && (direct.red_mask == 0 || direct.red_shift == red.0)
+                        && direct.red_mask == red.1
+                        && (direct.green_mask == 0 || direct.green_shift == green.0)
+                        && direct.green_mask == green.1
+                        && (direct.blue_mask == 0 || direct.blue_shift == blue.0)
+                        && direct.blue_mask == blue.1
+                }
+                None => false,
+            })
+            .copied()
+    }
+
+    pub fn find_for_visual(&self, visual: xcb_system::xcb_visualid_t) -> Option<PictureFormat> {
+        let (_, id) = self.visuals.iter().find(|(id, _)| *id == visual)?;
+
+        self.formats.iter().find(|format| format.id == *id).copied()
+    }
+}
+
+pub struct QueryVersion(render::QueryVersionRequest);
+
+impl Request for QueryVersion {
+    type Reply = (u32, u32);
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<(u32, u32), Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        Ok((reply.major_version, reply.minor_version))
+    }
+}
+
+pub fn query_version(connection: &Connection) -> Cookie<'_, QueryVersion> {
+    render::query_version(connection, 0, 11).wrap(QueryVersion)
+}
+
+// QueryPictFormats isn't generated, PICTSCREEN has a variable size.
+pub struct QueryPictureFormats;
+
+impl Request for QueryPictureFormats {
+    type Reply = PictureFormats;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<PictureFormats, Error> {
+        let reply = wait_for_reply::<xcb_render_query_pict_formats_reply_t>(connection, sequence)?;
+
+        let formats = unsafe {
+            std::slice::from_raw_parts(
+                xcb_system::xcb_render_query_pict_formats_formats(reply.as_ptr()),
+                xcb_system::xcb_render_query_pict_formats_formats_length(reply.as_ptr()) as usize,
+            )
+        }
+        .iter()
+        .map(|format| PictureFormat {
+            id: format.id,
+            depth: format.depth,
+            direct: if u32::from(format.type_) == render::PictType::DIRECT.0 {
+                Some(DirectFormat {
+                    red_shift: format.direct.red_shift,
+                    red_mask: format.direct.red_mask,
+                    green_shift: format.direct.green_shift,
+                    green_mask: format.direct.green_mask,
+                    blue_shift: format.direct.blue_shift,
+                    blue_mask: format.direct.blue_mask,
+                    alpha_shift: format.direct.alpha_shift,
+                    alpha_mask: format.direct.alpha_mask,
+                })
+            } else {
+                None
+            },
+        })
+        .collect();
+
+        let mut visuals = vec![];
+        let mut screens =
+            unsafe { xcb_system::xcb_render_query_pict_formats_screens_iterator(reply.as_ptr()) };
+
+        while screens.rem > 0 {
+            let mut depths =
+                unsafe { xcb_system::xcb_render_pictscreen_depths_iterator(screens.data) };
+
+            while depths.rem > 0 {
+                let depth_visuals = unsafe {
+                    std::slice::from_raw_parts(
+                        xcb_system::xcb_render_pictdepth_visuals(depths.data),
+                        xcb_system::xcb_render_pictdepth_visuals_length(depths.data) as usize,
+                    )
+                };
+
+                visuals.extend(
+                    depth_visuals
+                        .iter()
+                        .map(|visual| (visual.visual, visual.format)),
+                );
+
+                unsafe { xcb_system::xcb_render_pictdepth_next(&mut depths) };
+            }
+
+            unsafe { xcb_system::xcb_render_pictscreen_next(&mut screens) };
+        }
+
+        Ok(PictureFormats { formats, visuals })
+    }
+}
+
+pub fn query_picture_formats(connection: &Connection) -> Cookie<'_, QueryPictureFormats> {
+    let cookie = unsafe { xcb_system::xcb_render_query_pict_formats(connection.get_connection()) };
+
+    Cookie::new(cookie.sequence, QueryPictureFormats, connection)
+}
+
+#[derive(Debug)]
+pub struct OwnedPicture<'a> {
+    connection: &'a Connection,
+    handle: xcb_render_picture_t,
+}
+
+impl<'a> OwnedPicture<'a> {
+    pub fn new(
+        connection: &'a Connection,
+        drawable: &dyn Drawable,
+        format: PictureFormat,
+    ) -> Result<Self, Error> {
+        let handle = unsafe { xcb_system::xcb_generate_id(connection.get_connection()) };
+
+        render::create_picture(
+            connection,
+            handle,
+            drawable.drawable_id(),
+            format.id,
+            &render::CreatePictureValueList::default(),
+        )
+        .get_result()?;
+
+        Ok(Self { connection, handle })
+    }
+
+    pub fn solid_fill(connection: &'a Connection, color: RenderColor) -> Result<Self, Error> {
+        let handle = unsafe { xcb_system::xcb_generate_id(connection.get_connection()) };
+
+        render::create_solid_fill(connection, handle, color.into()).get_result()?;
+
+        Ok(Self { connection, handle })
+    }
+
+    pub fn id(&self) -> u32 {
+        self.handle
+    }
+
+    pub fn fill_rectangles(
+        &self,
+        op: PictOp,
+        color: RenderColor,
+        rectangles: &[Rectangle],
+    ) -> Cookie<'_, Void> {
+        let rectangles: Vec<xproto::Rectangle> = rectangles
+            .iter()
+            .map(|rectangle| xproto::Rectangle {
+                x: rectangle.x,
+                y: rectangle.y,
+                width: rectangle.width,
+                height: rectangle.height,
+            })
+            .collect();
+
+        render::fill_rectangles(
+            self.connection,
+            op.raw(),
+            self.handle,
+            color.into(),
+            &rectangles,
+        )
+    }
+
+    fn free(&self) -> Cookie<'_, Void> {
+        render::free_picture(self.connection, self.handle)
+    }
+}
+
+impl Drop for OwnedPicture<'_> {
+    fn drop(&mut self) {
+        self.free().discard();
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GlyphInfo {
+    pub width: u16,
+    pub height: u16,
+    pub x: i16,
+    pub y: i16,
+    pub x_offset: i16,
+    pub y_offset: i16,
+}
+
+#[derive(Debug)]
+pub struct OwnedGlyphSet<'a> {
+    connection: &'a Connection,
+    handle: xcb_render_glyphset_t,
+    format: PictureFormat,
+}
+
+impl<'a> OwnedGlyphSet<'a> {
+    pub fn new(connection: &'a Connection, format: PictureFormat) -> Result<Self, Error> {
+        let handle = unsafe { xcb_system::xcb_generate_id(connection.get_connection()) };
+
+        render::create_glyph_set(connection, handle, format.id).get_result()?;
+
+        Ok(Self {
+            connection,
+            handle,
+            format,
+        })
+    }
+
+    pub fn format(&self) -> PictureFormat {
+        self.format
+    }
+
+    // Rows of `data` have to be padded to 4 bytes, as the server expects.
+    pub fn add_glyph(&self, id: u32, info: GlyphInfo, data: &[u8]) -> Cookie<'_, Void> {
+        let info = render::Glyphinfo {
+            width: info.width,
+            height: info.height,
+            x: info.x,
+            y: info.y,
+            x_off: info.x_offset,
+            y_off: info.y_offset,
+        };
+
+        render::add_glyphs(self.connection, self.handle, &[id], &[info], data)
+    }
+
+    pub fn composite(
+        &self,
+        op: PictOp,
+        source: &OwnedPicture,
+        destination: &OwnedPicture,
+        x: i16,
+        y: i16,
+        glyphs: &[u32],
+    ) -> Cookie<'_, Void> {
+        let mut commands: Vec<u8> = vec![];
+
+        for (i, chunk) in glyphs.chunks(MAXIMUM_GLYPHS_PER_ELEMENT).enumerate() {
+            let (delta_x, delta_y) = if i == 0 { (x, y) } else { (0, 0) };
+
+            commands.push(chunk.len() as u8);
+            commands.extend_from_slice(&[0; 3]);
+            commands.extend_from_slice(&delta_x.to_ne_bytes());
+            commands.extend_from_slice(&delta_y.to_ne_bytes());
+
+            for glyph in chunk {
+                commands.extend_from_slice(&glyph.to_ne_bytes());
+            }
+        }
+
+        render::composite_glyphs_32(
+            self.connection,
+            op.raw(),
+            source.id(),
+            destination.id(),
+            self.format.id,
+            self.handle,
+            0,
+            0,
+            &commands,
+        )
+    }
+
+    fn free(&self) -> Cookie<'_, Void> {
+        render::free_glyph_set(self.connection, self.handle)
+    }
+}
+
+impl Drop for OwnedGlyphSet<'_> {
+    fn drop(&mut self) {
+        self.free().discard();
+    }
+}
@@ -1,4 +1,6 @@
 use crate::connection::Connection;
+use std::marker::PhantomData;
+use std::ops::Deref;
 use std::string::FromUtf8Error;
 use xcb_system::xcb_generic_error_t;
 
@@ -12,7 +14,14 @@ pub enum Error {
     InvalidScreen,
     UnknownError(u32),
     ScreenNotFound(u32),
+    UnsupportedDepth(u8),
+    InvalidImageData,
+    InvalidColor(String),
+    VisualNotFound(u32),
+    CursorNotFound(String),
+    InvalidCursorFile,
     FromUtf8Error(FromUtf8Error),
+    InvalidReply,
 }
 
 impl From<FromUtf8Error> for Error {
@@ -21,50 +30,327 @@ impl From<FromUtf8Error> for Error {
     }
 }
 
-type XcbResultWithError<T> = (T, *mut xcb_generic_error_t);
+// Identifies a request sent on a connection. Only cookies hold one, so a request can only be
+// waited for through the cookie it was sent with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Sequence(u32);
 
-pub struct XcbResult<'a, TRawReply, TReply> {
-    awaiter: Box<dyn Fn(&'a Connection) -> XcbResultWithError<TRawReply>>,
-    converter: Box<dyn Fn(TRawReply) -> TReply>,
+impl Sequence {
+    pub(crate) fn new(sequence: u32) -> Self {
+        Sequence(sequence)
+    }
+}
+
+// A request, knowing what it gets back and how to turn libxcb's reply into it. Requests
+// without a reply only tell whether they failed, they are `Void`.
+pub trait Request {
+    type Reply;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<Self::Reply, Error>;
+
+    // Tells libxcb to throw away what the request gets back instead of keeping it until it's
+    // waited for.
+    fn discard(self, connection: &Connection, sequence: Sequence)
+    where
+        Self: Sized,
+    {
+        unsafe { xcb_system::xcb_discard_reply(connection.get_connection(), sequence.0) };
+    }
+}
+
+// A reply as libxcb returns it, freed once it has been converted.
+pub(crate) struct RawReply<T>(*mut T);
+
+impl<T> RawReply<T> {
+    pub(crate) fn as_ptr(&self) -> *const T {
+        self.0
+    }
+}
+
+impl<T> Deref for RawReply<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.0 }
+    }
+}
+
+impl<T> Drop for RawReply<T> {
+    fn drop(&mut self) {
+        unsafe { libc::free(self.0 as *mut libc::c_void) };
+    }
+}
+
+fn into_error(error: *mut xcb_generic_error_t) -> Error {
+    let code = unsafe { *error }.error_code;
+
+    unsafe { libc::free(error as *mut libc::c_void) };
+
+    Error::UnknownError(code.into())
+}
+
+// Waits for the reply of a checked request, `T` being the raw reply it has.
+pub(crate) fn wait_for_reply<T>(
+    connection: &Connection,
+    sequence: Sequence,
+) -> Result<RawReply<T>, Error> {
+    let mut error: *mut xcb_generic_error_t = std::ptr::null_mut();
+
+    let reply = unsafe {
+        xcb_system::xcb_wait_for_reply(connection.get_connection(), sequence.0, &mut error)
+    };
+
+    if !error.is_null() {
+        Err(into_error(error))
+    } else if reply.is_null() {
+        Err(Error::ConnectionFailed)
+    } else {
+        Ok(RawReply(reply as *mut T))
+    }
+}
+
+// Waits until the server has handled a checked request without a reply.
+pub(crate) fn check(connection: &Connection, sequence: Sequence) -> Result<(), Error> {
+    let error = unsafe {
+        xcb_system::xcb_request_check(
+            connection.get_connection(),
+            xcb_system::xcb_void_cookie_t {
+                sequence: sequence.0,
+            },
+        )
+    };
+
+    if error.is_null() {
+        Ok(())
+    } else {
+        Err(into_error(error))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Void;
+
+impl Request for Void {
+    type Reply = ();
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<(), Error> {
+        check(connection, sequence)
+    }
+}
+
+// Requests are sent as soon as they're made, only getting their result waits for the server.
+// Making all the requests first and only then getting their results, through `join` or
+// `Connection::batch`, has them share one round trip instead of taking one each. A cookie
+// dropped without getting its result discards it, so libxcb doesn't keep the reply or error
+// until the connection is closed.
+//
+// A request sent through a cookie is checked, its error comes back from `get_result`.
+#[must_use = "the result of a request is discarded when its cookie is dropped"]
+pub struct Cookie<'a, R: Request = Void> {
     connection: &'a Connection,
+    sequence: Sequence,
+    // Only taken when the result is got, discarded or handed on, so dropping the cookie
+    // discards it otherwise.
+    request: Option<R>,
+}
+
+impl<'a> Cookie<'a, Void> {
+    pub(crate) fn new_void(
+        cookie: xcb_system::xcb_void_cookie_t,
+        connection: &'a Connection,
+    ) -> Self {
+        Self::new(cookie.sequence, Void, connection)
+    }
 }
 
-impl<'a> XcbResult<'a, (), ()> {
-    pub fn new_void(cookie: xcb_system::xcb_void_cookie_t, connection: &'a Connection) -> Self {
+impl<'a, R: Request> Cookie<'a, R> {
+    pub(crate) fn new(sequence: u32, request: R, connection: &'a Connection) -> Self {
         Self {
-            awaiter: Box::new(move |connection| {
-                ((), unsafe {
-                    xcb_system::xcb_request_check(connection.get_connection(), cookie)
-                })
-            }),
-            converter: Box::new(|result| result),
             connection,
+            sequence: Sequence::new(sequence),
+            request: Some(request),
         }
     }
+
+    pub fn get_result(mut self) -> Result<R::Reply, Error> {
+        self.take().wait(self.connection, self.sequence)
+    }
+
+    // Throws the reply, or the error of a request without one, away when it arrives instead
+    // of waiting for it.
+    pub fn discard(mut self) {
+        self.take().discard(self.connection, self.sequence);
+    }
+
+    pub(crate) fn request(&self) -> &R {
+        self.request
+            .as_ref()
+            .expect("the request is only taken by consuming the cookie")
+    }
+
+    // Hands the request to a wrapper that reads the reply through it and builds a result of
+    // its own, for wrappers that need more than the reply to do so.
+    pub(crate) fn wrap<W: Request>(mut self, wrapper: impl FnOnce(R) -> W) -> Cookie<'a, W> {
+        Cookie::new(self.sequence.0, wrapper(self.take()), self.connection)
+    }
+
+    // Converts the reply further once it arrives.
+    pub fn map<T, F: FnOnce(R::Reply) -> T>(self, mapper: F) -> Cookie<'a, Mapped<R, F, T>> {
+        self.wrap(|request| Mapped {
+            request,
+            mapper,
+            mapped: PhantomData,
+        })
+    }
+
+    fn take(&mut self) -> R {
+        self.request
+            .take()
+            .expect("the request is only taken by consuming the cookie")
+    }
 }
 
-impl<'a, TRawReply, TReply> XcbResult<'a, TRawReply, TReply> {
-    pub fn new(
-        awaiter: Box<dyn Fn(&'a Connection) -> XcbResultWithError<TRawReply>>,
-        converter: Box<dyn Fn(TRawReply) -> TReply>,
-        connection: &'a Connection,
-    ) -> Self {
+impl<R: Request> Drop for Cookie<'_, R> {
+    fn drop(&mut self) {
+        if let Some(request) = self.request.take() {
+            request.discard(self.connection, self.sequence);
+        }
+    }
+}
+
+// A request whose reply is converted further, see `Cookie::map`.
+pub struct Mapped<R, F, T> {
+    request: R,
+    mapper: F,
+    mapped: PhantomData<fn() -> T>,
+}
+
+impl<R: Request, F: FnOnce(R::Reply) -> T, T> Request for Mapped<R, F, T> {
+    type Reply = T;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<T, Error> {
+        self.request.wait(connection, sequence).map(self.mapper)
+    }
+
+    fn discard(self, connection: &Connection, sequence: Sequence) {
+        self.request.discard(connection, sequence);
+    }
+}
+
+// A request sent unchecked, if it fails its error arrives through the event queue with this
+// sequence number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UncheckedCookie {
+    sequence: u32,
+}
+
+impl UncheckedCookie {
+    pub(crate) fn new(cookie: xcb_system::xcb_void_cookie_t) -> Self {
         Self {
-            awaiter,
-            converter,
-            connection,
+            sequence: cookie.sequence,
         }
     }
 
-    pub fn get_result(self) -> Result<TReply, Error> {
-        let result = (self.awaiter)(self.connection);
+    pub fn sequence_number(&self) -> u32 {
+        self.sequence
+    }
+}
+
+// Gets the results in order. They're collected before the first one is awaited, so an
+// iterator making the requests has made all of them by then.
+pub fn join<'a, R: Request>(
+    cookies: impl IntoIterator<Item = Cookie<'a, R>>,
+) -> Vec<Result<R::Reply, Error>> {
+    let cookies: Vec<_> = cookies.into_iter().collect();
+
+    cookies.into_iter().map(Cookie::get_result).collect()
+}
+
+// Results to get together, see `Connection::batch`.
+pub struct Batch<'a, R: Request> {
+    cookies: Vec<Cookie<'a, R>>,
+}
+
+impl<'a, R: Request> Batch<'a, R> {
+    pub(crate) fn new() -> Self {
+        Self { cookies: vec![] }
+    }
+
+    // Returns the index of the result in what the batch resolves to.
+    pub fn push(&mut self, cookie: Cookie<'a, R>) -> usize {
+        self.cookies.push(cookie);
+
+        self.cookies.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.cookies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cookies.is_empty()
+    }
+
+    pub(crate) fn resolve(self) -> Vec<Result<R::Reply, Error>> {
+        join(self.cookies)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    // Counts how often it's discarded instead of asking libxcb to.
+    struct Counted<'a> {
+        discarded: &'a Cell<u32>,
+    }
+
+    impl Request for Counted<'_> {
+        type Reply = ();
+
+        fn wait(self, _: &Connection, _: Sequence) -> Result<(), Error> {
+            Ok(())
+        }
 
-        if result.1.is_null() {
-            Ok((self.converter)(result.0))
-        } else {
-            Err(Error::UnknownError(
-                unsafe { *(result.1) }.error_code.into(),
-            ))
+        fn discard(self, _: &Connection, _: Sequence) {
+            self.discarded.set(self.discarded.get() + 1);
         }
     }
+
+    #[test]
+    fn dropped_cookies_discard_their_reply() {
+        let connection = Connection::disconnected();
+        let discarded = Cell::new(0);
+        let counted = || Counted {
+            discarded: &discarded,
+        };
+
+        drop(Cookie::new(1, counted(), &connection));
+        assert_eq!(discarded.get(), 1);
+
+        drop(Cookie::new(2, counted(), &connection).map(|_| 0));
+        assert_eq!(discarded.get(), 2);
+    }
+
+    #[test]
+    fn consumed_cookies_are_not_discarded_again() {
+        let connection = Connection::disconnected();
+        let discarded = Cell::new(0);
+        let counted = || Counted {
+            discarded: &discarded,
+        };
+
+        Cookie::new(1, counted(), &connection).get_result().unwrap();
+        assert_eq!(discarded.get(), 0);
+
+        Cookie::new(2, counted(), &connection).discard();
+        assert_eq!(discarded.get(), 1);
+
+        let mut batch = Batch::new();
+        batch.push(Cookie::new(3, counted(), &connection));
+        batch.push(Cookie::new(4, counted(), &connection));
+        assert_eq!(batch.resolve().len(), 2);
+        assert_eq!(discarded.get(), 1);
+    }
 }
@@ -0,0 +1,211 @@
+use crate::connection::Connection;
+use crate::window::WindowHandle;
+use xcb_system::{xcb_depth_iterator_t, xcb_screen_iterator_t, xcb_screen_t, xcb_visualtype_t};
+
+// SCREEN and DEPTH have variable sizes and aren't generated, so the setup is walked with
+// libxcb's iterators.
+#[derive(Debug, Copy, Clone)]
+pub struct Screen<'a> {
+    pub number: u32,
+    pub root: WindowHandle<'a>,
+    pub width_in_pixels: u16,
+    pub height_in_pixels: u16,
+    pub width_in_millimeters: u16,
+    pub height_in_millimeters: u16,
+    pub root_depth: u8,
+    pub root_visual: u32,
+    pub default_colormap: u32,
+    pub white_pixel: u32,
+    pub black_pixel: u32,
+    raw: *const xcb_screen_t,
+}
+
+impl<'a> Screen<'a> {
+    pub(crate) fn new(connection: &'a Connection, number: u32, raw: *const xcb_screen_t) -> Self {
+        let screen = unsafe { *raw };
+
+        Self {
+            number,
+            root: WindowHandle::new(screen.root, connection),
+            width_in_pixels: screen.width_in_pixels,
+            height_in_pixels: screen.height_in_pixels,
+            width_in_millimeters: screen.width_in_millimeters,
+            height_in_millimeters: screen.height_in_millimeters,
+            root_depth: screen.root_depth,
+            root_visual: screen.root_visual,
+            default_colormap: screen.default_colormap,
+            white_pixel: screen.white_pixel,
+            black_pixel: screen.black_pixel,
+            raw,
+        }
+    }
+
+    // The depths windows on this screen can be created with, each with its visuals.
+    pub fn depths(&self) -> Depths<'a> {
+        Depths {
+            iterator: unsafe { xcb_system::xcb_screen_allowed_depths_iterator(self.raw) },
+            screen: std::marker::PhantomData,
+        }
+    }
+
+    // Returns the visual with its depth.
+    pub fn find_visual(&self, visual: u32) -> Option<(u8, VisualType)> {
+        self.depths().find_map(|depth| {
+            depth
+                .visuals
+                .iter()
+                .find(|visual_type| visual_type.id == visual)
+                .map(|visual_type| (depth.depth, *visual_type))
+        })
+    }
+
+    // A 32-bit TrueColor visual whose top 8 bits are left to the alpha channel, which
+    // compositors take as the window's transparency.
+    pub fn find_argb_visual(&self) -> Option<VisualType> {
+        self.depths()
+            .filter(|depth| depth.depth == 32)
+            .flat_map(|depth| depth.visuals)
+            .find(VisualType::is_argb)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VisualClass {
+    StaticGray,
+    GrayScale,
+    StaticColor,
+    PseudoColor,
+    TrueColor,
+    DirectColor,
+}
+
+impl VisualClass {
+    fn from_raw(raw: u8) -> Option<Self> {
+        match u32::from(raw) {
+            xcb_system::xcb_visual_class_t_XCB_VISUAL_CLASS_STATIC_GRAY => {
+                Some(VisualClass::StaticGray)
+            }
+            xcb_system::xcb_visual_class_t_XCB_VISUAL_CLASS_GRAY_SCALE => {
+                Some(VisualClass::GrayScale)
+            }
+            xcb_system::xcb_visual_class_t_XCB_VISUAL_CLASS_STATIC_COLOR => {
+                Some(VisualClass::StaticColor)
+            }
+            xcb_system::xcb_visual_class_t_XCB_VISUAL_CLASS_PSEUDO_COLOR => {
+                Some(VisualClass::PseudoColor)
+            }
+            xcb_system::xcb_visual_class_t_XCB_VISUAL_CLASS_TRUE_COLOR => {
+                Some(VisualClass::TrueColor)
+            }
+            xcb_system::xcb_visual_class_t_XCB_VISUAL_CLASS_DIRECT_COLOR => {
+                Some(VisualClass::DirectColor)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct VisualType {
+    pub id: u32,
+    pub class: VisualClass,
+    pub bits_per_rgb_value: u8,
+    pub colormap_entries: u16,
+    pub red_mask: u32,
+    pub green_mask: u32,
+    pub blue_mask: u32,
+}
+
+impl VisualType {
+    fn from_raw(raw: &xcb_visualtype_t) -> Option<Self> {
+        Some(Self {
+            id: raw.visual_id,
+            class: VisualClass::from_raw(raw._class)?,
+            bits_per_rgb_value: raw.bits_per_rgb_value,
+            colormap_entries: raw.colormap_entries,
+            red_mask: raw.red_mask,
+            green_mask: raw.green_mask,
+            blue_mask: raw.blue_mask,
+        })
+    }
+
+    pub fn is_argb(&self) -> bool {
+        self.class == VisualClass::TrueColor
+            && self.red_mask == 0x00ff_0000
+            && self.green_mask == 0x0000_ff00
+            && self.blue_mask == 0x0000_00ff
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Depth {
+    pub depth: u8,
+    // Empty for depths that only pixmaps can have.
+    pub visuals: Vec<VisualType>,
+}
+
+pub struct Depths<'a> {
+    iterator: xcb_depth_iterator_t,
+    screen: std::marker::PhantomData<&'a xcb_screen_t>,
+}
+
+impl Iterator for Depths<'_> {
+    type Item = Depth;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iterator.rem <= 0 || self.iterator.data.is_null() {
+            return None;
+        }
+
+        let visuals = unsafe {
+            std::slice::from_raw_parts(
+                xcb_system::xcb_depth_visuals(self.iterator.data),
+                xcb_system::xcb_depth_visuals_length(self.iterator.data) as usize,
+            )
+        }
+        .iter()
+        .filter_map(VisualType::from_raw)
+        .collect();
+        let depth = Depth {
+            depth: unsafe { (*self.iterator.data).depth },
+            visuals,
+        };
+
+        unsafe { xcb_system::xcb_depth_next(&mut self.iterator) };
+
+        Some(depth)
+    }
+}
+
+pub struct Screens<'a> {
+    connection: &'a Connection,
+    iterator: xcb_screen_iterator_t,
+    number: u32,
+}
+
+impl<'a> Screens<'a> {
+    pub(crate) fn new(connection: &'a Connection, iterator: xcb_screen_iterator_t) -> Self {
+        Self {
+            connection,
+            iterator,
+            number: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Screens<'a> {
+    type Item = Screen<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iterator.rem <= 0 || self.iterator.data.is_null() {
+            return None;
+        }
+
+        let screen = Screen::new(self.connection, self.number, self.iterator.data);
+
+        unsafe { xcb_system::xcb_screen_next(&mut self.iterator) };
+        self.number += 1;
+
+        Some(screen)
+    }
+}
@@ -1,15 +1,13 @@
 use crate::connection::Connection;
-use crate::event::EventMask;
-use crate::result::{Error, XcbResult};
+use crate::cursor::OwnedCursor;
+use crate::event::{EventMask, ModMask, MouseButton};
+use crate::grab::{ButtonGrab, KeyGrab};
+use crate::proto::xproto;
+use crate::result::{Cookie, Error, Request, Sequence, Void};
 use crate::Rectangle;
-use std::ffi::c_void;
-use xcb_system::{
-    xcb_change_window_attributes_checked, xcb_config_window_t_XCB_CONFIG_WINDOW_HEIGHT,
-    xcb_config_window_t_XCB_CONFIG_WINDOW_WIDTH, xcb_config_window_t_XCB_CONFIG_WINDOW_X,
-    xcb_config_window_t_XCB_CONFIG_WINDOW_Y, xcb_configure_window, xcb_cw_t_XCB_CW_EVENT_MASK,
-    xcb_generic_error_t, xcb_get_geometry_reply_t, xcb_get_window_attributes_reply_t,
-    xcb_map_window, xcb_unmap_window, xcb_window_t,
-};
+use xcb_system::{xcb_configure_notify_event_t, xcb_window_t};
+
+pub const NONE: u32 = xcb_system::XCB_NONE;
 
 #[derive(Debug, Copy, Clone)]
 pub struct WindowHandle<'a> {
@@ -17,23 +15,513 @@ pub struct WindowHandle<'a> {
     handle: xcb_window_t,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BitGravity {
+    Forget,
+    NorthWest,
+    North,
+    NorthEast,
+    West,
+    Center,
+    East,
+    SouthWest,
+    South,
+    SouthEast,
+    Static,
+}
+
+impl BitGravity {
+    pub(crate) fn from_raw(raw: u8) -> Self {
+        match raw {
+            1 => BitGravity::NorthWest,
+            2 => BitGravity::North,
+            3 => BitGravity::NorthEast,
+            4 => BitGravity::West,
+            5 => BitGravity::Center,
+            6 => BitGravity::East,
+            7 => BitGravity::SouthWest,
+            8 => BitGravity::South,
+            9 => BitGravity::SouthEast,
+            10 => BitGravity::Static,
+            _ => BitGravity::Forget,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WindowGravity {
+    Unmap,
+    NorthWest,
+    North,
+    NorthEast,
+    West,
+    Center,
+    East,
+    SouthWest,
+    South,
+    SouthEast,
+    Static,
+}
+
+impl WindowGravity {
+    pub(crate) fn from_raw(raw: u8) -> Self {
+        match raw {
+            1 => WindowGravity::NorthWest,
+            2 => WindowGravity::North,
+            3 => WindowGravity::NorthEast,
+            4 => WindowGravity::West,
+            5 => WindowGravity::Center,
+            6 => WindowGravity::East,
+            7 => WindowGravity::SouthWest,
+            8 => WindowGravity::South,
+            9 => WindowGravity::SouthEast,
+            10 => WindowGravity::Static,
+            _ => WindowGravity::Unmap,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BackingStore {
+    NotUseful = 0,
+    WhenMapped = 1,
+    Always = 2,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MapState {
+    Unmapped,
+    Unviewable,
+    Viewable,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WindowClass {
+    CopyFromParent = 0,
+    InputOutput = 1,
+    InputOnly = 2,
+}
+
 #[derive(Debug, Copy, Clone)]
-pub struct Attributes {}
+pub struct Attributes {
+    pub backing_store: BackingStore,
+    pub visual: u32,
+    pub class: WindowClass,
+    pub bit_gravity: BitGravity,
+    pub win_gravity: WindowGravity,
+    pub save_under: bool,
+    pub map_is_installed: bool,
+    pub map_state: MapState,
+    pub override_redirect: bool,
+    pub colormap: u32,
+    pub all_event_masks: EventMask,
+    pub your_event_mask: EventMask,
+    pub do_not_propagate_mask: EventMask,
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+pub struct AttributeValues {
+    background_pixmap: Option<u32>,
+    background_pixel: Option<u32>,
+    border_pixmap: Option<u32>,
+    border_pixel: Option<u32>,
+    bit_gravity: Option<BitGravity>,
+    win_gravity: Option<WindowGravity>,
+    backing_store: Option<BackingStore>,
+    override_redirect: Option<bool>,
+    save_under: Option<bool>,
+    event_mask: Option<EventMask>,
+    do_not_propagate_mask: Option<EventMask>,
+    colormap: Option<u32>,
+    cursor: Option<u32>,
+}
+
+impl AttributeValues {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn background_pixmap(mut self, pixmap: u32) -> Self {
+        self.background_pixmap = Some(pixmap);
+        self
+    }
+
+    pub fn background_pixel(mut self, pixel: u32) -> Self {
+        self.background_pixel = Some(pixel);
+        self
+    }
+
+    pub fn border_pixmap(mut self, pixmap: u32) -> Self {
+        self.border_pixmap = Some(pixmap);
+        self
+    }
+
+    pub fn border_pixel(mut self, pixel: u32) -> Self {
+        self.border_pixel = Some(pixel);
+        self
+    }
+
+    pub fn bit_gravity(mut self, gravity: BitGravity) -> Self {
+        self.bit_gravity = Some(gravity);
+        self
+    }
+
+    pub fn win_gravity(mut self, gravity: WindowGravity) -> Self {
+        self.win_gravity = Some(gravity);
+        self
+    }
+
+    pub fn backing_store(mut self, backing_store: BackingStore) -> Self {
+        self.backing_store = Some(backing_store);
+        self
+    }
+
+    pub fn override_redirect(mut self, override_redirect: bool) -> Self {
+        self.override_redirect = Some(override_redirect);
+        self
+    }
+
+    pub fn save_under(mut self, save_under: bool) -> Self {
+        self.save_under = Some(save_under);
+        self
+    }
+
+    pub fn event_mask(mut self, events: EventMask) -> Self {
+        self.event_mask = Some(events);
+        self
+    }
+
+    pub fn do_not_propagate_mask(mut self, events: EventMask) -> Self {
+        self.do_not_propagate_mask = Some(events);
+        self
+    }
+
+    pub fn colormap(mut self, colormap: u32) -> Self {
+        self.colormap = Some(colormap);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: &OwnedCursor) -> Self {
+        self.cursor = Some(cursor.id());
+        self
+    }
+}
+
+// CreateWindow and ChangeWindowAttributes take the same values.
+macro_rules! attribute_value_list {
+    ($value_list:ty) => {
+        impl From<&AttributeValues> for $value_list {
+            fn from(values: &AttributeValues) -> Self {
+                Self {
+                    background_pixmap: values.background_pixmap,
+                    background_pixel: values.background_pixel,
+                    border_pixmap: values.border_pixmap,
+                    border_pixel: values.border_pixel,
+                    bit_gravity: values.bit_gravity.map(|gravity| gravity as u32),
+                    win_gravity: values.win_gravity.map(|gravity| gravity as u32),
+                    backing_store: values
+                        .backing_store
+                        .map(|backing_store| backing_store as u32),
+                    backing_planes: None,
+                    backing_pixel: None,
+                    override_redirect: values.override_redirect.map(u32::from),
+                    save_under: values.save_under.map(u32::from),
+                    event_mask: values.event_mask.map(|events| events.bits()),
+                    do_not_propogate_mask: values.do_not_propagate_mask.map(|events| events.bits()),
+                    colormap: values.colormap,
+                    cursor: values.cursor,
+                }
+            }
+        }
+    };
+}
+
+attribute_value_list!(xproto::CreateWindowValueList);
+attribute_value_list!(xproto::ChangeWindowAttributesValueList);
 
 #[derive(Debug, Copy, Clone)]
 pub struct Geometry {
     pub rectangle: Rectangle,
 }
 
+#[derive(Debug, Copy, Clone)]
+pub struct PointerPosition {
+    pub root: u32,
+    // The child of the queried window the pointer is in.
+    pub child: Option<u32>,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub x: i16,
+    pub y: i16,
+    pub modifiers: ModMask,
+    // False when the pointer is on another screen, the window relative position is zero then.
+    pub same_screen: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Property {
+    pub property_type: u32,
+    pub format: u8,
+    pub value: Vec<u8>,
+}
+
+impl Property {
+    pub fn values32(&self) -> Vec<u32> {
+        self.value
+            .chunks_exact(4)
+            .map(|chunk| u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StackMode {
+    Above = 0,
+    Below = 1,
+    TopIf = 2,
+    BottomIf = 3,
+    Opposite = 4,
+}
+
+impl StackMode {
+    pub(crate) fn from_raw(raw: u8) -> Self {
+        match raw {
+            1 => StackMode::Below,
+            2 => StackMode::TopIf,
+            3 => StackMode::BottomIf,
+            4 => StackMode::Opposite,
+            _ => StackMode::Above,
+        }
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ConfigureRequest {
+    pub x: Option<i16>,
+    pub y: Option<i16>,
+    pub width: Option<u16>,
+    pub height: Option<u16>,
+    pub border_width: Option<u16>,
+    pub sibling: Option<xcb_window_t>,
+    pub stack_mode: Option<StackMode>,
+}
+
+impl ConfigureRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn x(mut self, x: i16) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    pub fn y(mut self, y: i16) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn position(self, x: i16, y: i16) -> Self {
+        self.x(x).y(y)
+    }
+
+    pub fn size(self, width: u16, height: u16) -> Self {
+        self.width(width).height(height)
+    }
+
+    pub fn rectangle(self, rectangle: Rectangle) -> Self {
+        self.position(rectangle.x, rectangle.y)
+            .size(rectangle.width, rectangle.height)
+    }
+
+    pub fn border_width(mut self, border_width: u16) -> Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    pub fn sibling(mut self, sibling: &dyn Window) -> Self {
+        self.sibling = Some(sibling.id());
+        self
+    }
+
+    pub fn stack_mode(mut self, stack_mode: StackMode) -> Self {
+        self.stack_mode = Some(stack_mode);
+        self
+    }
+
+    pub(crate) fn from_event(event: &xproto::ConfigureRequestEvent) -> Self {
+        let requested = xproto::ConfigWindow::from_bits_truncate(u32::from(event.value_mask));
+
+        Self {
+            x: Some(event.x).filter(|_| requested.contains(xproto::ConfigWindow::X)),
+            y: Some(event.y).filter(|_| requested.contains(xproto::ConfigWindow::Y)),
+            width: Some(event.width).filter(|_| requested.contains(xproto::ConfigWindow::WIDTH)),
+            height: Some(event.height).filter(|_| requested.contains(xproto::ConfigWindow::HEIGHT)),
+            border_width: Some(event.border_width)
+                .filter(|_| requested.contains(xproto::ConfigWindow::BORDER_WIDTH)),
+            sibling: Some(event.sibling)
+                .filter(|_| requested.contains(xproto::ConfigWindow::SIBLING)),
+            stack_mode: Some(StackMode::from_raw(event.stack_mode))
+                .filter(|_| requested.contains(xproto::ConfigWindow::STACK_MODE)),
+        }
+    }
+
+    // Coordinates are sign extended to 32 bits.
+    pub(crate) fn value_list(&self) -> xproto::ConfigureWindowValueList {
+        xproto::ConfigureWindowValueList {
+            x: self.x.map(i32::from),
+            y: self.y.map(i32::from),
+            width: self.width.map(u32::from),
+            height: self.height.map(u32::from),
+            border_width: self.border_width.map(u32::from),
+            sibling: self.sibling,
+            stack_mode: self.stack_mode.map(|stack_mode| stack_mode as u32),
+        }
+    }
+}
+
+pub struct GetAttributes(xproto::GetWindowAttributesRequest);
+
+impl Request for GetAttributes {
+    type Reply = Attributes;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<Attributes, Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        Ok(Attributes {
+            backing_store: match u32::from(reply.backing_store) {
+                xcb_system::xcb_backing_store_t_XCB_BACKING_STORE_WHEN_MAPPED => {
+                    BackingStore::WhenMapped
+                }
+                xcb_system::xcb_backing_store_t_XCB_BACKING_STORE_ALWAYS => BackingStore::Always,
+                _ => BackingStore::NotUseful,
+            },
+            visual: reply.visual,
+            class: match u32::from(reply.class) {
+                xcb_system::xcb_window_class_t_XCB_WINDOW_CLASS_INPUT_ONLY => {
+                    WindowClass::InputOnly
+                }
+                _ => WindowClass::InputOutput,
+            },
+            bit_gravity: BitGravity::from_raw(reply.bit_gravity),
+            win_gravity: WindowGravity::from_raw(reply.win_gravity),
+            save_under: reply.save_under,
+            map_is_installed: reply.map_is_installed,
+            map_state: match u32::from(reply.map_state) {
+                xcb_system::xcb_map_state_t_XCB_MAP_STATE_VIEWABLE => MapState::Viewable,
+                xcb_system::xcb_map_state_t_XCB_MAP_STATE_UNVIEWABLE => MapState::Unviewable,
+                _ => MapState::Unmapped,
+            },
+            override_redirect: reply.override_redirect,
+            colormap: reply.colormap,
+            all_event_masks: EventMask::from_bits_truncate(reply.all_event_masks),
+            your_event_mask: EventMask::from_bits_truncate(reply.your_event_mask),
+            do_not_propagate_mask: EventMask::from_bits_truncate(u32::from(
+                reply.do_not_propagate_mask,
+            )),
+        })
+    }
+}
+
+pub struct GetGeometry(xproto::GetGeometryRequest);
+
+impl Request for GetGeometry {
+    type Reply = Geometry;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<Geometry, Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        Ok(Geometry {
+            rectangle: Rectangle {
+                x: reply.x,
+                y: reply.y,
+                width: reply.width,
+                height: reply.height,
+            },
+        })
+    }
+}
+
+pub struct QueryPointer(xproto::QueryPointerRequest);
+
+impl Request for QueryPointer {
+    type Reply = PointerPosition;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<PointerPosition, Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        Ok(PointerPosition {
+            root: reply.root,
+            child: Some(reply.child).filter(|child| *child != xcb_system::XCB_NONE),
+            root_x: reply.root_x,
+            root_y: reply.root_y,
+            x: reply.win_x,
+            y: reply.win_y,
+            modifiers: ModMask::from_bits_truncate(u32::from(reply.mask)),
+            same_screen: reply.same_screen,
+        })
+    }
+}
+
+pub struct GetProperty(xproto::GetPropertyRequest);
+
+impl Request for GetProperty {
+    type Reply = Option<Property>;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<Option<Property>, Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        if reply.type_ == xcb_system::XCB_NONE {
+            return Ok(None);
+        }
+
+        Ok(Some(Property {
+            property_type: reply.type_,
+            format: reply.format,
+            // Already in bytes, whatever the format.
+            value: reply.value,
+        }))
+    }
+}
+
 pub trait Window {
-    fn set_event_mask(&self, events: EventMask) -> XcbResult<(), ()>;
-    fn map(&self) -> XcbResult<(), ()>;
-    fn unmap(&self) -> XcbResult<(), ()>;
-    fn configure(&self, rectangle: Rectangle) -> XcbResult<(), ()>;
-    fn get_attributes(&self) -> XcbResult<xcb_get_window_attributes_reply_t, Attributes>;
-    fn get_geometry(&self) -> XcbResult<xcb_get_geometry_reply_t, Geometry>;
-
-    fn reparent(&self, new_parent: &dyn Window, x_offset: i16, y_offset: i16) -> XcbResult<(), ()>;
+    fn set_event_mask(&self, events: EventMask) -> Cookie<'_, Void>;
+    fn change_attributes(&self, values: &AttributeValues) -> Cookie<'_, Void>;
+    fn map(&self) -> Cookie<'_, Void>;
+    fn unmap(&self) -> Cookie<'_, Void>;
+    fn configure(&self, rectangle: Rectangle) -> Cookie<'_, Void>;
+    fn configure_with(&self, request: &ConfigureRequest) -> Cookie<'_, Void>;
+    fn raise(&self) -> Cookie<'_, Void>;
+    fn lower(&self) -> Cookie<'_, Void>;
+    fn send_configure_notify(&self, rectangle: Rectangle, border_width: u16) -> Cookie<'_, Void>;
+    fn grab_button(&self, grab: &ButtonGrab) -> Cookie<'_, Void>;
+    fn ungrab_button(&self, button: Option<MouseButton>, modifiers: ModMask) -> Cookie<'_, Void>;
+    fn grab_key(&self, grab: &KeyGrab) -> Cookie<'_, Void>;
+    fn ungrab_key(&self, keycode: Option<u8>, modifiers: ModMask) -> Cookie<'_, Void>;
+    fn get_attributes(&self) -> Cookie<'_, GetAttributes>;
+    fn get_geometry(&self) -> Cookie<'_, GetGeometry>;
+    fn query_pointer(&self) -> Cookie<'_, QueryPointer>;
+    fn get_property(&self, property: u32, property_type: u32) -> Cookie<'_, GetProperty>;
+
+    fn change_property8(&self, property: u32, property_type: u32, value: &[u8])
+        -> Cookie<'_, Void>;
+    fn change_property32(
+        &self,
+        property: u32,
+        property_type: u32,
+        values: &[u32],
+    ) -> Cookie<'_, Void>;
+    fn reparent(&self, new_parent: &dyn Window, x_offset: i16, y_offset: i16) -> Cookie<'_, Void>;
     fn id(&self) -> u32;
 }
 
@@ -44,57 +532,216 @@ pub struct OwnedWindow<'a> {
 
 impl<'a> OwnedWindow<'a> {
     pub fn new(connection: &'a Connection, rectangle: Rectangle) -> Result<Self, Error> {
-        let handle = unsafe { xcb_system::xcb_generate_id(connection.get_connection()) };
-        unsafe {
-            xcb_system::xcb_create_window(
-                connection.get_connection(),
-                xcb_system::XCB_COPY_FROM_PARENT as u8,
-                handle,
-                connection.get_root_window()?.handle,
-                rectangle.x,
-                rectangle.y,
-                rectangle.width,
-                rectangle.height,
-                0,
-                xcb_system::xcb_window_class_t_XCB_WINDOW_CLASS_INPUT_OUTPUT as u16,
-                connection.get_root_visual()?,
-                0,
-                std::ptr::null(),
-            )
+        Self::builder(connection, rectangle).build()?.get_result()
+    }
+
+    pub fn builder(connection: &'a Connection, rectangle: Rectangle) -> WindowBuilder<'a> {
+        WindowBuilder {
+            connection,
+            rectangle,
+            parent: None,
+            class: WindowClass::InputOutput,
+            depth: xcb_system::XCB_COPY_FROM_PARENT as u8,
+            visual: xcb_system::XCB_COPY_FROM_PARENT,
+            border_width: 0,
+            attributes: AttributeValues::default(),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct WindowBuilder<'a> {
+    connection: &'a Connection,
+    rectangle: Rectangle,
+    parent: Option<xcb_window_t>,
+    class: WindowClass,
+    depth: u8,
+    visual: u32,
+    border_width: u16,
+    attributes: AttributeValues,
+}
+
+impl<'a> WindowBuilder<'a> {
+    pub fn parent(mut self, parent: &dyn Window) -> Self {
+        self.parent = Some(parent.id());
+        self
+    }
+
+    pub fn class(mut self, class: WindowClass) -> Self {
+        self.class = class;
+        self
+    }
+
+    pub fn depth(mut self, depth: u8) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    // A visual that differs from the parent's also needs a colormap and a border pixel or
+    // pixmap in the attributes, otherwise the server answers with a BadMatch.
+    pub fn visual(mut self, visual: u32) -> Self {
+        self.visual = visual;
+        self
+    }
+
+    pub fn border_width(mut self, border_width: u16) -> Self {
+        self.border_width = border_width;
+        self
+    }
+
+    pub fn attributes(mut self, attributes: AttributeValues) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    // Only looking up the root window can fail before the request is sent, whether the window
+    // was created comes back through the cookie.
+    pub fn build(self) -> Result<Cookie<'a, CreateWindow<'a>>, Error> {
+        let parent = match self.parent {
+            Some(parent) => parent,
+            None => self.connection.get_root_window()?.handle,
         };
+        let handle = unsafe { xcb_system::xcb_generate_id(self.connection.get_connection()) };
+
+        Ok(xproto::create_window(
+            self.connection,
+            self.depth,
+            handle,
+            parent,
+            self.rectangle.x,
+            self.rectangle.y,
+            self.rectangle.width,
+            self.rectangle.height,
+            self.border_width,
+            self.class as u16,
+            self.visual,
+            &(&self.attributes).into(),
+        )
+        .wrap(|_| CreateWindow {
+            handle: WindowHandle::new(handle, self.connection),
+        }))
+    }
+}
 
-        Ok(Self {
-            handle: WindowHandle::new(handle, connection),
+// The window belongs to whoever gets the result, a discarded one is destroyed right away.
+pub struct CreateWindow<'a> {
+    handle: WindowHandle<'a>,
+}
+
+impl<'a> Request for CreateWindow<'a> {
+    type Reply = OwnedWindow<'a>;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<OwnedWindow<'a>, Error> {
+        Void.wait(connection, sequence)?;
+
+        Ok(OwnedWindow {
+            handle: self.handle,
         })
     }
+
+    fn discard(self, connection: &Connection, sequence: Sequence) {
+        Void.discard(connection, sequence);
+        self.handle.destroy().discard();
+    }
+}
+
+impl<'a> Cookie<'a, CreateWindow<'a>> {
+    // Requests on the window can be made before its result is got, the server handles them
+    // in order.
+    pub fn window(&self) -> WindowHandle<'a> {
+        self.request().handle
+    }
 }
 
 impl Window for OwnedWindow<'_> {
-    fn set_event_mask(&self, events: EventMask) -> XcbResult<(), ()> {
+    fn set_event_mask(&self, events: EventMask) -> Cookie<'_, Void> {
         self.handle.set_event_mask(events)
     }
 
-    fn map(&self) -> XcbResult<(), ()> {
+    fn change_attributes(&self, values: &AttributeValues) -> Cookie<'_, Void> {
+        self.handle.change_attributes(values)
+    }
+
+    fn map(&self) -> Cookie<'_, Void> {
         self.handle.map()
     }
 
-    fn unmap(&self) -> XcbResult<(), ()> {
+    fn unmap(&self) -> Cookie<'_, Void> {
         self.handle.unmap()
     }
 
-    fn configure(&self, rectangle: Rectangle) -> XcbResult<(), ()> {
+    fn configure(&self, rectangle: Rectangle) -> Cookie<'_, Void> {
         self.handle.configure(rectangle)
     }
 
-    fn get_attributes(&self) -> XcbResult<xcb_get_window_attributes_reply_t, Attributes> {
+    fn configure_with(&self, request: &ConfigureRequest) -> Cookie<'_, Void> {
+        self.handle.configure_with(request)
+    }
+
+    fn raise(&self) -> Cookie<'_, Void> {
+        self.handle.raise()
+    }
+
+    fn lower(&self) -> Cookie<'_, Void> {
+        self.handle.lower()
+    }
+
+    fn send_configure_notify(&self, rectangle: Rectangle, border_width: u16) -> Cookie<'_, Void> {
+        self.handle.send_configure_notify(rectangle, border_width)
+    }
+
+    fn grab_button(&self, grab: &ButtonGrab) -> Cookie<'_, Void> {
+        self.handle.grab_button(grab)
+    }
+
+    fn ungrab_button(&self, button: Option<MouseButton>, modifiers: ModMask) -> Cookie<'_, Void> {
+        self.handle.ungrab_button(button, modifiers)
+    }
+
+    fn grab_key(&self, grab: &KeyGrab) -> Cookie<'_, Void> {
+        self.handle.grab_key(grab)
+    }
+
+    fn ungrab_key(&self, keycode: Option<u8>, modifiers: ModMask) -> Cookie<'_, Void> {
+        self.handle.ungrab_key(keycode, modifiers)
+    }
+
+    fn get_attributes(&self) -> Cookie<'_, GetAttributes> {
         self.handle.get_attributes()
     }
 
-    fn get_geometry(&self) -> XcbResult<xcb_get_geometry_reply_t, Geometry> {
+    fn get_geometry(&self) -> Cookie<'_, GetGeometry> {
         self.handle.get_geometry()
     }
 
-    fn reparent(&self, new_parent: &dyn Window, x_offset: i16, y_offset: i16) -> XcbResult<(), ()> {
+    fn query_pointer(&self) -> Cookie<'_, QueryPointer> {
+        self.handle.query_pointer()
+    }
+
+    fn get_property(&self, property: u32, property_type: u32) -> Cookie<'_, GetProperty> {
+        self.handle.get_property(property, property_type)
+    }
+
+    fn change_property8(
+        &self,
+        property: u32,
+        property_type: u32,
+        value: &[u8],
+    ) -> Cookie<'_, Void> {
+        self.handle.change_property8(property, property_type, value)
+    }
+
+    fn change_property32(
+        &self,
+        property: u32,
+        property_type: u32,
+        values: &[u32],
+    ) -> Cookie<'_, Void> {
+        self.handle
+            .change_property32(property, property_type, values)
+    }
+
+    fn reparent(&self, new_parent: &dyn Window, x_offset: i16, y_offset: i16) -> Cookie<'_, Void> {
         self.handle.reparent(new_parent, x_offset, y_offset)
     }
 
@@ -104,11 +751,10 @@ impl Window for OwnedWindow<'_> {
 }
 
 impl Drop for OwnedWindow<'_> {
+    // Children are destroyed along with their parent, so the window may already be gone and
+    // the error is thrown away.
     fn drop(&mut self) {
-        self.handle
-            .destroy()
-            .get_result()
-            .expect("Failed to destroy window");
+        self.handle.destroy().discard();
     }
 }
 
@@ -117,131 +763,200 @@ impl<'a> WindowHandle<'a> {
         Self { handle, connection }
     }
 
-    pub fn destroy(&self) -> XcbResult<(), ()> {
-        let cookie = unsafe {
-            xcb_system::xcb_destroy_window(self.connection.get_connection(), self.handle)
-        };
-
-        XcbResult::new_void(cookie, self.connection)
+    pub fn destroy(&self) -> Cookie<'_, Void> {
+        xproto::destroy_window(self.connection, self.handle)
     }
 }
 
 impl Window for WindowHandle<'_> {
-    fn set_event_mask(&self, events: EventMask) -> XcbResult<(), ()> {
-        let mask = events.bits();
+    fn set_event_mask(&self, events: EventMask) -> Cookie<'_, Void> {
+        self.change_attributes(&AttributeValues::new().event_mask(events))
+    }
+
+    fn change_attributes(&self, values: &AttributeValues) -> Cookie<'_, Void> {
+        xproto::change_window_attributes(self.connection, self.handle, &values.into())
+    }
+
+    fn map(&self) -> Cookie<'_, Void> {
+        xproto::map_window(self.connection, self.handle)
+    }
+
+    fn unmap(&self) -> Cookie<'_, Void> {
+        xproto::unmap_window(self.connection, self.handle)
+    }
+
+    fn configure(&self, rectangle: Rectangle) -> Cookie<'_, Void> {
+        self.configure_with(&ConfigureRequest::new().rectangle(rectangle))
+    }
+
+    fn configure_with(&self, request: &ConfigureRequest) -> Cookie<'_, Void> {
+        xproto::configure_window(self.connection, self.handle, &request.value_list())
+    }
+
+    fn raise(&self) -> Cookie<'_, Void> {
+        self.configure_with(&ConfigureRequest::new().stack_mode(StackMode::Above))
+    }
+
+    fn lower(&self) -> Cookie<'_, Void> {
+        self.configure_with(&ConfigureRequest::new().stack_mode(StackMode::Below))
+    }
+
+    fn send_configure_notify(&self, rectangle: Rectangle, border_width: u16) -> Cookie<'_, Void> {
+        let notify = xcb_configure_notify_event_t {
+            response_type: xcb_system::XCB_CONFIGURE_NOTIFY as u8,
+            pad0: 0,
+            sequence: 0,
+            event: self.handle,
+            window: self.handle,
+            above_sibling: xcb_system::XCB_NONE,
+            x: rectangle.x,
+            y: rectangle.y,
+            width: rectangle.width,
+            height: rectangle.height,
+            border_width,
+            override_redirect: 0,
+            pad1: 0,
+        };
+
+        // The server always reads 32 bytes of event data, which is more than the size of
+        // the ConfigureNotify structure.
+        let mut event = [0u8; 32];
 
         unsafe {
-            let cookie = xcb_change_window_attributes_checked(
-                self.connection.get_connection(),
-                self.handle,
-                xcb_cw_t_XCB_CW_EVENT_MASK,
-                &mask as *const u32 as *const c_void,
+            std::ptr::write_unaligned(
+                event.as_mut_ptr() as *mut xcb_configure_notify_event_t,
+                notify,
             );
-
-            XcbResult::new_void(cookie, self.connection)
         }
+
+        xproto::send_event(
+            self.connection,
+            false,
+            self.handle,
+            EventMask::STRUCTURE_NOTIFY.bits(),
+            &event,
+        )
     }
 
-    fn map(&self) -> XcbResult<(), ()> {
-        let result = unsafe { xcb_map_window(self.connection.get_connection(), self.handle) };
+    fn grab_button(&self, grab: &ButtonGrab) -> Cookie<'_, Void> {
+        xproto::grab_button(
+            self.connection,
+            grab.owner_events,
+            self.handle,
+            grab.event_mask.bits() as u16,
+            grab.pointer_mode as u8,
+            grab.keyboard_mode as u8,
+            grab.confine_to,
+            grab.cursor,
+            grab.button.map_or(0, MouseButton::to_raw),
+            grab.modifiers.bits() as u16,
+        )
+    }
 
-        XcbResult::new_void(result, self.connection)
+    fn ungrab_button(&self, button: Option<MouseButton>, modifiers: ModMask) -> Cookie<'_, Void> {
+        xproto::ungrab_button(
+            self.connection,
+            button.map_or(0, MouseButton::to_raw),
+            self.handle,
+            modifiers.bits() as u16,
+        )
     }
 
-    fn unmap(&self) -> XcbResult<(), ()> {
-        let result = unsafe { xcb_unmap_window(self.connection.get_connection(), self.handle) };
+    fn grab_key(&self, grab: &KeyGrab) -> Cookie<'_, Void> {
+        xproto::grab_key(
+            self.connection,
+            grab.owner_events,
+            self.handle,
+            grab.modifiers.bits() as u16,
+            grab.keycode.unwrap_or(0),
+            grab.pointer_mode as u8,
+            grab.keyboard_mode as u8,
+        )
+    }
 
-        XcbResult::new_void(result, self.connection)
+    fn ungrab_key(&self, keycode: Option<u8>, modifiers: ModMask) -> Cookie<'_, Void> {
+        xproto::ungrab_key(
+            self.connection,
+            keycode.unwrap_or(0),
+            self.handle,
+            modifiers.bits() as u16,
+        )
     }
 
-    fn configure(&self, rectangle: Rectangle) -> XcbResult<(), ()> {
-        let values = vec![
-            rectangle.x as u32,
-            rectangle.y as u32,
-            u32::from(rectangle.width),
-            u32::from(rectangle.height),
-        ];
-        let result = unsafe {
-            xcb_configure_window(
-                self.connection.get_connection(),
-                self.handle,
-                (xcb_config_window_t_XCB_CONFIG_WINDOW_X
-                    | xcb_config_window_t_XCB_CONFIG_WINDOW_Y
-                    | xcb_config_window_t_XCB_CONFIG_WINDOW_WIDTH
-                    | xcb_config_window_t_XCB_CONFIG_WINDOW_HEIGHT) as u16,
-                values.as_ptr() as *const c_void,
-            )
-        };
+    fn get_attributes(&self) -> Cookie<'_, GetAttributes> {
+        xproto::get_window_attributes(self.connection, self.handle).wrap(GetAttributes)
+    }
 
-        XcbResult::new_void(result, self.connection)
+    fn get_geometry(&self) -> Cookie<'_, GetGeometry> {
+        xproto::get_geometry(self.connection, self.handle).wrap(GetGeometry)
     }
 
-    fn get_attributes(&self) -> XcbResult<xcb_get_window_attributes_reply_t, Attributes> {
-        let cookie = unsafe {
-            xcb_system::xcb_get_window_attributes(self.connection.get_connection(), self.handle)
-        };
+    fn query_pointer(&self) -> Cookie<'_, QueryPointer> {
+        xproto::query_pointer(self.connection, self.handle).wrap(QueryPointer)
+    }
 
-        XcbResult::new(
-            Box::new(move |connection| {
-                let mut error: *mut xcb_generic_error_t = std::ptr::null_mut();
-
-                let reply = unsafe {
-                    *xcb_system::xcb_get_window_attributes_reply(
-                        connection.get_connection(),
-                        cookie,
-                        &mut error,
-                    )
-                };
-
-                (reply, error)
-            }),
-            Box::new(|_reply| Attributes {}),
+    fn get_property(&self, property: u32, property_type: u32) -> Cookie<'_, GetProperty> {
+        xproto::get_property(
             self.connection,
+            false,
+            self.handle,
+            property,
+            property_type,
+            0,
+            u32::MAX / 4,
         )
+        .wrap(GetProperty)
     }
 
-    fn get_geometry(&self) -> XcbResult<xcb_get_geometry_reply_t, Geometry> {
-        let cookie =
-            unsafe { xcb_system::xcb_get_geometry(self.connection.get_connection(), self.handle) };
-
-        XcbResult::new(
-            Box::new(move |connection| {
-                let mut error: *mut xcb_generic_error_t = std::ptr::null_mut();
-
-                let reply = unsafe {
-                    *xcb_system::xcb_get_geometry_reply(
-                        connection.get_connection(),
-                        cookie,
-                        &mut error,
-                    )
-                };
-
-                (reply, error)
-            }),
-            Box::new(|reply| Geometry {
-                rectangle: Rectangle {
-                    x: reply.x,
-                    y: reply.y,
-                    width: reply.width,
-                    height: reply.height,
-                },
-            }),
+    fn change_property8(
+        &self,
+        property: u32,
+        property_type: u32,
+        value: &[u8],
+    ) -> Cookie<'_, Void> {
+        xproto::change_property(
             self.connection,
+            xproto::PropMode::REPLACE.0 as u8,
+            self.handle,
+            property,
+            property_type,
+            8,
+            value.len() as u32,
+            value,
         )
     }
 
-    fn reparent(&self, new_parent: &dyn Window, x_offset: i16, y_offset: i16) -> XcbResult<(), ()> {
-        let cookie = unsafe {
-            xcb_system::xcb_reparent_window(
-                self.connection.get_connection(),
-                self.handle,
-                new_parent.id(),
-                x_offset,
-                y_offset,
-            )
-        };
+    fn change_property32(
+        &self,
+        property: u32,
+        property_type: u32,
+        values: &[u32],
+    ) -> Cookie<'_, Void> {
+        let value: Vec<u8> = values
+            .iter()
+            .flat_map(|value| value.to_ne_bytes())
+            .collect();
+
+        xproto::change_property(
+            self.connection,
+            xproto::PropMode::REPLACE.0 as u8,
+            self.handle,
+            property,
+            property_type,
+            32,
+            values.len() as u32,
+            &value,
+        )
+    }
 
-        XcbResult::new_void(cookie, self.connection)
+    fn reparent(&self, new_parent: &dyn Window, x_offset: i16, y_offset: i16) -> Cookie<'_, Void> {
+        xproto::reparent_window(
+            self.connection,
+            self.handle,
+            new_parent.id(),
+            x_offset,
+            y_offset,
+        )
     }
 
     fn id(&self) -> u32 {
@@ -0,0 +1,49 @@
+use crate::connection::Connection;
+use crate::extension::Extension;
+use crate::proto::xinerama;
+use crate::result::{Cookie, Error, Request, Sequence};
+use crate::Rectangle;
+
+pub fn is_present(connection: &Connection) -> bool {
+    connection.extension_data(Extension::Xinerama).is_some()
+}
+
+pub struct IsActive(xinerama::IsActiveRequest);
+
+impl Request for IsActive {
+    type Reply = bool;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<bool, Error> {
+        Ok(self.0.wait(connection, sequence)?.state != 0)
+    }
+}
+
+// The extension can be present without the screens being combined.
+pub fn is_active(connection: &Connection) -> Cookie<'_, IsActive> {
+    xinerama::is_active(connection).wrap(IsActive)
+}
+
+pub struct QueryScreens(xinerama::QueryScreensRequest);
+
+impl Request for QueryScreens {
+    type Reply = Vec<Rectangle>;
+
+    fn wait(self, connection: &Connection, sequence: Sequence) -> Result<Vec<Rectangle>, Error> {
+        let reply = self.0.wait(connection, sequence)?;
+
+        Ok(reply
+            .screen_info
+            .iter()
+            .map(|screen| Rectangle {
+                x: screen.x_org,
+                y: screen.y_org,
+                width: screen.width,
+                height: screen.height,
+            })
+            .collect())
+    }
+}
+
+pub fn query_screens(connection: &Connection) -> Cookie<'_, QueryScreens> {
+    xinerama::query_screens(connection).wrap(QueryScreens)
+}